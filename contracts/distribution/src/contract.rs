@@ -1,13 +1,18 @@
-use cosmwasm_std::{
-    entry_point, to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult,
-};
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
 use cw2::set_contract_version;
 
-use distribution_pkg::config::Config;
-use distribution_pkg::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
-use member_pkg::member_contract_querier::query_member_contract_config;
+use distribution_pkg::config::{Config, PauseConfig};
+use distribution_pkg::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use shared_pkg::{
+    migrate::{assert_can_migrate, migrate_state},
+    rbac::{grant_role, Role},
+};
 
 use crate::state::CONFIG;
+use crate::util::config::assert_contract_enabled;
+use crate::util::migrate::STATE_MIGRATIONS;
 use crate::{execute, query, ContractError};
 
 pub const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
@@ -27,17 +32,24 @@ pub fn instantiate(
     )?;
 
     let member_contract_addr = deps.api.addr_validate(&msg.member_contract_addr)?;
-    // TODO: P0: check all contract, do we need to set contract version?
     let config = Config {
         enabled: false,
+        // Nothing is paused by default
+        pause_config: PauseConfig::default(),
         // Default to sender
         admin_addr: deps
             .api
             .addr_validate(&msg.admin_addr.unwrap_or(info.sender.to_string()))?,
         member_contract_addr: member_contract_addr.clone(),
-        distribute_caller_allowlist: vec![member_contract_addr],
+        // Default to no time-lock, admin can set one after instantiation
+        config_change_timelock: None,
     };
 
+    // Admin in config always holds admin role, it can grant roles to more addresses later
+    grant_role(deps.storage, &config.admin_addr, &Role::Admin)?;
+    // Member contract distributes trading fees, admin grants distributor role to thread contract once it is deployed
+    grant_role(deps.storage, &member_contract_addr, &Role::Distributor)?;
+
     CONFIG.save(deps.storage, &config)?;
 
     Ok(Response::new())
@@ -46,19 +58,12 @@ pub fn instantiate(
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
-    let member_contract_addr = config.member_contract_addr;
-    let membership_contract_config =
-        query_member_contract_config(deps.as_ref(), member_contract_addr.clone());
-    // TODO: P2: update this when we support user setting their own fee denom
-    let fee_denom = membership_contract_config
-        .default_fee_config
-        .fee_denom
-        .as_str();
+    let member_contract_addr = config.member_contract_addr.clone();
     match msg {
         ExecuteMsg::Enable(_) => {
             cw_utils::nonpayable(&info)?;
@@ -70,15 +75,39 @@ pub fn execute(
         }
         ExecuteMsg::UpdateConfig(data) => {
             cw_utils::nonpayable(&info)?;
-            execute::config::update_config(deps, info, data)
+            execute::config::update_config(deps, env, info, data)
+        }
+        ExecuteMsg::ExecutePendingConfigChange(data) => {
+            cw_utils::nonpayable(&info)?;
+            execute::config::execute_pending_config_change(deps, env, info, data)
         }
-        ExecuteMsg::AddToDistributeCallerAllowlist(data) => {
+        ExecuteMsg::CancelPendingConfigChange(data) => {
             cw_utils::nonpayable(&info)?;
-            execute::config::add_to_distribute_caller_allowlist(deps, info, data)
+            execute::config::cancel_pending_config_change(deps, info, data)
         }
-        ExecuteMsg::RemoveFromDistributeCallerAllowlist(data) => {
+        ExecuteMsg::ProposeNewAdmin(data) => {
             cw_utils::nonpayable(&info)?;
-            execute::config::remove_from_distribute_caller_allowlist(deps, info, data)
+            execute::config::propose_new_admin(deps, env, info, data)
+        }
+        ExecuteMsg::AcceptAdmin(_) => {
+            cw_utils::nonpayable(&info)?;
+            execute::config::accept_admin(deps, env, info)
+        }
+        ExecuteMsg::CancelAdminProposal(_) => {
+            cw_utils::nonpayable(&info)?;
+            execute::config::cancel_admin_proposal(deps, info)
+        }
+        ExecuteMsg::GrantRole(data) => {
+            cw_utils::nonpayable(&info)?;
+            execute::config::grant_role(deps, info, data)
+        }
+        ExecuteMsg::RevokeRole(data) => {
+            cw_utils::nonpayable(&info)?;
+            execute::config::revoke_role(deps, info, data, config)
+        }
+        ExecuteMsg::UpdatePauseConfig(data) => {
+            cw_utils::nonpayable(&info)?;
+            execute::config::update_pause_config(deps, info, data)
         }
         // TODO: P0: fix me, pass everything from membership contract
         // Do not query it inside execute as it contains un committed state
@@ -105,8 +134,25 @@ pub fn execute(
         // TODO: P0: fix me, pass everything from membership contract
         // Do not query it inside execute as it contains un committed state
         ExecuteMsg::Distribute(data) => {
-            cw_utils::must_pay(&info, fee_denom)?;
-            execute::reward::distribute(deps, info, data, config.distribute_caller_allowlist)
+            // Each membership distributes in its own fee denom, so take denom from what is paid
+            let fee = cw_utils::one_coin(&info)?;
+            // Still accept fees when paused so trading and thread actions are not blocked
+            execute::reward::distribute(
+                deps,
+                info,
+                data,
+                fee,
+                config.pause_config.distribute_paused,
+            )
+        }
+        ExecuteMsg::ReleasePausedDistributions(data) => {
+            cw_utils::nonpayable(&info)?;
+            execute::reward::release_paused_distributions(
+                deps,
+                data,
+                member_contract_addr,
+                config.pause_config.distribute_paused,
+            )
         }
         // TODO: P0: fix me, pass everything from membership contract
         // Do not query it inside execute as it contains un committed state
@@ -115,17 +161,48 @@ pub fn execute(
             execute::user::update_user_pending_reward(deps, info, data, member_contract_addr)
         }
         ExecuteMsg::ClaimReward(data) => {
+            assert_contract_enabled(&config)?;
+            if config.pause_config.claim_reward_paused {
+                return Err(ContractError::ClaimRewardPaused {});
+            }
             cw_utils::nonpayable(&info)?;
-            execute::user::claim_reward(deps, data, member_contract_addr, fee_denom)
+            execute::user::claim_reward(deps, data, member_contract_addr)
         }
     }
 }
 
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(mut deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let previous_version = assert_can_migrate(
+        deps.storage,
+        &format!("crates.io:{CONTRACT_NAME}"),
+        CONTRACT_VERSION,
+    )?;
+
+    migrate_state(deps.branch(), &env, &previous_version, STATE_MIGRATIONS)?;
+
+    set_contract_version(
+        deps.storage,
+        format!("crates.io:{CONTRACT_NAME}"),
+        CONTRACT_VERSION,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute("previous_version", previous_version.to_string())
+        .add_attribute("new_version", CONTRACT_VERSION))
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
     let config = CONFIG.load(deps.storage)?;
     match msg {
         QueryMsg::QueryConfig(_) => to_binary(&query::config::query_config(deps)?),
+        QueryMsg::QueryPauseStatus(_) => to_binary(&query::config::query_pause_status(deps)?),
+        QueryMsg::QueryRoles(data) => to_binary(&query::config::query_roles(deps, data)?),
+        QueryMsg::QueryPendingConfigChanges(_) => {
+            to_binary(&query::config::query_pending_config_changes(deps)?)
+        }
         QueryMsg::QueryUserReward(data) => to_binary(&query::user::query_user_reward(
             deps,
             data,
@@ -1,5 +1,6 @@
-use cosmwasm_std::{StdError, Uint64};
+use cosmwasm_std::{OverflowError, StdError, Uint64};
 use cw_utils::PaymentError;
+use shared_pkg::migrate::MigrateError;
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
@@ -10,6 +11,12 @@ pub enum ContractError {
     #[error("{0}")]
     Payment(#[from] PaymentError),
 
+    #[error("{0}")]
+    Migrate(#[from] MigrateError),
+
+    #[error("{0}")]
+    Overflow(#[from] OverflowError),
+
     // ========================== ADMIN ==========================
     #[error("Unauthorized")]
     Unauthorized {},
@@ -23,14 +30,68 @@ pub enum ContractError {
     #[error("Only admin can update config")]
     OnlyAdminCanUpdateConfig {},
 
+    #[error("Only admin can grant role")]
+    OnlyAdminCanGrantRole {},
+
+    #[error("Only admin can revoke role")]
+    OnlyAdminCanRevokeRole {},
+
+    #[error("Address already has role {role}")]
+    AddressAlreadyHasRole { role: String },
+
+    #[error("Address does not have role {role}")]
+    AddressDoesNotHaveRole { role: String },
+
+    #[error("Cannot revoke role {role} from address set in config, update config instead")]
+    CannotRevokeRoleOfConfigAddress { role: String },
+
+    #[error("Only admin can propose new admin")]
+    OnlyAdminCanProposeNewAdmin {},
+
+    #[error("Only admin can cancel admin proposal")]
+    OnlyAdminCanCancelAdminProposal {},
+
+    #[error("Only proposed admin can accept admin")]
+    OnlyProposedAdminCanAcceptAdmin {},
+
+    #[error("No pending admin proposal")]
+    NoPendingAdminProposal {},
+
+    #[error("Admin proposal expired")]
+    AdminProposalExpired {},
+
+    #[error("Only admin can execute pending config change")]
+    OnlyAdminCanExecutePendingConfigChange {},
+
+    #[error("Only admin can cancel pending config change")]
+    OnlyAdminCanCancelPendingConfigChange {},
+
+    #[error("Pending config change {id} does not exist")]
+    PendingConfigChangeNotExist { id: Uint64 },
+
+    #[error("Pending config change is time-locked until {executable_at}")]
+    PendingConfigChangeStillTimeLocked { executable_at: String },
+
+    #[error("Only admin can update pause config")]
+    OnlyAdminCanUpdatePauseConfig {},
+
+    #[error("Contract disabled")]
+    ContractDisabled {},
+
+    #[error("Claiming reward is paused")]
+    ClaimRewardPaused {},
+
+    #[error("Cannot release paused distributions while distribute is paused")]
+    CannotReleasePausedDistributionsWhileDistributePaused {},
+
     #[error("Only membership contract can setup distribution for new membership")]
     OnlyMembershipContractCanSetupDistributionForNewMembership {},
 
     #[error("Only membership contract can setup distribution for new member")]
     OnlyMembershipContractCanSetupDistributionForNewMember {},
 
-    #[error("Only distribute allowlist addresses can distribute")]
-    OnlyDistributeAllowlistAddressesCanDistribute {},
+    #[error("Only distributor can distribute")]
+    OnlyDistributorCanDistribute {},
 
     #[error("Only membership contract can update user pending reward")]
     OnlyMembershipContractCanUpdateUserPendingReward {},
@@ -41,15 +102,6 @@ pub enum ContractError {
     #[error("Cannot update pending reward before setup distribution")]
     CannotUpdatePendingRewardBeforeSetupDistribution {},
 
-    #[error("Only admin can add to distribution caller allowlist")]
-    OnlyAdminCanAddToDistributionCallerAllowlist {},
-
-    #[error("Only admin can remove from distribution caller allowlist")]
-    OnlyAdminCanRemoveFromDistributionCallerAllowlist {},
-
-    #[error("Address not in distribution caller allowlist")]
-    AddressNotInDistributionCallerAllowlist {},
-
     // ========================== USER ==========================
     #[error("Distribution already setup for membership issuer")]
     DistributionAlreadySetupForMembershipIssuer {},
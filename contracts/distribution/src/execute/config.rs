@@ -1,16 +1,26 @@
-use cosmwasm_std::{DepsMut, MessageInfo, Response};
+use cosmwasm_std::{Deps, DepsMut, Env, MessageInfo, Response, Uint64};
+use distribution_pkg::config::Config;
+use shared_pkg::admin_transfer::{AdminProposal, ProposeNewAdminMsg};
+use shared_pkg::rbac::{
+    self, has_any_role, has_role, transfer_role, GrantRoleMsg, RevokeRoleMsg, Role,
+};
+use shared_pkg::timelock::{
+    normalize_timelock, CancelPendingConfigChangeMsg, ExecutePendingConfigChangeMsg,
+    PendingConfigChange,
+};
 
-use crate::state::CONFIG;
+use crate::state::{
+    CONFIG, NEXT_PENDING_CONFIG_CHANGE_ID, PENDING_ADMIN_PROPOSAL, PENDING_CONFIG_CHANGES,
+};
+use crate::util::config::split_timelocked_config_changes;
 use crate::ContractError;
 
-use distribution_pkg::msg::{
-    AddToDistributeCallerAllowlistMsg, RemoveFromDistributeCallerAllowlistMsg, UpdateConfigMsg,
-};
+use distribution_pkg::msg::{UpdateConfigMsg, UpdatePauseConfigMsg};
 
 pub fn enable(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
     let mut config = CONFIG.load(deps.storage)?;
 
-    if info.sender != config.admin_addr {
+    if !has_role(deps.storage, &info.sender, &Role::Admin)? {
         return Err(ContractError::OnlyAdminCanEnable {});
     }
 
@@ -24,7 +34,7 @@ pub fn enable(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractErro
 pub fn disable(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
     let mut config = CONFIG.load(deps.storage)?;
 
-    if info.sender != config.admin_addr {
+    if !has_role(deps.storage, &info.sender, &Role::Admin)? {
         return Err(ContractError::OnlyAdminCanDisable {});
     }
 
@@ -35,82 +45,284 @@ pub fn disable(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractErr
     Ok(Response::new().add_attribute("action", "disable"))
 }
 
+pub fn update_pause_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    data: UpdatePauseConfigMsg,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    if !has_any_role(deps.storage, &info.sender, &[Role::Admin, Role::Pauser])? {
+        return Err(ContractError::OnlyAdminCanUpdatePauseConfig {});
+    }
+
+    // Fees held while paused are released separately by ReleasePausedDistributions
+    if let Some(distribute_paused) = data.distribute_paused {
+        config.pause_config.distribute_paused = distribute_paused;
+    }
+    if let Some(claim_reward_paused) = data.claim_reward_paused {
+        config.pause_config.claim_reward_paused = claim_reward_paused;
+    }
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attribute("action", "update_pause_config"))
+}
+
 pub fn update_config(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     data: UpdateConfigMsg,
 ) -> Result<Response, ContractError> {
-    let mut config = CONFIG.load(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
 
-    if info.sender != config.admin_addr {
+    if !has_role(deps.storage, &info.sender, &Role::Admin)? {
         return Err(ContractError::OnlyAdminCanUpdateConfig {});
     }
 
-    config.admin_addr = match data.admin_addr {
-        None => config.admin_addr,
-        Some(data) => deps.api.addr_validate(data.as_str())?,
+    let (changes, timelocked_changes) = match config.config_change_timelock {
+        None => (data, None),
+        Some(config_change_timelock) => {
+            let (changes, timelocked_changes) = split_timelocked_config_changes(data);
+            (
+                changes,
+                timelocked_changes.map(|timelocked_changes| {
+                    (timelocked_changes, config_change_timelock.after(&env.block))
+                }),
+            )
+        }
     };
 
+    let new_config = apply_config_changes(deps.as_ref(), config, changes)?;
+    CONFIG.save(deps.storage, &new_config)?;
+
+    let mut response = Response::new().add_attribute("action", "update_config");
+
+    if let Some((timelocked_changes, executable_at)) = timelocked_changes {
+        // Reject invalid changes now instead of when the time-lock has passed
+        apply_config_changes(deps.as_ref(), new_config, timelocked_changes.clone())?;
+
+        let id = NEXT_PENDING_CONFIG_CHANGE_ID
+            .may_load(deps.storage)?
+            .unwrap_or(Uint64::one());
+        PENDING_CONFIG_CHANGES.save(
+            deps.storage,
+            id.u64(),
+            &PendingConfigChange {
+                id,
+                changes: timelocked_changes,
+                proposed_by: info.sender,
+                executable_at,
+            },
+        )?;
+        NEXT_PENDING_CONFIG_CHANGE_ID.save(deps.storage, &(id + Uint64::one()))?;
+
+        response = response
+            .add_attribute("pending_config_change_id", id)
+            .add_attribute("executable_at", executable_at.to_string());
+    }
+
+    Ok(response)
+}
+
+pub fn execute_pending_config_change(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    data: ExecutePendingConfigChangeMsg,
+) -> Result<Response, ContractError> {
+    if !has_role(deps.storage, &info.sender, &Role::Admin)? {
+        return Err(ContractError::OnlyAdminCanExecutePendingConfigChange {});
+    }
+
+    let pending_config_change = PENDING_CONFIG_CHANGES
+        .may_load(deps.storage, data.id.u64())?
+        .ok_or(ContractError::PendingConfigChangeNotExist { id: data.id })?;
+
+    if !pending_config_change.executable_at.is_expired(&env.block) {
+        return Err(ContractError::PendingConfigChangeStillTimeLocked {
+            executable_at: pending_config_change.executable_at.to_string(),
+        });
+    }
+
+    let config = CONFIG.load(deps.storage)?;
+    let new_config = apply_config_changes(deps.as_ref(), config, pending_config_change.changes)?;
+    CONFIG.save(deps.storage, &new_config)?;
+    PENDING_CONFIG_CHANGES.remove(deps.storage, data.id.u64());
+
+    Ok(Response::new()
+        .add_attribute("action", "execute_pending_config_change")
+        .add_attribute("pending_config_change_id", data.id))
+}
+
+pub fn cancel_pending_config_change(
+    deps: DepsMut,
+    info: MessageInfo,
+    data: CancelPendingConfigChangeMsg,
+) -> Result<Response, ContractError> {
+    if !has_role(deps.storage, &info.sender, &Role::Admin)? {
+        return Err(ContractError::OnlyAdminCanCancelPendingConfigChange {});
+    }
+
+    if !PENDING_CONFIG_CHANGES.has(deps.storage, data.id.u64()) {
+        return Err(ContractError::PendingConfigChangeNotExist { id: data.id });
+    }
+
+    PENDING_CONFIG_CHANGES.remove(deps.storage, data.id.u64());
+
+    Ok(Response::new()
+        .add_attribute("action", "cancel_pending_config_change")
+        .add_attribute("pending_config_change_id", data.id))
+}
+
+// Apply changes on top of config and validate the result, nothing is saved
+fn apply_config_changes(
+    deps: Deps,
+    mut config: Config,
+    data: UpdateConfigMsg,
+) -> Result<Config, ContractError> {
     config.member_contract_addr = match data.member_contract_addr {
         None => config.member_contract_addr,
         Some(data) => deps.api.addr_validate(data.as_str())?,
     };
 
-    CONFIG.save(deps.storage, &config)?;
+    config.config_change_timelock = match data.config_change_timelock {
+        None => config.config_change_timelock,
+        Some(config_change_timelock) => normalize_timelock(config_change_timelock),
+    };
 
-    Ok(Response::new().add_attribute("action", "update_config"))
+    Ok(config)
 }
 
-pub fn add_to_distribute_caller_allowlist(
+pub fn grant_role(
     deps: DepsMut,
     info: MessageInfo,
-    data: AddToDistributeCallerAllowlistMsg,
+    data: GrantRoleMsg,
 ) -> Result<Response, ContractError> {
-    let mut config = CONFIG.load(deps.storage)?;
+    if !has_role(deps.storage, &info.sender, &Role::Admin)? {
+        return Err(ContractError::OnlyAdminCanGrantRole {});
+    }
 
-    if info.sender != config.admin_addr {
-        return Err(ContractError::OnlyAdminCanAddToDistributionCallerAllowlist {});
+    let addr = deps.api.addr_validate(data.addr.as_str())?;
+    if has_role(deps.storage, &addr, &data.role)? {
+        return Err(ContractError::AddressAlreadyHasRole {
+            role: data.role.as_str().to_string(),
+        });
     }
 
-    config
-        .distribute_caller_allowlist
-        .push(deps.api.addr_validate(data.added_addr.as_str())?);
+    rbac::grant_role(deps.storage, &addr, &data.role)?;
 
-    CONFIG.save(deps.storage, &config)?;
+    Ok(Response::new()
+        .add_attribute("action", "grant_role")
+        .add_attribute("addr", addr)
+        .add_attribute("role", data.role.as_str()))
+}
+
+pub fn revoke_role(
+    deps: DepsMut,
+    info: MessageInfo,
+    data: RevokeRoleMsg,
+    config: Config,
+) -> Result<Response, ContractError> {
+    if !has_role(deps.storage, &info.sender, &Role::Admin)? {
+        return Err(ContractError::OnlyAdminCanRevokeRole {});
+    }
+
+    let addr = deps.api.addr_validate(data.addr.as_str())?;
+    // Otherwise config would point to an address without the role, and revoking the last admin would lock the contract
+    if data.role == Role::Admin && addr == config.admin_addr {
+        return Err(ContractError::CannotRevokeRoleOfConfigAddress {
+            role: data.role.as_str().to_string(),
+        });
+    }
+
+    if !has_role(deps.storage, &addr, &data.role)? {
+        return Err(ContractError::AddressDoesNotHaveRole {
+            role: data.role.as_str().to_string(),
+        });
+    }
 
-    Ok(Response::new().add_attribute("action", "add_to_distribute_caller_allowlist"))
+    rbac::revoke_role(deps.storage, &addr, &data.role);
+
+    Ok(Response::new()
+        .add_attribute("action", "revoke_role")
+        .add_attribute("addr", addr)
+        .add_attribute("role", data.role.as_str()))
 }
 
-pub fn remove_from_distribute_caller_allowlist(
+pub fn propose_new_admin(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
-    data: RemoveFromDistributeCallerAllowlistMsg,
+    data: ProposeNewAdminMsg,
 ) -> Result<Response, ContractError> {
-    let mut config = CONFIG.load(deps.storage)?;
+    if !has_role(deps.storage, &info.sender, &Role::Admin)? {
+        return Err(ContractError::OnlyAdminCanProposeNewAdmin {});
+    }
 
-    if info.sender != config.admin_addr {
-        return Err(ContractError::OnlyAdminCanAddToDistributionCallerAllowlist {});
+    let new_admin_addr = deps.api.addr_validate(data.new_admin_addr.as_str())?;
+    let expiration = data.expiration.unwrap_or_default();
+    if expiration.is_expired(&env.block) {
+        return Err(ContractError::AdminProposalExpired {});
     }
 
-    let remove_addr = deps.api.addr_validate(data.remove_addr.as_str())?;
+    PENDING_ADMIN_PROPOSAL.save(
+        deps.storage,
+        &AdminProposal {
+            new_admin_addr: new_admin_addr.clone(),
+            expiration,
+        },
+    )?;
 
-    let mut exist = false;
-    let mut idx = 0;
-    for (i, addr) in config.distribute_caller_allowlist.iter().enumerate() {
-        if *addr == remove_addr {
-            exist = true;
-            idx = i;
-            break;
-        }
+    Ok(Response::new()
+        .add_attribute("action", "propose_new_admin")
+        .add_attribute("new_admin_addr", new_admin_addr)
+        .add_attribute("expiration", expiration.to_string()))
+}
+
+pub fn accept_admin(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    let admin_proposal = PENDING_ADMIN_PROPOSAL
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoPendingAdminProposal {})?;
+
+    if info.sender != admin_proposal.new_admin_addr {
+        return Err(ContractError::OnlyProposedAdminCanAcceptAdmin {});
     }
 
-    if !exist {
-        return Err(ContractError::AddressNotInDistributionCallerAllowlist {});
+    if admin_proposal.expiration.is_expired(&env.block) {
+        return Err(ContractError::AdminProposalExpired {});
     }
 
-    config.distribute_caller_allowlist.remove(idx);
+    let mut config = CONFIG.load(deps.storage)?;
+    let previous_admin_addr = config.admin_addr;
+    transfer_role(
+        deps.storage,
+        &previous_admin_addr,
+        &admin_proposal.new_admin_addr,
+        &Role::Admin,
+    )?;
+    config.admin_addr = admin_proposal.new_admin_addr;
 
     CONFIG.save(deps.storage, &config)?;
+    PENDING_ADMIN_PROPOSAL.remove(deps.storage);
+
+    Ok(Response::new()
+        .add_attribute("action", "accept_admin")
+        .add_attribute("previous_admin_addr", previous_admin_addr)
+        .add_attribute("new_admin_addr", config.admin_addr))
+}
+
+pub fn cancel_admin_proposal(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    if !has_role(deps.storage, &info.sender, &Role::Admin)? {
+        return Err(ContractError::OnlyAdminCanCancelAdminProposal {});
+    }
+
+    if PENDING_ADMIN_PROPOSAL.may_load(deps.storage)?.is_none() {
+        return Err(ContractError::NoPendingAdminProposal {});
+    }
+
+    PENDING_ADMIN_PROPOSAL.remove(deps.storage);
 
-    Ok(Response::new().add_attribute("action", "add_to_distribute_caller_allowlist"))
+    Ok(Response::new().add_attribute("action", "cancel_admin_proposal"))
 }
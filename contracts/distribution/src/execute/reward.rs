@@ -1,11 +1,20 @@
-use cosmwasm_std::{Addr, Decimal, DepsMut, MessageInfo, Response, Uint128};
+use cosmwasm_std::{
+    Addr, Coin, Decimal, DepsMut, MessageInfo, Order, Response, StdResult, Storage, Uint128,
+};
 
 use distribution_pkg::msg::{
-    DistributeMsg, SetupDistributionForNewMemberMsg, SetupDistributionForNewMembershipMsg,
+    DistributeMsg, ReleasePausedDistributionsMsg, SetupDistributionForNewMemberMsg,
+    SetupDistributionForNewMembershipMsg,
 };
 
+use member_pkg::member_contract_querier::MemberContractQuerier;
+use shared_pkg::rbac::{has_role, Role};
+
 use crate::{
-    state::{ALL_USERS_DISTRIBUTIONS, GLOBAL_INDICES},
+    state::{
+        ALL_USERS_DISTRIBUTIONS, DEFAULT_RELEASE_LIMIT, GLOBAL_INDICES, MAX_RELEASE_LIMIT,
+        PAUSED_DISTRIBUTIONS,
+    },
     ContractError,
 };
 
@@ -20,21 +29,33 @@ pub fn setup_distribution_for_new_membership(
     }
 
     let membership_issuer_user_id = data.membership_issuer_user_id.u64();
+    let fee_denom = data.fee_denom.as_str();
     let global_index = Decimal::zero();
     let pending_reward = Uint128::zero();
 
-    GLOBAL_INDICES.update(
+    // Membership issuer can only be setup once, regardless of denom
+    if GLOBAL_INDICES
+        .prefix(membership_issuer_user_id)
+        .range(deps.storage, None, None, Order::Ascending)
+        .next()
+        .is_some()
+    {
+        return Err(ContractError::GlobalIndicesAlreadySetupForMembershipIssuer {});
+    }
+
+    GLOBAL_INDICES.save(
         deps.storage,
-        membership_issuer_user_id,
-        |existing| match existing {
-            None => Ok(global_index),
-            Some(_) => Err(ContractError::GlobalIndicesAlreadySetupForMembershipIssuer {}),
-        },
+        (membership_issuer_user_id, fee_denom),
+        &global_index,
     )?;
 
     ALL_USERS_DISTRIBUTIONS.update(
         deps.storage,
-        (membership_issuer_user_id, membership_issuer_user_id),
+        (
+            membership_issuer_user_id,
+            membership_issuer_user_id,
+            fee_denom,
+        ),
         |existing| match existing {
             None => Ok((global_index, pending_reward)),
             Some(_) => Err(ContractError::DistributionAlreadySetupForMembershipIssuer {}),
@@ -43,7 +64,8 @@ pub fn setup_distribution_for_new_membership(
 
     Ok(Response::new()
         .add_attribute("action", "setup_distribution_for_new_membership")
-        .add_attribute("membership_issuer_user_id", data.membership_issuer_user_id))
+        .add_attribute("membership_issuer_user_id", data.membership_issuer_user_id)
+        .add_attribute("fee_denom", data.fee_denom))
 }
 
 pub fn setup_distribution_for_new_member(
@@ -58,17 +80,24 @@ pub fn setup_distribution_for_new_member(
 
     let membership_issuer_user_id = data.membership_issuer_user_id.u64();
     let user_id = data.user_id.u64();
-    let global_index = GLOBAL_INDICES.load(deps.storage, membership_issuer_user_id)?;
     let pending_reward = Uint128::zero();
 
-    ALL_USERS_DISTRIBUTIONS.update(
-        deps.storage,
-        (membership_issuer_user_id, user_id),
-        |existing| match existing {
-            None => Ok((global_index, pending_reward)),
-            Some(_) => Err(ContractError::DistributionAlreadySetupForMembershipIssuer {}),
-        },
-    )?;
+    // New member starts from current global index of every denom membership has distributed
+    let global_indices = GLOBAL_INDICES
+        .prefix(membership_issuer_user_id)
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(String, Decimal)>>>()?;
+
+    for (fee_denom, global_index) in global_indices {
+        ALL_USERS_DISTRIBUTIONS.update(
+            deps.storage,
+            (membership_issuer_user_id, user_id, fee_denom.as_str()),
+            |existing| match existing {
+                None => Ok((global_index, pending_reward)),
+                Some(_) => Err(ContractError::DistributionAlreadySetupForMembershipIssuer {}),
+            },
+        )?;
+    }
 
     Ok(Response::new()
         .add_attribute("action", "setup_distribution_for_new_member")
@@ -78,31 +107,117 @@ pub fn setup_distribution_for_new_member(
 
 /// Distributes new rewards for a membership program, using funds found in MessageInfo.
 /// Will increase global index for each of the assets being distributed.
+/// When distribute is paused, the fee is held until distribute is unpaused.
 pub fn distribute(
     deps: DepsMut,
     info: MessageInfo,
     data: DistributeMsg,
-    distribute_caller_allowlist: Vec<Addr>,
+    fee: Coin,
+    distribute_paused: bool,
 ) -> Result<Response, ContractError> {
-    if distribute_caller_allowlist
-        .iter()
-        .all(|addr| *addr != info.sender)
-    {
-        return Err(ContractError::OnlyDistributeAllowlistAddressesCanDistribute {});
+    if !has_role(deps.storage, &info.sender, &Role::Distributor)? {
+        return Err(ContractError::OnlyDistributorCanDistribute {});
     }
 
     let membership_issuer_user_id = data.membership_issuer_user_id.u64();
 
-    GLOBAL_INDICES.update(
-        deps.storage,
-        membership_issuer_user_id,
-        |index| match index {
-            None => Err(ContractError::CannotDistributeBeforeSetupDistribution {}),
-            Some(index) => Ok(index + data.index_increment),
-        },
-    )?;
+    if GLOBAL_INDICES
+        .prefix(membership_issuer_user_id)
+        .range(deps.storage, None, None, Order::Ascending)
+        .next()
+        .is_none()
+    {
+        return Err(ContractError::CannotDistributeBeforeSetupDistribution {});
+    }
+
+    // Index increment is based on supply at the time of distribute, which is stale once unpaused
+    // so hold the fee itself and split it by supply at the time of unpause
+    if distribute_paused {
+        PAUSED_DISTRIBUTIONS.update(
+            deps.storage,
+            (membership_issuer_user_id, fee.denom.as_str()),
+            |paused_amount| -> Result<Uint128, ContractError> {
+                Ok(paused_amount.unwrap_or_default().checked_add(fee.amount)?)
+            },
+        )?;
+    } else {
+        increase_global_index(
+            deps.storage,
+            membership_issuer_user_id,
+            &fee.denom,
+            data.index_increment,
+        )?;
+    }
 
     Ok(Response::new()
         .add_attribute("action", "distribute")
-        .add_attribute("membership_issuer_user_id", data.membership_issuer_user_id))
+        .add_attribute("paused", distribute_paused.to_string())
+        .add_attribute("membership_issuer_user_id", data.membership_issuer_user_id)
+        .add_attribute("fee_denom", fee.denom))
+}
+
+// Split fees held while distribute was paused among current holders of each membership
+// Released entries are removed, so each call picks up where the previous one stopped
+pub fn release_paused_distributions(
+    deps: DepsMut,
+    data: ReleasePausedDistributionsMsg,
+    member_contract_addr: Addr,
+    distribute_paused: bool,
+) -> Result<Response, ContractError> {
+    if distribute_paused {
+        return Err(ContractError::CannotReleasePausedDistributionsWhileDistributePaused {});
+    }
+
+    let member_contract_querier = MemberContractQuerier::new(deps.querier, member_contract_addr);
+    let paused_distributions = PAUSED_DISTRIBUTIONS
+        .range(deps.storage, None, None, Order::Ascending)
+        .take(
+            data.limit
+                .unwrap_or(DEFAULT_RELEASE_LIMIT)
+                .min(MAX_RELEASE_LIMIT) as usize,
+        )
+        .collect::<StdResult<Vec<((u64, String), Uint128)>>>()?;
+    let released_count = paused_distributions.len();
+
+    for ((membership_issuer_user_id, fee_denom), paused_amount) in paused_distributions {
+        // Issuer always holds at least 1 membership so supply is never 0
+        let membership_supply =
+            member_contract_querier.membership_supply(membership_issuer_user_id)?;
+        increase_global_index(
+            deps.storage,
+            membership_issuer_user_id,
+            &fee_denom,
+            Decimal::from_ratio(paused_amount, membership_supply),
+        )?;
+        PAUSED_DISTRIBUTIONS.remove(
+            deps.storage,
+            (membership_issuer_user_id, fee_denom.as_str()),
+        );
+    }
+
+    let all_released = PAUSED_DISTRIBUTIONS.is_empty(deps.storage);
+
+    Ok(Response::new()
+        .add_attribute("action", "release_paused_distributions")
+        .add_attribute("released_count", released_count.to_string())
+        .add_attribute("all_released", all_released.to_string()))
+}
+
+// Membership can receive fees in a denom other than its own
+// e.g. thread creator's members get paid in the denom of the membership asked to
+// Global index of such denom starts from 0 the first time it is distributed
+fn increase_global_index(
+    storage: &mut dyn Storage,
+    membership_issuer_user_id: u64,
+    fee_denom: &str,
+    index_increment: Decimal,
+) -> Result<(), ContractError> {
+    GLOBAL_INDICES.update(
+        storage,
+        (membership_issuer_user_id, fee_denom),
+        |index| -> Result<Decimal, ContractError> {
+            Ok(index.unwrap_or_default().checked_add(index_increment)?)
+        },
+    )?;
+    Ok(())
 }
@@ -1,16 +1,16 @@
 use cosmwasm_std::{
-    Addr, BankMsg, Coin, CosmosMsg, DepsMut, Fraction, MessageInfo, Response, Uint128, Uint64,
+    Addr, BankMsg, Coin, CosmosMsg, DepsMut, MessageInfo, Response, Uint128, Uint64,
 };
 
 use distribution_pkg::msg::{
     ClaimRewardsMsg, QueryUserRewardMsg, UpdateUserPendingRewardMsg, UserRewardResponse,
 };
-use member_pkg::member_contract_querier::query_user_by_id;
+use member_pkg::member_contract_querier::MemberContractQuerier;
+use shared_pkg::event::RewardClaimEvent;
 
 use crate::{
-    query::user::query_user_reward,
-    state::{ALL_USERS_DISTRIBUTIONS, GLOBAL_INDICES},
-    ContractError,
+    query::user::query_user_reward, state::ALL_USERS_DISTRIBUTIONS,
+    util::distribution::load_user_distributions, ContractError,
 };
 
 /// Will calculate any accrued reward since the last update to user's reward.
@@ -28,26 +28,28 @@ pub fn update_user_pending_reward(
     let user_id = data.user_id.u64();
     let user_previous_amount = data.user_previous_amount;
 
-    let (previous_user_index, previous_pending_reward) =
-        ALL_USERS_DISTRIBUTIONS.load(deps.storage, (membership_issuer_user_id, user_id))?;
-    let global_index = GLOBAL_INDICES.load(deps.storage, membership_issuer_user_id)?;
-    let new_user_index = global_index;
-
-    let user_index_diff = global_index.checked_sub(previous_user_index).unwrap();
-    let new_user_reward = user_previous_amount
-        .checked_multiply_ratio(user_index_diff.numerator(), user_index_diff.denominator())
-        .unwrap()
-        .checked_add(previous_pending_reward)
-        .unwrap();
-
-    ALL_USERS_DISTRIBUTIONS.update(
-        deps.storage,
-        (membership_issuer_user_id, user_id),
-        |existing| match existing {
-            None => Err(ContractError::CannotUpdatePendingRewardBeforeSetupDistribution {}),
-            Some((_, _)) => Ok((new_user_index, new_user_reward)),
-        },
-    )?;
+    let distributions =
+        match load_user_distributions(deps.storage, membership_issuer_user_id, user_id)? {
+            None => return Err(ContractError::CannotUpdatePendingRewardBeforeSetupDistribution {}),
+            Some(distributions) => distributions,
+        };
+
+    // Accrue reward in every denom membership has distributed
+    for distribution in distributions {
+        let new_user_index = distribution.global_index;
+
+        let new_user_reward = distribution.total_reward(user_previous_amount)?;
+
+        ALL_USERS_DISTRIBUTIONS.save(
+            deps.storage,
+            (
+                membership_issuer_user_id,
+                user_id,
+                distribution.fee_denom.as_str(),
+            ),
+            &(new_user_index, new_user_reward),
+        )?;
+    }
 
     Ok(Response::new()
         .add_attribute("action", "update_user_pending_reward")
@@ -58,17 +60,20 @@ pub fn claim_reward(
     deps: DepsMut,
     data: ClaimRewardsMsg,
     member_contract_addr: Addr,
-    fee_denom: &str,
 ) -> Result<Response, ContractError> {
     let deps_ref = deps.as_ref();
 
     let membership_issuer_user_id = data.membership_issuer_user_id.u64();
     let user_id = data.user_id.u64();
-    let user = query_user_by_id(deps_ref, member_contract_addr.clone(), user_id);
+    let user = MemberContractQuerier::new(deps.querier, member_contract_addr.clone())
+        .user_by_id(user_id)?
+        .ok_or(ContractError::UserNotExist {})?;
 
-    let global_index = GLOBAL_INDICES.load(deps.storage, membership_issuer_user_id)?;
-    let new_user_index = global_index;
-    let new_pending_reward = Uint128::zero();
+    let distributions =
+        match load_user_distributions(deps.storage, membership_issuer_user_id, user_id)? {
+            None => return Err(ContractError::CannotClaimRewardBeforeSetupDistribution {}),
+            Some(distributions) => distributions,
+        };
 
     let resp: UserRewardResponse = query_user_reward(
         deps_ref,
@@ -79,29 +84,50 @@ pub fn claim_reward(
         member_contract_addr,
     )?;
 
-    let reward = resp.amount;
+    let new_pending_reward = Uint128::zero();
 
-    // Bump user index to global index and set user pending reward to 0
-    ALL_USERS_DISTRIBUTIONS.update(
-        deps.storage,
-        (membership_issuer_user_id, user_id),
-        |existing| match existing {
-            None => Err(ContractError::CannotClaimRewardBeforeSetupDistribution {}),
-            Some((_, _)) => Ok((new_user_index, new_pending_reward)),
-        },
-    )?;
+    // Bump user index to global index and set user pending reward to 0 for every denom
+    for distribution in distributions {
+        ALL_USERS_DISTRIBUTIONS.save(
+            deps.storage,
+            (
+                membership_issuer_user_id,
+                user_id,
+                distribution.fee_denom.as_str(),
+            ),
+            &(distribution.global_index, new_pending_reward),
+        )?;
+    }
 
-    let msg = CosmosMsg::Bank(BankMsg::Send {
-        to_address: user.addr.to_string(),
-        amount: vec![Coin {
-            denom: fee_denom.to_string(),
-            amount: reward,
-        }],
-    });
+    // Bank send rejects zero amount coins
+    let rewards: Vec<Coin> = resp
+        .rewards
+        .into_iter()
+        .filter(|coin| !coin.amount.is_zero())
+        .collect();
 
-    Ok(Response::new()
-        .add_message(msg)
+    let mut resp = Response::new()
         .add_attribute("action", "claim_reward")
         .add_attribute("user_id", data.user_id)
-        .add_attribute("membership_issuer_user_id", data.membership_issuer_user_id))
+        .add_attribute("membership_issuer_user_id", data.membership_issuer_user_id);
+
+    // Nothing is sent and no reward claim event is emitted when there is nothing to claim
+    if !rewards.is_empty() {
+        resp = resp
+            .add_message(CosmosMsg::Bank(BankMsg::Send {
+                to_address: user.addr.to_string(),
+                amount: rewards.clone(),
+            }))
+            .add_event(
+                RewardClaimEvent {
+                    membership_issuer_user_id: data.membership_issuer_user_id,
+                    user_id: data.user_id,
+                    recipient_addr: user.addr,
+                    rewards,
+                }
+                .into(),
+            );
+    }
+
+    Ok(resp)
 }
@@ -4,3 +4,4 @@ mod error;
 mod execute;
 mod query;
 pub mod state;
+mod util;
@@ -1,9 +1,40 @@
-use crate::state::CONFIG;
-use cosmwasm_std::{Deps, StdResult};
+use crate::state::{CONFIG, PENDING_ADMIN_PROPOSAL, PENDING_CONFIG_CHANGES};
+use cosmwasm_std::{Deps, Order, StdResult};
 
-use distribution_pkg::msg::ConfigResponse;
+use distribution_pkg::msg::{ConfigResponse, PauseStatusResponse, PendingConfigChangesResponse};
+use shared_pkg::rbac::{load_roles, QueryRolesMsg, RolesResponse};
 
 pub fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
     let config = CONFIG.load(deps.storage)?;
     Ok(ConfigResponse { config })
 }
+
+pub fn query_pause_status(deps: Deps) -> StdResult<PauseStatusResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    Ok(PauseStatusResponse {
+        enabled: config.enabled,
+        paused_operations: config.pause_config.paused_operations(),
+        pause_config: config.pause_config,
+    })
+}
+
+pub fn query_roles(deps: Deps, data: QueryRolesMsg) -> StdResult<RolesResponse> {
+    let addr = deps.api.addr_validate(data.addr.as_str())?;
+    Ok(RolesResponse {
+        roles: load_roles(deps.storage, &addr)?,
+    })
+}
+
+pub fn query_pending_config_changes(deps: Deps) -> StdResult<PendingConfigChangesResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let pending_config_changes = PENDING_CONFIG_CHANGES
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, pending_config_change)| pending_config_change))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(PendingConfigChangesResponse {
+        pending_admin_proposal: PENDING_ADMIN_PROPOSAL.may_load(deps.storage)?,
+        config_change_timelock: config.config_change_timelock,
+        pending_config_changes,
+    })
+}
@@ -1,9 +1,9 @@
-use cosmwasm_std::{Addr, Deps, Fraction, StdResult};
+use cosmwasm_std::{Addr, Coin, Deps, StdError, StdResult};
 
 use distribution_pkg::msg::{QueryUserRewardMsg, UserRewardResponse};
-use member_pkg::member_contract_querier::query_is_user_a_member_and_membership_amount;
+use member_pkg::member_contract_querier::MemberContractQuerier;
 
-use crate::state::{ALL_USERS_DISTRIBUTIONS, GLOBAL_INDICES};
+use crate::util::distribution::load_user_distributions;
 
 pub fn query_user_reward(
     deps: Deps,
@@ -13,24 +13,22 @@ pub fn query_user_reward(
     let membership_issuer_user_id = data.membership_issuer_user_id.u64();
     let user_id = data.user_id.u64();
 
-    let global_index = GLOBAL_INDICES.load(deps.storage, membership_issuer_user_id)?;
-    let (user_index, pending_reward) =
-        ALL_USERS_DISTRIBUTIONS.load(deps.storage, (membership_issuer_user_id, user_id))?;
+    let distributions = load_user_distributions(deps.storage, membership_issuer_user_id, user_id)?
+        .ok_or_else(|| StdError::not_found("user distribution"))?;
 
     // Query membership contract for user membership amount
-    let (_, user_amount) = query_is_user_a_member_and_membership_amount(
-        deps,
-        member_contract_addr,
-        membership_issuer_user_id,
-        user_id,
-    );
+    let (_, user_amount) = MemberContractQuerier::new(deps.querier, member_contract_addr)
+        .is_member_and_membership_amount(membership_issuer_user_id, user_id)?;
 
-    let user_index_diff = global_index.checked_sub(user_index).unwrap();
-    let new_reward = user_amount
-        .checked_multiply_ratio(user_index_diff.numerator(), user_index_diff.denominator())
-        .unwrap();
+    let rewards = distributions
+        .into_iter()
+        .map(|distribution| {
+            Ok(Coin {
+                amount: distribution.total_reward(user_amount)?,
+                denom: distribution.fee_denom,
+            })
+        })
+        .collect::<StdResult<Vec<Coin>>>()?;
 
-    Ok(UserRewardResponse {
-        amount: new_reward + pending_reward,
-    })
+    Ok(UserRewardResponse { rewards })
 }
@@ -1,19 +1,39 @@
-use cosmwasm_std::{Decimal, Uint128};
+use cosmwasm_std::{Decimal, Uint128, Uint64};
 use cw_storage_plus::{Item, Map};
 
-use distribution_pkg::config::Config;
+use distribution_pkg::{config::Config, msg::UpdateConfigMsg};
+use shared_pkg::{admin_transfer::AdminProposal, timelock::PendingConfigChange};
 
 pub const DEFAULT_QUERY_LIMIT: u32 = 5;
 pub const MAX_QUERY_LIMIT: u32 = 25;
 
+// Each released membership costs a member contract query, so release is done in batches
+pub const DEFAULT_RELEASE_LIMIT: u32 = 10;
+pub const MAX_RELEASE_LIMIT: u32 = 30;
+
 pub const CONFIG: Item<Config> = Item::new("CONFIG");
 
+// New admin proposed by current admin, removed once accepted or cancelled
+pub const PENDING_ADMIN_PROPOSAL: Item<AdminProposal> = Item::new("PENDING_ADMIN_PROPOSAL");
+
+// Next available ID of pending config change, start from 1
+pub const NEXT_PENDING_CONFIG_CHANGE_ID: Item<Uint64> = Item::new("NEXT_PENDING_CONFIG_CHANGE_ID");
+
+// Key is pending config change ID, value is config changes waiting for the time-lock
+// Removed once executed or cancelled
+pub const PENDING_CONFIG_CHANGES: Map<u64, PendingConfigChange<UpdateConfigMsg>> =
+    Map::new("PENDING_CONFIG_CHANGES");
+
 /// Tracks global index for rewards.
 /// Global index is simply a decimal number representing the amount of currency rewards paid
 /// for a unit of user weight, since the beginning of time.
-/// Key is membership issuer's user ID, value is global index.
-pub const GLOBAL_INDICES: Map<u64, Decimal> = Map::new("GLOBAL_INDICES");
+/// Key is (membership issuer's user ID, fee denom), value is global index.
+pub const GLOBAL_INDICES: Map<(u64, &str), Decimal> = Map::new("GLOBAL_INDICES");
+
+/// Key is (membership issuer's user ID, fee denom), value is total fee distributed while distribute is paused.
+/// Split among holders of the membership at the time it is released, anyone can release it once distribute is unpaused.
+pub const PAUSED_DISTRIBUTIONS: Map<(u64, &str), Uint128> = Map::new("PAUSED_DISTRIBUTIONS");
 
-/// Key is (membership issuer's user ID, member's user ID), value is (user index, pending reward).
-pub const ALL_USERS_DISTRIBUTIONS: Map<(u64, u64), (Decimal, Uint128)> =
+/// Key is (membership issuer's user ID, member's user ID, fee denom), value is (user index, pending reward).
+pub const ALL_USERS_DISTRIBUTIONS: Map<(u64, u64, &str), (Decimal, Uint128)> =
     Map::new("ALL_USERS_DISTRIBUTIONS");
@@ -0,0 +1,35 @@
+use distribution_pkg::{config::Config, msg::UpdateConfigMsg};
+
+use crate::ContractError;
+
+// All user facing functions are disabled when contract is disabled
+// Admin functions stay available so admin can turn it back on
+pub fn assert_contract_enabled(config: &Config) -> Result<(), ContractError> {
+    if !config.enabled {
+        return Err(ContractError::ContractDisabled {});
+    }
+
+    Ok(())
+}
+
+// Every change waits for the time-lock, member contract decides who can setup and update rewards
+// Returns changes to apply right away, and changes to queue or None if there is nothing to queue
+pub fn split_timelocked_config_changes(
+    data: UpdateConfigMsg,
+) -> (UpdateConfigMsg, Option<UpdateConfigMsg>) {
+    let UpdateConfigMsg {
+        member_contract_addr,
+        config_change_timelock,
+    } = data;
+
+    let timelocked_changes = UpdateConfigMsg {
+        member_contract_addr,
+        config_change_timelock,
+    };
+
+    if timelocked_changes == UpdateConfigMsg::default() {
+        (UpdateConfigMsg::default(), None)
+    } else {
+        (UpdateConfigMsg::default(), Some(timelocked_changes))
+    }
+}
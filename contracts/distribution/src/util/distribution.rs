@@ -0,0 +1,63 @@
+use cosmwasm_std::{Decimal, Fraction, Order, StdError, StdResult, Storage, Uint128};
+
+use crate::state::{ALL_USERS_DISTRIBUTIONS, GLOBAL_INDICES};
+
+// User's distribution of a membership in one denom
+pub struct UserDistribution {
+    pub fee_denom: String,
+    pub global_index: Decimal,
+    pub user_index: Decimal,
+    pub pending_reward: Uint128,
+}
+
+impl UserDistribution {
+    // Pending reward plus what user_amount memberships accrued since user index was last updated
+    pub fn total_reward(&self, user_amount: Uint128) -> StdResult<Uint128> {
+        let user_index_diff = self.global_index.checked_sub(self.user_index)?;
+        let new_reward = user_amount
+            .checked_multiply_ratio(user_index_diff.numerator(), user_index_diff.denominator())
+            .map_err(|err| StdError::generic_err(err.to_string()))?;
+        Ok(new_reward.checked_add(self.pending_reward)?)
+    }
+}
+
+// Load user's distribution of every denom membership has distributed
+// Returns None if distribution has not been setup for the user
+// Denom first distributed after user joined has no user entry yet, user index starts from 0 for it
+// because user has been a member since before the global index of the denom existed
+pub fn load_user_distributions(
+    storage: &dyn Storage,
+    membership_issuer_user_id: u64,
+    user_id: u64,
+) -> StdResult<Option<Vec<UserDistribution>>> {
+    if ALL_USERS_DISTRIBUTIONS
+        .prefix((membership_issuer_user_id, user_id))
+        .range(storage, None, None, Order::Ascending)
+        .next()
+        .is_none()
+    {
+        return Ok(None);
+    }
+
+    let distributions = GLOBAL_INDICES
+        .prefix(membership_issuer_user_id)
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (fee_denom, global_index) = item?;
+            let (user_index, pending_reward) = ALL_USERS_DISTRIBUTIONS
+                .may_load(
+                    storage,
+                    (membership_issuer_user_id, user_id, fee_denom.as_str()),
+                )?
+                .unwrap_or((Decimal::zero(), Uint128::zero()));
+            Ok(UserDistribution {
+                fee_denom,
+                global_index,
+                user_index,
+                pending_reward,
+            })
+        })
+        .collect::<StdResult<Vec<UserDistribution>>>()?;
+
+    Ok(Some(distributions))
+}
@@ -0,0 +1,110 @@
+use cosmwasm_schema::{cw_serde, serde::Deserialize};
+use cosmwasm_std::{from_slice, Addr, Decimal, DepsMut, Env, Order, StdError, StdResult, Uint128};
+use cw_storage_plus::{Item, Map};
+
+use distribution_pkg::config::{Config, PauseConfig};
+use shared_pkg::{
+    migrate::StateMigration,
+    rbac::{grant_role, Role},
+};
+
+use crate::state::{ALL_USERS_DISTRIBUTIONS, CONFIG, GLOBAL_INDICES};
+use crate::ContractError;
+
+// Registered state migrations, sorted by version
+// When a release changes how config, indices or user distributions are stored:
+// keep the old struct in this file, add a function that loads data in old schema and saves it in new schema,
+// then register the function with the version of that release
+pub const STATE_MIGRATIONS: &[(&str, StateMigration<ContractError>)] =
+    &[("0.2.0", migrate_state_to_v0_2_0)];
+
+// ========== 0.1.0 ==========
+
+#[cw_serde]
+struct ConfigV0_1_0 {
+    admin_addr: Addr,
+    member_contract_addr: Addr,
+    enabled: bool,
+    distribute_caller_allowlist: Vec<Addr>,
+}
+
+const CONFIG_V0_1_0: Item<ConfigV0_1_0> = Item::new("CONFIG");
+
+// Same namespaces as GLOBAL_INDICES and ALL_USERS_DISTRIBUTIONS, before they were keyed by fee denom
+const GLOBAL_INDICES_V0_1_0: Map<u64, Decimal> = Map::new("GLOBAL_INDICES");
+const ALL_USERS_DISTRIBUTIONS_V0_1_0: Map<(u64, u64), (Decimal, Uint128)> =
+    Map::new("ALL_USERS_DISTRIBUTIONS");
+
+// Only the part of member contract config needed here
+// Not cw_serde so the rest of the config is ignored, whichever version of member contract is deployed
+#[derive(Deserialize)]
+#[serde(crate = "cosmwasm_schema::serde")]
+struct MemberConfigFeeDenom {
+    default_fee_config: MemberFeeConfigFeeDenom,
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "cosmwasm_schema::serde")]
+struct MemberFeeConfigFeeDenom {
+    fee_denom: String,
+}
+
+// ========== 0.2.0 ==========
+
+// Adds everything introduced since 0.1.0 with the same defaults as instantiate
+// 0.1.0 only distributed member contract's default fee denom, indices and rewards are rekeyed by that denom
+// Admin in config is granted admin role since roles were introduced
+// Addresses in distribute caller allowlist are granted distributor role, which replaced the allowlist
+fn migrate_state_to_v0_2_0(deps: DepsMut, _env: &Env) -> Result<(), ContractError> {
+    let old_config = CONFIG_V0_1_0.load(deps.storage)?;
+
+    let config = Config {
+        admin_addr: old_config.admin_addr,
+        member_contract_addr: old_config.member_contract_addr,
+        enabled: old_config.enabled,
+        pause_config: PauseConfig::default(),
+        config_change_timelock: None,
+    };
+    CONFIG.save(deps.storage, &config)?;
+
+    grant_role(deps.storage, &config.admin_addr, &Role::Admin)?;
+    for addr in &old_config.distribute_caller_allowlist {
+        grant_role(deps.storage, addr, &Role::Distributor)?;
+    }
+
+    // Raw query so it works whether member contract is migrated before or after this contract
+    let member_config: MemberConfigFeeDenom = deps
+        .querier
+        .query_wasm_raw(config.member_contract_addr.clone(), b"CONFIG".as_slice())?
+        .map(|bytes| from_slice(&bytes))
+        .transpose()?
+        .ok_or_else(|| StdError::not_found("member contract config"))?;
+    let fee_denom = member_config.default_fee_config.fee_denom;
+
+    // Collect first, old and new keys share the namespace being read
+    let global_indices = GLOBAL_INDICES_V0_1_0
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for (membership_issuer_user_id, global_index) in global_indices {
+        GLOBAL_INDICES_V0_1_0.remove(deps.storage, membership_issuer_user_id);
+        GLOBAL_INDICES.save(
+            deps.storage,
+            (membership_issuer_user_id, fee_denom.as_str()),
+            &global_index,
+        )?;
+    }
+
+    let users_distributions = ALL_USERS_DISTRIBUTIONS_V0_1_0
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for ((membership_issuer_user_id, user_id), distribution) in users_distributions {
+        ALL_USERS_DISTRIBUTIONS_V0_1_0.remove(deps.storage, (membership_issuer_user_id, user_id));
+        ALL_USERS_DISTRIBUTIONS.save(
+            deps.storage,
+            (membership_issuer_user_id, user_id, fee_denom.as_str()),
+            &distribution,
+        )?;
+    }
+
+    Ok(())
+}
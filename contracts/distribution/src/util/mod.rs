@@ -0,0 +1,3 @@
+pub(crate) mod config;
+pub(crate) mod distribution;
+pub(crate) mod migrate;
@@ -0,0 +1,4 @@
+mod test_admin_can_migrate_contract_from_first_release;
+mod test_only_distributor_can_distribute;
+mod test_pause_distribute_still_allows_sell;
+mod test_release_paused_distributions_in_batches;
@@ -0,0 +1,159 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    Addr, Coin, Decimal, DepsMut, Env, MessageInfo, Response, StdResult, Uint128, Uint64,
+};
+use cw_multi_test::{ContractWrapper, Executor};
+use cw_storage_plus::{Item, Map};
+
+use distribution::contract::{execute, query, CONTRACT_NAME, CONTRACT_VERSION};
+use distribution_pkg::msg::{ConfigResponse, MigrateMsg, QueryConfigMsg, QueryMsg};
+use shared_pkg::rbac::{QueryRolesMsg, Role, RolesResponse};
+
+use crate::helpers::{
+    assert_balance, claim_reward, contract_cw_distribution, get_fund_from_faucet,
+    proper_instantiate, query_user_reward, register_user, FEE_DENOM,
+};
+
+// Config as stored by the first release of distribution contract
+#[cw_serde]
+struct ConfigV0_1_0 {
+    admin_addr: Addr,
+    member_contract_addr: Addr,
+    enabled: bool,
+    distribute_caller_allowlist: Vec<Addr>,
+}
+
+#[cw_serde]
+struct FirstReleaseInstantiateMsg {
+    member_contract_addr: String,
+}
+
+// Pretends to be the first release of distribution contract
+// Membership of user 1 distributed fees before, user 2 has 100 uluna pending reward
+fn instantiate_as_first_release(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: FirstReleaseInstantiateMsg,
+) -> StdResult<Response> {
+    cw2::set_contract_version(deps.storage, format!("crates.io:{CONTRACT_NAME}"), "0.1.0")?;
+
+    let member_contract_addr = deps.api.addr_validate(&msg.member_contract_addr)?;
+    Item::new("CONFIG").save(
+        deps.storage,
+        &ConfigV0_1_0 {
+            admin_addr: info.sender,
+            member_contract_addr: member_contract_addr.clone(),
+            enabled: true,
+            distribute_caller_allowlist: vec![member_contract_addr],
+        },
+    )?;
+    Map::<u64, Decimal>::new("GLOBAL_INDICES").save(deps.storage, 1, &Decimal::percent(50))?;
+    Map::<(u64, u64), (Decimal, Uint128)>::new("ALL_USERS_DISTRIBUTIONS").save(
+        deps.storage,
+        (1, 2),
+        &(Decimal::percent(50), Uint128::from(100_u64)),
+    )?;
+
+    Ok(Response::new())
+}
+
+#[test]
+fn test_admin_can_migrate_contract_from_first_release() {
+    let (mut app, _, cw_member_contract_addr, admin_addr, _, user_1_addr, user_2_addr) =
+        proper_instantiate();
+    let user_1_id = Uint64::one();
+    let user_2_id = Uint64::from(2_u64);
+
+    register_user(&mut app, &cw_member_contract_addr, &user_1_addr).unwrap();
+    register_user(&mut app, &cw_member_contract_addr, &user_2_addr).unwrap();
+
+    let first_release_code_id = app.store_code(Box::new(ContractWrapper::new(
+        execute,
+        instantiate_as_first_release,
+        query,
+    )));
+    let cw_distribution_contract_addr = app
+        .instantiate_contract(
+            first_release_code_id,
+            admin_addr.clone(),
+            &FirstReleaseInstantiateMsg {
+                member_contract_addr: cw_member_contract_addr.to_string(),
+            },
+            &[],
+            "cw_distribution_first_release",
+            Some(admin_addr.to_string()),
+        )
+        .unwrap();
+    // Pending reward is held by the contract
+    get_fund_from_faucet(
+        &mut app,
+        cw_distribution_contract_addr.clone(),
+        Uint128::from(100_u64),
+    );
+
+    let new_code_id = app.store_code(contract_cw_distribution());
+    app.migrate_contract(
+        admin_addr.clone(),
+        cw_distribution_contract_addr.clone(),
+        &MigrateMsg {},
+        new_code_id,
+    )
+    .unwrap();
+    let contract_version =
+        cw2::query_contract_info(&app.wrap(), cw_distribution_contract_addr.clone()).unwrap();
+    assert_eq!(contract_version.version, CONTRACT_VERSION);
+
+    let config: ConfigResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_distribution_contract_addr.clone(),
+            &QueryMsg::QueryConfig(QueryConfigMsg {}),
+        )
+        .unwrap();
+    assert_eq!(config.config.admin_addr, admin_addr);
+    assert_eq!(config.config.member_contract_addr, cw_member_contract_addr);
+    assert!(config.config.enabled);
+    assert_eq!(config.config.config_change_timelock, None);
+
+    let roles: RolesResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_distribution_contract_addr.clone(),
+            &QueryMsg::QueryRoles(QueryRolesMsg {
+                addr: admin_addr.to_string(),
+            }),
+        )
+        .unwrap();
+    assert_eq!(roles.roles, vec![Role::Admin]);
+
+    // Distribute caller allowlist is replaced with distributor role
+    let roles: RolesResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_distribution_contract_addr.clone(),
+            &QueryMsg::QueryRoles(QueryRolesMsg {
+                addr: cw_member_contract_addr.to_string(),
+            }),
+        )
+        .unwrap();
+    assert_eq!(roles.roles, vec![Role::Distributor]);
+
+    // Pending reward is kept in member contract's default fee denom and can be claimed
+    assert_eq!(
+        query_user_reward(&app, &cw_distribution_contract_addr, user_1_id, user_2_id),
+        vec![Coin {
+            denom: FEE_DENOM.to_string(),
+            amount: Uint128::from(100_u64),
+        }]
+    );
+    claim_reward(
+        &mut app,
+        &cw_distribution_contract_addr,
+        &user_2_addr,
+        user_1_id,
+        user_2_id,
+    )
+    .unwrap();
+    assert_balance(&app, &user_2_addr, Uint128::from(100_u64), FEE_DENOM);
+}
@@ -0,0 +1,94 @@
+use cosmwasm_std::{Coin, Decimal, Uint128, Uint64};
+
+use distribution::ContractError;
+use shared_pkg::rbac::Role;
+
+use crate::helpers::{
+    assert_err, distribute, enable_membership, get_fund_from_faucet, grant_role,
+    proper_instantiate, query_user_reward, register_user, revoke_role, FEE_DENOM,
+    SOCIAL_MEDIA_HANDLE_1,
+};
+
+#[test]
+fn test_only_distributor_can_distribute() {
+    let (
+        mut app,
+        cw_distribution_contract_addr,
+        cw_member_contract_addr,
+        admin_addr,
+        registration_admin_addr,
+        user_1_addr,
+        _,
+    ) = proper_instantiate();
+    let user_1_id = Uint64::one();
+    let fee = Coin {
+        denom: FEE_DENOM.to_string(),
+        amount: Uint128::from(10_u8),
+    };
+
+    register_user(&mut app, &cw_member_contract_addr, &user_1_addr).unwrap();
+    enable_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+        SOCIAL_MEDIA_HANDLE_1,
+    );
+    get_fund_from_faucet(&mut app, user_1_addr.clone(), Uint128::from(100_u8));
+
+    assert_err(
+        distribute(
+            &mut app,
+            &cw_distribution_contract_addr,
+            &user_1_addr,
+            user_1_id,
+            Decimal::from_ratio(fee.amount, 1_u8),
+            fee.clone(),
+        ),
+        ContractError::OnlyDistributorCanDistribute {},
+    );
+
+    // Admin grants distributor role, e.g. to a newly deployed contract that pays members
+    grant_role(
+        &mut app,
+        &cw_distribution_contract_addr,
+        &admin_addr,
+        &user_1_addr,
+        Role::Distributor,
+    )
+    .unwrap();
+    distribute(
+        &mut app,
+        &cw_distribution_contract_addr,
+        &user_1_addr,
+        user_1_id,
+        Decimal::from_ratio(fee.amount, 1_u8),
+        fee.clone(),
+    )
+    .unwrap();
+    // User 1 is the only holder of its membership
+    assert_eq!(
+        query_user_reward(&app, &cw_distribution_contract_addr, user_1_id, user_1_id),
+        vec![fee.clone()]
+    );
+
+    revoke_role(
+        &mut app,
+        &cw_distribution_contract_addr,
+        &admin_addr,
+        &user_1_addr,
+        Role::Distributor,
+    )
+    .unwrap();
+    assert_err(
+        distribute(
+            &mut app,
+            &cw_distribution_contract_addr,
+            &user_1_addr,
+            user_1_id,
+            Decimal::from_ratio(fee.amount, 1_u8),
+            fee,
+        ),
+        ContractError::OnlyDistributorCanDistribute {},
+    );
+}
@@ -0,0 +1,130 @@
+use cosmwasm_std::{Uint128, Uint64};
+
+use crate::helpers::{
+    assert_balance, buy_membership, claim_reward, enable_membership, proper_instantiate,
+    query_user_reward, register_user, release_paused_distributions, sell_membership,
+    update_pause_config, FEE_DENOM, SOCIAL_MEDIA_HANDLE_1,
+};
+
+#[test]
+fn test_pause_distribute_still_allows_sell() {
+    let (
+        mut app,
+        cw_distribution_contract_addr,
+        cw_member_contract_addr,
+        admin_addr,
+        registration_admin_addr,
+        user_1_addr,
+        user_2_addr,
+    ) = proper_instantiate();
+    let user_1_id = Uint64::one();
+    let user_2_id = Uint64::from(2_u8);
+    let uint_128_amount_5 = Uint128::from(5_u8);
+    let uint_128_amount_10 = Uint128::from(10_u8);
+
+    register_user(&mut app, &cw_member_contract_addr, &user_1_addr).unwrap();
+    register_user(&mut app, &cw_member_contract_addr, &user_2_addr).unwrap();
+    enable_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+        SOCIAL_MEDIA_HANDLE_1,
+    );
+    buy_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &user_2_addr,
+        user_1_id,
+        uint_128_amount_10,
+    )
+    .unwrap();
+
+    let reward_before_pause =
+        query_user_reward(&app, &cw_distribution_contract_addr, user_1_id, user_2_id);
+    let distribution_balance_before_pause = app
+        .wrap()
+        .query_balance(&cw_distribution_contract_addr, FEE_DENOM)
+        .unwrap()
+        .amount;
+
+    // ================ Trading keeps working while distribute is paused ================
+    update_pause_config(
+        &mut app,
+        &cw_distribution_contract_addr,
+        &admin_addr,
+        Some(true),
+        None,
+    )
+    .unwrap();
+    buy_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &user_2_addr,
+        user_1_id,
+        uint_128_amount_10,
+    )
+    .unwrap();
+    sell_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &user_2_addr,
+        user_1_id,
+        uint_128_amount_5,
+    )
+    .unwrap();
+
+    // Fees are held by distribution contract but not added to members' rewards yet
+    assert!(
+        app.wrap()
+            .query_balance(&cw_distribution_contract_addr, FEE_DENOM)
+            .unwrap()
+            .amount
+            > distribution_balance_before_pause
+    );
+    assert_eq!(
+        query_user_reward(&app, &cw_distribution_contract_addr, user_1_id, user_2_id),
+        reward_before_pause
+    );
+
+    // ================ Held fees go to members once released after unpause ================
+    update_pause_config(
+        &mut app,
+        &cw_distribution_contract_addr,
+        &admin_addr,
+        Some(false),
+        None,
+    )
+    .unwrap();
+    assert_eq!(
+        query_user_reward(&app, &cw_distribution_contract_addr, user_1_id, user_2_id),
+        reward_before_pause
+    );
+    // Anyone can release held fees
+    release_paused_distributions(&mut app, &cw_distribution_contract_addr, &user_2_addr, None)
+        .unwrap();
+    let reward_after_unpause =
+        query_user_reward(&app, &cw_distribution_contract_addr, user_1_id, user_2_id);
+    assert_eq!(reward_after_unpause.len(), 1);
+    assert!(reward_after_unpause[0].amount > reward_before_pause[0].amount);
+
+    let user_2_balance = app
+        .wrap()
+        .query_balance(&user_2_addr, FEE_DENOM)
+        .unwrap()
+        .amount;
+    claim_reward(
+        &mut app,
+        &cw_distribution_contract_addr,
+        &user_2_addr,
+        user_1_id,
+        user_2_id,
+    )
+    .unwrap();
+    assert_balance(
+        &app,
+        &user_2_addr,
+        user_2_balance + reward_after_unpause[0].amount,
+        FEE_DENOM,
+    );
+}
@@ -0,0 +1,138 @@
+use cosmwasm_std::{Uint128, Uint64};
+use cw_multi_test::AppResponse;
+
+use distribution::ContractError;
+
+use crate::helpers::{
+    assert_err, buy_membership, enable_membership, proper_instantiate, query_user_reward,
+    register_user, release_paused_distributions, update_pause_config, SOCIAL_MEDIA_HANDLE_1,
+    SOCIAL_MEDIA_HANDLE_2,
+};
+
+fn attribute(res: &AppResponse, key: &str) -> String {
+    res.events
+        .iter()
+        .filter(|event| event.ty == "wasm")
+        .flat_map(|event| event.attributes.iter())
+        .find(|attribute| attribute.key == key)
+        .unwrap()
+        .value
+        .clone()
+}
+
+#[test]
+fn test_release_paused_distributions_in_batches() {
+    let (
+        mut app,
+        cw_distribution_contract_addr,
+        cw_member_contract_addr,
+        admin_addr,
+        registration_admin_addr,
+        user_1_addr,
+        user_2_addr,
+    ) = proper_instantiate();
+    let user_1_id = Uint64::one();
+    let user_2_id = Uint64::from(2_u8);
+    let uint_128_amount_10 = Uint128::from(10_u8);
+
+    register_user(&mut app, &cw_member_contract_addr, &user_1_addr).unwrap();
+    register_user(&mut app, &cw_member_contract_addr, &user_2_addr).unwrap();
+    enable_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+        SOCIAL_MEDIA_HANDLE_1,
+    );
+    enable_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_2_id,
+        SOCIAL_MEDIA_HANDLE_2,
+    );
+
+    // Both memberships distribute fees while distribute is paused
+    update_pause_config(
+        &mut app,
+        &cw_distribution_contract_addr,
+        &admin_addr,
+        Some(true),
+        None,
+    )
+    .unwrap();
+    buy_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &user_2_addr,
+        user_1_id,
+        uint_128_amount_10,
+    )
+    .unwrap();
+    buy_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &user_1_addr,
+        user_2_id,
+        uint_128_amount_10,
+    )
+    .unwrap();
+    let user_2_reward_before_release =
+        query_user_reward(&app, &cw_distribution_contract_addr, user_1_id, user_2_id);
+    let user_1_reward_before_release =
+        query_user_reward(&app, &cw_distribution_contract_addr, user_2_id, user_1_id);
+
+    assert_err(
+        release_paused_distributions(&mut app, &cw_distribution_contract_addr, &user_1_addr, None),
+        ContractError::CannotReleasePausedDistributionsWhileDistributePaused {},
+    );
+
+    update_pause_config(
+        &mut app,
+        &cw_distribution_contract_addr,
+        &admin_addr,
+        Some(false),
+        None,
+    )
+    .unwrap();
+
+    // Only membership of user 1 is released in the first batch
+    let res = release_paused_distributions(
+        &mut app,
+        &cw_distribution_contract_addr,
+        &user_1_addr,
+        Some(1),
+    )
+    .unwrap();
+    assert_eq!(attribute(&res, "released_count"), "1");
+    assert_eq!(attribute(&res, "all_released"), "false");
+    assert_ne!(
+        query_user_reward(&app, &cw_distribution_contract_addr, user_1_id, user_2_id),
+        user_2_reward_before_release
+    );
+    assert_eq!(
+        query_user_reward(&app, &cw_distribution_contract_addr, user_2_id, user_1_id),
+        user_1_reward_before_release
+    );
+
+    // Next batch picks up the rest
+    let res = release_paused_distributions(
+        &mut app,
+        &cw_distribution_contract_addr,
+        &user_1_addr,
+        Some(1),
+    )
+    .unwrap();
+    assert_eq!(attribute(&res, "released_count"), "1");
+    assert_eq!(attribute(&res, "all_released"), "true");
+    assert_ne!(
+        query_user_reward(&app, &cw_distribution_contract_addr, user_2_id, user_1_id),
+        user_1_reward_before_release
+    );
+
+    let res =
+        release_paused_distributions(&mut app, &cw_distribution_contract_addr, &user_1_addr, None)
+            .unwrap();
+    assert_eq!(attribute(&res, "released_count"), "0");
+    assert_eq!(attribute(&res, "all_released"), "true");
+}
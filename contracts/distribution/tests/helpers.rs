@@ -0,0 +1,466 @@
+use anyhow::Result as AnyResult;
+use cosmwasm_std::{Addr, Coin, Decimal, Empty, Uint128, Uint64};
+use cw_multi_test::{App, AppBuilder, AppResponse, Contract, ContractWrapper, Executor};
+
+use distribution::{
+    contract::{execute, instantiate, migrate, query},
+    ContractError,
+};
+use distribution_pkg::msg::{
+    ClaimRewardsMsg, DistributeMsg, EnableMsg, ExecuteMsg, InstantiateMsg, QueryMsg,
+    QueryUserRewardMsg, ReleasePausedDistributionsMsg, UpdatePauseConfigMsg, UserRewardResponse,
+};
+use member::contract::{
+    execute as member_execute, instantiate as member_instantiate, migrate as member_migrate,
+    query as member_query,
+};
+use member_pkg::msg::{
+    AddToFeeDenomAllowlistMsg, BuyMembershipMsg, CostToBuyMembershipResponse, EnableMembershipMsg,
+    EnableMsg as MemberEnableMsg, EnableOpenRegistrationMsg, ExecuteMsg as MemberExecuteMsg,
+    InstantiateMsg as MemberInstantiateMsg, LinkSocialMediaMsg, QueryCostToBuyMembershipMsg,
+    QueryMsg as MemberQueryMsg, RegisterMsg, SellMembershipMsg,
+    UpdateConfigMsg as MemberUpdateConfigMsg,
+};
+use shared_pkg::rbac::{GrantRoleMsg, RevokeRoleMsg, Role};
+
+pub const FAUCET: &str = "faucet";
+
+pub const ADMIN: &str = "terra1";
+pub const REGISTRATION_ADMIN: &str = "terra2";
+pub const PROTOCOL_FEE_COLLECTOR: &str = "terra3";
+
+pub const USER_1: &str = "terra4";
+pub const USER_2: &str = "terra5";
+
+pub const SOCIAL_MEDIA_HANDLE_1: &str = "twitter1";
+pub const SOCIAL_MEDIA_HANDLE_2: &str = "twitter2";
+// Platform link_social_media links handles on
+pub const SOCIAL_MEDIA_PLATFORM: &str = "twitter";
+
+pub const FEE_DENOM: &str = "uluna";
+// Admin adds it to fee denom allowlist in tests that need a second denom
+pub const FEE_DENOM_2: &str = "uusdc";
+
+fn mock_app() -> App {
+    AppBuilder::new().build(|router, _, storage| {
+        router
+            .bank
+            .init_balance(
+                storage,
+                &Addr::unchecked(FAUCET),
+                vec![
+                    Coin {
+                        denom: FEE_DENOM.to_string(),
+                        // 1_000_000_000 uLuna i.e. 1k LUNA since 1 LUNA = 1_000_000 uLuna
+                        amount: Uint128::new(1_000_000_000),
+                    },
+                    Coin {
+                        denom: FEE_DENOM_2.to_string(),
+                        amount: Uint128::new(1_000_000_000),
+                    },
+                ],
+            )
+            .unwrap();
+    })
+}
+
+pub fn contract_cw_distribution() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(execute, instantiate, query).with_migrate(migrate);
+    Box::new(contract)
+}
+
+pub fn contract_cw_member() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(member_execute, member_instantiate, member_query)
+        .with_migrate(member_migrate);
+    Box::new(contract)
+}
+
+// Returns (app, distribution contract, member contract, admin, registration admin, user 1, user 2)
+pub fn proper_instantiate() -> (App, Addr, Addr, Addr, Addr, Addr, Addr) {
+    let mut app = mock_app();
+
+    let cw_member_contract_code_id = app.store_code(contract_cw_member());
+    let cw_member_contract_addr = app
+        .instantiate_contract(
+            cw_member_contract_code_id,
+            Addr::unchecked(ADMIN),
+            &MemberInstantiateMsg {
+                admin_addr: Some(ADMIN.to_string()),
+                registration_admin_addr: Some(REGISTRATION_ADMIN.to_string()),
+                protocol_fee_collector_addr: Some(PROTOCOL_FEE_COLLECTOR.to_string()),
+                fee_denom: Some(FEE_DENOM.to_string()),
+                protocol_fee_membership_trading_fee_bps: None,
+                protocol_fee_referral_fee_bps: None,
+                default_trading_fee_bps_of_membership: None,
+                default_membership_trading_fee_membership_issuer_fee_bps: None,
+                default_membership_trading_fee_membership_holder_fee_bps: None,
+                default_bonding_curve: None,
+                sigmoid_max_trade_amount: None,
+                default_holding_cap: None,
+                only_verified_buyers: None,
+                price_candle_interval: None,
+                user_fee_change_timelock: None,
+                user_fee_ranges: None,
+            },
+            &[],
+            "cw_member",
+            Some(ADMIN.to_string()),
+        )
+        .unwrap();
+
+    let cw_distribution_contract_code_id = app.store_code(contract_cw_distribution());
+    let cw_distribution_contract_addr = app
+        .instantiate_contract(
+            cw_distribution_contract_code_id,
+            Addr::unchecked(ADMIN),
+            &InstantiateMsg {
+                member_contract_addr: cw_member_contract_addr.to_string(),
+                admin_addr: Some(ADMIN.to_string()),
+            },
+            &[],
+            "cw_distribution",
+            // Admin is able to migrate contract
+            Some(ADMIN.to_string()),
+        )
+        .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        cw_member_contract_addr.clone(),
+        &MemberExecuteMsg::UpdateConfig(MemberUpdateConfigMsg {
+            distribution_contract_addr: Some(cw_distribution_contract_addr.to_string()),
+            ..MemberUpdateConfigMsg::default()
+        }),
+        &[],
+    )
+    .unwrap();
+
+    // Contracts are instantiated disabled, admin turns them on
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        cw_member_contract_addr.clone(),
+        &MemberExecuteMsg::Enable(MemberEnableMsg {}),
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        cw_member_contract_addr.clone(),
+        &MemberExecuteMsg::EnableOpenRegistration(EnableOpenRegistrationMsg {}),
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        cw_distribution_contract_addr.clone(),
+        &ExecuteMsg::Enable(EnableMsg {}),
+        &[],
+    )
+    .unwrap();
+
+    (
+        app,
+        cw_distribution_contract_addr,
+        cw_member_contract_addr,
+        Addr::unchecked(ADMIN),
+        Addr::unchecked(REGISTRATION_ADMIN),
+        Addr::unchecked(USER_1),
+        Addr::unchecked(USER_2),
+    )
+}
+
+pub fn get_fund_from_faucet(app: &mut App, addr: Addr, amount: Uint128) {
+    get_fund_from_faucet_in_denom(app, addr, amount, FEE_DENOM)
+}
+
+pub fn get_fund_from_faucet_in_denom(app: &mut App, addr: Addr, amount: Uint128, denom: &str) {
+    app.send_tokens(
+        Addr::unchecked(FAUCET),
+        addr,
+        &[Coin {
+            denom: denom.to_string(),
+            amount,
+        }],
+    )
+    .unwrap();
+}
+
+pub fn add_to_fee_denom_allowlist(
+    app: &mut App,
+    cw_member_contract_addr: &Addr,
+    sender_addr: &Addr,
+    denom: &str,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        sender_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &MemberExecuteMsg::AddToFeeDenomAllowlist(AddToFeeDenomAllowlistMsg {
+            denom: denom.to_string(),
+        }),
+        &[],
+    )
+}
+
+pub fn register_user(
+    app: &mut App,
+    cw_member_contract_addr: &Addr,
+    sender_addr: &Addr,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        sender_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &MemberExecuteMsg::Register(RegisterMsg {
+            referrer_user_id: None,
+            user_addr: None,
+        }),
+        &[],
+    )
+}
+
+// Link social media of user then enable its membership on default bonding curve and fee denom
+pub fn enable_membership(
+    app: &mut App,
+    cw_member_contract_addr: &Addr,
+    registration_admin_addr: &Addr,
+    user_id: Uint64,
+    social_media_handle: &str,
+) {
+    enable_membership_with_fee_denom(
+        app,
+        cw_member_contract_addr,
+        registration_admin_addr,
+        user_id,
+        social_media_handle,
+        FEE_DENOM,
+    )
+}
+
+// Same as enable_membership but membership is bought, sold and distributed in fee_denom
+pub fn enable_membership_with_fee_denom(
+    app: &mut App,
+    cw_member_contract_addr: &Addr,
+    registration_admin_addr: &Addr,
+    user_id: Uint64,
+    social_media_handle: &str,
+    fee_denom: &str,
+) {
+    app.execute_contract(
+        registration_admin_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &MemberExecuteMsg::LinkSocialMedia(LinkSocialMediaMsg {
+            user_id,
+            platform: SOCIAL_MEDIA_PLATFORM.to_string(),
+            social_media_handle: social_media_handle.to_string(),
+        }),
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        registration_admin_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &MemberExecuteMsg::EnableMembership(EnableMembershipMsg {
+            user_id,
+            bonding_curve: None,
+            fee_denom: Some(fee_denom.to_string()),
+        }),
+        &[],
+    )
+    .unwrap();
+}
+
+pub fn buy_membership(
+    app: &mut App,
+    cw_member_contract_addr: &Addr,
+    sender_addr: &Addr,
+    membership_issuer_user_id: Uint64,
+    amount: Uint128,
+) -> AnyResult<AppResponse> {
+    let cost: CostToBuyMembershipResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr,
+            &MemberQueryMsg::QueryCostToBuyMembership(QueryCostToBuyMembershipMsg {
+                membership_issuer_user_id,
+                amount,
+            }),
+        )
+        .unwrap();
+    get_fund_from_faucet_in_denom(
+        app,
+        sender_addr.clone(),
+        cost.total_needed_from_user,
+        &cost.fee_denom,
+    );
+    app.execute_contract(
+        sender_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &MemberExecuteMsg::BuyMembership(BuyMembershipMsg {
+            membership_issuer_user_id,
+            amount,
+            max_total_cost: None,
+        }),
+        &[Coin {
+            denom: cost.fee_denom,
+            amount: cost.total_needed_from_user,
+        }],
+    )
+}
+
+// Fees are deducted from proceeds so seller does not need to send any funds
+pub fn sell_membership(
+    app: &mut App,
+    cw_member_contract_addr: &Addr,
+    sender_addr: &Addr,
+    membership_issuer_user_id: Uint64,
+    amount: Uint128,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        sender_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &MemberExecuteMsg::SellMembership(SellMembershipMsg {
+            membership_issuer_user_id,
+            amount,
+            min_proceeds: None,
+            deduct_fees_from_proceeds: Some(true),
+        }),
+        &[],
+    )
+}
+
+pub fn update_pause_config(
+    app: &mut App,
+    cw_distribution_contract_addr: &Addr,
+    sender_addr: &Addr,
+    distribute_paused: Option<bool>,
+    claim_reward_paused: Option<bool>,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        sender_addr.clone(),
+        cw_distribution_contract_addr.clone(),
+        &ExecuteMsg::UpdatePauseConfig(UpdatePauseConfigMsg {
+            distribute_paused,
+            claim_reward_paused,
+        }),
+        &[],
+    )
+}
+
+pub fn release_paused_distributions(
+    app: &mut App,
+    cw_distribution_contract_addr: &Addr,
+    sender_addr: &Addr,
+    limit: Option<u32>,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        sender_addr.clone(),
+        cw_distribution_contract_addr.clone(),
+        &ExecuteMsg::ReleasePausedDistributions(ReleasePausedDistributionsMsg { limit }),
+        &[],
+    )
+}
+
+pub fn grant_role(
+    app: &mut App,
+    cw_distribution_contract_addr: &Addr,
+    sender_addr: &Addr,
+    addr: &Addr,
+    role: Role,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        sender_addr.clone(),
+        cw_distribution_contract_addr.clone(),
+        &ExecuteMsg::GrantRole(GrantRoleMsg {
+            addr: addr.to_string(),
+            role,
+        }),
+        &[],
+    )
+}
+
+pub fn revoke_role(
+    app: &mut App,
+    cw_distribution_contract_addr: &Addr,
+    sender_addr: &Addr,
+    addr: &Addr,
+    role: Role,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        sender_addr.clone(),
+        cw_distribution_contract_addr.clone(),
+        &ExecuteMsg::RevokeRole(RevokeRoleMsg {
+            addr: addr.to_string(),
+            role,
+        }),
+        &[],
+    )
+}
+
+pub fn distribute(
+    app: &mut App,
+    cw_distribution_contract_addr: &Addr,
+    sender_addr: &Addr,
+    membership_issuer_user_id: Uint64,
+    index_increment: Decimal,
+    fee: Coin,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        sender_addr.clone(),
+        cw_distribution_contract_addr.clone(),
+        &ExecuteMsg::Distribute(DistributeMsg {
+            membership_issuer_user_id,
+            index_increment,
+        }),
+        &[fee],
+    )
+}
+
+pub fn claim_reward(
+    app: &mut App,
+    cw_distribution_contract_addr: &Addr,
+    sender_addr: &Addr,
+    membership_issuer_user_id: Uint64,
+    user_id: Uint64,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        sender_addr.clone(),
+        cw_distribution_contract_addr.clone(),
+        &ExecuteMsg::ClaimReward(ClaimRewardsMsg {
+            membership_issuer_user_id,
+            user_id,
+        }),
+        &[],
+    )
+}
+
+pub fn query_user_reward(
+    app: &App,
+    cw_distribution_contract_addr: &Addr,
+    membership_issuer_user_id: Uint64,
+    user_id: Uint64,
+) -> Vec<Coin> {
+    let res: UserRewardResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_distribution_contract_addr,
+            &QueryMsg::QueryUserReward(QueryUserRewardMsg {
+                membership_issuer_user_id,
+                user_id,
+            }),
+        )
+        .unwrap();
+    res.rewards
+}
+
+pub fn assert_err(res: AnyResult<AppResponse>, err: ContractError) {
+    match res {
+        Ok(_) => panic!("Result was not an error"),
+        Err(generic_err) => {
+            let contract_err: ContractError = generic_err.downcast().unwrap();
+            assert_eq!(contract_err, err);
+        }
+    }
+}
+
+pub fn assert_balance(app: &App, user_addr: &Addr, expected_balance: Uint128, denom: &str) {
+    let balance = app.wrap().query_balance(user_addr, denom).unwrap();
+    assert_eq!(balance.amount, expected_balance);
+}
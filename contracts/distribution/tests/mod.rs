@@ -0,0 +1,3 @@
+mod admin;
+mod helpers;
+mod user;
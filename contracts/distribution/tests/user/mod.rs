@@ -0,0 +1,2 @@
+mod test_claim_reward_emits_event_only_when_reward_claimed;
+mod test_claim_reward_pays_each_membership_in_its_fee_denom;
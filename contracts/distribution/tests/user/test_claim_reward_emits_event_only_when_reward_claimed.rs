@@ -0,0 +1,96 @@
+use cosmwasm_std::{Uint128, Uint64};
+
+use crate::helpers::{
+    buy_membership, claim_reward, enable_membership, proper_instantiate, query_user_reward,
+    register_user, FEE_DENOM, SOCIAL_MEDIA_HANDLE_1,
+};
+
+const REWARD_CLAIM_EVENT: &str = "wasm-cw_friend.reward_claim";
+
+#[test]
+fn test_claim_reward_emits_event_only_when_reward_claimed() {
+    let (
+        mut app,
+        cw_distribution_contract_addr,
+        cw_member_contract_addr,
+        _,
+        registration_admin_addr,
+        user_1_addr,
+        user_2_addr,
+    ) = proper_instantiate();
+    let user_1_id = Uint64::one();
+    let user_2_id = Uint64::from(2_u8);
+    let uint_128_amount_10 = Uint128::from(10_u8);
+
+    register_user(&mut app, &cw_member_contract_addr, &user_1_addr).unwrap();
+    register_user(&mut app, &cw_member_contract_addr, &user_2_addr).unwrap();
+    enable_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+        SOCIAL_MEDIA_HANDLE_1,
+    );
+    // User 2 earns part of the all members fee of its second buy
+    for _ in 0..2 {
+        buy_membership(
+            &mut app,
+            &cw_member_contract_addr,
+            &user_2_addr,
+            user_1_id,
+            uint_128_amount_10,
+        )
+        .unwrap();
+    }
+    let reward = query_user_reward(&app, &cw_distribution_contract_addr, user_1_id, user_2_id);
+    assert_eq!(reward.len(), 1);
+    assert!(reward[0].amount > Uint128::zero());
+
+    // ================ Claiming reward emits event with claimed coins ================
+    let res = claim_reward(
+        &mut app,
+        &cw_distribution_contract_addr,
+        &user_2_addr,
+        user_1_id,
+        user_2_id,
+    )
+    .unwrap();
+    let event = res
+        .events
+        .iter()
+        .find(|event| event.ty == REWARD_CLAIM_EVENT)
+        .unwrap();
+    let attribute = |key: &str| {
+        event
+            .attributes
+            .iter()
+            .find(|attr| attr.key == key)
+            .unwrap()
+            .value
+            .clone()
+    };
+    assert_eq!(
+        attribute("membership_issuer_user_id"),
+        user_1_id.to_string()
+    );
+    assert_eq!(attribute("user_id"), user_2_id.to_string());
+    assert_eq!(attribute("recipient_addr"), user_2_addr.to_string());
+    assert_eq!(
+        attribute("rewards"),
+        format!("{}{FEE_DENOM}", reward[0].amount)
+    );
+
+    // ================ Nothing left to claim, no event ================
+    let res = claim_reward(
+        &mut app,
+        &cw_distribution_contract_addr,
+        &user_2_addr,
+        user_1_id,
+        user_2_id,
+    )
+    .unwrap();
+    assert!(res
+        .events
+        .iter()
+        .all(|event| event.ty != REWARD_CLAIM_EVENT));
+}
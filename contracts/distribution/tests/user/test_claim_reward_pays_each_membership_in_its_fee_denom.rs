@@ -0,0 +1,122 @@
+use cosmwasm_std::{Uint128, Uint64};
+
+use crate::helpers::{
+    add_to_fee_denom_allowlist, assert_balance, buy_membership, claim_reward, enable_membership,
+    enable_membership_with_fee_denom, proper_instantiate, query_user_reward, register_user,
+    FEE_DENOM, FEE_DENOM_2, SOCIAL_MEDIA_HANDLE_1, SOCIAL_MEDIA_HANDLE_2,
+};
+
+#[test]
+fn test_claim_reward_pays_each_membership_in_its_fee_denom() {
+    let (
+        mut app,
+        cw_distribution_contract_addr,
+        cw_member_contract_addr,
+        admin_addr,
+        registration_admin_addr,
+        user_1_addr,
+        user_2_addr,
+    ) = proper_instantiate();
+    let user_1_id = Uint64::one();
+    let user_2_id = Uint64::from(2_u8);
+    let uint_128_amount_10 = Uint128::from(10_u8);
+
+    add_to_fee_denom_allowlist(&mut app, &cw_member_contract_addr, &admin_addr, FEE_DENOM_2)
+        .unwrap();
+    register_user(&mut app, &cw_member_contract_addr, &user_1_addr).unwrap();
+    register_user(&mut app, &cw_member_contract_addr, &user_2_addr).unwrap();
+    enable_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+        SOCIAL_MEDIA_HANDLE_1,
+    );
+    enable_membership_with_fee_denom(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_2_id,
+        SOCIAL_MEDIA_HANDLE_2,
+        FEE_DENOM_2,
+    );
+
+    // User 2 holds user 1's membership priced in FEE_DENOM
+    // User 1 holds user 2's membership priced in FEE_DENOM_2
+    for _ in 0..2 {
+        buy_membership(
+            &mut app,
+            &cw_member_contract_addr,
+            &user_2_addr,
+            user_1_id,
+            uint_128_amount_10,
+        )
+        .unwrap();
+        buy_membership(
+            &mut app,
+            &cw_member_contract_addr,
+            &user_1_addr,
+            user_2_id,
+            uint_128_amount_10,
+        )
+        .unwrap();
+    }
+
+    // ================ Reward of each membership is only in its fee denom ================
+    let user_2_reward =
+        query_user_reward(&app, &cw_distribution_contract_addr, user_1_id, user_2_id);
+    assert_eq!(user_2_reward.len(), 1);
+    assert_eq!(user_2_reward[0].denom, FEE_DENOM);
+    assert!(user_2_reward[0].amount > Uint128::zero());
+
+    let user_1_reward =
+        query_user_reward(&app, &cw_distribution_contract_addr, user_2_id, user_1_id);
+    assert_eq!(user_1_reward.len(), 1);
+    assert_eq!(user_1_reward[0].denom, FEE_DENOM_2);
+    assert!(user_1_reward[0].amount > Uint128::zero());
+
+    // ================ Claiming pays out reward in the membership's denom only ================
+    let user_1_balance_before = app.wrap().query_balance(&user_1_addr, FEE_DENOM).unwrap();
+    let user_1_balance_2_before = app.wrap().query_balance(&user_1_addr, FEE_DENOM_2).unwrap();
+    claim_reward(
+        &mut app,
+        &cw_distribution_contract_addr,
+        &user_1_addr,
+        user_2_id,
+        user_1_id,
+    )
+    .unwrap();
+    assert_balance(
+        &app,
+        &user_1_addr,
+        user_1_balance_2_before.amount + user_1_reward[0].amount,
+        FEE_DENOM_2,
+    );
+    assert_balance(&app, &user_1_addr, user_1_balance_before.amount, FEE_DENOM);
+
+    let user_2_balance_before = app.wrap().query_balance(&user_2_addr, FEE_DENOM).unwrap();
+    claim_reward(
+        &mut app,
+        &cw_distribution_contract_addr,
+        &user_2_addr,
+        user_1_id,
+        user_2_id,
+    )
+    .unwrap();
+    assert_balance(
+        &app,
+        &user_2_addr,
+        user_2_balance_before.amount + user_2_reward[0].amount,
+        FEE_DENOM,
+    );
+
+    // Nothing left to claim in either denom
+    assert_eq!(
+        query_user_reward(&app, &cw_distribution_contract_addr, user_2_id, user_1_id)[0].amount,
+        Uint128::zero()
+    );
+    assert_eq!(
+        query_user_reward(&app, &cw_distribution_contract_addr, user_1_id, user_2_id)[0].amount,
+        Uint128::zero()
+    );
+}
@@ -1,21 +1,42 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    entry_point, to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Uint64,
+    to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Uint128, Uint64,
 };
 use cw2::set_contract_version;
 
 use member_pkg::{
-    config::{Config, FeeConfig, ProtocolFeeConfig},
-    msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
+    config::{Config, FeeConfig, MembershipEligibility, PauseConfig, ProtocolFeeConfig},
+    msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg},
+};
+use shared_pkg::{
+    fee_share_config::FeeShareConfig,
+    migrate::{assert_can_migrate, migrate_state},
+    rbac::{grant_role, Role},
+    timelock::{normalize_timelock, DEFAULT_USER_FEE_CHANGE_TIMELOCK},
 };
-use shared_pkg::fee_share_config::FeeShareConfig;
 
 use crate::state::{CONFIG, NEXT_USER_ID};
-use crate::util::fee_share::assert_config_fee_share_sum_to_100;
+use crate::util::{
+    config::assert_contract_enabled,
+    fee_denom::load_membership_fee_denom,
+    fee_range::{assert_default_fee_config_in_ranges, assert_user_fee_ranges_valid},
+    fee_share::{assert_config_fee_share_sum_to_10000, assert_referral_fee_bps_within_10000},
+    migrate::STATE_MIGRATIONS,
+    price::{assert_bonding_curve_valid, assert_sigmoid_max_trade_amount_valid},
+};
 use crate::{execute, query, ContractError};
 
 pub const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
 pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+// Admin can add or remove platforms after instantiation
+pub const DEFAULT_SOCIAL_MEDIA_PLATFORM_ALLOWLIST: [&str; 4] =
+    ["twitter", "github", "farcaster", "telegram"];
+
+// Admin can raise it if gas allows, or lower it if sigmoid trades run out of gas
+pub const DEFAULT_SIGMOID_MAX_TRADE_AMOUNT: u128 = 1_000;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -29,8 +50,12 @@ pub fn instantiate(
         CONTRACT_VERSION,
     )?;
 
+    let fee_denom = msg.fee_denom.unwrap_or("uluna".to_string());
+
     let config = Config {
         enabled: false,
+        // Nothing is paused by default
+        pause_config: PauseConfig::default(),
         enable_open_registration: false,
         // Default to sender
         admin_addr: deps
@@ -41,6 +66,8 @@ pub fn instantiate(
             &msg.registration_admin_addr
                 .unwrap_or(info.sender.to_string()),
         )?,
+        // Attestation is disabled until admin sets a pubkey
+        attestation_pubkey: None,
         distribution_contract_addr: None,
         // Default to sender
         protocol_fee_collector_addr: deps.api.addr_validate(
@@ -48,40 +75,83 @@ pub fn instantiate(
                 .unwrap_or(info.sender.to_string()),
         )?,
         default_fee_config: FeeConfig {
-            fee_denom: msg.fee_denom.unwrap_or("uluna".to_string()),
+            fee_denom: fee_denom.clone(),
             // By default, pay 5% of the total price of buying or selling amount of key to buy or sell
-            trading_fee_percentage_of_membership: msg
-                .default_trading_fee_percentage_of_membership
-                .unwrap_or(Uint64::from(5_u64)),
+            trading_fee_bps_of_membership: msg
+                .default_trading_fee_bps_of_membership
+                .unwrap_or(Uint64::from(500_u64)),
+            // Default to no cap
+            holding_cap: msg.default_holding_cap,
         },
         protocol_fee_config: ProtocolFeeConfig {
-            // Default to 10%
+            // Default to 1_000 bps, i.e. 10%
             // e.g. user pays 10 LUNA to buy 5 keys
-            // Assume key issuer uses default_trading_fee_percentage_of_key which is 5%
+            // Assume key issuer uses default_trading_fee_bps_of_key which is 500 bps, i.e. 5%
             // And key issuer uses default_key_trading_fee_share_config which is 50% for key issuer and 50% for key holder
             // In total user pays 10.55 LUNA
             // 0.25 LUNA goes to key issuer, 0.25 LUNA gets splitted by all key holders proportionally
             // 0.05 (because 10% of 0.5 is 0.05) LUNA goes to protocol fee collector
-            membership_trading_fee_percentage: msg
-                .protocol_fee_membership_trading_fee_percentage
-                .unwrap_or(Uint64::from(10_u64)),
+            membership_trading_fee_bps: msg
+                .protocol_fee_membership_trading_fee_bps
+                .unwrap_or(Uint64::from(1_000_u64)),
+            // Default to 0, admin can turn on referral program later
+            // e.g. 2_000 bps means 20% of the protocol fee goes to trader's referrer
+            referral_fee_bps: msg.protocol_fee_referral_fee_bps.unwrap_or(Uint64::zero()),
         },
         default_fee_share_config: FeeShareConfig {
             // Default 80% goes to membership issuer
-            share_to_issuer_percentage: msg
-                .default_membership_trading_fee_membership_issuer_fee_percentage
-                .unwrap_or(Uint64::from(80_u64)),
+            share_to_issuer_bps: msg
+                .default_membership_trading_fee_membership_issuer_fee_bps
+                .unwrap_or(Uint64::from(8_000_u64)),
             // Default 20% goes to all members
-            share_to_all_members_percentage: msg
-                .default_membership_trading_fee_membership_holder_fee_percentage
-                .unwrap_or(Uint64::from(20_u64)),
+            share_to_all_members_bps: msg
+                .default_membership_trading_fee_membership_holder_fee_bps
+                .unwrap_or(Uint64::from(2_000_u64)),
         },
+        // Default to the original quadratic curve
+        default_bonding_curve: msg.default_bonding_curve.unwrap_or_default(),
+        sigmoid_max_trade_amount: msg
+            .sigmoid_max_trade_amount
+            .unwrap_or(Uint128::from(DEFAULT_SIGMOID_MAX_TRADE_AMOUNT)),
+        // Default fee denom is always allowed, admin can add more denoms later
+        fee_denom_allowlist: vec![fee_denom],
+        // Default to allow anyone to buy membership
+        only_verified_buyers: msg.only_verified_buyers.unwrap_or(false),
+        // Default to hourly candles
+        price_candle_interval: msg.price_candle_interval.unwrap_or_default(),
+        social_media_platform_allowlist: DEFAULT_SOCIAL_MEDIA_PLATFORM_ALLOWLIST
+            .iter()
+            .map(|platform| platform.to_string())
+            .collect(),
+        // Default to any platform in the allowlist
+        membership_eligibility: MembershipEligibility::default(),
+        // Default to no time-lock, admin can set one after instantiation
+        config_change_timelock: None,
+        user_fee_change_timelock: normalize_timelock(
+            msg.user_fee_change_timelock
+                .unwrap_or(DEFAULT_USER_FEE_CHANGE_TIMELOCK),
+        ),
+        // Default to 0% to 100% for every issuer fee
+        user_fee_ranges: msg.user_fee_ranges.unwrap_or_default(),
     };
 
     NEXT_USER_ID.save(deps.storage, &Uint64::one())?;
 
+    // Addresses in config always hold their role, admin can grant the same role to more addresses later
+    grant_role(deps.storage, &config.admin_addr, &Role::Admin)?;
+    grant_role(
+        deps.storage,
+        &config.registration_admin_addr,
+        &Role::RegistrationOperator,
+    )?;
+
     CONFIG.save(deps.storage, &config)?;
-    assert_config_fee_share_sum_to_100(config.default_fee_share_config)?;
+    assert_config_fee_share_sum_to_10000(config.default_fee_share_config)?;
+    assert_referral_fee_bps_within_10000(config.protocol_fee_config)?;
+    assert_bonding_curve_valid(&config.default_bonding_curve)?;
+    assert_sigmoid_max_trade_amount_valid(config.sigmoid_max_trade_amount)?;
+    assert_user_fee_ranges_valid(&config.user_fee_ranges)?;
+    assert_default_fee_config_in_ranges(&config.default_fee_config, &config.user_fee_ranges)?;
 
     Ok(Response::new())
 }
@@ -89,13 +159,11 @@ pub fn instantiate(
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
-    // TODO: update this accordingly after user can set their own fee denom
-    let fee_denom = config.default_fee_config.fee_denom.as_str();
 
     match msg {
         ExecuteMsg::Enable(_) => {
@@ -116,62 +184,216 @@ pub fn execute(
         }
         ExecuteMsg::UpdateConfig(data) => {
             cw_utils::nonpayable(&info)?;
-            execute::config::update_config(deps, info, data)
+            execute::config::update_config(deps, env, info, data)
+        }
+        ExecuteMsg::ExecutePendingConfigChange(data) => {
+            cw_utils::nonpayable(&info)?;
+            execute::config::execute_pending_config_change(deps, env, info, data)
+        }
+        ExecuteMsg::CancelPendingConfigChange(data) => {
+            cw_utils::nonpayable(&info)?;
+            execute::config::cancel_pending_config_change(deps, info, data)
+        }
+        ExecuteMsg::ProposeNewAdmin(data) => {
+            cw_utils::nonpayable(&info)?;
+            execute::config::propose_new_admin(deps, env, info, data)
+        }
+        ExecuteMsg::AcceptAdmin(_) => {
+            cw_utils::nonpayable(&info)?;
+            execute::config::accept_admin(deps, env, info)
         }
-        ExecuteMsg::Register(_) => {
+        ExecuteMsg::CancelAdminProposal(_) => {
             cw_utils::nonpayable(&info)?;
-            execute::user::register(deps, info)
+            execute::config::cancel_admin_proposal(deps, info)
+        }
+        ExecuteMsg::GrantRole(data) => {
+            cw_utils::nonpayable(&info)?;
+            execute::config::grant_role(deps, info, data)
+        }
+        ExecuteMsg::RevokeRole(data) => {
+            cw_utils::nonpayable(&info)?;
+            execute::config::revoke_role(deps, info, data, config)
+        }
+        ExecuteMsg::UpdatePauseConfig(data) => {
+            cw_utils::nonpayable(&info)?;
+            execute::config::update_pause_config(deps, info, data)
+        }
+        ExecuteMsg::AddToFeeDenomAllowlist(data) => {
+            cw_utils::nonpayable(&info)?;
+            execute::config::add_to_fee_denom_allowlist(deps, info, data)
+        }
+        ExecuteMsg::RemoveFromFeeDenomAllowlist(data) => {
+            cw_utils::nonpayable(&info)?;
+            execute::config::remove_from_fee_denom_allowlist(deps, info, data)
+        }
+        ExecuteMsg::AddToSocialMediaPlatformAllowlist(data) => {
+            cw_utils::nonpayable(&info)?;
+            execute::config::add_to_social_media_platform_allowlist(deps, info, data)
+        }
+        ExecuteMsg::RemoveFromSocialMediaPlatformAllowlist(data) => {
+            cw_utils::nonpayable(&info)?;
+            execute::config::remove_from_social_media_platform_allowlist(deps, info, data)
+        }
+        ExecuteMsg::Register(data) => {
+            assert_contract_enabled(&config)?;
+            if config.pause_config.registration_paused {
+                return Err(ContractError::RegistrationPaused {});
+            }
+            cw_utils::nonpayable(&info)?;
+            execute::user::register(deps, info, data, config)
         }
         ExecuteMsg::LinkSocialMedia(data) => {
+            assert_contract_enabled(&config)?;
+            cw_utils::nonpayable(&info)?;
+            execute::user::link_social_media(deps, env, info, data, config)
+        }
+        ExecuteMsg::LinkSocialMediaWithAttestation(data) => {
+            assert_contract_enabled(&config)?;
+            cw_utils::nonpayable(&info)?;
+            execute::user::link_social_media_with_attestation(deps, env, info, data, config)
+        }
+        ExecuteMsg::UnlinkSocialMedia(data) => {
+            assert_contract_enabled(&config)?;
             cw_utils::nonpayable(&info)?;
-            execute::user::link_social_media(deps, info, data, config)
+            execute::user::unlink_social_media(deps, info, data)
         }
         ExecuteMsg::EnableMembership(data) => {
+            assert_contract_enabled(&config)?;
+            cw_utils::nonpayable(&info)?;
+            execute::user::enable_membership(deps, env, info, data, config)
+        }
+        ExecuteMsg::MigrateUser(data) => {
+            assert_contract_enabled(&config)?;
+            cw_utils::nonpayable(&info)?;
+            execute::user::migrate_user(deps, info, data)
+        }
+        ExecuteMsg::AcceptMigrateUser(data) => {
+            assert_contract_enabled(&config)?;
+            cw_utils::nonpayable(&info)?;
+            execute::user::accept_migrate_user(deps, info, data)
+        }
+        ExecuteMsg::SetHotAddr(data) => {
+            assert_contract_enabled(&config)?;
             cw_utils::nonpayable(&info)?;
-            execute::user::enable_membership(deps, info, data, config)
+            execute::user::set_hot_addr(deps, info, data)
+        }
+        ExecuteMsg::RemoveHotAddr(_) => {
+            assert_contract_enabled(&config)?;
+            cw_utils::nonpayable(&info)?;
+            execute::user::remove_hot_addr(deps, info)
         }
         ExecuteMsg::UpdateUserConfig(data) => {
+            assert_contract_enabled(&config)?;
             cw_utils::nonpayable(&info)?;
-            execute::user::update_user_config(deps, info, data)
+            execute::user::update_user_config(deps, env, info, data)
         }
         ExecuteMsg::BuyMembership(data) => {
-            let user_paid_amount = cw_utils::must_pay(&info, fee_denom)?;
+            assert_contract_enabled(&config)?;
+            if config.pause_config.buy_membership_paused {
+                return Err(ContractError::BuyMembershipPaused {});
+            }
+            // Membership is traded in the denom its issuer picked
+            let fee_denom =
+                load_membership_fee_denom(deps.as_ref(), data.membership_issuer_user_id.u64())?;
+            let user_paid_amount = cw_utils::must_pay(&info, &fee_denom)?;
             execute::member::buy_membership(
                 deps,
+                env,
                 info,
                 data,
                 config.clone(),
                 user_paid_amount,
-                fee_denom.to_string(),
+                fee_denom,
             )
         }
         ExecuteMsg::SellMembership(data) => {
-            let user_paid_amount = cw_utils::must_pay(&info, fee_denom)?;
+            assert_contract_enabled(&config)?;
+            if config.pause_config.sell_membership_paused {
+                return Err(ContractError::SellMembershipPaused {});
+            }
+            let fee_denom =
+                load_membership_fee_denom(deps.as_ref(), data.membership_issuer_user_id.u64())?;
+            // Seller pays nothing upfront when fees are deducted from proceeds
+            let user_paid_amount = if data.deduct_fees_from_proceeds.unwrap_or(false) {
+                cw_utils::nonpayable(&info)?;
+                Uint128::zero()
+            } else {
+                cw_utils::must_pay(&info, &fee_denom)?
+            };
             execute::member::sell_membership(
                 deps,
+                env,
                 info,
                 data,
                 config.clone(),
                 user_paid_amount,
-                fee_denom.to_string(),
+                fee_denom,
             )
         }
     }
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn migrate(mut deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let previous_version = assert_can_migrate(
+        deps.storage,
+        &format!("crates.io:{CONTRACT_NAME}"),
+        CONTRACT_VERSION,
+    )?;
+
+    migrate_state(deps.branch(), &env, &previous_version, STATE_MIGRATIONS)?;
+
+    set_contract_version(
+        deps.storage,
+        format!("crates.io:{CONTRACT_NAME}"),
+        CONTRACT_VERSION,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute("previous_version", previous_version.to_string())
+        .add_attribute("new_version", CONTRACT_VERSION))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     let config = CONFIG.load(deps.storage)?;
     match msg {
         QueryMsg::QueryConfig(_) => to_binary(&query::config::query_config(deps)?),
-        QueryMsg::QueryUserByAddr(data) => to_binary(&query::user::query_user_by_addr(deps, data)?),
-        QueryMsg::QueryUserByID(data) => to_binary(&query::user::query_user_by_id(deps, data)?),
+        QueryMsg::QueryPauseStatus(_) => to_binary(&query::config::query_pause_status(deps)?),
+        QueryMsg::QueryRoles(data) => to_binary(&query::config::query_roles(deps, data)?),
+        QueryMsg::QueryUserFeeRanges(_) => to_binary(&query::config::query_user_fee_ranges(deps)?),
+        QueryMsg::QueryPendingConfigChanges(_) => {
+            to_binary(&query::config::query_pending_config_changes(deps)?)
+        }
+        QueryMsg::QueryUserByAddr(data) => {
+            to_binary(&query::user::query_user_by_addr(deps, env, data)?)
+        }
+        QueryMsg::QueryUserByID(data) => {
+            to_binary(&query::user::query_user_by_id(deps, env, data)?)
+        }
+        QueryMsg::QueryUsersByIDs(data) => {
+            to_binary(&query::user::query_users_by_ids(deps, env, data)?)
+        }
+        QueryMsg::QueryUsersByAddrs(data) => {
+            to_binary(&query::user::query_users_by_addrs(deps, env, data)?)
+        }
         QueryMsg::QueryUsersPaginatedByAddr(data) => {
             to_binary(&query::user::query_users_paginated_by_addr(deps, data)?)
         }
         QueryMsg::QueryUsersPaginatedByID(data) => {
             to_binary(&query::user::query_users_paginated_by_id(deps, data)?)
         }
+        QueryMsg::QueryUserBySocialMediaHandle(data) => to_binary(
+            &query::user::query_user_by_social_media_handle(deps, env, data)?,
+        ),
+        QueryMsg::QueryUsersBySocialMediaHandlePrefix(data) => to_binary(
+            &query::user::query_users_by_social_media_handle_prefix(deps, data)?,
+        ),
+        QueryMsg::QueryPendingUserMigration(data) => {
+            to_binary(&query::user::query_pending_user_migration(deps, data)?)
+        }
+        QueryMsg::QueryReferees(data) => to_binary(&query::user::query_referees(deps, data)?),
         QueryMsg::QueryMembershipSupply(data) => {
             to_binary(&query::member::query_membership_supply(deps, data)?)
         }
@@ -184,10 +406,17 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
             to_binary(&query::member::query_memberships(deps, data)?)
         }
         QueryMsg::QueryCostToBuyMembership(data) => to_binary(
-            &query::cost::query_cost_to_buy_membership(deps, data, config)?,
+            &query::cost::query_cost_to_buy_membership(deps, env, data, config)?,
         ),
         QueryMsg::QueryCostToSellMembership(data) => to_binary(
-            &query::cost::query_cost_to_sell_membership(deps, data, config)?,
+            &query::cost::query_cost_to_sell_membership(deps, env, data, config)?,
         ),
+        QueryMsg::QueryTradeHistory(data) => {
+            to_binary(&query::trade::query_trade_history(deps, data)?)
+        }
+        QueryMsg::QueryPriceCandles(data) => {
+            to_binary(&query::trade::query_price_candles(deps, data, config)?)
+        }
+        QueryMsg::QueryTwap(data) => to_binary(&query::trade::query_twap(deps, env, data)?),
     }
 }
@@ -1,5 +1,6 @@
-use cosmwasm_std::{StdError, Uint128, Uint64};
+use cosmwasm_std::{OverflowError, StdError, Uint128, Uint64};
 use cw_utils::PaymentError;
+use shared_pkg::{migrate::MigrateError, pricing::PricingError};
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
@@ -10,6 +11,15 @@ pub enum ContractError {
     #[error("{0}")]
     Payment(#[from] PaymentError),
 
+    #[error("{0}")]
+    Migrate(#[from] MigrateError),
+
+    #[error("{0}")]
+    Overflow(#[from] OverflowError),
+
+    #[error("{0}")]
+    Pricing(#[from] PricingError),
+
     // ========================== ADMIN ==========================
     #[error("Unauthorized")]
     Unauthorized {},
@@ -29,6 +39,92 @@ pub enum ContractError {
     #[error("Only admin can update config")]
     OnlyAdminCanUpdateConfig {},
 
+    #[error("Only admin can grant role")]
+    OnlyAdminCanGrantRole {},
+
+    #[error("Only admin can revoke role")]
+    OnlyAdminCanRevokeRole {},
+
+    #[error("Address already has role {role}")]
+    AddressAlreadyHasRole { role: String },
+
+    #[error("Address does not have role {role}")]
+    AddressDoesNotHaveRole { role: String },
+
+    #[error("Cannot revoke role {role} from address set in config, update config instead")]
+    CannotRevokeRoleOfConfigAddress { role: String },
+
+    #[error("Only admin can propose new admin")]
+    OnlyAdminCanProposeNewAdmin {},
+
+    #[error("Only admin can cancel admin proposal")]
+    OnlyAdminCanCancelAdminProposal {},
+
+    #[error("Only proposed admin can accept admin")]
+    OnlyProposedAdminCanAcceptAdmin {},
+
+    #[error("No pending admin proposal")]
+    NoPendingAdminProposal {},
+
+    #[error("Admin proposal expired")]
+    AdminProposalExpired {},
+
+    #[error("Only admin, or fee manager for fee only changes, can execute pending config change")]
+    OnlyAdminOrFeeManagerCanExecutePendingConfigChange {},
+
+    #[error("Only admin, or fee manager for fee only changes, can cancel pending config change")]
+    OnlyAdminOrFeeManagerCanCancelPendingConfigChange {},
+
+    #[error("Pending config change {id} does not exist")]
+    PendingConfigChangeNotExist { id: Uint64 },
+
+    #[error("Pending config change is time-locked until {executable_at}")]
+    PendingConfigChangeStillTimeLocked { executable_at: String },
+
+    #[error("Only admin can update fee denom allowlist")]
+    OnlyAdminCanUpdateFeeDenomAllowlist {},
+
+    #[error("Fee denom {denom:?} already in allowlist")]
+    FeeDenomAlreadyInAllowlist { denom: String },
+
+    #[error("Fee denom {denom:?} not in allowlist")]
+    FeeDenomNotInAllowlist { denom: String },
+
+    #[error("Cannot remove default fee denom from allowlist")]
+    CannotRemoveDefaultFeeDenomFromAllowlist {},
+
+    #[error("Only admin can update social media platform allowlist")]
+    OnlyAdminCanUpdateSocialMediaPlatformAllowlist {},
+
+    #[error("Social media platform {platform:?} already in allowlist")]
+    SocialMediaPlatformAlreadyInAllowlist { platform: String },
+
+    #[error("Social media platform {platform:?} not in allowlist")]
+    SocialMediaPlatformNotInAllowlist { platform: String },
+
+    #[error(
+        "Cannot remove social media platform required by membership eligibility from allowlist"
+    )]
+    CannotRemoveMembershipEligibilityPlatformFromAllowlist {},
+
+    #[error("Only admin can update pause config")]
+    OnlyAdminCanUpdatePauseConfig {},
+
+    #[error("Contract disabled")]
+    ContractDisabled {},
+
+    #[error("Registration is paused")]
+    RegistrationPaused {},
+
+    #[error("Buying membership is paused")]
+    BuyMembershipPaused {},
+
+    #[error("Selling membership is paused")]
+    SellMembershipPaused {},
+
+    #[error("Open registration disabled, only registration admin can register on behalf of user")]
+    OpenRegistrationDisabled {},
+
     #[error("Address already registered")]
     AddressAlreadyRegistered {},
 
@@ -36,6 +132,9 @@ pub enum ContractError {
     UserIDAlreadyUsedDuringRegistration {},
 
     // ========================== REGISTRATION ADMIN ==========================
+    #[error("Only registration admin can register on behalf of user")]
+    OnlyRegistrationAdminCanRegisterOnBehalfOfUser {},
+
     #[error("Only registration admin can link social media on behalf of user")]
     OnlyRegistrationAdminCanLinkSocialMediaOnBehalfOfUser {},
 
@@ -61,29 +160,112 @@ pub enum ContractError {
     #[error("User not exist")]
     UserNotExist {},
 
+    #[error("Distribution contract not set")]
+    DistributionContractNotSet {},
+
     #[error("User already registered membership")]
     UserAlreadyRegisteredMembership {},
 
     #[error("User already linked social media")]
     UserAlreadyLinkedSocialMedia {},
 
+    #[error("User has not linked social media platform {platform:?}")]
+    UserHasNotLinkedSocialMedia { platform: String },
+
+    #[error("Attestation not enabled")]
+    AttestationNotEnabled {},
+
+    #[error("Invalid attestation pubkey")]
+    InvalidAttestationPubkey {},
+
+    #[error("Invalid attestation signature")]
+    InvalidAttestationSignature {},
+
+    #[error("Attestation expired")]
+    AttestationExpired {},
+
+    #[error("Attestation is for another chain or contract")]
+    AttestationNotForThisContract {},
+
+    #[error("Attestation nonce {nonce} already used")]
+    AttestationNonceAlreadyUsed { nonce: Uint64 },
+
+    #[error("Only user itself can submit its attestation")]
+    OnlyUserCanSubmitItsAttestation {},
+
+    #[error("Only registration admin or user itself can unlink social media")]
+    OnlyRegistrationAdminOrUserCanUnlinkSocialMedia {},
+
+    #[error("Social media handle cannot be empty")]
+    SocialMediaHandleCannotBeEmpty {},
+
+    #[error("Social media handle already linked to another user")]
+    SocialMediaHandleAlreadyLinked {},
+
     #[error("User has not registered membership")]
     UserHasNotRegisteredMembership {},
 
     #[error("User cannot register membership before linking social media")]
     UserCannotRegisterMembershipBeforeLinkingSocialMedia {},
 
+    #[error("User cannot register membership before linking social media platform {platform:?}")]
+    UserCannotRegisterMembershipBeforeLinkingSocialMediaPlatform { platform: String },
+
+    #[error("Referrer not exist")]
+    ReferrerNotExist {},
+
+    #[error("Cannot migrate user to its current address")]
+    CannotMigrateUserToItsCurrentAddress {},
+
+    #[error("User has no pending migration")]
+    UserHasNoPendingMigration {},
+
+    #[error("Only the proposed new address can accept user migration")]
+    OnlyProposedNewAddressCanAcceptUserMigration {},
+
+    #[error("Hot address cannot be the same as cold address")]
+    HotAddrCannotBeSameAsColdAddr {},
+
+    #[error("User has no hot address")]
+    UserHasNoHotAddr {},
+
+    #[error("Only cold address can perform this action, hot address is not allowed")]
+    OnlyColdAddrAllowed {},
+
     // ========================== BUY / SELL ==========================
     #[error(
         "Insufficient funds to pay during buy membership: needed {needed:?}, available {available:?}"
     )]
     InsufficientFundsToPayDuringBuy { needed: Uint128, available: Uint128 },
 
+    #[error("Total cost to buy membership exceeds max total cost: total cost {total_cost:?}, max total cost {max_total_cost:?}")]
+    BuyMembershipTotalCostExceedsMaxTotalCost {
+        total_cost: Uint128,
+        max_total_cost: Uint128,
+    },
+
+    #[error("Proceeds from selling membership are below min proceeds: proceeds {proceeds:?}, min proceeds {min_proceeds:?}")]
+    SellMembershipProceedsBelowMinProceeds {
+        proceeds: Uint128,
+        min_proceeds: Uint128,
+    },
+
+    #[error(
+        "Proceeds from selling membership cannot cover fees: proceeds {proceeds:?}, fees {fees:?}"
+    )]
+    SellMembershipProceedsCannotCoverFees { proceeds: Uint128, fees: Uint128 },
+
     #[error(
         "Insufficient funds to pay during sell membership: needed {needed:?}, available {available:?}"
     )]
     InsufficientFundsToPayDuringSell { needed: Uint128, available: Uint128 },
 
+    #[error("Only verified user, i.e. user with linked social media, can buy this membership")]
+    OnlyVerifiedUserCanBuyMembership {},
+
+    #[error("Holding cap exceeded: cap {cap:?}, attempted to hold {attempted:?}")]
+    HoldingCapExceeded { cap: Uint128, attempted: Uint128 },
+
     #[error("Insufficient memberships to sell: trying to sell {sell:?}, available {available:?}")]
     InsufficientMembershipsToSell { sell: Uint128, available: Uint128 },
 
@@ -96,8 +278,44 @@ pub enum ContractError {
     },
 
     // ========================== OTHERS ==========================
-    #[error("Membership trading fee share percentage must sum to 100")]
-    MembershipTradingFeeSharePercentageMustSumTo100 {},
+    #[error("Membership trading fee share bps must sum to 10000")]
+    MembershipTradingFeeShareBpsMustSumTo10000 {},
+
+    #[error("Referral fee bps cannot exceed 10000")]
+    ReferralFeeBpsCannotExceed10000 {},
+
+    #[error("Min cannot exceed max in user fee range of {fee:?}: min {min:?}, max {max:?}")]
+    InvalidUserFeeRange {
+        fee: String,
+        min: Uint64,
+        max: Uint64,
+    },
+
+    #[error("Fee {fee:?} out of allowed range: bps {bps:?}, min {min:?}, max {max:?}")]
+    FeeBpsOutOfRange {
+        fee: String,
+        bps: Uint64,
+        min: Uint64,
+        max: Uint64,
+    },
+
+    #[error("Bonding curve divisor cannot be zero")]
+    BondingCurveDivisorCannotBeZero {},
+
+    #[error("Bonding curve inflection supply cannot be zero")]
+    BondingCurveInflectionSupplyCannotBeZero {},
+
+    #[error("Bonding curve growth cannot be zero")]
+    BondingCurveGrowthCannotBeZero {},
+
+    #[error("Sigmoid max trade amount cannot be zero")]
+    SigmoidMaxTradeAmountCannotBeZero {},
+
+    #[error("Trade amount {amount} exceeds sigmoid bonding curve max trade amount {max_amount}")]
+    ExceedSigmoidMaxTradeAmount {
+        amount: Uint128,
+        max_amount: Uint128,
+    },
 
     #[error("Exceed query limit: given {given:?}, limit {limit:?}")]
     ExceedQueryLimit { given: Uint64, limit: Uint64 },
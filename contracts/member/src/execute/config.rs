@@ -1,16 +1,42 @@
-use cosmwasm_std::{DepsMut, MessageInfo, Response};
-use member_pkg::config::{FeeConfig, ProtocolFeeConfig};
+use cosmwasm_std::{Deps, DepsMut, Env, MessageInfo, Response, Uint64};
+use member_pkg::config::{Config, FeeConfig, MembershipEligibility, ProtocolFeeConfig};
+use member_pkg::user::normalize_social_media_platform;
+use shared_pkg::admin_transfer::{AdminProposal, ProposeNewAdminMsg};
 use shared_pkg::fee_share_config::FeeShareConfig;
+use shared_pkg::rbac::{
+    self, has_any_role, has_role, transfer_role, GrantRoleMsg, RevokeRoleMsg, Role,
+};
+use shared_pkg::timelock::{
+    normalize_timelock, CancelPendingConfigChangeMsg, ExecutePendingConfigChangeMsg,
+    PendingConfigChange,
+};
 
 use crate::ContractError;
-use crate::{state::CONFIG, util::fee_share::assert_config_fee_share_sum_to_100};
-
-use member_pkg::msg::UpdateConfigMsg;
+use crate::{
+    state::{
+        CONFIG, NEXT_PENDING_CONFIG_CHANGE_ID, PENDING_ADMIN_PROPOSAL, PENDING_CONFIG_CHANGES,
+    },
+    util::{
+        attestation::assert_attestation_pubkey_valid,
+        config::{can_update_config, split_timelocked_config_changes},
+        fee_range::{assert_default_fee_config_in_ranges, assert_user_fee_ranges_valid},
+        fee_share::{assert_config_fee_share_sum_to_10000, assert_referral_fee_bps_within_10000},
+        holding_cap::parse_holding_cap,
+        price::{assert_bonding_curve_valid, assert_sigmoid_max_trade_amount_valid},
+        social_media::assert_social_media_platform_in_allowlist,
+    },
+};
+
+use member_pkg::msg::{
+    AddToFeeDenomAllowlistMsg, AddToSocialMediaPlatformAllowlistMsg,
+    RemoveFromFeeDenomAllowlistMsg, RemoveFromSocialMediaPlatformAllowlistMsg, UpdateConfigMsg,
+    UpdatePauseConfigMsg,
+};
 
 pub fn enable(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
     let mut config = CONFIG.load(deps.storage)?;
 
-    if info.sender != config.admin_addr {
+    if !has_role(deps.storage, &info.sender, &Role::Admin)? {
         return Err(ContractError::OnlyAdminCanEnable {});
     }
 
@@ -24,7 +50,7 @@ pub fn enable(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractErro
 pub fn disable(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
     let mut config = CONFIG.load(deps.storage)?;
 
-    if info.sender != config.admin_addr {
+    if !has_role(deps.storage, &info.sender, &Role::Admin)? {
         return Err(ContractError::OnlyAdminCanDisable {});
     }
 
@@ -41,7 +67,7 @@ pub fn enable_open_registration(
 ) -> Result<Response, ContractError> {
     let mut config = CONFIG.load(deps.storage)?;
 
-    if info.sender != config.admin_addr {
+    if !has_role(deps.storage, &info.sender, &Role::Admin)? {
         return Err(ContractError::OnlyAdminCanEnableOpenRegistration {});
     }
 
@@ -58,7 +84,7 @@ pub fn disable_open_registration(
 ) -> Result<Response, ContractError> {
     let mut config = CONFIG.load(deps.storage)?;
 
-    if info.sender != config.admin_addr {
+    if !has_role(deps.storage, &info.sender, &Role::Admin)? {
         return Err(ContractError::OnlyAdminCanDisableOpenRegistration {});
     }
 
@@ -69,27 +95,167 @@ pub fn disable_open_registration(
     Ok(Response::new().add_attribute("action", "disable_open_registration"))
 }
 
+pub fn update_pause_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    data: UpdatePauseConfigMsg,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    if !has_any_role(deps.storage, &info.sender, &[Role::Admin, Role::Pauser])? {
+        return Err(ContractError::OnlyAdminCanUpdatePauseConfig {});
+    }
+
+    if let Some(registration_paused) = data.registration_paused {
+        config.pause_config.registration_paused = registration_paused;
+    }
+    if let Some(buy_membership_paused) = data.buy_membership_paused {
+        config.pause_config.buy_membership_paused = buy_membership_paused;
+    }
+    if let Some(sell_membership_paused) = data.sell_membership_paused {
+        config.pause_config.sell_membership_paused = sell_membership_paused;
+    }
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attribute("action", "update_pause_config"))
+}
+
 pub fn update_config(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     data: UpdateConfigMsg,
 ) -> Result<Response, ContractError> {
-    let mut config = CONFIG.load(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
 
-    if info.sender != config.admin_addr {
+    if !can_update_config(deps.storage, &info.sender, &data)? {
         return Err(ContractError::OnlyAdminCanUpdateConfig {});
     }
 
-    config.admin_addr = match data.admin_addr {
-        None => config.admin_addr,
-        Some(data) => deps.api.addr_validate(data.as_str())?,
+    let (changes, timelocked_changes) = match config.config_change_timelock {
+        None => (data, None),
+        Some(config_change_timelock) => {
+            let (changes, timelocked_changes) = split_timelocked_config_changes(data);
+            (
+                changes,
+                timelocked_changes.map(|timelocked_changes| {
+                    (timelocked_changes, config_change_timelock.after(&env.block))
+                }),
+            )
+        }
     };
 
+    let new_config = apply_config_changes(deps.as_ref(), config.clone(), changes)?;
+    if new_config.registration_admin_addr != config.registration_admin_addr {
+        transfer_role(
+            deps.storage,
+            &config.registration_admin_addr,
+            &new_config.registration_admin_addr,
+            &Role::RegistrationOperator,
+        )?;
+    }
+    CONFIG.save(deps.storage, &new_config)?;
+
+    let mut response = Response::new().add_attribute("action", "update_config");
+
+    if let Some((timelocked_changes, executable_at)) = timelocked_changes {
+        // Reject invalid changes now instead of when the time-lock has passed
+        apply_config_changes(deps.as_ref(), new_config, timelocked_changes.clone())?;
+
+        let id = NEXT_PENDING_CONFIG_CHANGE_ID
+            .may_load(deps.storage)?
+            .unwrap_or(Uint64::one());
+        PENDING_CONFIG_CHANGES.save(
+            deps.storage,
+            id.u64(),
+            &PendingConfigChange {
+                id,
+                changes: timelocked_changes,
+                proposed_by: info.sender,
+                executable_at,
+            },
+        )?;
+        NEXT_PENDING_CONFIG_CHANGE_ID.save(deps.storage, &(id + Uint64::one()))?;
+
+        response = response
+            .add_attribute("pending_config_change_id", id)
+            .add_attribute("executable_at", executable_at.to_string());
+    }
+
+    Ok(response)
+}
+
+pub fn execute_pending_config_change(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    data: ExecutePendingConfigChangeMsg,
+) -> Result<Response, ContractError> {
+    let pending_config_change = PENDING_CONFIG_CHANGES
+        .may_load(deps.storage, data.id.u64())?
+        .ok_or(ContractError::PendingConfigChangeNotExist { id: data.id })?;
+
+    if !can_update_config(deps.storage, &info.sender, &pending_config_change.changes)? {
+        return Err(ContractError::OnlyAdminOrFeeManagerCanExecutePendingConfigChange {});
+    }
+
+    if !pending_config_change.executable_at.is_expired(&env.block) {
+        return Err(ContractError::PendingConfigChangeStillTimeLocked {
+            executable_at: pending_config_change.executable_at.to_string(),
+        });
+    }
+
+    let config = CONFIG.load(deps.storage)?;
+    let new_config = apply_config_changes(deps.as_ref(), config, pending_config_change.changes)?;
+    CONFIG.save(deps.storage, &new_config)?;
+    PENDING_CONFIG_CHANGES.remove(deps.storage, data.id.u64());
+
+    Ok(Response::new()
+        .add_attribute("action", "execute_pending_config_change")
+        .add_attribute("pending_config_change_id", data.id))
+}
+
+pub fn cancel_pending_config_change(
+    deps: DepsMut,
+    info: MessageInfo,
+    data: CancelPendingConfigChangeMsg,
+) -> Result<Response, ContractError> {
+    let pending_config_change = PENDING_CONFIG_CHANGES
+        .may_load(deps.storage, data.id.u64())?
+        .ok_or(ContractError::PendingConfigChangeNotExist { id: data.id })?;
+
+    if !can_update_config(deps.storage, &info.sender, &pending_config_change.changes)? {
+        return Err(ContractError::OnlyAdminOrFeeManagerCanCancelPendingConfigChange {});
+    }
+
+    PENDING_CONFIG_CHANGES.remove(deps.storage, data.id.u64());
+
+    Ok(Response::new()
+        .add_attribute("action", "cancel_pending_config_change")
+        .add_attribute("pending_config_change_id", data.id))
+}
+
+// Apply changes on top of config and validate the result, nothing is saved
+fn apply_config_changes(
+    deps: Deps,
+    mut config: Config,
+    data: UpdateConfigMsg,
+) -> Result<Config, ContractError> {
     config.registration_admin_addr = match data.registration_admin_addr {
         None => config.registration_admin_addr,
         Some(data) => deps.api.addr_validate(data.as_str())?,
     };
 
+    config.attestation_pubkey = match data.attestation_pubkey {
+        None => config.attestation_pubkey,
+        Some(attestation_pubkey) if attestation_pubkey.is_empty() => None,
+        Some(attestation_pubkey) => {
+            assert_attestation_pubkey_valid(&attestation_pubkey)?;
+            Some(attestation_pubkey)
+        }
+    };
+
     config.distribution_contract_addr = match data.distribution_contract_addr {
         None => config.distribution_contract_addr,
         Some(data) => Some(deps.api.addr_validate(data.as_str())?),
@@ -101,35 +267,328 @@ pub fn update_config(
     };
 
     config.protocol_fee_config = ProtocolFeeConfig {
-        membership_trading_fee_percentage: data
-            .protocol_fee_membership_trading_fee_percentage
-            .unwrap_or(config.protocol_fee_config.membership_trading_fee_percentage),
+        membership_trading_fee_bps: data
+            .protocol_fee_membership_trading_fee_bps
+            .unwrap_or(config.protocol_fee_config.membership_trading_fee_bps),
+        referral_fee_bps: data
+            .protocol_fee_referral_fee_bps
+            .unwrap_or(config.protocol_fee_config.referral_fee_bps),
     };
 
     config.default_fee_config = FeeConfig {
         fee_denom: config.default_fee_config.fee_denom,
-        trading_fee_percentage_of_membership: data
-            .default_trading_fee_percentage_of_membership
-            .unwrap_or(
-                config
-                    .default_fee_config
-                    .trading_fee_percentage_of_membership,
-            ),
+        trading_fee_bps_of_membership: data
+            .default_trading_fee_bps_of_membership
+            .unwrap_or(config.default_fee_config.trading_fee_bps_of_membership),
+        holding_cap: match data.default_holding_cap {
+            None => config.default_fee_config.holding_cap,
+            Some(holding_cap) => parse_holding_cap(holding_cap),
+        },
     };
 
     config.default_fee_share_config = FeeShareConfig {
-        share_to_issuer_percentage: data
-            .default_share_to_issuer_percentage
-            .unwrap_or(config.default_fee_share_config.share_to_issuer_percentage),
-        share_to_all_members_percentage: data.default_share_to_all_members_percentage.unwrap_or(
-            config
-                .default_fee_share_config
-                .share_to_all_members_percentage,
-        ),
+        share_to_issuer_bps: data
+            .default_share_to_issuer_bps
+            .unwrap_or(config.default_fee_share_config.share_to_issuer_bps),
+        share_to_all_members_bps: data
+            .default_share_to_all_members_bps
+            .unwrap_or(config.default_fee_share_config.share_to_all_members_bps),
+    };
+
+    config.only_verified_buyers = data
+        .only_verified_buyers
+        .unwrap_or(config.only_verified_buyers);
+
+    config.price_candle_interval = data
+        .price_candle_interval
+        .unwrap_or(config.price_candle_interval);
+
+    config.membership_eligibility = match data.membership_eligibility {
+        None => config.membership_eligibility,
+        Some(MembershipEligibility::SpecificPlatform { platform }) => {
+            let platform = normalize_social_media_platform(&platform);
+            assert_social_media_platform_in_allowlist(&config, &platform)?;
+            MembershipEligibility::SpecificPlatform { platform }
+        }
+        Some(membership_eligibility) => membership_eligibility,
+    };
+
+    config.default_bonding_curve = data
+        .default_bonding_curve
+        .unwrap_or(config.default_bonding_curve);
+
+    config.sigmoid_max_trade_amount = data
+        .sigmoid_max_trade_amount
+        .unwrap_or(config.sigmoid_max_trade_amount);
+
+    config.config_change_timelock = match data.config_change_timelock {
+        None => config.config_change_timelock,
+        Some(config_change_timelock) => normalize_timelock(config_change_timelock),
+    };
+
+    config.user_fee_change_timelock = match data.user_fee_change_timelock {
+        None => config.user_fee_change_timelock,
+        Some(user_fee_change_timelock) => normalize_timelock(user_fee_change_timelock),
     };
 
+    config.user_fee_ranges = data.user_fee_ranges.unwrap_or(config.user_fee_ranges);
+
+    assert_config_fee_share_sum_to_10000(config.default_fee_share_config.clone())?;
+    assert_referral_fee_bps_within_10000(config.protocol_fee_config.clone())?;
+    assert_bonding_curve_valid(&config.default_bonding_curve)?;
+    assert_sigmoid_max_trade_amount_valid(config.sigmoid_max_trade_amount)?;
+    assert_user_fee_ranges_valid(&config.user_fee_ranges)?;
+    assert_default_fee_config_in_ranges(&config.default_fee_config, &config.user_fee_ranges)?;
+
+    Ok(config)
+}
+
+pub fn grant_role(
+    deps: DepsMut,
+    info: MessageInfo,
+    data: GrantRoleMsg,
+) -> Result<Response, ContractError> {
+    if !has_role(deps.storage, &info.sender, &Role::Admin)? {
+        return Err(ContractError::OnlyAdminCanGrantRole {});
+    }
+
+    let addr = deps.api.addr_validate(data.addr.as_str())?;
+    if has_role(deps.storage, &addr, &data.role)? {
+        return Err(ContractError::AddressAlreadyHasRole {
+            role: data.role.as_str().to_string(),
+        });
+    }
+
+    rbac::grant_role(deps.storage, &addr, &data.role)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "grant_role")
+        .add_attribute("addr", addr)
+        .add_attribute("role", data.role.as_str()))
+}
+
+pub fn revoke_role(
+    deps: DepsMut,
+    info: MessageInfo,
+    data: RevokeRoleMsg,
+    config: Config,
+) -> Result<Response, ContractError> {
+    if !has_role(deps.storage, &info.sender, &Role::Admin)? {
+        return Err(ContractError::OnlyAdminCanRevokeRole {});
+    }
+
+    let addr = deps.api.addr_validate(data.addr.as_str())?;
+    // Otherwise config would point to an address without the role, and revoking the last admin would lock the contract
+    if (data.role == Role::Admin && addr == config.admin_addr)
+        || (data.role == Role::RegistrationOperator && addr == config.registration_admin_addr)
+    {
+        return Err(ContractError::CannotRevokeRoleOfConfigAddress {
+            role: data.role.as_str().to_string(),
+        });
+    }
+
+    if !has_role(deps.storage, &addr, &data.role)? {
+        return Err(ContractError::AddressDoesNotHaveRole {
+            role: data.role.as_str().to_string(),
+        });
+    }
+
+    rbac::revoke_role(deps.storage, &addr, &data.role);
+
+    Ok(Response::new()
+        .add_attribute("action", "revoke_role")
+        .add_attribute("addr", addr)
+        .add_attribute("role", data.role.as_str()))
+}
+
+pub fn propose_new_admin(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    data: ProposeNewAdminMsg,
+) -> Result<Response, ContractError> {
+    if !has_role(deps.storage, &info.sender, &Role::Admin)? {
+        return Err(ContractError::OnlyAdminCanProposeNewAdmin {});
+    }
+
+    let new_admin_addr = deps.api.addr_validate(data.new_admin_addr.as_str())?;
+    let expiration = data.expiration.unwrap_or_default();
+    if expiration.is_expired(&env.block) {
+        return Err(ContractError::AdminProposalExpired {});
+    }
+
+    PENDING_ADMIN_PROPOSAL.save(
+        deps.storage,
+        &AdminProposal {
+            new_admin_addr: new_admin_addr.clone(),
+            expiration,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "propose_new_admin")
+        .add_attribute("new_admin_addr", new_admin_addr)
+        .add_attribute("expiration", expiration.to_string()))
+}
+
+pub fn accept_admin(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    let admin_proposal = PENDING_ADMIN_PROPOSAL
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoPendingAdminProposal {})?;
+
+    if info.sender != admin_proposal.new_admin_addr {
+        return Err(ContractError::OnlyProposedAdminCanAcceptAdmin {});
+    }
+
+    if admin_proposal.expiration.is_expired(&env.block) {
+        return Err(ContractError::AdminProposalExpired {});
+    }
+
+    let mut config = CONFIG.load(deps.storage)?;
+    let previous_admin_addr = config.admin_addr;
+    transfer_role(
+        deps.storage,
+        &previous_admin_addr,
+        &admin_proposal.new_admin_addr,
+        &Role::Admin,
+    )?;
+    config.admin_addr = admin_proposal.new_admin_addr;
+
+    CONFIG.save(deps.storage, &config)?;
+    PENDING_ADMIN_PROPOSAL.remove(deps.storage);
+
+    Ok(Response::new()
+        .add_attribute("action", "accept_admin")
+        .add_attribute("previous_admin_addr", previous_admin_addr)
+        .add_attribute("new_admin_addr", config.admin_addr))
+}
+
+pub fn cancel_admin_proposal(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    if !has_role(deps.storage, &info.sender, &Role::Admin)? {
+        return Err(ContractError::OnlyAdminCanCancelAdminProposal {});
+    }
+
+    if PENDING_ADMIN_PROPOSAL.may_load(deps.storage)?.is_none() {
+        return Err(ContractError::NoPendingAdminProposal {});
+    }
+
+    PENDING_ADMIN_PROPOSAL.remove(deps.storage);
+
+    Ok(Response::new().add_attribute("action", "cancel_admin_proposal"))
+}
+
+pub fn add_to_fee_denom_allowlist(
+    deps: DepsMut,
+    info: MessageInfo,
+    data: AddToFeeDenomAllowlistMsg,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    if !has_any_role(deps.storage, &info.sender, &[Role::Admin, Role::FeeManager])? {
+        return Err(ContractError::OnlyAdminCanUpdateFeeDenomAllowlist {});
+    }
+
+    if config.fee_denom_allowlist.contains(&data.denom) {
+        return Err(ContractError::FeeDenomAlreadyInAllowlist { denom: data.denom });
+    }
+
+    config.fee_denom_allowlist.push(data.denom.clone());
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "add_to_fee_denom_allowlist")
+        .add_attribute("denom", data.denom))
+}
+
+pub fn remove_from_fee_denom_allowlist(
+    deps: DepsMut,
+    info: MessageInfo,
+    data: RemoveFromFeeDenomAllowlistMsg,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    if !has_any_role(deps.storage, &info.sender, &[Role::Admin, Role::FeeManager])? {
+        return Err(ContractError::OnlyAdminCanUpdateFeeDenomAllowlist {});
+    }
+
+    // Default fee denom is used by memberships that don't pick a denom, it must always be allowed
+    if data.denom == config.default_fee_config.fee_denom {
+        return Err(ContractError::CannotRemoveDefaultFeeDenomFromAllowlist {});
+    }
+
+    if !config.fee_denom_allowlist.contains(&data.denom) {
+        return Err(ContractError::FeeDenomNotInAllowlist { denom: data.denom });
+    }
+
+    config
+        .fee_denom_allowlist
+        .retain(|denom| denom != &data.denom);
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "remove_from_fee_denom_allowlist")
+        .add_attribute("denom", data.denom))
+}
+
+pub fn add_to_social_media_platform_allowlist(
+    deps: DepsMut,
+    info: MessageInfo,
+    data: AddToSocialMediaPlatformAllowlistMsg,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    if !has_role(deps.storage, &info.sender, &Role::Admin)? {
+        return Err(ContractError::OnlyAdminCanUpdateSocialMediaPlatformAllowlist {});
+    }
+
+    let platform = normalize_social_media_platform(&data.platform);
+    if config.social_media_platform_allowlist.contains(&platform) {
+        return Err(ContractError::SocialMediaPlatformAlreadyInAllowlist { platform });
+    }
+
+    config
+        .social_media_platform_allowlist
+        .push(platform.clone());
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "add_to_social_media_platform_allowlist")
+        .add_attribute("platform", platform))
+}
+
+pub fn remove_from_social_media_platform_allowlist(
+    deps: DepsMut,
+    info: MessageInfo,
+    data: RemoveFromSocialMediaPlatformAllowlistMsg,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    if !has_role(deps.storage, &info.sender, &Role::Admin)? {
+        return Err(ContractError::OnlyAdminCanUpdateSocialMediaPlatformAllowlist {});
+    }
+
+    let platform = normalize_social_media_platform(&data.platform);
+    // Otherwise no new user could become eligible for membership
+    if config.membership_eligibility
+        == (MembershipEligibility::SpecificPlatform {
+            platform: platform.clone(),
+        })
+    {
+        return Err(ContractError::CannotRemoveMembershipEligibilityPlatformFromAllowlist {});
+    }
+
+    assert_social_media_platform_in_allowlist(&config, &platform)?;
+
+    config
+        .social_media_platform_allowlist
+        .retain(|p| p != &platform);
+
     CONFIG.save(deps.storage, &config)?;
-    assert_config_fee_share_sum_to_100(config.default_fee_share_config)?;
 
-    Ok(Response::new().add_attribute("action", "update_config"))
+    Ok(Response::new()
+        .add_attribute("action", "remove_from_social_media_platform_allowlist")
+        .add_attribute("platform", platform))
 }
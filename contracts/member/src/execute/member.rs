@@ -1,6 +1,6 @@
 use cosmwasm_std::{
-    to_binary, BankMsg, Coin, CosmosMsg, Decimal, DepsMut, MessageInfo, Response, Uint128, Uint64,
-    WasmMsg,
+    to_binary, BankMsg, Coin, CosmosMsg, Decimal, DepsMut, Env, MessageInfo, Response, Uint128,
+    Uint64, WasmMsg,
 };
 use distribution_pkg::msg::{
     DistributeMsg, ExecuteMsg, SetupDistributionForNewMemberMsg, UpdateUserPendingRewardMsg,
@@ -11,36 +11,68 @@ use member_pkg::{
         BuyMembershipMsg, CostToBuyMembershipResponse, CostToSellMembershipResponse,
         QueryCostToBuyMembershipMsg, QueryCostToSellMembershipMsg, SellMembershipMsg,
     },
+    trade::Trade,
+};
+use shared_pkg::{
+    event::{TradeEvent, TradeSide},
+    refund::build_refund_msg,
 };
 
 use crate::{
     query::cost::{query_cost_to_buy_membership, query_cost_to_sell_membership},
     state::{ALL_MEMBERSHIPS_MEMBERS, ALL_USERS, ALL_USERS_MEMBERSHIPS},
+    util::{
+        holding_cap::assert_within_holding_cap,
+        price::assert_within_sigmoid_max_trade_amount,
+        referral::pay_referral_fee,
+        trade::{next_trade_id, record_trade},
+        twap::observe_price,
+        user::{load_user_by_cold_addr, load_user_by_id},
+    },
     ContractError,
 };
 
 pub fn buy_membership(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     data: BuyMembershipMsg,
     config: Config,
     user_paid_amount: Uint128,
     fee_denom: String,
 ) -> Result<Response, ContractError> {
-    let buyer_user_id = ALL_USERS().load(deps.storage, &info.sender)?.id.u64();
+    let buyer = load_user_by_cold_addr(deps.as_ref(), &info.sender)?;
+    let buyer_user_id = buyer.id.u64();
 
     let membership_issuer_user_id = data.membership_issuer_user_id.u64();
-    let membership_issuer = ALL_USERS()
-        .idx
-        .id
-        .item(deps.storage, membership_issuer_user_id)?
-        .unwrap()
-        .1;
+    let membership_issuer = load_user_by_id(deps.as_ref(), membership_issuer_user_id)?;
     let membership_issuer_addr_ref = &membership_issuer.addr;
+    let membership_issued_by_me = membership_issuer
+        .membership_issued_by_me
+        .as_ref()
+        .ok_or(ContractError::UserHasNotRegisteredMembership {})?;
+    let distribution_contract_addr = config
+        .distribution_contract_addr
+        .clone()
+        .ok_or(ContractError::DistributionContractNotSet {})?
+        .to_string();
+    assert_within_sigmoid_max_trade_amount(
+        &membership_issued_by_me.bonding_curve,
+        config.sigmoid_max_trade_amount,
+        data.amount,
+    )?;
+
+    // Either protocol or membership issuer can require buyer to be verified
+    if (config.only_verified_buyers || membership_issuer.only_verified_buyers)
+        && buyer.social_links.is_empty()
+    {
+        return Err(ContractError::OnlyVerifiedUserCanBuyMembership {});
+    }
 
     let cost_to_buy_membership_response: CostToBuyMembershipResponse =
         query_cost_to_buy_membership(
             deps.as_ref(),
+            env.clone(),
             QueryCostToBuyMembershipMsg {
                 membership_issuer_user_id: Uint64::from(membership_issuer_user_id),
                 amount: data.amount,
@@ -48,6 +80,15 @@ pub fn buy_membership(
             config.clone(),
         )?;
 
+    if let Some(max_total_cost) = data.max_total_cost {
+        if cost_to_buy_membership_response.total_needed_from_user > max_total_cost {
+            return Err(ContractError::BuyMembershipTotalCostExceedsMaxTotalCost {
+                total_cost: cost_to_buy_membership_response.total_needed_from_user,
+                max_total_cost,
+            });
+        }
+    }
+
     if cost_to_buy_membership_response.total_needed_from_user > user_paid_amount {
         return Err(ContractError::InsufficientFundsToPayDuringBuy {
             needed: cost_to_buy_membership_response.total_needed_from_user,
@@ -58,50 +99,70 @@ pub fn buy_membership(
     let buyer_previous_hold_amount = ALL_USERS_MEMBERSHIPS
         .may_load(deps.storage, (buyer_user_id, membership_issuer_user_id))?
         .unwrap_or(Uint128::zero());
-    let buyer_new_hold_amount = buyer_previous_hold_amount + data.amount;
+    let buyer_new_hold_amount = buyer_previous_hold_amount.checked_add(data.amount)?;
+    assert_within_holding_cap(
+        cost_to_buy_membership_response.holding_cap,
+        buyer_new_hold_amount,
+    )?;
 
-    let previous_total_supply = membership_issuer
-        .membership_issued_by_me
-        .unwrap()
-        .membership_supply;
+    let previous_total_supply = membership_issued_by_me.membership_supply;
+    let new_total_supply = previous_total_supply.checked_add(data.amount)?;
 
-    let mut msgs_vec = vec![
-        // Send all member fee to distribution contract
-        CosmosMsg::Wasm(WasmMsg::Execute {
-            contract_addr: config
-                .distribution_contract_addr
-                .clone()
-                .unwrap()
-                .to_string(),
-            msg: to_binary(&ExecuteMsg::Distribute(DistributeMsg {
-                membership_issuer_user_id: Uint64::from(membership_issuer_user_id),
-                index_increment: Decimal::from_ratio(
-                    cost_to_buy_membership_response.all_members_fee,
-                    previous_total_supply,
-                ),
-            }))?,
-            funds: vec![Coin {
-                denom: fee_denom.clone(),
-                amount: cost_to_buy_membership_response.all_members_fee,
-            }],
-        }),
-        // Send membership issuer fee to membership issuer
-        CosmosMsg::Bank(BankMsg::Send {
-            to_address: membership_issuer_addr_ref.to_string(),
-            amount: vec![Coin {
-                denom: fee_denom.clone(),
-                amount: cost_to_buy_membership_response.issuer_fee,
-            }],
-        }),
-        // Send protocol fee to fee collector
-        CosmosMsg::Bank(BankMsg::Send {
-            to_address: config.protocol_fee_collector_addr.to_string(),
-            amount: vec![Coin {
-                denom: fee_denom,
-                amount: cost_to_buy_membership_response.protocol_fee,
-            }],
-        }),
-    ];
+    let mut msgs_vec = vec![];
+    if cost_to_buy_membership_response.all_members_fee > Uint128::zero() {
+        msgs_vec.push(
+            // Send all member fee to distribution contract
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: distribution_contract_addr.clone(),
+                msg: to_binary(&ExecuteMsg::Distribute(DistributeMsg {
+                    membership_issuer_user_id: Uint64::from(membership_issuer_user_id),
+                    index_increment: Decimal::from_ratio(
+                        cost_to_buy_membership_response.all_members_fee,
+                        previous_total_supply,
+                    ),
+                }))?,
+                funds: vec![Coin {
+                    denom: fee_denom.clone(),
+                    amount: cost_to_buy_membership_response.all_members_fee,
+                }],
+            }),
+        );
+    }
+    if cost_to_buy_membership_response.issuer_fee > Uint128::zero() {
+        msgs_vec.push(
+            // Send membership issuer fee to membership issuer
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: membership_issuer_addr_ref.to_string(),
+                amount: vec![Coin {
+                    denom: fee_denom.clone(),
+                    amount: cost_to_buy_membership_response.issuer_fee,
+                }],
+            }),
+        );
+    }
+    if cost_to_buy_membership_response.protocol_fee > Uint128::zero() {
+        msgs_vec.push(
+            // Send protocol fee to fee collector
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: config.protocol_fee_collector_addr.to_string(),
+                amount: vec![Coin {
+                    denom: fee_denom.clone(),
+                    amount: cost_to_buy_membership_response.protocol_fee,
+                }],
+            }),
+        );
+    }
+
+    // Send referral fee to buyer's referrer
+    if let Some(msg) = pay_referral_fee(
+        deps.storage,
+        &config,
+        &buyer,
+        cost_to_buy_membership_response.referral_fee,
+        &fee_denom,
+    )? {
+        msgs_vec.push(msg);
+    }
 
     // Update membership supply
     ALL_USERS().update(
@@ -110,10 +171,13 @@ pub fn buy_membership(
         |user| match user {
             None => Err(ContractError::UserNotExist {}),
             Some(mut user) => {
-                user.membership_issued_by_me
+                let membership_issued_by_me = user
+                    .membership_issued_by_me
                     .as_mut()
-                    .unwrap()
-                    .membership_supply += data.amount;
+                    .ok_or(ContractError::UserHasNotRegisteredMembership {})?;
+                membership_issued_by_me.membership_supply = membership_issued_by_me
+                    .membership_supply
+                    .checked_add(data.amount)?;
                 Ok(user)
             }
         },
@@ -126,7 +190,13 @@ pub fn buy_membership(
             |user| match user {
                 None => Err(ContractError::UserNotExist {}),
                 Some(mut user) => {
-                    user.membership_issued_by_me.as_mut().unwrap().member_count += Uint128::one();
+                    let membership_issued_by_me = user
+                        .membership_issued_by_me
+                        .as_mut()
+                        .ok_or(ContractError::UserHasNotRegisteredMembership {})?;
+                    membership_issued_by_me.member_count = membership_issued_by_me
+                        .member_count
+                        .checked_add(Uint128::one())?;
                     Ok(user)
                 }
             },
@@ -134,7 +204,7 @@ pub fn buy_membership(
         ALL_USERS().update(deps.storage, &info.sender, |user| match user {
             None => Err(ContractError::UserNotExist {}),
             Some(mut user) => {
-                user.user_member_count += Uint128::one();
+                user.user_member_count = user.user_member_count.checked_add(Uint128::one())?;
                 Ok(user)
             }
         })?;
@@ -151,7 +221,33 @@ pub fn buy_membership(
         &buyer_new_hold_amount,
     )?;
 
-    let distribution_contract_addr = config.distribution_contract_addr.unwrap().to_string();
+    let trade = Trade {
+        id: next_trade_id(deps.storage, membership_issuer_user_id)?,
+        block_height: Uint64::from(env.block.height),
+        timestamp: env.block.time,
+        trader_user_id: Uint64::from(buyer_user_id),
+        side: TradeSide::Buy,
+        amount: data.amount,
+        price: cost_to_buy_membership_response.price,
+        protocol_fee: cost_to_buy_membership_response.protocol_fee,
+        referral_fee: cost_to_buy_membership_response.referral_fee,
+        issuer_fee: cost_to_buy_membership_response.issuer_fee,
+        all_members_fee: cost_to_buy_membership_response.all_members_fee,
+    };
+    record_trade(
+        deps.storage,
+        membership_issuer_user_id,
+        &config.price_candle_interval,
+        &trade,
+    )?;
+    observe_price(
+        deps.storage,
+        membership_issuer_user_id,
+        env.block.time,
+        membership_issued_by_me
+            .bonding_curve
+            .calculate_price(new_total_supply, Uint128::one())?,
+    )?;
 
     if buyer_previous_hold_amount == Uint128::zero() {
         msgs_vec.push(
@@ -184,38 +280,82 @@ pub fn buy_membership(
         }),
     );
 
-    Ok(Response::new().add_messages(msgs_vec))
+    // Refund what user paid on top of what is needed
+    let (refund_amount, refund_msg) = build_refund_msg(
+        &info.sender,
+        user_paid_amount,
+        cost_to_buy_membership_response.total_needed_from_user,
+        &fee_denom,
+    );
+    if let Some(msg) = refund_msg {
+        msgs_vec.push(msg);
+    }
+
+    Ok(Response::new()
+        .add_messages(msgs_vec)
+        .add_attribute("action", "buy_membership")
+        .add_attribute("refunded_amount", refund_amount)
+        .add_event(
+            TradeEvent {
+                side: TradeSide::Buy,
+                trader_user_id: Uint64::from(buyer_user_id),
+                trader_addr: info.sender,
+                membership_issuer_user_id: Uint64::from(membership_issuer_user_id),
+                amount: data.amount,
+                price: cost_to_buy_membership_response.price,
+                protocol_fee: cost_to_buy_membership_response.protocol_fee,
+                referral_fee: cost_to_buy_membership_response.referral_fee,
+                issuer_fee: cost_to_buy_membership_response.issuer_fee,
+                all_members_fee: cost_to_buy_membership_response.all_members_fee,
+                total_paid: cost_to_buy_membership_response.total_needed_from_user,
+                proceeds: Uint128::zero(),
+                refunded_amount: refund_amount,
+                fee_denom,
+                new_supply: new_total_supply,
+                trader_new_hold_amount: buyer_new_hold_amount,
+            }
+            .into(),
+        ))
 }
 
 pub fn sell_membership(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     data: SellMembershipMsg,
     config: Config,
     user_paid_amount: Uint128,
     fee_denom: String,
 ) -> Result<Response, ContractError> {
-    let seller_user_id = ALL_USERS().load(deps.storage, &info.sender)?.id.u64();
+    let seller = load_user_by_cold_addr(deps.as_ref(), &info.sender)?;
+    let seller_user_id = seller.id.u64();
 
     let membership_issuer_user_id = data.membership_issuer_user_id.u64();
-    let membership_issuer = ALL_USERS()
-        .idx
-        .id
-        .item(deps.storage, membership_issuer_user_id)?
-        .unwrap()
-        .1;
+    let membership_issuer = load_user_by_id(deps.as_ref(), membership_issuer_user_id)?;
     let membership_issuer_addr_ref = &membership_issuer.addr;
-
-    let previous_total_supply = membership_issuer
+    let membership_issued_by_me = membership_issuer
         .membership_issued_by_me
-        .unwrap()
-        .membership_supply;
+        .as_ref()
+        .ok_or(ContractError::UserHasNotRegisteredMembership {})?;
+    let distribution_contract_addr = config
+        .distribution_contract_addr
+        .clone()
+        .ok_or(ContractError::DistributionContractNotSet {})?
+        .to_string();
+    assert_within_sigmoid_max_trade_amount(
+        &membership_issued_by_me.bonding_curve,
+        config.sigmoid_max_trade_amount,
+        data.amount,
+    )?;
+
+    let previous_total_supply = membership_issued_by_me.membership_supply;
     if previous_total_supply <= data.amount {
         return Err(ContractError::CannotSellLastMembership {
             sell: data.amount,
             total_supply: previous_total_supply,
         });
     }
+    let new_total_supply = previous_total_supply.checked_sub(data.amount)?;
 
     let seller_previous_hold_amount = ALL_USERS_MEMBERSHIPS
         .may_load(deps.storage, (seller_user_id, membership_issuer_user_id))?
@@ -226,11 +366,12 @@ pub fn sell_membership(
             available: seller_previous_hold_amount,
         });
     }
-    let seller_new_hold_amount = seller_previous_hold_amount - data.amount;
+    let seller_new_hold_amount = seller_previous_hold_amount.checked_sub(data.amount)?;
 
     let cost_to_sell_membership_response: CostToSellMembershipResponse =
         query_cost_to_sell_membership(
             deps.as_ref(),
+            env.clone(),
             QueryCostToSellMembershipMsg {
                 membership_issuer_user_id: Uint64::from(membership_issuer_user_id),
                 amount: data.amount,
@@ -238,9 +379,40 @@ pub fn sell_membership(
             config.clone(),
         )?;
 
-    if cost_to_sell_membership_response.total_needed_from_user > user_paid_amount {
+    let deduct_fees_from_proceeds = data.deduct_fees_from_proceeds.unwrap_or(false);
+    // What seller receives, fees are either paid with attached funds or deducted from price
+    let (proceeds, total_needed_from_user) = if deduct_fees_from_proceeds {
+        if cost_to_sell_membership_response.total_needed_from_user
+            > cost_to_sell_membership_response.price
+        {
+            return Err(ContractError::SellMembershipProceedsCannotCoverFees {
+                proceeds: cost_to_sell_membership_response.price,
+                fees: cost_to_sell_membership_response.total_needed_from_user,
+            });
+        }
+        (
+            cost_to_sell_membership_response.net_proceeds,
+            Uint128::zero(),
+        )
+    } else {
+        (
+            cost_to_sell_membership_response.price,
+            cost_to_sell_membership_response.total_needed_from_user,
+        )
+    };
+
+    if let Some(min_proceeds) = data.min_proceeds {
+        if proceeds < min_proceeds {
+            return Err(ContractError::SellMembershipProceedsBelowMinProceeds {
+                proceeds,
+                min_proceeds,
+            });
+        }
+    }
+
+    if total_needed_from_user > user_paid_amount {
         return Err(ContractError::InsufficientFundsToPayDuringSell {
-            needed: cost_to_sell_membership_response.total_needed_from_user,
+            needed: total_needed_from_user,
             available: user_paid_amount,
         });
     }
@@ -252,10 +424,13 @@ pub fn sell_membership(
         |user| match user {
             None => Err(ContractError::UserNotExist {}),
             Some(mut user) => {
-                user.membership_issued_by_me
+                let membership_issued_by_me = user
+                    .membership_issued_by_me
                     .as_mut()
-                    .unwrap()
-                    .membership_supply -= data.amount;
+                    .ok_or(ContractError::UserHasNotRegisteredMembership {})?;
+                membership_issued_by_me.membership_supply = membership_issued_by_me
+                    .membership_supply
+                    .checked_sub(data.amount)?;
                 Ok(user)
             }
         },
@@ -268,7 +443,13 @@ pub fn sell_membership(
             |user| match user {
                 None => Err(ContractError::UserNotExist {}),
                 Some(mut user) => {
-                    user.membership_issued_by_me.as_mut().unwrap().member_count -= Uint128::one();
+                    let membership_issued_by_me = user
+                        .membership_issued_by_me
+                        .as_mut()
+                        .ok_or(ContractError::UserHasNotRegisteredMembership {})?;
+                    membership_issued_by_me.member_count = membership_issued_by_me
+                        .member_count
+                        .checked_sub(Uint128::one())?;
                     Ok(user)
                 }
             },
@@ -276,7 +457,7 @@ pub fn sell_membership(
         ALL_USERS().update(deps.storage, &info.sender, |user| match user {
             None => Err(ContractError::UserNotExist {}),
             Some(mut user) => {
-                user.user_member_count -= Uint128::one();
+                user.user_member_count = user.user_member_count.checked_sub(Uint128::one())?;
                 Ok(user)
             }
         })?;
@@ -294,56 +475,107 @@ pub fn sell_membership(
         &seller_new_hold_amount,
     )?;
 
-    let mut msgs_vec = vec![
-        // Send all member fee to distribution contract
-        CosmosMsg::Wasm(WasmMsg::Execute {
-            contract_addr: config
-                .distribution_contract_addr
-                .clone()
-                .unwrap()
-                .to_string(),
-            msg: to_binary(&ExecuteMsg::Distribute(DistributeMsg {
-                membership_issuer_user_id: Uint64::from(membership_issuer_user_id),
-                index_increment: Decimal::from_ratio(
-                    cost_to_sell_membership_response.all_members_fee,
-                    previous_total_supply,
-                ),
-            }))?,
-            funds: vec![Coin {
-                denom: fee_denom.clone(),
-                amount: cost_to_sell_membership_response.all_members_fee,
-            }],
-        }),
-        // Send membership issuer fee to membership issuer
-        CosmosMsg::Bank(BankMsg::Send {
-            to_address: membership_issuer_addr_ref.to_string(),
-            amount: vec![Coin {
-                denom: fee_denom.clone(),
-                amount: cost_to_sell_membership_response.issuer_fee,
-            }],
-        }),
-        // Send protocol fee to fee collector
-        CosmosMsg::Bank(BankMsg::Send {
-            to_address: config.protocol_fee_collector_addr.to_string(),
-            amount: vec![Coin {
-                denom: fee_denom.clone(),
-                amount: cost_to_sell_membership_response.protocol_fee,
-            }],
-        }),
-        // Send sell amount to seller
-        CosmosMsg::Bank(BankMsg::Send {
-            to_address: info.sender.to_string(),
-            amount: vec![Coin {
-                denom: fee_denom,
-                amount: cost_to_sell_membership_response.price,
-            }],
-        }),
-    ];
+    let trade = Trade {
+        id: next_trade_id(deps.storage, membership_issuer_user_id)?,
+        block_height: Uint64::from(env.block.height),
+        timestamp: env.block.time,
+        trader_user_id: Uint64::from(seller_user_id),
+        side: TradeSide::Sell,
+        amount: data.amount,
+        price: cost_to_sell_membership_response.price,
+        protocol_fee: cost_to_sell_membership_response.protocol_fee,
+        referral_fee: cost_to_sell_membership_response.referral_fee,
+        issuer_fee: cost_to_sell_membership_response.issuer_fee,
+        all_members_fee: cost_to_sell_membership_response.all_members_fee,
+    };
+    record_trade(
+        deps.storage,
+        membership_issuer_user_id,
+        &config.price_candle_interval,
+        &trade,
+    )?;
+    observe_price(
+        deps.storage,
+        membership_issuer_user_id,
+        env.block.time,
+        membership_issued_by_me
+            .bonding_curve
+            .calculate_price(new_total_supply, Uint128::one())?,
+    )?;
+
+    let mut msgs_vec = vec![];
+    if cost_to_sell_membership_response.all_members_fee > Uint128::zero() {
+        msgs_vec.push(
+            // Send all member fee to distribution contract
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: distribution_contract_addr.clone(),
+                msg: to_binary(&ExecuteMsg::Distribute(DistributeMsg {
+                    membership_issuer_user_id: Uint64::from(membership_issuer_user_id),
+                    index_increment: Decimal::from_ratio(
+                        cost_to_sell_membership_response.all_members_fee,
+                        previous_total_supply,
+                    ),
+                }))?,
+                funds: vec![Coin {
+                    denom: fee_denom.clone(),
+                    amount: cost_to_sell_membership_response.all_members_fee,
+                }],
+            }),
+        );
+    }
+    if cost_to_sell_membership_response.issuer_fee > Uint128::zero() {
+        msgs_vec.push(
+            // Send membership issuer fee to membership issuer
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: membership_issuer_addr_ref.to_string(),
+                amount: vec![Coin {
+                    denom: fee_denom.clone(),
+                    amount: cost_to_sell_membership_response.issuer_fee,
+                }],
+            }),
+        );
+    }
+    if cost_to_sell_membership_response.protocol_fee > Uint128::zero() {
+        msgs_vec.push(
+            // Send protocol fee to fee collector
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: config.protocol_fee_collector_addr.to_string(),
+                amount: vec![Coin {
+                    denom: fee_denom.clone(),
+                    amount: cost_to_sell_membership_response.protocol_fee,
+                }],
+            }),
+        );
+    }
+
+    if !proceeds.is_zero() {
+        msgs_vec.push(
+            // Send sell amount to seller
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: info.sender.to_string(),
+                amount: vec![Coin {
+                    denom: fee_denom.clone(),
+                    amount: proceeds,
+                }],
+            }),
+        );
+    }
+
+    // Send referral fee to seller's referrer
+    if let Some(msg) = pay_referral_fee(
+        deps.storage,
+        &config,
+        &seller,
+        cost_to_sell_membership_response.referral_fee,
+        &fee_denom,
+    )? {
+        msgs_vec.push(msg);
+    }
 
     msgs_vec.push(
         // Update seller's pending reward in distribution contract
         CosmosMsg::Wasm(WasmMsg::Execute {
-            contract_addr: config.distribution_contract_addr.unwrap().to_string(),
+            contract_addr: distribution_contract_addr,
             msg: to_binary(&ExecuteMsg::UpdateUserPendingReward(
                 UpdateUserPendingRewardMsg {
                     user_id: Uint64::from(seller_user_id),
@@ -355,5 +587,40 @@ pub fn sell_membership(
         }),
     );
 
-    Ok(Response::new().add_messages(msgs_vec))
+    // Refund what user paid on top of what is needed
+    let (refund_amount, refund_msg) = build_refund_msg(
+        &info.sender,
+        user_paid_amount,
+        total_needed_from_user,
+        &fee_denom,
+    );
+    if let Some(msg) = refund_msg {
+        msgs_vec.push(msg);
+    }
+
+    Ok(Response::new()
+        .add_messages(msgs_vec)
+        .add_attribute("action", "sell_membership")
+        .add_attribute("refunded_amount", refund_amount)
+        .add_event(
+            TradeEvent {
+                side: TradeSide::Sell,
+                trader_user_id: Uint64::from(seller_user_id),
+                trader_addr: info.sender,
+                membership_issuer_user_id: Uint64::from(membership_issuer_user_id),
+                amount: data.amount,
+                price: cost_to_sell_membership_response.price,
+                protocol_fee: cost_to_sell_membership_response.protocol_fee,
+                referral_fee: cost_to_sell_membership_response.referral_fee,
+                issuer_fee: cost_to_sell_membership_response.issuer_fee,
+                all_members_fee: cost_to_sell_membership_response.all_members_fee,
+                total_paid: total_needed_from_user,
+                proceeds,
+                refunded_amount: refund_amount,
+                fee_denom,
+                new_supply: new_total_supply,
+                trader_new_hold_amount: seller_new_hold_amount,
+            }
+            .into(),
+        ))
 }
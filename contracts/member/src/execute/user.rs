@@ -1,34 +1,91 @@
 use cosmwasm_std::{
-    to_binary, CosmosMsg, DepsMut, MessageInfo, Response, Uint128, Uint64, WasmMsg,
+    to_binary, CosmosMsg, DepsMut, Env, MessageInfo, Response, Uint128, Uint64, WasmMsg,
 };
 
-use distribution_pkg::msg::{
-    ExecuteMsg, SetupDistributionForNewMemberMsg, SetupDistributionForNewMembershipMsg,
-};
+use cw_utils::Expiration;
+use distribution_pkg::msg::{ExecuteMsg, SetupDistributionForNewMembershipMsg};
 use member_pkg::{
-    config::{Config, FeeConfig},
-    msg::{EnableMembershipMsg, LinkSocialMediaMsg, UpdateUserConfigMsg},
-    user::{MembershipIssuedByMe, User},
+    config::Config,
+    msg::{
+        AcceptMigrateUserMsg, EnableMembershipMsg, LinkSocialMediaMsg,
+        LinkSocialMediaWithAttestationMsg, MigrateUserMsg, RegisterMsg, SetHotAddrMsg,
+        UnlinkSocialMediaMsg, UpdateUserConfigMsg,
+    },
+    user::{
+        normalize_social_media_handle, normalize_social_media_platform, MembershipIssuedByMe,
+        PendingUserFeeChange, SocialLink, User, UserFeeConfig,
+    },
+};
+use shared_pkg::{
+    fee_share_config::FeeShareConfig,
+    pricing::BondingCurve,
+    rbac::{has_any_role, has_role, Role},
 };
-use shared_pkg::fee_share_config::FeeShareConfig;
 
 use crate::{
-    state::{ALL_MEMBERSHIPS_MEMBERS, ALL_USERS, ALL_USERS_MEMBERSHIPS, NEXT_USER_ID},
-    util::fee_share::assert_user_fee_share_sum_to_100,
+    state::{
+        ALL_HOT_ADDRS, ALL_MEMBERSHIPS_MEMBERS, ALL_PENDING_USER_FEE_CHANGES,
+        ALL_REFERRERS_REFEREES, ALL_SOCIAL_MEDIA_HANDLES, ALL_USERS, ALL_USERS_MEMBERSHIPS, CONFIG,
+        NEXT_USER_ID, PENDING_USER_MIGRATIONS, USED_ATTESTATION_NONCES,
+    },
+    util::{
+        attestation::verify_attestation,
+        fee_denom::assert_fee_denom_in_allowlist,
+        fee_range::assert_fee_bps_in_range,
+        fee_share::assert_user_fee_share_sum_to_10000,
+        price::assert_bonding_curve_valid,
+        social_media::{assert_eligible_for_membership, assert_social_media_platform_in_allowlist},
+        twap::observe_price,
+        user::{assert_addr_not_registered, load_user_by_cold_addr, load_user_by_id},
+        user_fee_change::{apply_user_fee_change, with_effective_user_fees},
+    },
     ContractError,
 };
 
-pub fn register(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
-    let sender_addr_ref = &info.sender;
-    if ALL_USERS()
-        .may_load(deps.storage, sender_addr_ref)?
-        .is_some()
-    {
-        return Err(ContractError::AddressAlreadyRegistered {});
-    }
+pub fn register(
+    deps: DepsMut,
+    info: MessageInfo,
+    data: RegisterMsg,
+    config: Config,
+) -> Result<Response, ContractError> {
+    let user_addr = match data.user_addr {
+        None => {
+            if !config.enable_open_registration {
+                return Err(ContractError::OpenRegistrationDisabled {});
+            }
+            info.sender
+        }
+        Some(user_addr) => {
+            if !has_role(deps.storage, &info.sender, &Role::RegistrationOperator)? {
+                return Err(ContractError::OnlyRegistrationAdminCanRegisterOnBehalfOfUser {});
+            }
+            deps.api.addr_validate(&user_addr)?
+        }
+    };
+    let user_addr_ref = &user_addr;
+    assert_addr_not_registered(deps.as_ref(), user_addr_ref)?;
 
     let user_id = NEXT_USER_ID.load(deps.storage)?;
 
+    // Referrer must be an existing user
+    if let Some(referrer_user_id) = data.referrer_user_id {
+        let referrer = match ALL_USERS()
+            .idx
+            .id
+            .item(deps.storage, referrer_user_id.u64())?
+        {
+            None => return Err(ContractError::ReferrerNotExist {}),
+            Some((_, referrer)) => referrer,
+        };
+        ALL_USERS().update(deps.storage, &referrer.addr, |user| match user {
+            None => Err(ContractError::ReferrerNotExist {}),
+            Some(mut user) => {
+                user.referee_count += Uint128::one();
+                Ok(user)
+            }
+        })?;
+    }
+
     if ALL_USERS()
         .idx
         .id
@@ -40,88 +97,274 @@ pub fn register(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractEr
 
     ALL_USERS().save(
         deps.storage,
-        sender_addr_ref,
+        user_addr_ref,
         &User {
             id: user_id,
-            addr: info.sender.clone(),
-            social_media_handle: None,
+            addr: user_addr.clone(),
+            hot_addr: None,
+            social_links: vec![],
             membership_issued_by_me: None,
             // TODO: P1: support custom fee config during registration and update
-            fee_config: None,
+            fee_config: UserFeeConfig::default(),
             fee_share_config: None,
             user_member_count: Uint128::zero(),
+            referrer_user_id: data.referrer_user_id,
+            referee_count: Uint128::zero(),
+            only_verified_buyers: false,
         },
     )?;
 
     NEXT_USER_ID.save(deps.storage, &(user_id + Uint64::one()))?;
 
+    // Referee is listed under referrer before it pays any referral fee
+    if let Some(referrer_user_id) = data.referrer_user_id {
+        ALL_REFERRERS_REFEREES.save(
+            deps.storage,
+            (
+                referrer_user_id.u64(),
+                user_id.u64(),
+                &config.default_fee_config.fee_denom,
+            ),
+            &Uint128::zero(),
+        )?;
+    }
+
     Ok(Response::new()
         .add_attribute("action", "register_user")
         .add_attribute("user_id", user_id)
-        .add_attribute("user_addr", info.sender))
+        .add_attribute("user_addr", user_addr)
+        .add_attribute(
+            "referrer_user_id",
+            data.referrer_user_id
+                .map_or("none".to_string(), |id| id.to_string()),
+        ))
 }
 
 pub fn link_social_media(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     data: LinkSocialMediaMsg,
     config: Config,
 ) -> Result<Response, ContractError> {
-    if info.sender != config.registration_admin_addr {
+    if !has_role(deps.storage, &info.sender, &Role::RegistrationOperator)? {
         return Err(ContractError::OnlyRegistrationAdminCanLinkSocialMediaOnBehalfOfUser {});
     }
 
+    let user = load_user_by_id(deps.as_ref(), data.user_id.u64())?;
+
+    let platform = link_user_social_media(
+        deps,
+        &env,
+        &config,
+        &user,
+        &data.platform,
+        &data.social_media_handle,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "link_social_media")
+        .add_attribute("user_id", user.id)
+        .add_attribute("user_addr", user.addr)
+        .add_attribute("platform", platform)
+        .add_attribute("social_media_handle", data.social_media_handle))
+}
+
+// Link handle on platform to user, shared by registration admin and attestation flow
+// Returns normalized platform
+fn link_user_social_media(
+    deps: DepsMut,
+    env: &Env,
+    config: &Config,
+    user: &User,
+    platform: &str,
+    social_media_handle: &str,
+) -> Result<String, ContractError> {
+    let platform = normalize_social_media_platform(platform);
+    assert_social_media_platform_in_allowlist(config, &platform)?;
+
+    let normalized_social_media_handle = normalize_social_media_handle(social_media_handle);
+    if normalized_social_media_handle.is_empty() {
+        return Err(ContractError::SocialMediaHandleCannotBeEmpty {});
+    }
+    // Same handle on the same platform cannot be linked to multiple users
+    if ALL_SOCIAL_MEDIA_HANDLES.has(deps.storage, (&platform, &normalized_social_media_handle)) {
+        return Err(ContractError::SocialMediaHandleAlreadyLinked {});
+    }
+
+    ALL_USERS().update(deps.storage, &user.addr, |user| match user {
+        // User should exist in USERS as it should be registered
+        None => Err(ContractError::UserNotExist {}),
+        Some(mut user) => {
+            // User should not have linked a social media handle on the platform yet
+            if user.social_link(&platform).is_some() {
+                return Err(ContractError::UserAlreadyLinkedSocialMedia {});
+            }
+            user.social_links.push(SocialLink {
+                platform: platform.clone(),
+                handle: social_media_handle.to_string(),
+                linked_at: env.block.time,
+            });
+            Ok(user)
+        }
+    })?;
+    ALL_SOCIAL_MEDIA_HANDLES.save(
+        deps.storage,
+        (&platform, &normalized_social_media_handle),
+        &user.id.u64(),
+    )?;
+
+    Ok(platform)
+}
+
+pub fn link_social_media_with_attestation(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    data: LinkSocialMediaWithAttestationMsg,
+    config: Config,
+) -> Result<Response, ContractError> {
+    let attestation = data.attestation;
+
     let user = ALL_USERS()
         .idx
         .id
-        .item(deps.storage, data.user_id.u64())?
-        .unwrap()
+        .item(deps.storage, attestation.user_id.u64())?
+        .ok_or(ContractError::UserNotExist {})?
         .1;
+    // Attestation is not a bearer token, only the attested user can submit it
+    if info.sender != user.addr {
+        return Err(ContractError::OnlyUserCanSubmitItsAttestation {});
+    }
+
+    verify_attestation(deps.as_ref(), &env, &config, &attestation, &data.signature)?;
+    USED_ATTESTATION_NONCES.save(deps.storage, attestation.nonce.u64(), &user.id.u64())?;
+
+    let platform = link_user_social_media(
+        deps.branch(),
+        &env,
+        &config,
+        &user,
+        &attestation.platform,
+        &attestation.social_media_handle,
+    )?;
+
+    let mut response = Response::new()
+        .add_attribute("action", "link_social_media_with_attestation")
+        .add_attribute("user_id", user.id)
+        .add_attribute("user_addr", user.addr.clone())
+        .add_attribute("platform", platform)
+        .add_attribute("social_media_handle", attestation.social_media_handle)
+        .add_attribute("nonce", attestation.nonce);
+
+    if attestation.enable_membership {
+        // Reload user so eligibility sees the link just added
+        let user = ALL_USERS().load(deps.storage, &user.addr)?;
+        let setup_distribution_msg = enable_user_membership(
+            deps,
+            &env,
+            config,
+            &user,
+            data.bonding_curve,
+            data.fee_denom,
+        )?;
+        response = response
+            .add_message(setup_distribution_msg)
+            .add_attribute("membership_enabled", "true");
+    }
+
+    Ok(response)
+}
+
+pub fn unlink_social_media(
+    deps: DepsMut,
+    info: MessageInfo,
+    data: UnlinkSocialMediaMsg,
+) -> Result<Response, ContractError> {
+    let user = load_user_by_id(deps.as_ref(), data.user_id.u64())?;
+
+    if info.sender != user.addr
+        && !has_any_role(
+            deps.storage,
+            &info.sender,
+            &[Role::RegistrationOperator, Role::Moderator],
+        )?
+    {
+        return Err(ContractError::OnlyRegistrationAdminOrUserCanUnlinkSocialMedia {});
+    }
+
+    // Platform could have been removed from allowlist after linking, user can still unlink it
+    let platform = normalize_social_media_platform(&data.platform);
+    let social_link = match user.social_link(&platform) {
+        Some(social_link) => social_link.clone(),
+        None => return Err(ContractError::UserHasNotLinkedSocialMedia { platform }),
+    };
+
+    // Free the handle so it can be linked again
+    ALL_SOCIAL_MEDIA_HANDLES.remove(
+        deps.storage,
+        (
+            &platform,
+            &normalize_social_media_handle(&social_link.handle),
+        ),
+    );
 
     ALL_USERS().update(deps.storage, &user.addr, |user| match user {
-        // User should exist in USERS as it should be registered
         None => Err(ContractError::UserNotExist {}),
-        Some(user) => {
-            // User should not have linked a social media handle yet
-            if user.social_media_handle.is_some() {
-                return Err(ContractError::UserAlreadyLinkedSocialMedia {});
-            }
-            let updated_user = User {
-                id: user.id,
-                addr: user.addr,
-                social_media_handle: Some(data.social_media_handle.clone()),
-                membership_issued_by_me: user.membership_issued_by_me,
-                fee_config: user.fee_config,
-                fee_share_config: user.fee_share_config,
-                user_member_count: user.user_member_count,
-            };
-            Ok(updated_user)
+        Some(mut user) => {
+            user.social_links
+                .retain(|social_link| social_link.platform != platform);
+            Ok(user)
         }
     })?;
 
     Ok(Response::new()
-        .add_attribute("action", "link_social_media")
+        .add_attribute("action", "unlink_social_media")
         .add_attribute("user_id", user.id)
         .add_attribute("user_addr", user.addr)
-        .add_attribute("social_media_handle", data.social_media_handle))
+        .add_attribute("platform", social_link.platform)
+        .add_attribute("social_media_handle", social_link.handle))
 }
 
 pub fn enable_membership(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     data: EnableMembershipMsg,
     config: Config,
 ) -> Result<Response, ContractError> {
-    if info.sender != config.registration_admin_addr {
+    if !has_role(deps.storage, &info.sender, &Role::RegistrationOperator)? {
         return Err(ContractError::OnlyRegistrationAdminCanEnableMembershipOnBehalfOfUser {});
     }
 
-    let user = ALL_USERS()
-        .idx
-        .id
-        .item(deps.storage, data.user_id.u64())?
-        .unwrap()
-        .1;
+    let user = load_user_by_id(deps.as_ref(), data.user_id.u64())?;
+
+    let setup_distribution_msg = enable_user_membership(
+        deps,
+        &env,
+        config,
+        &user,
+        data.bonding_curve,
+        data.fee_denom,
+    )?;
+
+    Ok(Response::new()
+        .add_message(setup_distribution_msg)
+        .add_attribute("action", "enable_membership")
+        .add_attribute("user_id", user.id)
+        .add_attribute("user_addr", user.addr))
+}
+
+// Enable membership of user, shared by registration admin and attestation flow
+// Returns msg to setup distribution of the new membership
+fn enable_user_membership(
+    deps: DepsMut,
+    env: &Env,
+    config: Config,
+    user: &User,
+    bonding_curve: Option<BondingCurve>,
+    fee_denom: Option<String>,
+) -> Result<CosmosMsg, ContractError> {
     let user_id = user.id.u64();
 
     // User should not have a Membership yet
@@ -129,10 +372,15 @@ pub fn enable_membership(
         return Err(ContractError::UserAlreadyRegisteredMembership {});
     }
 
-    // User must already have a social media handle
-    if user.social_media_handle.is_none() {
-        return Err(ContractError::UserCannotRegisterMembershipBeforeLinkingSocialMedia {});
-    }
+    assert_eligible_for_membership(&config, user)?;
+
+    let bonding_curve = bonding_curve.unwrap_or(config.default_bonding_curve.clone());
+    assert_bonding_curve_valid(&bonding_curve)?;
+    // Price history starts from the first membership issuer holds
+    let spot_price = bonding_curve.calculate_price(Uint128::one(), Uint128::one())?;
+
+    let fee_denom = fee_denom.unwrap_or(config.default_fee_config.fee_denom.clone());
+    assert_fee_denom_in_allowlist(&config, &fee_denom)?;
 
     ALL_USERS().update(deps.storage, &user.addr, |user| match user {
         // User should exist in USERS as it should be registered
@@ -141,15 +389,21 @@ pub fn enable_membership(
             let updated_user = User {
                 id: user.id,
                 addr: user.addr,
-                social_media_handle: user.social_media_handle,
+                hot_addr: user.hot_addr,
+                social_links: user.social_links,
                 membership_issued_by_me: Some(MembershipIssuedByMe {
                     membership_supply: Uint128::one(),
                     member_count: Uint128::one(),
+                    bonding_curve,
+                    fee_denom: fee_denom.clone(),
                 }),
                 fee_config: user.fee_config,
                 fee_share_config: user.fee_share_config,
                 // User is a new member of itself
                 user_member_count: user.user_member_count + Uint128::one(),
+                referrer_user_id: user.referrer_user_id,
+                referee_count: user.referee_count,
+                only_verified_buyers: user.only_verified_buyers,
             };
             Ok(updated_user)
         }
@@ -173,90 +427,274 @@ pub fn enable_membership(
         }
     })?;
 
-    let distribution_contract_addr = config.distribution_contract_addr.unwrap().to_string();
-
-    let msgs_vec = vec![
-        // Setup distribution for new membership program
-        CosmosMsg::Wasm(WasmMsg::Execute {
-            contract_addr: distribution_contract_addr.clone(),
-            msg: to_binary(&ExecuteMsg::SetupDistributionForNewMembership(
-                SetupDistributionForNewMembershipMsg {
-                    membership_issuer_user_id: Uint64::from(user_id),
-                },
-            ))?,
-            funds: vec![],
-        }),
-        // Setup distribution for new member, since user is its own first member
-        CosmosMsg::Wasm(WasmMsg::Execute {
-            contract_addr: distribution_contract_addr,
-            msg: to_binary(&ExecuteMsg::SetupDistributionForNewMember(
-                SetupDistributionForNewMemberMsg {
-                    membership_issuer_user_id: Uint64::from(user_id),
-                    user_id: Uint64::from(user_id),
-                },
-            ))?,
-            funds: vec![],
-        }),
-    ];
+    observe_price(deps.storage, user_id, env.block.time, spot_price)?;
 
-    Ok(Response::new()
-        .add_messages(msgs_vec)
-        .add_attribute("action", "enable_membership")
-        .add_attribute("user_id", user.id)
-        .add_attribute("user_addr", user.addr))
+    let distribution_contract_addr = config
+        .distribution_contract_addr
+        .ok_or(ContractError::DistributionContractNotSet {})?
+        .to_string();
+
+    // Setup distribution for new membership program
+    // This also sets up distribution for the user itself, since user is its own first member
+    Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: distribution_contract_addr,
+        msg: to_binary(&ExecuteMsg::SetupDistributionForNewMembership(
+            SetupDistributionForNewMembershipMsg {
+                membership_issuer_user_id: Uint64::from(user_id),
+                fee_denom,
+            },
+        ))?,
+        funds: vec![],
+    }))
 }
 
 pub fn update_user_config(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     data: UpdateUserConfigMsg,
 ) -> Result<Response, ContractError> {
     let user_id = data.user_id.u64();
-    let user = ALL_USERS().idx.id.item(deps.storage, user_id)?.unwrap().1;
-    let user_addr_ref = &user.addr;
+    let user = load_user_by_id(deps.as_ref(), user_id)?;
 
-    if info.sender != *user_addr_ref {
+    if info.sender != user.addr {
         return Err(
             ContractError::OnlyMembershipIssuerCanUpdateItsTradingFeePercentageOfMembership {},
         );
     }
 
-    ALL_USERS().update(deps.storage, user_addr_ref, |user| match user {
-        // User should exist in USERS as it should be registered
-        None => Err(ContractError::UserNotExist {}),
-        Some(user) => {
-            let updated_user = User {
-                id: user.id,
-                addr: user.addr,
-                social_media_handle: user.social_media_handle,
-                membership_issued_by_me: user.membership_issued_by_me,
-                fee_config: match data.trading_fee_percentage_of_membership {
-                    None => user.fee_config,
-                    Some(data) => Some(FeeConfig {
-                        trading_fee_percentage_of_membership: data,
-                        fee_denom: user.fee_config.unwrap().fee_denom,
-                    }),
-                },
-                fee_share_config: if data.share_to_issuer_percentage.is_none() {
-                    user.fee_share_config
-                } else {
-                    Some(FeeShareConfig {
-                        share_to_issuer_percentage: data.share_to_issuer_percentage.unwrap(),
-                        share_to_all_members_percentage: data
-                            .share_to_all_members_percentage
-                            .unwrap(),
-                    })
-                },
-                user_member_count: user.user_member_count,
-            };
-            Ok(updated_user)
+    let config = CONFIG.load(deps.storage)?;
+
+    // Write fee change that has taken effect to user before it gets replaced
+    let (mut user, pending_fee_change) =
+        with_effective_user_fees(deps.storage, &env.block, &config.user_fee_ranges, user)?;
+    ALL_PENDING_USER_FEE_CHANGES.remove(deps.storage, user_id);
+
+    // Holding cap and verified buyers only restrict buyers so they take effect right away
+    if let Some(holding_cap) = data.holding_cap {
+        user.fee_config.holding_cap = Some(holding_cap);
+    }
+    user.only_verified_buyers = data
+        .only_verified_buyers
+        .unwrap_or(user.only_verified_buyers);
+
+    if let Some(trading_fee_bps_of_membership) = data.trading_fee_bps_of_membership {
+        assert_fee_bps_in_range(
+            "trading_fee_bps_of_membership",
+            trading_fee_bps_of_membership,
+            &config.user_fee_ranges.trading_fee_bps_of_membership,
+        )?;
+    }
+
+    // Both shares are set together, one alone cannot sum to 10000
+    let fee_share_config = match (data.share_to_issuer_bps, data.share_to_all_members_bps) {
+        (Some(share_to_issuer_bps), Some(share_to_all_members_bps)) => Some(FeeShareConfig {
+            share_to_issuer_bps,
+            share_to_all_members_bps,
+        }),
+        (None, None) => None,
+        _ => return Err(ContractError::MembershipTradingFeeShareBpsMustSumTo10000 {}),
+    };
+    if let Some(fee_share_config) = &fee_share_config {
+        assert_user_fee_share_sum_to_10000(fee_share_config)?;
+    }
+
+    let mut fee_change = PendingUserFeeChange {
+        trading_fee_bps_of_membership: data.trading_fee_bps_of_membership,
+        fee_share_config,
+        effective_at: match config.user_fee_change_timelock {
+            None => Expiration::AtHeight(env.block.height),
+            Some(user_fee_change_timelock) => user_fee_change_timelock.after(&env.block),
+        },
+    };
+
+    let mut response = Response::new()
+        .add_attribute("action", "update_user_config")
+        .add_attribute("user_id", user.id)
+        .add_attribute("membership_issuer_addr", user.addr.to_string());
+
+    if fee_change.is_empty() {
+        // Nothing to replace, keep fee change still waiting for the time-lock
+        if let Some(pending_fee_change) = pending_fee_change {
+            ALL_PENDING_USER_FEE_CHANGES.save(deps.storage, user_id, &pending_fee_change)?;
         }
-    })?;
+    } else if config.user_fee_change_timelock.is_none() {
+        // Fee change left from when time-lock was set is replaced as well
+        apply_user_fee_change(&mut user, fee_change);
+    } else {
+        // New fee change replaces the pending one, fees it leaves out keep their pending value
+        // Time-lock restarts so holders always get the full delay to react
+        if let Some(pending_fee_change) = pending_fee_change {
+            fee_change.trading_fee_bps_of_membership = fee_change
+                .trading_fee_bps_of_membership
+                .or(pending_fee_change.trading_fee_bps_of_membership);
+            fee_change.fee_share_config = fee_change
+                .fee_share_config
+                .or(pending_fee_change.fee_share_config);
+        }
+        response = response.add_attribute(
+            "fee_change_effective_at",
+            fee_change.effective_at.to_string(),
+        );
+        ALL_PENDING_USER_FEE_CHANGES.save(deps.storage, user_id, &fee_change)?;
+    }
+
+    ALL_USERS().save(deps.storage, &user.addr, &user)?;
+
+    Ok(response)
+}
+
+pub fn migrate_user(
+    deps: DepsMut,
+    info: MessageInfo,
+    data: MigrateUserMsg,
+) -> Result<Response, ContractError> {
+    let user = load_user_by_cold_addr(deps.as_ref(), &info.sender)?;
+
+    let new_user_addr = deps.api.addr_validate(data.new_user_addr.as_str())?;
+    if new_user_addr == user.addr {
+        return Err(ContractError::CannotMigrateUserToItsCurrentAddress {});
+    }
+    // New address cannot be used by another user, otherwise we would overwrite that user
+    assert_addr_not_registered(deps.as_ref(), &new_user_addr)?;
 
-    assert_user_fee_share_sum_to_100(deps.as_ref(), user_id)?;
+    // Proposing again overwrites the previous pending migration
+    PENDING_USER_MIGRATIONS.save(deps.storage, user.id.u64(), &new_user_addr)?;
 
     Ok(Response::new()
-        .add_attribute("action", "update_user_config")
+        .add_attribute("action", "migrate_user")
+        .add_attribute("user_id", user.id)
+        .add_attribute("user_addr", user.addr)
+        .add_attribute("new_user_addr", new_user_addr))
+}
+
+pub fn accept_migrate_user(
+    deps: DepsMut,
+    info: MessageInfo,
+    data: AcceptMigrateUserMsg,
+) -> Result<Response, ContractError> {
+    let user_id = data.user_id.u64();
+
+    let new_user_addr = match PENDING_USER_MIGRATIONS.may_load(deps.storage, user_id)? {
+        None => return Err(ContractError::UserHasNoPendingMigration {}),
+        Some(new_user_addr) => new_user_addr,
+    };
+    if info.sender != new_user_addr {
+        return Err(ContractError::OnlyProposedNewAddressCanAcceptUserMigration {});
+    }
+
+    // New address might have been registered after the migration was proposed
+    assert_addr_not_registered(deps.as_ref(), &new_user_addr)?;
+
+    let user = match ALL_USERS().idx.id.item(deps.storage, user_id)? {
+        None => return Err(ContractError::UserNotExist {}),
+        Some((_, user)) => user,
+    };
+    let old_user_addr = user.addr.clone();
+
+    // Remove before save so the unique user ID index is freed for the new key
+    ALL_USERS().remove(deps.storage, &old_user_addr)?;
+    ALL_USERS().save(
+        deps.storage,
+        &new_user_addr,
+        &User {
+            id: user.id,
+            addr: new_user_addr.clone(),
+            hot_addr: user.hot_addr,
+            social_links: user.social_links,
+            membership_issued_by_me: user.membership_issued_by_me,
+            fee_config: user.fee_config,
+            fee_share_config: user.fee_share_config,
+            user_member_count: user.user_member_count,
+            referrer_user_id: user.referrer_user_id,
+            referee_count: user.referee_count,
+            only_verified_buyers: user.only_verified_buyers,
+        },
+    )?;
+
+    PENDING_USER_MIGRATIONS.remove(deps.storage, user_id);
+
+    Ok(Response::new()
+        .add_attribute("action", "accept_migrate_user")
         .add_attribute("user_id", user.id)
-        .add_attribute("membership_issuer_addr", user_addr_ref.to_string()))
+        .add_attribute("old_user_addr", old_user_addr)
+        .add_attribute("new_user_addr", new_user_addr))
+}
+
+pub fn set_hot_addr(
+    deps: DepsMut,
+    info: MessageInfo,
+    data: SetHotAddrMsg,
+) -> Result<Response, ContractError> {
+    let user = load_user_by_cold_addr(deps.as_ref(), &info.sender)?;
+
+    let hot_addr = deps.api.addr_validate(data.hot_addr.as_str())?;
+    if hot_addr == user.addr {
+        return Err(ContractError::HotAddrCannotBeSameAsColdAddr {});
+    }
+    assert_addr_not_registered(deps.as_ref(), &hot_addr)?;
+
+    // Replace previous hot address if any
+    if let Some(previous_hot_addr) = &user.hot_addr {
+        ALL_HOT_ADDRS.remove(deps.storage, previous_hot_addr);
+    }
+    ALL_HOT_ADDRS.save(deps.storage, &hot_addr, &user.id.u64())?;
+
+    ALL_USERS().save(
+        deps.storage,
+        &user.addr,
+        &User {
+            id: user.id,
+            addr: user.addr.clone(),
+            hot_addr: Some(hot_addr.clone()),
+            social_links: user.social_links,
+            membership_issued_by_me: user.membership_issued_by_me,
+            fee_config: user.fee_config,
+            fee_share_config: user.fee_share_config,
+            user_member_count: user.user_member_count,
+            referrer_user_id: user.referrer_user_id,
+            referee_count: user.referee_count,
+            only_verified_buyers: user.only_verified_buyers,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_hot_addr")
+        .add_attribute("user_id", user.id)
+        .add_attribute("user_addr", user.addr)
+        .add_attribute("hot_addr", hot_addr))
+}
+
+pub fn remove_hot_addr(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    let user = load_user_by_cold_addr(deps.as_ref(), &info.sender)?;
+
+    let hot_addr = match user.hot_addr {
+        None => return Err(ContractError::UserHasNoHotAddr {}),
+        Some(hot_addr) => hot_addr,
+    };
+    ALL_HOT_ADDRS.remove(deps.storage, &hot_addr);
+
+    ALL_USERS().save(
+        deps.storage,
+        &user.addr,
+        &User {
+            id: user.id,
+            addr: user.addr.clone(),
+            hot_addr: None,
+            social_links: user.social_links,
+            membership_issued_by_me: user.membership_issued_by_me,
+            fee_config: user.fee_config,
+            fee_share_config: user.fee_share_config,
+            user_member_count: user.user_member_count,
+            referrer_user_id: user.referrer_user_id,
+            referee_count: user.referee_count,
+            only_verified_buyers: user.only_verified_buyers,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "remove_hot_addr")
+        .add_attribute("user_id", user.id)
+        .add_attribute("user_addr", user.addr)
+        .add_attribute("hot_addr", hot_addr))
 }
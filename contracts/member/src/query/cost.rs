@@ -1,4 +1,6 @@
-use cosmwasm_std::{Deps, StdResult, Uint128};
+use cosmwasm_std::{Deps, Env, StdError, StdResult, Uint128};
+
+use shared_pkg::pricing::checked_multiply_basis_points;
 
 use member_pkg::{
     config::Config,
@@ -6,127 +8,158 @@ use member_pkg::{
         CostToBuyMembershipResponse, CostToSellMembershipResponse, QueryCostToBuyMembershipMsg,
         QueryCostToSellMembershipMsg,
     },
+    user::{MembershipIssuedByMe, User},
 };
 
-use crate::{
-    state::ALL_USERS,
-    util::price::{calculate_price, multiply_percentage},
-};
+use crate::util::{user::may_load_user_by_id, user_fee_change::with_effective_user_fees};
 
-fn shared(
+// Issuer fee change applies from the block it takes effect, even if nobody has written it to user yet
+fn load_membership_issuer(
     deps: Deps,
-    config: Config,
+    env: &Env,
+    config: &Config,
     membership_issuer_user_id: u64,
+) -> StdResult<(User, MembershipIssuedByMe)> {
+    let issuer = may_load_user_by_id(deps, membership_issuer_user_id)?
+        .ok_or_else(|| StdError::not_found("User"))?;
+    let membership_issued_by_me = issuer
+        .membership_issued_by_me
+        .clone()
+        .ok_or_else(|| StdError::generic_err("Membership not enabled"))?;
+    let (issuer, _) =
+        with_effective_user_fees(deps.storage, &env.block, &config.user_fee_ranges, issuer)?;
+    Ok((issuer, membership_issued_by_me))
+}
+
+fn shared(
+    config: &Config,
+    issuer: &User,
+    membership_issued_by_me: &MembershipIssuedByMe,
     supply: Uint128,
     amount: Uint128,
-) -> (Uint128, Uint128, Uint128, Uint128) {
-    let price = calculate_price(supply, amount);
-
-    let issuer = ALL_USERS()
-        .idx
-        .id
-        .item(deps.storage, membership_issuer_user_id)
-        .unwrap()
-        .unwrap()
-        .1;
-
-    let fee = multiply_percentage(
+) -> StdResult<(Uint128, Uint128, Uint128, Uint128, Uint128)> {
+    // Price follows the bonding curve issuer picked when enabling membership
+    let price = membership_issued_by_me
+        .bonding_curve
+        .calculate_price(supply, amount)?;
+
+    let fee = checked_multiply_basis_points(
         price,
         issuer
             .fee_config
-            .unwrap_or(config.default_fee_config)
-            .trading_fee_percentage_of_membership,
-    );
+            .resolve(&config.default_fee_config)
+            .trading_fee_bps_of_membership,
+    )?;
 
     let fee_share_config = issuer
         .fee_share_config
-        .unwrap_or(config.default_fee_share_config);
+        .clone()
+        .unwrap_or(config.default_fee_share_config.clone());
 
-    let issuer_fee = multiply_percentage(fee, fee_share_config.share_to_issuer_percentage);
+    let issuer_fee = checked_multiply_basis_points(fee, fee_share_config.share_to_issuer_bps)?;
 
     let all_members_fee =
-        multiply_percentage(fee, fee_share_config.share_to_all_members_percentage);
-
-    let protocol_fee = multiply_percentage(
-        fee,
-        config.protocol_fee_config.membership_trading_fee_percentage,
-    );
-
-    (price, issuer_fee, all_members_fee, protocol_fee)
+        checked_multiply_basis_points(fee, fee_share_config.share_to_all_members_bps)?;
+
+    let total_protocol_fee =
+        checked_multiply_basis_points(fee, config.protocol_fee_config.membership_trading_fee_bps)?;
+    // Referral fee is part of protocol fee, so it doesn't change total cost to user
+    let referral_fee = checked_multiply_basis_points(
+        total_protocol_fee,
+        config.protocol_fee_config.referral_fee_bps,
+    )?;
+    let protocol_fee = total_protocol_fee.checked_sub(referral_fee)?;
+
+    Ok((
+        price,
+        issuer_fee,
+        all_members_fee,
+        protocol_fee,
+        referral_fee,
+    ))
 }
 
 pub fn query_cost_to_buy_membership(
     deps: Deps,
+    env: Env,
     data: QueryCostToBuyMembershipMsg,
     config: Config,
 ) -> StdResult<CostToBuyMembershipResponse> {
     let membership_issuer_user_id = data.membership_issuer_user_id.u64();
 
-    let old_supply = ALL_USERS()
-        .idx
-        .id
-        .item(deps.storage, membership_issuer_user_id)?
-        .unwrap()
-        .1
-        .membership_issued_by_me
-        .unwrap()
-        .membership_supply;
-
-    let (price, issuer_fee, all_members_fee, protocol_fee) = shared(
-        deps,
-        config,
-        membership_issuer_user_id,
+    let (membership_issuer, membership_issued_by_me) =
+        load_membership_issuer(deps, &env, &config, membership_issuer_user_id)?;
+    let old_supply = membership_issued_by_me.membership_supply;
+    // Issuer's own holding cap overrides config's default
+    let holding_cap = membership_issuer
+        .fee_config
+        .resolve(&config.default_fee_config)
+        .holding_cap;
+
+    let (price, issuer_fee, all_members_fee, protocol_fee, referral_fee) = shared(
+        &config,
+        &membership_issuer,
+        &membership_issued_by_me,
         old_supply,
         data.amount,
-    );
+    )?;
 
-    let total_needed_from_user = price + protocol_fee + issuer_fee + all_members_fee;
+    let total_needed_from_user = price
+        .checked_add(protocol_fee)?
+        .checked_add(referral_fee)?
+        .checked_add(issuer_fee)?
+        .checked_add(all_members_fee)?;
 
     Ok(CostToBuyMembershipResponse {
         price,
         protocol_fee,
+        referral_fee,
         issuer_fee,
         all_members_fee,
         total_needed_from_user,
+        fee_denom: membership_issued_by_me.fee_denom,
+        holding_cap,
     })
 }
 
 pub fn query_cost_to_sell_membership(
     deps: Deps,
+    env: Env,
     data: QueryCostToSellMembershipMsg,
     config: Config,
 ) -> StdResult<CostToSellMembershipResponse> {
     let membership_issuer_user_id = data.membership_issuer_user_id.u64();
 
-    let old_supply = ALL_USERS()
-        .idx
-        .id
-        .item(deps.storage, membership_issuer_user_id)?
-        .unwrap()
-        .1
-        .membership_issued_by_me
-        .unwrap()
-        .membership_supply;
+    let (membership_issuer, membership_issued_by_me) =
+        load_membership_issuer(deps, &env, &config, membership_issuer_user_id)?;
+    let old_supply = membership_issued_by_me.membership_supply;
 
-    let (price, issuer_fee, all_members_fee, protocol_fee) = shared(
-        deps,
-        config,
-        membership_issuer_user_id,
+    let (price, issuer_fee, all_members_fee, protocol_fee, referral_fee) = shared(
+        &config,
+        &membership_issuer,
+        &membership_issued_by_me,
         // We need this to make sure price is the same across buy and sell
         // e.g. old supply is 5, now buy 10 memberships, new supply is 15
         // Now sell 10 memberships, new supply is 5, price to buy 10 memberships should be the same as price to sell 10 memberships
         // Because before supply and after supply is the same
-        old_supply - data.amount,
+        old_supply.checked_sub(data.amount)?,
         data.amount,
-    );
+    )?;
 
-    let total_needed_from_user = protocol_fee + issuer_fee + all_members_fee;
+    let total_needed_from_user = protocol_fee
+        .checked_add(referral_fee)?
+        .checked_add(issuer_fee)?
+        .checked_add(all_members_fee)?;
+    let net_proceeds = price.saturating_sub(total_needed_from_user);
 
     Ok(CostToSellMembershipResponse {
         price,
         protocol_fee,
+        referral_fee,
         issuer_fee,
         all_members_fee,
         total_needed_from_user,
+        net_proceeds,
+        fee_denom: membership_issued_by_me.fee_denom,
     })
 }
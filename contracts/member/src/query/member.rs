@@ -1,4 +1,4 @@
-use cosmwasm_std::{Deps, Order, StdResult, Uint128, Uint64};
+use cosmwasm_std::{Deps, Order, StdError, StdResult, Uint128, Uint64};
 use cw_storage_plus::{Bound, PrefixBound};
 
 use member_pkg::{
@@ -10,37 +10,23 @@ use member_pkg::{
     user::{Member, Membership},
 };
 
-use crate::state::{
-    ALL_MEMBERSHIPS_MEMBERS, ALL_USERS, ALL_USERS_MEMBERSHIPS, DEFAULT_QUERY_LIMIT, MAX_QUERY_LIMIT,
+use crate::{
+    state::{ALL_MEMBERSHIPS_MEMBERS, ALL_USERS_MEMBERSHIPS, DEFAULT_QUERY_LIMIT, MAX_QUERY_LIMIT},
+    util::user::{load_membership_issued_by, may_load_user_by_id},
 };
 
 pub fn query_membership_supply(
     deps: Deps,
     data: QueryMembershipSupplyMsg,
 ) -> StdResult<MembershipSupplyResponse> {
-    let supply = ALL_USERS()
-        .idx
-        .id
-        .item(deps.storage, data.membership_issuer_user_id.u64())?
-        .unwrap()
-        .1
-        .membership_issued_by_me
-        .unwrap()
-        .membership_supply;
+    let supply =
+        load_membership_issued_by(deps, data.membership_issuer_user_id.u64())?.membership_supply;
 
     Ok(MembershipSupplyResponse { supply })
 }
 
 pub fn query_member_count(deps: Deps, data: QueryMemberCountMsg) -> StdResult<MemberCountResponse> {
-    let count = ALL_USERS()
-        .idx
-        .id
-        .item(deps.storage, data.membership_issuer_user_id.u64())?
-        .unwrap()
-        .1
-        .membership_issued_by_me
-        .unwrap()
-        .member_count;
+    let count = load_membership_issued_by(deps, data.membership_issuer_user_id.u64())?.member_count;
 
     Ok(MemberCountResponse { count })
 }
@@ -93,12 +79,8 @@ pub fn query_memberships(deps: Deps, data: QueryMembershipsMsg) -> StdResult<Mem
     })
     .collect::<StdResult<Vec<Membership>>>()?;
 
-    let total_count = ALL_USERS()
-        .idx
-        .id
-        .item(deps.storage, user_id)?
-        .unwrap()
-        .1
+    let total_count = may_load_user_by_id(deps, user_id)?
+        .ok_or_else(|| StdError::not_found("User"))?
         .user_member_count
         .u128() as usize;
 
@@ -144,14 +126,7 @@ pub fn query_members(deps: Deps, data: QueryMembersMsg) -> StdResult<MembersResp
     })
     .collect::<StdResult<Vec<Member>>>()?;
 
-    let total_count = ALL_USERS()
-        .idx
-        .id
-        .item(deps.storage, membership_issuer_user_id)?
-        .unwrap()
-        .1
-        .membership_issued_by_me
-        .unwrap()
+    let total_count = load_membership_issued_by(deps, membership_issuer_user_id)?
         .member_count
         .u128() as usize;
 
@@ -1,4 +1,5 @@
 pub(crate) mod config;
 pub(crate) mod cost;
 pub(crate) mod member;
+pub(crate) mod trade;
 pub(crate) mod user;
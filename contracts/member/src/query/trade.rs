@@ -0,0 +1,163 @@
+use cosmwasm_std::{Deps, Env, Order, StdError, StdResult, Timestamp, Uint128, Uint256, Uint64};
+use cw_storage_plus::Bound;
+
+use member_pkg::{
+    config::Config,
+    msg::{
+        PriceCandlesResponse, QueryPriceCandlesMsg, QueryTradeHistoryMsg, QueryTwapMsg,
+        TradeHistoryResponse, TwapResponse,
+    },
+    trade::{Candle, Trade},
+};
+
+use crate::{
+    state::{
+        ALL_MEMBERSHIPS_PRICE_CANDLES, ALL_MEMBERSHIPS_PRICE_OBSERVATIONS, ALL_MEMBERSHIPS_TRADES,
+        ALL_MEMBERSHIPS_TRADE_COUNT, DEFAULT_QUERY_LIMIT, MAX_QUERY_LIMIT,
+    },
+    util::{twap::load_latest_price_observation, user::load_membership_issued_by},
+};
+
+pub fn query_trade_history(
+    deps: Deps,
+    data: QueryTradeHistoryMsg,
+) -> StdResult<TradeHistoryResponse> {
+    let membership_issuer_user_id = data.membership_issuer_user_id.u64();
+
+    let trades = ALL_MEMBERSHIPS_TRADES
+        .prefix(membership_issuer_user_id)
+        .range(
+            deps.storage,
+            data.start_after_trade_id.map(|start_after_trade_id| {
+                if data.include_start_after.unwrap_or(false) {
+                    Bound::inclusive(start_after_trade_id.u64())
+                } else {
+                    Bound::exclusive(start_after_trade_id.u64())
+                }
+            }),
+            None,
+            Order::Ascending,
+        )
+        .take(
+            data.limit
+                .unwrap_or(DEFAULT_QUERY_LIMIT)
+                .min(MAX_QUERY_LIMIT) as usize,
+        )
+        .map(|item| item.map(|(_, trade)| trade))
+        .collect::<StdResult<Vec<Trade>>>()?;
+
+    let total_count = ALL_MEMBERSHIPS_TRADE_COUNT
+        .may_load(deps.storage, membership_issuer_user_id)?
+        .unwrap_or_default()
+        .u64() as usize;
+
+    Ok(TradeHistoryResponse {
+        count: trades.len(),
+        trades,
+        total_count,
+    })
+}
+
+pub fn query_price_candles(
+    deps: Deps,
+    data: QueryPriceCandlesMsg,
+    config: Config,
+) -> StdResult<PriceCandlesResponse> {
+    let interval = data.interval.unwrap_or(config.price_candle_interval);
+
+    let candles = ALL_MEMBERSHIPS_PRICE_CANDLES
+        .prefix((data.membership_issuer_user_id.u64(), interval.as_str()))
+        .range(
+            deps.storage,
+            data.start_after_start_time.map(|start_after_start_time| {
+                if data.include_start_after.unwrap_or(false) {
+                    Bound::inclusive(start_after_start_time.u64())
+                } else {
+                    Bound::exclusive(start_after_start_time.u64())
+                }
+            }),
+            None,
+            Order::Ascending,
+        )
+        .take(
+            data.limit
+                .unwrap_or(DEFAULT_QUERY_LIMIT)
+                .min(MAX_QUERY_LIMIT) as usize,
+        )
+        .map(|item| item.map(|(_, candle)| candle))
+        .collect::<StdResult<Vec<Candle>>>()?;
+
+    Ok(PriceCandlesResponse {
+        interval,
+        count: candles.len(),
+        candles,
+    })
+}
+
+pub fn query_twap(deps: Deps, env: Env, data: QueryTwapMsg) -> StdResult<TwapResponse> {
+    let membership_issuer_user_id = data.membership_issuer_user_id.u64();
+
+    let membership_issued_by_me = load_membership_issued_by(deps, membership_issuer_user_id)?;
+    let spot_price = membership_issued_by_me
+        .bonding_curve
+        .calculate_price(membership_issued_by_me.membership_supply, Uint128::one())?;
+
+    let latest_observation =
+        match load_latest_price_observation(deps.storage, membership_issuer_user_id)? {
+            // No trade since TWAP existed so spot price has never moved
+            None => {
+                return Ok(TwapResponse {
+                    twap: spot_price,
+                    spot_price,
+                    observed_seconds: Uint64::zero(),
+                })
+            }
+            Some(latest_observation) => latest_observation,
+        };
+
+    let now = env.block.time;
+    let window_start_seconds = now.seconds().saturating_sub(data.window_seconds.u64());
+    // Last observation at or before window start, or the first observation if history is shorter than window
+    let start_observation = match ALL_MEMBERSHIPS_PRICE_OBSERVATIONS
+        .prefix(membership_issuer_user_id)
+        .range(
+            deps.storage,
+            None,
+            Some(Bound::inclusive(window_start_seconds)),
+            Order::Descending,
+        )
+        .next()
+    {
+        Some(item) => item?.1,
+        None => {
+            ALL_MEMBERSHIPS_PRICE_OBSERVATIONS
+                .prefix(membership_issuer_user_id)
+                .range(deps.storage, None, None, Order::Ascending)
+                .next()
+                .transpose()?
+                .ok_or_else(|| StdError::not_found("Price observation"))?
+                .1
+        }
+    };
+    let start_time =
+        Timestamp::from_seconds(window_start_seconds.max(start_observation.timestamp.seconds()));
+
+    let observed_seconds = now.seconds() - start_time.seconds();
+    if observed_seconds == 0 {
+        return Ok(TwapResponse {
+            twap: latest_observation.price,
+            spot_price,
+            observed_seconds: Uint64::zero(),
+        });
+    }
+
+    let twap = (latest_observation.cumulative_price_at(now)
+        - start_observation.cumulative_price_at(start_time))
+        / Uint256::from(observed_seconds);
+
+    Ok(TwapResponse {
+        twap: twap.try_into()?,
+        spot_price,
+        observed_seconds: Uint64::from(observed_seconds),
+    })
+}
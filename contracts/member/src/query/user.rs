@@ -1,32 +1,114 @@
-use cosmwasm_std::{Deps, Order, StdResult, Uint64};
+use cosmwasm_std::{Coin, Deps, Env, Order, StdError, StdResult, Uint64};
 use cw_storage_plus::Bound;
 
 use member_pkg::{
     msg::{
-        QueryUserByAddrMsg, QueryUserByIDMsg, QueryUsersPaginatedByAddrMsg,
-        QueryUsersPaginatedByIDMsg, UserResponse, UsersResponse,
+        PendingUserMigrationResponse, QueryPendingUserMigrationMsg, QueryRefereesMsg,
+        QueryUserByAddrMsg, QueryUserByIDMsg, QueryUserBySocialMediaHandleMsg,
+        QueryUsersByAddrsMsg, QueryUsersByIDsMsg, QueryUsersBySocialMediaHandlePrefixMsg,
+        QueryUsersPaginatedByAddrMsg, QueryUsersPaginatedByIDMsg, RefereesResponse, UserResponse,
+        UsersByAddrsResponse, UsersByIDsResponse, UsersBySocialMediaHandlePrefixResponse,
+        UsersResponse,
     },
-    user::User,
+    user::{normalize_social_media_handle, normalize_social_media_platform, Referee, User},
 };
 
-use crate::state::{ALL_USERS, DEFAULT_QUERY_LIMIT, MAX_QUERY_LIMIT, NEXT_USER_ID};
+use crate::{
+    state::{
+        ALL_HOT_ADDRS, ALL_REFERRERS_REFEREES, ALL_SOCIAL_MEDIA_HANDLES, ALL_USERS, CONFIG,
+        DEFAULT_QUERY_LIMIT, MAX_QUERY_LIMIT, NEXT_USER_ID, PENDING_USER_MIGRATIONS,
+    },
+    util::{user::may_load_user_by_id, user_fee_change::with_effective_user_fees},
+};
 
-pub fn query_user_by_addr(deps: Deps, data: QueryUserByAddrMsg) -> StdResult<UserResponse> {
-    let user = ALL_USERS().load(
-        deps.storage,
-        &deps.api.addr_validate(data.user_addr.as_str())?,
-    )?;
-    Ok(UserResponse { user })
+// Show fees in effect at current block, with issuer fee change that has not taken effect yet
+fn user_response(deps: Deps, env: Env, user: User) -> StdResult<UserResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let (user, pending_fee_change) =
+        with_effective_user_fees(deps.storage, &env.block, &config.user_fee_ranges, user)?;
+    Ok(UserResponse {
+        user,
+        pending_fee_change,
+    })
 }
 
-pub fn query_user_by_id(deps: Deps, data: QueryUserByIDMsg) -> StdResult<UserResponse> {
-    let user = ALL_USERS()
-        .idx
-        .id
-        .item(deps.storage, data.user_id.u64())?
-        .unwrap()
-        .1;
-    Ok(UserResponse { user })
+// Fallback to hot address so other contracts can resolve user from either address
+fn may_load_user_by_addr(deps: Deps, user_addr: &str) -> StdResult<Option<User>> {
+    let user_addr = deps.api.addr_validate(user_addr)?;
+    match ALL_USERS().may_load(deps.storage, &user_addr)? {
+        Some(user) => Ok(Some(user)),
+        None => match ALL_HOT_ADDRS.may_load(deps.storage, &user_addr)? {
+            Some(user_id) => may_load_user_by_id(deps, user_id),
+            None => Ok(None),
+        },
+    }
+}
+
+// Batch lookups are capped so a single query cannot load unbounded number of users
+fn assert_batch_size_within_limit(given: usize) -> StdResult<()> {
+    if given > MAX_QUERY_LIMIT as usize {
+        return Err(StdError::generic_err(format!(
+            "Exceed query limit: given {given}, limit {MAX_QUERY_LIMIT}"
+        )));
+    }
+
+    Ok(())
+}
+
+pub fn query_user_by_addr(
+    deps: Deps,
+    env: Env,
+    data: QueryUserByAddrMsg,
+) -> StdResult<UserResponse> {
+    let user =
+        may_load_user_by_addr(deps, &data.user_addr)?.ok_or_else(|| StdError::not_found("User"))?;
+    user_response(deps, env, user)
+}
+
+pub fn query_user_by_id(deps: Deps, env: Env, data: QueryUserByIDMsg) -> StdResult<UserResponse> {
+    let user = may_load_user_by_id(deps, data.user_id.u64())?
+        .ok_or_else(|| StdError::not_found("User"))?;
+    user_response(deps, env, user)
+}
+
+pub fn query_users_by_ids(
+    deps: Deps,
+    env: Env,
+    data: QueryUsersByIDsMsg,
+) -> StdResult<UsersByIDsResponse> {
+    assert_batch_size_within_limit(data.user_ids.len())?;
+
+    let users = data
+        .user_ids
+        .iter()
+        .map(|user_id| {
+            may_load_user_by_id(deps, user_id.u64())?
+                .map(|user| user_response(deps, env.clone(), user).map(|res| res.user))
+                .transpose()
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(UsersByIDsResponse { users })
+}
+
+pub fn query_users_by_addrs(
+    deps: Deps,
+    env: Env,
+    data: QueryUsersByAddrsMsg,
+) -> StdResult<UsersByAddrsResponse> {
+    assert_batch_size_within_limit(data.user_addrs.len())?;
+
+    let users = data
+        .user_addrs
+        .iter()
+        .map(|user_addr| {
+            may_load_user_by_addr(deps, user_addr)?
+                .map(|user| user_response(deps, env.clone(), user).map(|res| res.user))
+                .transpose()
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(UsersByAddrsResponse { users })
 }
 
 pub fn query_users_paginated_by_addr(
@@ -119,3 +201,138 @@ pub fn query_users_paginated_by_id(
         users,
     })
 }
+
+pub fn query_user_by_social_media_handle(
+    deps: Deps,
+    env: Env,
+    data: QueryUserBySocialMediaHandleMsg,
+) -> StdResult<UserResponse> {
+    let user_id = ALL_SOCIAL_MEDIA_HANDLES
+        .may_load(
+            deps.storage,
+            (
+                &normalize_social_media_platform(&data.platform),
+                &normalize_social_media_handle(&data.social_media_handle),
+            ),
+        )?
+        .ok_or_else(|| StdError::not_found("User"))?;
+    let user = may_load_user_by_id(deps, user_id)?.ok_or_else(|| StdError::not_found("User"))?;
+
+    user_response(deps, env, user)
+}
+
+pub fn query_users_by_social_media_handle_prefix(
+    deps: Deps,
+    data: QueryUsersBySocialMediaHandlePrefixMsg,
+) -> StdResult<UsersBySocialMediaHandlePrefixResponse> {
+    let platform = normalize_social_media_platform(&data.platform);
+    let prefix = normalize_social_media_handle(&data.prefix);
+    let start_after_social_media_handle =
+        data.start_after_social_media_handle
+            .map(|start_after_social_media_handle| {
+                normalize_social_media_handle(&start_after_social_media_handle)
+            });
+
+    let min_bound = match &start_after_social_media_handle {
+        Some(start_after_social_media_handle) => {
+            if data.include_start_after.unwrap_or(false) {
+                Bound::inclusive(start_after_social_media_handle.as_str())
+            } else {
+                Bound::exclusive(start_after_social_media_handle.as_str())
+            }
+        }
+        None => Bound::inclusive(prefix.as_str()),
+    };
+
+    let users = ALL_SOCIAL_MEDIA_HANDLES
+        .prefix(&platform)
+        .range(deps.storage, Some(min_bound), None, Order::Ascending)
+        // Handles are sorted so all matches are next to each other
+        .take_while(|item| match item {
+            Ok((social_media_handle, _)) => social_media_handle.starts_with(&prefix),
+            Err(_) => true,
+        })
+        .take(
+            data.limit
+                .unwrap_or(DEFAULT_QUERY_LIMIT)
+                .min(MAX_QUERY_LIMIT) as usize,
+        )
+        .map(|item| {
+            let (_, user_id) = item?;
+            may_load_user_by_id(deps, user_id)?.ok_or_else(|| StdError::not_found("User"))
+        })
+        .collect::<StdResult<Vec<User>>>()?;
+
+    Ok(UsersBySocialMediaHandlePrefixResponse {
+        count: users.len(),
+        users,
+    })
+}
+
+pub fn query_pending_user_migration(
+    deps: Deps,
+    data: QueryPendingUserMigrationMsg,
+) -> StdResult<PendingUserMigrationResponse> {
+    let new_user_addr = PENDING_USER_MIGRATIONS.may_load(deps.storage, data.user_id.u64())?;
+    Ok(PendingUserMigrationResponse { new_user_addr })
+}
+
+pub fn query_referees(deps: Deps, data: QueryRefereesMsg) -> StdResult<RefereesResponse> {
+    let referrer_user_id = data.referrer_user_id.u64();
+
+    let limit = data
+        .limit
+        .unwrap_or(DEFAULT_QUERY_LIMIT)
+        .min(MAX_QUERY_LIMIT) as usize;
+
+    let include_start_after = data.include_start_after.unwrap_or(false);
+    // Keys are (referee's user ID, fee denom), empty string sorts before any denom of a referee
+    let min = data
+        .start_after_referee_user_id
+        .map(|start_after_referee_user_id| {
+            Bound::inclusive((start_after_referee_user_id.u64(), ""))
+        });
+
+    let mut referees: Vec<Referee> = vec![];
+    for item in ALL_REFERRERS_REFEREES.sub_prefix(referrer_user_id).range(
+        deps.storage,
+        min,
+        None,
+        Order::Ascending,
+    ) {
+        let ((referee_user_id, denom), earned) = item?;
+        let referee_user_id = Uint64::from(referee_user_id);
+        if !include_start_after && Some(referee_user_id) == data.start_after_referee_user_id {
+            continue;
+        }
+        let earned = (!earned.is_zero()).then_some(Coin {
+            denom,
+            amount: earned,
+        });
+        match referees.last_mut() {
+            Some(referee) if referee.referee_user_id == referee_user_id => {
+                referee.earned.extend(earned)
+            }
+            _ => {
+                if referees.len() == limit {
+                    break;
+                }
+                referees.push(Referee {
+                    referee_user_id,
+                    earned: earned.into_iter().collect(),
+                })
+            }
+        }
+    }
+
+    let total_count = may_load_user_by_id(deps, referrer_user_id)?
+        .ok_or_else(|| StdError::not_found("User"))?
+        .referee_count
+        .u128() as usize;
+
+    Ok(RefereesResponse {
+        count: referees.len(),
+        referees,
+        total_count,
+    })
+}
@@ -1,18 +1,34 @@
 use cosmwasm_std::{Addr, Uint128, Uint64};
 use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, UniqueIndex};
 
-use member_pkg::{config::Config, user::User};
+use member_pkg::{
+    config::Config,
+    msg::UpdateConfigMsg,
+    trade::{Candle, PriceObservation, Trade},
+    user::{PendingUserFeeChange, User},
+};
+use shared_pkg::{admin_transfer::AdminProposal, timelock::PendingConfigChange};
 
 pub const DEFAULT_QUERY_LIMIT: u32 = 5;
 pub const MAX_QUERY_LIMIT: u32 = 25;
 
 pub const CONFIG: Item<Config> = Item::new("CONFIG");
 
+// New admin proposed by current admin, removed once accepted or cancelled
+pub const PENDING_ADMIN_PROPOSAL: Item<AdminProposal> = Item::new("PENDING_ADMIN_PROPOSAL");
+
+// Next available ID of pending config change, start from 1
+pub const NEXT_PENDING_CONFIG_CHANGE_ID: Item<Uint64> = Item::new("NEXT_PENDING_CONFIG_CHANGE_ID");
+
+// Key is pending config change ID, value is config changes waiting for the time-lock
+// Removed once executed or cancelled
+pub const PENDING_CONFIG_CHANGES: Map<u64, PendingConfigChange<UpdateConfigMsg>> =
+    Map::new("PENDING_CONFIG_CHANGES");
+
 // Next available monotonically increasing global unique ID to identify each user
 // Start from 1
 pub const NEXT_USER_ID: Item<Uint64> = Item::new("NEXT_USER_ID");
 
-// TODO: P0: add another index to query user by social media handle?
 pub struct UserIndexes<'a> {
     pub id: UniqueIndex<'a, u64, User>,
 }
@@ -29,20 +45,62 @@ impl<'a> IndexList<User> for UserIndexes<'a> {
 #[allow(non_snake_case)]
 pub fn ALL_USERS<'a>() -> IndexedMap<'a, &'a Addr, User, UserIndexes<'a>> {
     let indexes = UserIndexes {
-        id: UniqueIndex::new(|user| (user.id.u64()), "ALL_USERS_USER_ID"),
+        id: UniqueIndex::new(|user| user.id.u64(), "ALL_USERS_USER_ID"),
     };
     IndexedMap::new("ALL_USERS", indexes)
 }
 
+// Key is (platform, normalized social media handle), value is user ID
+// A handle on a platform can only be linked to 1 user, also used to resolve user from handle
+// User can link multiple platforms so this cannot be a unique index of ALL_USERS
+pub const ALL_SOCIAL_MEDIA_HANDLES: Map<(&str, &str), u64> = Map::new("ALL_SOCIAL_MEDIA_HANDLES");
+
+// Key is attestation nonce, value is user ID who used it
+// Attestation with a used nonce is rejected so it cannot be replayed
+pub const USED_ATTESTATION_NONCES: Map<u64, u64> = Map::new("USED_ATTESTATION_NONCES");
+
+// Key is user's hot address, value is user ID
+// Used to resolve user from hot address, cold address is the key of ALL_USERS
+pub const ALL_HOT_ADDRS: Map<&Addr, u64> = Map::new("ALL_HOT_ADDRS");
+
 /// Note: we cannot use Map<Addr, Map<Addr, Uint128>> as map of map is not supported in cosmwasm
 /// Composite key is the workaround
 ///
 /// ALL_MEMBERSHIPS_MEMBERS and ALL_USERS_MEMBERSHIPS store the same data
 /// We store it twice just to make querying easier (either get all holders of 1 key or all keys held by 1 user)
 // TODO: P2: decide if we should store this in indexer, as we only need ALL_MEMBERSHIPS_MEMBERS or ALL_USERS_MEMBERSHIPS
-
 // Key is (membership issuer's user ID, member's user ID), value is amount of issuer's keys held by user
 pub const ALL_MEMBERSHIPS_MEMBERS: Map<(u64, u64), Uint128> = Map::new("ALL_MEMBERSHIPS_MEMBERS");
 
 // Key is (member's user ID, membership issuer's user ID), value is amount of issuer's keys held by user
 pub const ALL_USERS_MEMBERSHIPS: Map<(u64, u64), Uint128> = Map::new("ALL_USERS_MEMBERSHIPS");
+
+// Key is user ID, value is the new address user proposed to migrate to
+// Entry is removed once the new address accepts the migration
+pub const PENDING_USER_MIGRATIONS: Map<u64, Addr> = Map::new("PENDING_USER_MIGRATIONS");
+
+// Key is membership issuer's user ID, value is fee change waiting for user fee change time-lock
+// Applied on the fly by fee lookups once it takes effect, written to user on issuer's next fee update
+pub const ALL_PENDING_USER_FEE_CHANGES: Map<u64, PendingUserFeeChange> =
+    Map::new("ALL_PENDING_USER_FEE_CHANGES");
+
+// Key is (referrer's user ID, referee's user ID, fee denom)
+// Value is total referral fee referrer earned from referee in that denom
+pub const ALL_REFERRERS_REFEREES: Map<(u64, u64, &str), Uint128> =
+    Map::new("ALL_REFERRERS_REFEREES");
+
+// Key is (membership issuer's user ID, trade ID), value is trade
+// Trade ID starts from 1 for each membership
+pub const ALL_MEMBERSHIPS_TRADES: Map<(u64, u64), Trade> = Map::new("ALL_MEMBERSHIPS_TRADES");
+
+// Key is membership issuer's user ID, value is number of trades of the membership
+pub const ALL_MEMBERSHIPS_TRADE_COUNT: Map<u64, Uint64> = Map::new("ALL_MEMBERSHIPS_TRADE_COUNT");
+
+// Key is (membership issuer's user ID, candle interval, candle start time in seconds), value is candle
+pub const ALL_MEMBERSHIPS_PRICE_CANDLES: Map<(u64, &str, u64), Candle> =
+    Map::new("ALL_MEMBERSHIPS_PRICE_CANDLES");
+
+// Key is (membership issuer's user ID, observation time in seconds), value is price observation
+// First observation is saved when membership is enabled, then 1 per block time with trades
+pub const ALL_MEMBERSHIPS_PRICE_OBSERVATIONS: Map<(u64, u64), PriceObservation> =
+    Map::new("ALL_MEMBERSHIPS_PRICE_OBSERVATIONS");
@@ -0,0 +1,54 @@
+use cosmwasm_std::{Binary, Deps, Env};
+use member_pkg::{attestation::SocialMediaAttestation, config::Config};
+use sha2::{Digest, Sha256};
+
+use crate::{state::USED_ATTESTATION_NONCES, ContractError};
+
+// Secp256k1 pubkey is 33 bytes compressed or 65 bytes uncompressed
+pub fn assert_attestation_pubkey_valid(pubkey: &Binary) -> Result<(), ContractError> {
+    match (pubkey.len(), pubkey.first()) {
+        (33, Some(0x02 | 0x03)) | (65, Some(0x04)) => Ok(()),
+        _ => Err(ContractError::InvalidAttestationPubkey {}),
+    }
+}
+
+// Attestation must be for this contract, not expired, not used before and signed by attestation key
+pub fn verify_attestation(
+    deps: Deps,
+    env: &Env,
+    config: &Config,
+    attestation: &SocialMediaAttestation,
+    signature: &Binary,
+) -> Result<(), ContractError> {
+    let attestation_pubkey = match &config.attestation_pubkey {
+        Some(attestation_pubkey) => attestation_pubkey,
+        None => return Err(ContractError::AttestationNotEnabled {}),
+    };
+
+    if attestation.chain_id != env.block.chain_id
+        || attestation.contract_addr != env.contract.address
+    {
+        return Err(ContractError::AttestationNotForThisContract {});
+    }
+
+    if attestation.expires_at <= env.block.time {
+        return Err(ContractError::AttestationExpired {});
+    }
+
+    if USED_ATTESTATION_NONCES.has(deps.storage, attestation.nonce.u64()) {
+        return Err(ContractError::AttestationNonceAlreadyUsed {
+            nonce: attestation.nonce,
+        });
+    }
+
+    let message_hash = Sha256::digest(attestation.sign_bytes()?);
+    let verified = deps
+        .api
+        .secp256k1_verify(&message_hash, signature, attestation_pubkey)
+        .map_err(|_| ContractError::InvalidAttestationSignature {})?;
+    if !verified {
+        return Err(ContractError::InvalidAttestationSignature {});
+    }
+
+    Ok(())
+}
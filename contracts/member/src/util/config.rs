@@ -0,0 +1,122 @@
+use cosmwasm_std::{Addr, StdResult, Storage};
+use member_pkg::{config::Config, msg::UpdateConfigMsg};
+use shared_pkg::rbac::{has_role, Role};
+
+use crate::ContractError;
+
+// All user facing functions are disabled when contract is disabled
+// Admin functions stay available so admin can turn it back on
+pub fn assert_contract_enabled(config: &Config) -> Result<(), ContractError> {
+    if !config.enabled {
+        return Err(ContractError::ContractDisabled {});
+    }
+
+    Ok(())
+}
+
+// Admin can make any config change, fee manager only fee changes
+// Also applies to executing and cancelling pending config changes so fee manager cannot touch queued admin changes
+pub fn can_update_config(
+    storage: &dyn Storage,
+    addr: &Addr,
+    data: &UpdateConfigMsg,
+) -> StdResult<bool> {
+    Ok(has_role(storage, addr, &Role::Admin)?
+        || (is_fee_only_update(data) && has_role(storage, addr, &Role::FeeManager)?))
+}
+
+// Fee manager can only update fees and their ranges, everything else needs admin
+// Destructure without .. so a new field must be classified here before it compiles
+pub fn is_fee_only_update(data: &UpdateConfigMsg) -> bool {
+    let UpdateConfigMsg {
+        distribution_contract_addr,
+        registration_admin_addr,
+        protocol_fee_collector_addr,
+        protocol_fee_membership_trading_fee_bps: _,
+        protocol_fee_referral_fee_bps: _,
+        default_trading_fee_bps_of_membership: _,
+        default_share_to_issuer_bps: _,
+        default_share_to_all_members_bps: _,
+        default_bonding_curve,
+        sigmoid_max_trade_amount,
+        default_holding_cap,
+        only_verified_buyers,
+        price_candle_interval,
+        membership_eligibility,
+        attestation_pubkey,
+        config_change_timelock,
+        user_fee_change_timelock,
+        user_fee_ranges: _,
+    } = data;
+
+    distribution_contract_addr.is_none()
+        && registration_admin_addr.is_none()
+        && protocol_fee_collector_addr.is_none()
+        && default_bonding_curve.is_none()
+        && sigmoid_max_trade_amount.is_none()
+        && default_holding_cap.is_none()
+        && only_verified_buyers.is_none()
+        && price_candle_interval.is_none()
+        && membership_eligibility.is_none()
+        && attestation_pubkey.is_none()
+        && config_change_timelock.is_none()
+        && user_fee_change_timelock.is_none()
+}
+
+// Changes to where fees go, how much they are and the time-locks wait for the time-lock
+// Returns changes to apply right away, and changes to queue or None if there is nothing to queue
+pub fn split_timelocked_config_changes(
+    data: UpdateConfigMsg,
+) -> (UpdateConfigMsg, Option<UpdateConfigMsg>) {
+    let UpdateConfigMsg {
+        distribution_contract_addr,
+        registration_admin_addr,
+        protocol_fee_collector_addr,
+        protocol_fee_membership_trading_fee_bps,
+        protocol_fee_referral_fee_bps,
+        default_trading_fee_bps_of_membership,
+        default_share_to_issuer_bps,
+        default_share_to_all_members_bps,
+        default_bonding_curve,
+        sigmoid_max_trade_amount,
+        default_holding_cap,
+        only_verified_buyers,
+        price_candle_interval,
+        membership_eligibility,
+        attestation_pubkey,
+        config_change_timelock,
+        user_fee_change_timelock,
+        user_fee_ranges,
+    } = data;
+
+    let changes = UpdateConfigMsg {
+        registration_admin_addr,
+        default_bonding_curve,
+        sigmoid_max_trade_amount,
+        default_holding_cap,
+        only_verified_buyers,
+        price_candle_interval,
+        membership_eligibility,
+        attestation_pubkey,
+        ..UpdateConfigMsg::default()
+    };
+    let timelocked_changes = UpdateConfigMsg {
+        distribution_contract_addr,
+        protocol_fee_collector_addr,
+        protocol_fee_membership_trading_fee_bps,
+        protocol_fee_referral_fee_bps,
+        default_trading_fee_bps_of_membership,
+        default_share_to_issuer_bps,
+        default_share_to_all_members_bps,
+        config_change_timelock,
+        user_fee_change_timelock,
+        user_fee_ranges,
+        ..UpdateConfigMsg::default()
+    };
+
+    if timelocked_changes == UpdateConfigMsg::default() {
+        (changes, None)
+    } else {
+        (changes, Some(timelocked_changes))
+    }
+}
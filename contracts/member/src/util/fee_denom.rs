@@ -0,0 +1,28 @@
+use cosmwasm_std::Deps;
+use member_pkg::config::Config;
+
+use crate::{util::user::load_user_by_id, ContractError};
+
+pub fn assert_fee_denom_in_allowlist(config: &Config, denom: &str) -> Result<(), ContractError> {
+    if !config.fee_denom_allowlist.iter().any(|d| d == denom) {
+        return Err(ContractError::FeeDenomNotInAllowlist {
+            denom: denom.to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+// Load the denom membership issuer picked when enabling membership
+// Buy / sell of the membership must be paid in this denom
+pub fn load_membership_fee_denom(
+    deps: Deps,
+    membership_issuer_user_id: u64,
+) -> Result<String, ContractError> {
+    let membership_issuer = load_user_by_id(deps, membership_issuer_user_id)?;
+
+    match membership_issuer.membership_issued_by_me {
+        Some(membership_issued_by_me) => Ok(membership_issued_by_me.fee_denom),
+        None => Err(ContractError::UserHasNotRegisteredMembership {}),
+    }
+}
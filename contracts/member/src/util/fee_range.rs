@@ -0,0 +1,47 @@
+use cosmwasm_std::Uint64;
+use member_pkg::config::{FeeConfig, UserFeeRanges};
+use shared_pkg::fee_range::BpsRange;
+
+use crate::ContractError;
+
+pub fn assert_user_fee_ranges_valid(user_fee_ranges: &UserFeeRanges) -> Result<(), ContractError> {
+    let range = &user_fee_ranges.trading_fee_bps_of_membership;
+    if !range.is_valid() {
+        return Err(ContractError::InvalidUserFeeRange {
+            fee: "trading_fee_bps_of_membership".to_string(),
+            min: range.min,
+            max: range.max,
+        });
+    }
+
+    Ok(())
+}
+
+pub fn assert_fee_bps_in_range(
+    fee: &str,
+    bps: Uint64,
+    range: &BpsRange,
+) -> Result<(), ContractError> {
+    if !range.contains(bps) {
+        return Err(ContractError::FeeBpsOutOfRange {
+            fee: fee.to_string(),
+            bps,
+            min: range.min,
+            max: range.max,
+        });
+    }
+
+    Ok(())
+}
+
+// Users without their own fees pay the default fees, so default fees must be in the ranges too
+pub fn assert_default_fee_config_in_ranges(
+    default_fee_config: &FeeConfig,
+    user_fee_ranges: &UserFeeRanges,
+) -> Result<(), ContractError> {
+    assert_fee_bps_in_range(
+        "trading_fee_bps_of_membership",
+        default_fee_config.trading_fee_bps_of_membership,
+        &user_fee_ranges.trading_fee_bps_of_membership,
+    )
+}
@@ -1,32 +1,42 @@
-use cosmwasm_std::{Deps, Uint64};
-use shared_pkg::fee_share_config::FeeShareConfig;
+use cosmwasm_std::Uint64;
+use member_pkg::config::ProtocolFeeConfig;
+use shared_pkg::{fee_share_config::FeeShareConfig, pricing::ONE_HUNDRED_PERCENT_IN_BASIS_POINTS};
 
-use crate::{state::ALL_USERS, ContractError};
+use crate::ContractError;
 
-pub fn assert_config_fee_share_sum_to_100(
+pub fn assert_config_fee_share_sum_to_10000(
     default_fee_share_config: FeeShareConfig,
 ) -> Result<(), ContractError> {
-    if default_fee_share_config.share_to_issuer_percentage
-        + default_fee_share_config.share_to_all_members_percentage
-        != Uint64::from(100_u64)
+    if default_fee_share_config
+        .share_to_issuer_bps
+        .checked_add(default_fee_share_config.share_to_all_members_bps)
+        != Ok(Uint64::new(ONE_HUNDRED_PERCENT_IN_BASIS_POINTS))
     {
-        return Err(ContractError::MembershipTradingFeeSharePercentageMustSumTo100 {});
+        return Err(ContractError::MembershipTradingFeeShareBpsMustSumTo10000 {});
     }
 
     Ok(())
 }
 
-pub fn assert_user_fee_share_sum_to_100(deps: Deps, user_id: u64) -> Result<(), ContractError> {
-    let user = ALL_USERS().idx.id.item(deps.storage, user_id)?.unwrap().1;
+pub fn assert_user_fee_share_sum_to_10000(
+    user_fee_share_config: &FeeShareConfig,
+) -> Result<(), ContractError> {
+    if user_fee_share_config
+        .share_to_issuer_bps
+        .checked_add(user_fee_share_config.share_to_all_members_bps)
+        != Ok(Uint64::new(ONE_HUNDRED_PERCENT_IN_BASIS_POINTS))
+    {
+        return Err(ContractError::MembershipTradingFeeShareBpsMustSumTo10000 {});
+    }
 
-    if user.fee_share_config.is_some() {
-        let user_fee_share_config = user.fee_share_config.unwrap();
-        if user_fee_share_config.share_to_issuer_percentage
-            + user_fee_share_config.share_to_all_members_percentage
-            != Uint64::from(100_u64)
-        {
-            return Err(ContractError::MembershipTradingFeeSharePercentageMustSumTo100 {});
-        }
+    Ok(())
+}
+
+pub fn assert_referral_fee_bps_within_10000(
+    protocol_fee_config: ProtocolFeeConfig,
+) -> Result<(), ContractError> {
+    if protocol_fee_config.referral_fee_bps > Uint64::new(ONE_HUNDRED_PERCENT_IN_BASIS_POINTS) {
+        return Err(ContractError::ReferralFeeBpsCannotExceed10000 {});
     }
 
     Ok(())
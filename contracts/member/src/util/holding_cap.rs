@@ -0,0 +1,28 @@
+use cosmwasm_std::Uint128;
+
+use crate::ContractError;
+
+// Holding cap of 0 makes no sense as no one could buy, so update msgs use 0 to remove the cap
+pub fn parse_holding_cap(holding_cap: Uint128) -> Option<Uint128> {
+    if holding_cap.is_zero() {
+        None
+    } else {
+        Some(holding_cap)
+    }
+}
+
+pub fn assert_within_holding_cap(
+    holding_cap: Option<Uint128>,
+    new_hold_amount: Uint128,
+) -> Result<(), ContractError> {
+    if let Some(cap) = holding_cap {
+        if new_hold_amount > cap {
+            return Err(ContractError::HoldingCapExceeded {
+                cap,
+                attempted: new_hold_amount,
+            });
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,232 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, DepsMut, Env, Order, StdResult, Uint128, Uint64};
+use cw_storage_plus::{Item, Map};
+
+use member_pkg::{
+    config::{
+        Config, FeeConfig, MembershipEligibility, PauseConfig, ProtocolFeeConfig, UserFeeRanges,
+    },
+    trade::CandleInterval,
+    user::{normalize_social_media_handle, MembershipIssuedByMe, SocialLink, User, UserFeeConfig},
+};
+use shared_pkg::{
+    fee_share_config::FeeShareConfig,
+    migrate::StateMigration,
+    pricing::{checked_percentage_to_basis_points, BondingCurve},
+    rbac::{grant_role, Role},
+    timelock::{normalize_timelock, DEFAULT_USER_FEE_CHANGE_TIMELOCK},
+};
+
+use crate::contract::{DEFAULT_SIGMOID_MAX_TRADE_AMOUNT, DEFAULT_SOCIAL_MEDIA_PLATFORM_ALLOWLIST};
+use crate::state::{ALL_SOCIAL_MEDIA_HANDLES, ALL_USERS, CONFIG};
+use crate::ContractError;
+
+// Registered state migrations, sorted by version
+// When a release changes how User, Config or membership holdings are stored:
+// keep the old struct in this file, add a function that loads data in old schema and saves it in new schema,
+// then register the function with the version of that release
+pub const STATE_MIGRATIONS: &[(&str, StateMigration<ContractError>)] =
+    &[("0.2.0", migrate_state_to_v0_2_0)];
+
+// ========== 0.1.0 ==========
+
+#[cw_serde]
+struct ProtocolFeeConfigV0_1_0 {
+    membership_trading_fee_percentage: Uint64,
+}
+
+#[cw_serde]
+struct FeeConfigV0_1_0 {
+    fee_denom: String,
+    trading_fee_percentage_of_membership: Uint64,
+}
+
+#[cw_serde]
+struct FeeShareConfigV0_1_0 {
+    share_to_issuer_percentage: Uint64,
+    share_to_all_members_percentage: Uint64,
+}
+
+impl FeeShareConfigV0_1_0 {
+    fn into_bps(self) -> Result<FeeShareConfig, ContractError> {
+        Ok(FeeShareConfig {
+            share_to_issuer_bps: checked_percentage_to_basis_points(
+                self.share_to_issuer_percentage,
+            )?,
+            share_to_all_members_bps: checked_percentage_to_basis_points(
+                self.share_to_all_members_percentage,
+            )?,
+        })
+    }
+}
+
+#[cw_serde]
+struct ConfigV0_1_0 {
+    admin_addr: Addr,
+    distribution_contract_addr: Option<Addr>,
+    enabled: bool,
+    enable_open_registration: bool,
+    registration_admin_addr: Addr,
+    protocol_fee_collector_addr: Addr,
+    protocol_fee_config: ProtocolFeeConfigV0_1_0,
+    default_fee_config: FeeConfigV0_1_0,
+    default_fee_share_config: FeeShareConfigV0_1_0,
+}
+
+#[cw_serde]
+struct MembershipIssuedByMeV0_1_0 {
+    membership_supply: Uint128,
+    member_count: Uint128,
+}
+
+#[cw_serde]
+struct UserV0_1_0 {
+    id: Uint64,
+    addr: Addr,
+    social_media_handle: Option<String>,
+    membership_issued_by_me: Option<MembershipIssuedByMeV0_1_0>,
+    user_member_count: Uint128,
+    fee_config: Option<FeeConfigV0_1_0>,
+    fee_share_config: Option<FeeShareConfigV0_1_0>,
+}
+
+const CONFIG_V0_1_0: Item<ConfigV0_1_0> = Item::new("CONFIG");
+
+// Primary map of ALL_USERS, only read here, users are saved back through ALL_USERS so its index is rewritten too
+const ALL_USERS_V0_1_0: Map<&Addr, UserV0_1_0> = Map::new("ALL_USERS");
+
+// 0.1.0 only supported a single social media handle, it was always a twitter handle
+const SOCIAL_MEDIA_PLATFORM_V0_1_0: &str = "twitter";
+
+// ========== 0.2.0 ==========
+
+// Adds everything introduced since 0.1.0 with the same defaults as instantiate
+// Fees were whole percentages, they are converted to basis points
+// Memberships keep pricing and fee denom of 0.1.0, i.e. default bonding curve and default fee denom
+// 0.1.0 had no bonding curves, so there is no exponential growth to convert to basis points
+// Addresses in config are granted the role they hold since roles were introduced
+fn migrate_state_to_v0_2_0(deps: DepsMut, env: &Env) -> Result<(), ContractError> {
+    let old_config = CONFIG_V0_1_0.load(deps.storage)?;
+    let fee_denom = old_config.default_fee_config.fee_denom;
+
+    let config = Config {
+        admin_addr: old_config.admin_addr,
+        distribution_contract_addr: old_config.distribution_contract_addr,
+        enabled: old_config.enabled,
+        enable_open_registration: old_config.enable_open_registration,
+        pause_config: PauseConfig::default(),
+        registration_admin_addr: old_config.registration_admin_addr,
+        attestation_pubkey: None,
+        protocol_fee_collector_addr: old_config.protocol_fee_collector_addr,
+        protocol_fee_config: ProtocolFeeConfig {
+            membership_trading_fee_bps: checked_percentage_to_basis_points(
+                old_config
+                    .protocol_fee_config
+                    .membership_trading_fee_percentage,
+            )?,
+            referral_fee_bps: Uint64::zero(),
+        },
+        default_fee_config: FeeConfig {
+            fee_denom: fee_denom.clone(),
+            trading_fee_bps_of_membership: checked_percentage_to_basis_points(
+                old_config
+                    .default_fee_config
+                    .trading_fee_percentage_of_membership,
+            )?,
+            holding_cap: None,
+        },
+        default_fee_share_config: old_config.default_fee_share_config.into_bps()?,
+        default_bonding_curve: BondingCurve::default(),
+        sigmoid_max_trade_amount: Uint128::from(DEFAULT_SIGMOID_MAX_TRADE_AMOUNT),
+        fee_denom_allowlist: vec![fee_denom.clone()],
+        only_verified_buyers: false,
+        social_media_platform_allowlist: DEFAULT_SOCIAL_MEDIA_PLATFORM_ALLOWLIST
+            .iter()
+            .map(|platform| platform.to_string())
+            .collect(),
+        membership_eligibility: MembershipEligibility::default(),
+        price_candle_interval: CandleInterval::default(),
+        config_change_timelock: None,
+        user_fee_change_timelock: normalize_timelock(DEFAULT_USER_FEE_CHANGE_TIMELOCK),
+        user_fee_ranges: UserFeeRanges::default(),
+    };
+    CONFIG.save(deps.storage, &config)?;
+
+    grant_role(deps.storage, &config.admin_addr, &Role::Admin)?;
+    grant_role(
+        deps.storage,
+        &config.registration_admin_addr,
+        &Role::RegistrationOperator,
+    )?;
+
+    // Collect first, saving users while iterating would write to the range being read
+    let old_users = ALL_USERS_V0_1_0
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, user)| user))
+        .collect::<StdResult<Vec<UserV0_1_0>>>()?;
+
+    for old_user in old_users {
+        let social_links = match old_user.social_media_handle {
+            Some(handle) => {
+                let normalized_handle = normalize_social_media_handle(&handle);
+                // 0.1.0 didn't enforce unique handles, the user who registered first keeps the handle lookup
+                if !ALL_SOCIAL_MEDIA_HANDLES.has(
+                    deps.storage,
+                    (SOCIAL_MEDIA_PLATFORM_V0_1_0, normalized_handle.as_str()),
+                ) {
+                    ALL_SOCIAL_MEDIA_HANDLES.save(
+                        deps.storage,
+                        (SOCIAL_MEDIA_PLATFORM_V0_1_0, normalized_handle.as_str()),
+                        &old_user.id.u64(),
+                    )?;
+                }
+                vec![SocialLink {
+                    platform: SOCIAL_MEDIA_PLATFORM_V0_1_0.to_string(),
+                    handle,
+                    linked_at: env.block.time,
+                }]
+            }
+            None => vec![],
+        };
+
+        let user = User {
+            id: old_user.id,
+            addr: old_user.addr.clone(),
+            hot_addr: None,
+            social_links,
+            membership_issued_by_me: old_user.membership_issued_by_me.map(|membership| {
+                MembershipIssuedByMe {
+                    membership_supply: membership.membership_supply,
+                    member_count: membership.member_count,
+                    bonding_curve: BondingCurve::default(),
+                    fee_denom: fee_denom.clone(),
+                }
+            }),
+            user_member_count: old_user.user_member_count,
+            referrer_user_id: None,
+            referee_count: Uint128::zero(),
+            only_verified_buyers: false,
+            // 0.1.0 fee denom of user fee config was never used, membership has its own fee denom now
+            fee_config: UserFeeConfig {
+                trading_fee_bps_of_membership: old_user
+                    .fee_config
+                    .map(|fee_config| {
+                        checked_percentage_to_basis_points(
+                            fee_config.trading_fee_percentage_of_membership,
+                        )
+                    })
+                    .transpose()?,
+                holding_cap: None,
+            },
+            fee_share_config: old_user
+                .fee_share_config
+                .map(FeeShareConfigV0_1_0::into_bps)
+                .transpose()?,
+        };
+
+        // Old user cannot be loaded as new User, pass new user as old data so its ID index entry is replaced
+        ALL_USERS().replace(deps.storage, &old_user.addr, Some(&user), Some(&user))?;
+    }
+
+    Ok(())
+}
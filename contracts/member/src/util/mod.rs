@@ -1,2 +1,14 @@
+pub(crate) mod attestation;
+pub(crate) mod config;
+pub(crate) mod fee_denom;
+pub(crate) mod fee_range;
 pub(crate) mod fee_share;
+pub(crate) mod holding_cap;
+pub(crate) mod migrate;
 pub(crate) mod price;
+pub(crate) mod referral;
+pub(crate) mod social_media;
+pub(crate) mod trade;
+pub(crate) mod twap;
+pub(crate) mod user;
+pub(crate) mod user_fee_change;
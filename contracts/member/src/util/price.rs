@@ -1,35 +1,47 @@
-use cosmwasm_std::{Uint128, Uint64};
+use cosmwasm_std::Uint128;
+use shared_pkg::pricing::BondingCurve;
 
-pub fn calculate_price(supply: Uint128, amount: Uint128) -> Uint128 {
-    let supply_minus_one: Uint128 = supply - Uint128::one();
-    let two_times_supply_minus_one_plus_pne =
-        supply_minus_one * Uint128::from(2_u8) + Uint128::one();
+use crate::ContractError;
 
-    let sum1 = if supply.is_zero() {
-        Uint128::zero()
-    } else {
-        supply_minus_one * supply * two_times_supply_minus_one_plus_pne / Uint128::from(6_u8)
-    };
-
-    let supply_minus_one_plus_amount = supply_minus_one + amount;
-    let supply_plus_amount = supply + amount;
-    let two_times_supply_minus_one_plus_amount_plus_one =
-        supply_minus_one_plus_amount * Uint128::from(2_u8) + Uint128::one();
+pub fn assert_bonding_curve_valid(bonding_curve: &BondingCurve) -> Result<(), ContractError> {
+    match bonding_curve {
+        BondingCurve::Quadratic { divisor } if divisor.is_zero() => {
+            Err(ContractError::BondingCurveDivisorCannotBeZero {})
+        }
+        BondingCurve::Exponential { growth_bps, .. } if growth_bps.is_zero() => {
+            Err(ContractError::BondingCurveGrowthCannotBeZero {})
+        }
+        BondingCurve::Sigmoid {
+            inflection_supply, ..
+        } if inflection_supply.is_zero() => {
+            Err(ContractError::BondingCurveInflectionSupplyCannotBeZero {})
+        }
+        _ => Ok(()),
+    }
+}
 
-    let sum2 = if supply.is_zero() && amount == Uint128::one() {
-        Uint128::zero()
-    } else {
-        supply_minus_one_plus_amount
-            * supply_plus_amount
-            * two_times_supply_minus_one_plus_amount_plus_one
-            / Uint128::from(6_u8)
-    };
+pub fn assert_sigmoid_max_trade_amount_valid(
+    sigmoid_max_trade_amount: Uint128,
+) -> Result<(), ContractError> {
+    if sigmoid_max_trade_amount.is_zero() {
+        return Err(ContractError::SigmoidMaxTradeAmountCannotBeZero {});
+    }
 
-    let summation = sum2 - sum1;
-    // 1_000_000 because 1 LUNA = 1_000_000 uluna
-    summation * Uint128::from(1_000_000_u64) / Uint128::from(16_000_u32)
+    Ok(())
 }
 
-pub fn multiply_percentage(price: Uint128, percentage: Uint64) -> Uint128 {
-    price * Uint128::from(percentage) / Uint128::from(100_u8)
+// Sigmoid price is summed one membership at a time, so a large trade could run out of gas
+pub fn assert_within_sigmoid_max_trade_amount(
+    bonding_curve: &BondingCurve,
+    sigmoid_max_trade_amount: Uint128,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    if matches!(bonding_curve, BondingCurve::Sigmoid { .. }) && amount > sigmoid_max_trade_amount {
+        return Err(ContractError::ExceedSigmoidMaxTradeAmount {
+            amount,
+            max_amount: sigmoid_max_trade_amount,
+        });
+    }
+
+    Ok(())
 }
@@ -0,0 +1,48 @@
+use cosmwasm_std::{BankMsg, Coin, CosmosMsg, Storage, Uint128};
+use member_pkg::{config::Config, user::User};
+
+use crate::{
+    state::{ALL_REFERRERS_REFEREES, ALL_USERS},
+    ContractError,
+};
+
+// Build msg to pay referral fee to trader's referrer and record how much referrer earned from trader
+// If trader has no referrer then referral fee goes to protocol fee collector
+// Returns None if there is no referral fee to pay
+pub fn pay_referral_fee(
+    storage: &mut dyn Storage,
+    config: &Config,
+    trader: &User,
+    referral_fee: Uint128,
+    fee_denom: &str,
+) -> Result<Option<CosmosMsg>, ContractError> {
+    if referral_fee.is_zero() {
+        return Ok(None);
+    }
+
+    let to_address = match trader.referrer_user_id {
+        None => config.protocol_fee_collector_addr.to_string(),
+        Some(referrer_user_id) => {
+            let referrer = match ALL_USERS().idx.id.item(storage, referrer_user_id.u64())? {
+                None => return Err(ContractError::ReferrerNotExist {}),
+                Some((_, referrer)) => referrer,
+            };
+            ALL_REFERRERS_REFEREES.update(
+                storage,
+                (referrer_user_id.u64(), trader.id.u64(), fee_denom),
+                |earned| -> Result<Uint128, ContractError> {
+                    Ok(earned.unwrap_or_default().checked_add(referral_fee)?)
+                },
+            )?;
+            referrer.addr.to_string()
+        }
+    };
+
+    Ok(Some(CosmosMsg::Bank(BankMsg::Send {
+        to_address,
+        amount: vec![Coin {
+            denom: fee_denom.to_string(),
+            amount: referral_fee,
+        }],
+    })))
+}
@@ -0,0 +1,43 @@
+use member_pkg::config::{Config, MembershipEligibility};
+use member_pkg::user::User;
+
+use crate::ContractError;
+
+pub fn assert_social_media_platform_in_allowlist(
+    config: &Config,
+    platform: &str,
+) -> Result<(), ContractError> {
+    if !config
+        .social_media_platform_allowlist
+        .iter()
+        .any(|p| p == platform)
+    {
+        return Err(ContractError::SocialMediaPlatformNotInAllowlist {
+            platform: platform.to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+// User must have linked social media per membership eligibility to prevent impersonation
+pub fn assert_eligible_for_membership(config: &Config, user: &User) -> Result<(), ContractError> {
+    match &config.membership_eligibility {
+        MembershipEligibility::AnyLinkedPlatform => {
+            if user.social_links.is_empty() {
+                return Err(ContractError::UserCannotRegisterMembershipBeforeLinkingSocialMedia {});
+            }
+        }
+        MembershipEligibility::SpecificPlatform { platform } => {
+            if user.social_link(platform).is_none() {
+                return Err(
+                    ContractError::UserCannotRegisterMembershipBeforeLinkingSocialMediaPlatform {
+                        platform: platform.clone(),
+                    },
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
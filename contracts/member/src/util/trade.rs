@@ -0,0 +1,62 @@
+use cosmwasm_std::{Storage, Uint64};
+use member_pkg::trade::{Candle, CandleInterval, Trade};
+
+use crate::{
+    state::{ALL_MEMBERSHIPS_PRICE_CANDLES, ALL_MEMBERSHIPS_TRADES, ALL_MEMBERSHIPS_TRADE_COUNT},
+    ContractError,
+};
+
+pub fn next_trade_id(
+    storage: &dyn Storage,
+    membership_issuer_user_id: u64,
+) -> Result<Uint64, ContractError> {
+    Ok(ALL_MEMBERSHIPS_TRADE_COUNT
+        .may_load(storage, membership_issuer_user_id)?
+        .unwrap_or_default()
+        .checked_add(Uint64::one())?)
+}
+
+// Append trade to membership's trade history and fold it into the candle it falls into
+pub fn record_trade(
+    storage: &mut dyn Storage,
+    membership_issuer_user_id: u64,
+    candle_interval: &CandleInterval,
+    trade: &Trade,
+) -> Result<(), ContractError> {
+    ALL_MEMBERSHIPS_TRADES.save(storage, (membership_issuer_user_id, trade.id.u64()), trade)?;
+    ALL_MEMBERSHIPS_TRADE_COUNT.save(storage, membership_issuer_user_id, &trade.id)?;
+
+    let unit_price = trade.unit_price();
+    let start_time = candle_interval.candle_start_time(trade.timestamp);
+    ALL_MEMBERSHIPS_PRICE_CANDLES.update(
+        storage,
+        (
+            membership_issuer_user_id,
+            candle_interval.as_str(),
+            start_time.seconds(),
+        ),
+        |candle| -> Result<Candle, ContractError> {
+            Ok(match candle {
+                None => Candle {
+                    start_time,
+                    open: unit_price,
+                    high: unit_price,
+                    low: unit_price,
+                    close: unit_price,
+                    volume: trade.amount,
+                    trade_count: Uint64::one(),
+                },
+                Some(candle) => Candle {
+                    high: candle.high.max(unit_price),
+                    low: candle.low.min(unit_price),
+                    close: unit_price,
+                    volume: candle.volume.checked_add(trade.amount)?,
+                    trade_count: candle.trade_count.checked_add(Uint64::one())?,
+                    ..candle
+                },
+            })
+        },
+    )?;
+
+    Ok(())
+}
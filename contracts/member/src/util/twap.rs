@@ -0,0 +1,42 @@
+use cosmwasm_std::{Order, StdResult, Storage, Timestamp, Uint128, Uint256};
+use member_pkg::trade::PriceObservation;
+
+use crate::state::ALL_MEMBERSHIPS_PRICE_OBSERVATIONS;
+
+pub fn load_latest_price_observation(
+    storage: &dyn Storage,
+    membership_issuer_user_id: u64,
+) -> StdResult<Option<PriceObservation>> {
+    ALL_MEMBERSHIPS_PRICE_OBSERVATIONS
+        .prefix(membership_issuer_user_id)
+        .range(storage, None, None, Order::Descending)
+        .next()
+        .transpose()
+        .map(|item| item.map(|(_, observation)| observation))
+}
+
+// Accumulate the previous spot price up to now then start accumulating the new spot price
+// Multiple trades in the same block time overwrite the same observation, only the last price counts
+pub fn observe_price(
+    storage: &mut dyn Storage,
+    membership_issuer_user_id: u64,
+    now: Timestamp,
+    spot_price: Uint128,
+) -> StdResult<()> {
+    let now = Timestamp::from_seconds(now.seconds());
+    let cumulative_price = match load_latest_price_observation(storage, membership_issuer_user_id)?
+    {
+        // Membership enabled before TWAP existed, start accumulating from its first trade
+        None => Uint256::zero(),
+        Some(latest_observation) => latest_observation.cumulative_price_at(now),
+    };
+    ALL_MEMBERSHIPS_PRICE_OBSERVATIONS.save(
+        storage,
+        (membership_issuer_user_id, now.seconds()),
+        &PriceObservation {
+            timestamp: now,
+            cumulative_price,
+            price: spot_price,
+        },
+    )
+}
@@ -0,0 +1,55 @@
+use cosmwasm_std::{Addr, Deps, StdError, StdResult};
+use member_pkg::user::{MembershipIssuedByMe, User};
+
+use crate::{
+    state::{ALL_HOT_ADDRS, ALL_USERS},
+    ContractError,
+};
+
+// Address cannot be used by more than 1 user, either as cold address or hot address
+pub fn assert_addr_not_registered(deps: Deps, addr: &Addr) -> Result<(), ContractError> {
+    if ALL_USERS().may_load(deps.storage, addr)?.is_some() || ALL_HOT_ADDRS.has(deps.storage, addr)
+    {
+        return Err(ContractError::AddressAlreadyRegistered {});
+    }
+
+    Ok(())
+}
+
+// Load user by its cold address, hot address is rejected
+// Used by actions that only cold address can perform, e.g. buy / sell membership
+pub fn load_user_by_cold_addr(deps: Deps, addr: &Addr) -> Result<User, ContractError> {
+    match ALL_USERS().may_load(deps.storage, addr)? {
+        Some(user) => Ok(user),
+        None => {
+            if ALL_HOT_ADDRS.has(deps.storage, addr) {
+                Err(ContractError::OnlyColdAddrAllowed {})
+            } else {
+                Err(ContractError::UserNotExist {})
+            }
+        }
+    }
+}
+
+pub fn may_load_user_by_id(deps: Deps, user_id: u64) -> StdResult<Option<User>> {
+    Ok(ALL_USERS()
+        .idx
+        .id
+        .item(deps.storage, user_id)?
+        .map(|(_, user)| user))
+}
+
+pub fn load_user_by_id(deps: Deps, user_id: u64) -> Result<User, ContractError> {
+    may_load_user_by_id(deps, user_id)?.ok_or(ContractError::UserNotExist {})
+}
+
+// Error instead of panic so contracts querying it get a readable error
+pub fn load_membership_issued_by(
+    deps: Deps,
+    membership_issuer_user_id: u64,
+) -> StdResult<MembershipIssuedByMe> {
+    may_load_user_by_id(deps, membership_issuer_user_id)?
+        .ok_or_else(|| StdError::not_found("User"))?
+        .membership_issued_by_me
+        .ok_or_else(|| StdError::generic_err("Membership not enabled"))
+}
@@ -0,0 +1,50 @@
+use cosmwasm_std::{BlockInfo, StdResult, Storage};
+use member_pkg::{
+    config::UserFeeRanges,
+    user::{PendingUserFeeChange, User},
+};
+
+use crate::state::ALL_PENDING_USER_FEE_CHANGES;
+
+// Write fee change onto user, fees not in the change are kept
+pub fn apply_user_fee_change(user: &mut User, fee_change: PendingUserFeeChange) {
+    if let Some(trading_fee_bps_of_membership) = fee_change.trading_fee_bps_of_membership {
+        user.fee_config.trading_fee_bps_of_membership = Some(trading_fee_bps_of_membership);
+    }
+
+    if let Some(fee_share_config) = fee_change.fee_share_config {
+        user.fee_share_config = Some(fee_share_config);
+    }
+}
+
+// Admin may have tightened the ranges after fee change was made, fees now out of range are dropped
+fn drop_fees_out_of_range(
+    mut fee_change: PendingUserFeeChange,
+    user_fee_ranges: &UserFeeRanges,
+) -> PendingUserFeeChange {
+    fee_change.trading_fee_bps_of_membership = fee_change
+        .trading_fee_bps_of_membership
+        .filter(|bps| user_fee_ranges.trading_fee_bps_of_membership.contains(*bps));
+    fee_change
+}
+
+// User with the fees in effect at block, pending fee change is applied once it takes effect
+// Also returns pending fee change that has not taken effect yet
+pub fn with_effective_user_fees(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    user_fee_ranges: &UserFeeRanges,
+    mut user: User,
+) -> StdResult<(User, Option<PendingUserFeeChange>)> {
+    let pending_fee_change = ALL_PENDING_USER_FEE_CHANGES
+        .may_load(storage, user.id.u64())?
+        .map(|fee_change| drop_fees_out_of_range(fee_change, user_fee_ranges));
+    match pending_fee_change {
+        Some(fee_change) if fee_change.is_empty() => Ok((user, None)),
+        Some(fee_change) if fee_change.effective_at.is_expired(block) => {
+            apply_user_fee_change(&mut user, fee_change);
+            Ok((user, None))
+        }
+        pending_fee_change => Ok((user, pending_fee_change)),
+    }
+}
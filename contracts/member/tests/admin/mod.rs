@@ -1,4 +1,10 @@
+mod test_admin_can_grant_and_revoke_roles;
+mod test_admin_can_migrate_contract;
+mod test_admin_can_migrate_contract_from_first_release;
+mod test_admin_transfer_and_config_change_timelock;
 mod test_admin_update_happy_case;
 mod test_default_config;
-mod test_fee_share_must_sum_100;
+mod test_disabled_contract_rejects_user_actions;
+mod test_fee_share_must_sum_to_10000_bps;
 mod test_only_admin_can_update_config;
+mod test_pause_buy_membership_still_allows_sell_and_claim;
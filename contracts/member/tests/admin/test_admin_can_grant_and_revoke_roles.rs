@@ -0,0 +1,290 @@
+use cosmwasm_std::{Addr, Uint64};
+use cw_multi_test::Executor;
+
+use member::ContractError;
+use member_pkg::msg::{ExecuteMsg, UpdatePauseConfigMsg};
+use shared_pkg::rbac::Role;
+
+use crate::helpers::{
+    accept_admin, add_to_fee_denom_allowlist, assert_err, assert_roles, grant_role,
+    link_social_media, proper_instantiate, propose_new_admin, register_user, revoke_role,
+    update_config, FEE_DENOM_2, SOCIAL_MEDIA_HANDLE_1, USER_3,
+};
+
+#[test]
+fn test_admin_can_grant_and_revoke_roles() {
+    let (
+        mut app,
+        cw_member_contract_addr,
+        admin_addr,
+        registration_admin_addr,
+        _,
+        user_1_addr,
+        user_2_addr,
+    ) = proper_instantiate();
+    let user_3_addr = Addr::unchecked(USER_3);
+    let pause_buy_membership_msg = ExecuteMsg::UpdatePauseConfig(UpdatePauseConfigMsg {
+        registration_paused: None,
+        buy_membership_paused: Some(true),
+        sell_membership_paused: None,
+    });
+
+    // Addresses in config hold their role from instantiation
+    assert_roles(
+        &app,
+        &cw_member_contract_addr,
+        &admin_addr,
+        vec![Role::Admin],
+    );
+    assert_roles(
+        &app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        vec![Role::RegistrationOperator],
+    );
+    assert_roles(&app, &cw_member_contract_addr, &user_1_addr, vec![]);
+
+    // Only admin can grant role
+    assert_err(
+        grant_role(
+            &mut app,
+            &cw_member_contract_addr,
+            &user_1_addr,
+            &user_1_addr,
+            Role::Pauser,
+        ),
+        ContractError::OnlyAdminCanGrantRole {},
+    );
+
+    // Pauser can pause but cannot update config
+    grant_role(
+        &mut app,
+        &cw_member_contract_addr,
+        &admin_addr,
+        &user_1_addr,
+        Role::Pauser,
+    )
+    .unwrap();
+    assert_err(
+        grant_role(
+            &mut app,
+            &cw_member_contract_addr,
+            &admin_addr,
+            &user_1_addr,
+            Role::Pauser,
+        ),
+        ContractError::AddressAlreadyHasRole {
+            role: "pauser".to_string(),
+        },
+    );
+    app.execute_contract(
+        user_1_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &pause_buy_membership_msg,
+        &[],
+    )
+    .unwrap();
+    assert_err(
+        update_config(
+            &mut app,
+            &cw_member_contract_addr,
+            &user_1_addr,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(true),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ),
+        ContractError::OnlyAdminCanUpdateConfig {},
+    );
+
+    // Fee manager can update fees and fee denom allowlist but nothing else
+    grant_role(
+        &mut app,
+        &cw_member_contract_addr,
+        &admin_addr,
+        &user_2_addr,
+        Role::FeeManager,
+    )
+    .unwrap();
+    update_config(
+        &mut app,
+        &cw_member_contract_addr,
+        &user_2_addr,
+        None,
+        None,
+        None,
+        Some(Uint64::from(20_u8)),
+        None,
+        Some(Uint64::from(10_u8)),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+    assert_err(
+        update_config(
+            &mut app,
+            &cw_member_contract_addr,
+            &user_2_addr,
+            None,
+            None,
+            None,
+            Some(Uint64::from(20_u8)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(true),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ),
+        ContractError::OnlyAdminCanUpdateConfig {},
+    );
+    add_to_fee_denom_allowlist(
+        &mut app,
+        &cw_member_contract_addr,
+        &user_2_addr,
+        FEE_DENOM_2,
+    )
+    .unwrap();
+
+    // Multiple addresses can hold registration operator role
+    grant_role(
+        &mut app,
+        &cw_member_contract_addr,
+        &admin_addr,
+        &user_1_addr,
+        Role::RegistrationOperator,
+    )
+    .unwrap();
+    assert_roles(
+        &app,
+        &cw_member_contract_addr,
+        &user_1_addr,
+        vec![Role::RegistrationOperator, Role::Pauser],
+    );
+    register_user(&mut app, &cw_member_contract_addr, &user_2_addr).unwrap();
+    link_social_media(
+        &mut app,
+        &cw_member_contract_addr,
+        &user_1_addr,
+        Uint64::one(),
+        SOCIAL_MEDIA_HANDLE_1,
+    )
+    .unwrap();
+
+    // Addresses in config keep their role until config is updated
+    assert_err(
+        revoke_role(
+            &mut app,
+            &cw_member_contract_addr,
+            &admin_addr,
+            &admin_addr,
+            Role::Admin,
+        ),
+        ContractError::CannotRevokeRoleOfConfigAddress {
+            role: "admin".to_string(),
+        },
+    );
+    assert_err(
+        revoke_role(
+            &mut app,
+            &cw_member_contract_addr,
+            &admin_addr,
+            &registration_admin_addr,
+            Role::RegistrationOperator,
+        ),
+        ContractError::CannotRevokeRoleOfConfigAddress {
+            role: "registration_operator".to_string(),
+        },
+    );
+
+    // Revoked pauser can no longer pause
+    revoke_role(
+        &mut app,
+        &cw_member_contract_addr,
+        &admin_addr,
+        &user_1_addr,
+        Role::Pauser,
+    )
+    .unwrap();
+    assert_err(
+        app.execute_contract(
+            user_1_addr.clone(),
+            cw_member_contract_addr.clone(),
+            &pause_buy_membership_msg,
+            &[],
+        ),
+        ContractError::OnlyAdminCanUpdatePauseConfig {},
+    );
+    assert_err(
+        revoke_role(
+            &mut app,
+            &cw_member_contract_addr,
+            &admin_addr,
+            &user_1_addr,
+            Role::Pauser,
+        ),
+        ContractError::AddressDoesNotHaveRole {
+            role: "pauser".to_string(),
+        },
+    );
+
+    // Replacing admin moves admin role to the new admin
+    propose_new_admin(
+        &mut app,
+        &cw_member_contract_addr,
+        &admin_addr,
+        &user_3_addr,
+        None,
+    )
+    .unwrap();
+    accept_admin(&mut app, &cw_member_contract_addr, &user_3_addr).unwrap();
+    assert_roles(&app, &cw_member_contract_addr, &admin_addr, vec![]);
+    assert_roles(
+        &app,
+        &cw_member_contract_addr,
+        &user_3_addr,
+        vec![Role::Admin],
+    );
+    assert_err(
+        grant_role(
+            &mut app,
+            &cw_member_contract_addr,
+            &admin_addr,
+            &admin_addr,
+            Role::Pauser,
+        ),
+        ContractError::OnlyAdminCanGrantRole {},
+    );
+}
@@ -0,0 +1,127 @@
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use cw_multi_test::{ContractWrapper, Executor};
+
+use member::{
+    contract::{execute, instantiate, migrate, query, CONTRACT_NAME, CONTRACT_VERSION},
+    ContractError,
+};
+use member_pkg::msg::{InstantiateMsg, MigrateMsg, QueryMsg, QueryUserByAddrMsg, UserResponse};
+use shared_pkg::migrate::MigrateError;
+
+use crate::helpers::{
+    contract_cw_distribution, contract_cw_thread, proper_instantiate, register_user,
+};
+
+// Pretends to be a newer release of member contract that is already deployed
+fn instantiate_as_newer_version(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    let res = instantiate(deps.branch(), env, info, msg)?;
+    cw2::set_contract_version(deps.storage, format!("crates.io:{CONTRACT_NAME}"), "99.0.0")?;
+    Ok(res)
+}
+
+#[test]
+fn test_admin_can_migrate_contract() {
+    let (mut app, cw_member_contract_addr, admin_addr, _, _, user_1_addr, _) = proper_instantiate();
+
+    register_user(&mut app, &cw_member_contract_addr, &user_1_addr).unwrap();
+
+    // Admin migrates to new code of the same version, users are kept
+    let new_code_id = app.store_code(contract_cw_thread());
+    app.migrate_contract(
+        admin_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &MigrateMsg {},
+        new_code_id,
+    )
+    .unwrap();
+    let contract_version =
+        cw2::query_contract_info(&app.wrap(), cw_member_contract_addr.clone()).unwrap();
+    assert_eq!(
+        contract_version.contract,
+        format!("crates.io:{CONTRACT_NAME}")
+    );
+    assert_eq!(contract_version.version, CONTRACT_VERSION);
+    let query_user_1_res: UserResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryUserByAddr(QueryUserByAddrMsg {
+                user_addr: user_1_addr.to_string(),
+            }),
+        )
+        .unwrap();
+    assert_eq!(query_user_1_res.user.addr, user_1_addr);
+
+    // Cannot migrate member contract to code of another contract
+    let cw_distribution_contract_code_id = app.store_code(contract_cw_distribution());
+    let err: distribution::ContractError = app
+        .migrate_contract(
+            admin_addr.clone(),
+            cw_member_contract_addr.clone(),
+            &MigrateMsg {},
+            cw_distribution_contract_code_id,
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        distribution::ContractError::Migrate(MigrateError::CannotMigrateFromDifferentContract {
+            previous_contract: format!("crates.io:{CONTRACT_NAME}"),
+        })
+    );
+
+    // Cannot downgrade a newer deployment to current code
+    let newer_version_code_id = app.store_code(Box::new(
+        ContractWrapper::new(execute, instantiate_as_newer_version, query).with_migrate(migrate),
+    ));
+    let newer_version_contract_addr = app
+        .instantiate_contract(
+            newer_version_code_id,
+            admin_addr.clone(),
+            &InstantiateMsg {
+                admin_addr: None,
+                registration_admin_addr: None,
+                protocol_fee_collector_addr: None,
+                fee_denom: None,
+                protocol_fee_membership_trading_fee_bps: None,
+                protocol_fee_referral_fee_bps: None,
+                default_trading_fee_bps_of_membership: None,
+                default_membership_trading_fee_membership_issuer_fee_bps: None,
+                default_membership_trading_fee_membership_holder_fee_bps: None,
+                default_bonding_curve: None,
+                sigmoid_max_trade_amount: None,
+                default_holding_cap: None,
+                only_verified_buyers: None,
+                price_candle_interval: None,
+                user_fee_change_timelock: None,
+                user_fee_ranges: None,
+            },
+            &[],
+            "cw_thread_newer_version",
+            Some(admin_addr.to_string()),
+        )
+        .unwrap();
+    let err: ContractError = app
+        .migrate_contract(
+            admin_addr.clone(),
+            newer_version_contract_addr,
+            &MigrateMsg {},
+            new_code_id,
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::Migrate(MigrateError::CannotMigrateToOlderVersion {
+            previous_version: "99.0.0".to_string(),
+            new_version: CONTRACT_VERSION.to_string(),
+        })
+    );
+}
@@ -0,0 +1,314 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    Addr, DepsMut, Empty, Env, MessageInfo, Response, StdResult, Timestamp, Uint128, Uint64,
+};
+use cw_multi_test::{ContractWrapper, Executor};
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, UniqueIndex};
+
+use member::contract::{execute, query, CONTRACT_NAME, CONTRACT_VERSION};
+use member_pkg::{
+    config::FeeConfig,
+    msg::{
+        MigrateMsg, QueryMsg, QueryUserByAddrMsg, QueryUserByIDMsg,
+        QueryUserBySocialMediaHandleMsg, UserResponse,
+    },
+    user::{MembershipIssuedByMe, SocialLink, UserFeeConfig},
+};
+use shared_pkg::{fee_share_config::FeeShareConfig, pricing::BondingCurve, rbac::Role};
+
+use crate::helpers::{
+    assert_roles, contract_cw_thread, proper_instantiate, query_config, ADMIN, FEE_DENOM,
+    PROTOCOL_FEE_COLLECTOR, REGISTRATION_ADMIN, SOCIAL_MEDIA_PLATFORM, USER_1, USER_2,
+};
+
+// Config and user as stored by the first release of member contract
+
+#[cw_serde]
+struct ProtocolFeeConfigV0_1_0 {
+    membership_trading_fee_percentage: Uint64,
+}
+
+#[cw_serde]
+struct FeeConfigV0_1_0 {
+    fee_denom: String,
+    trading_fee_percentage_of_membership: Uint64,
+}
+
+#[cw_serde]
+struct FeeShareConfigV0_1_0 {
+    share_to_issuer_percentage: Uint64,
+    share_to_all_members_percentage: Uint64,
+}
+
+#[cw_serde]
+struct ConfigV0_1_0 {
+    admin_addr: Addr,
+    distribution_contract_addr: Option<Addr>,
+    enabled: bool,
+    enable_open_registration: bool,
+    registration_admin_addr: Addr,
+    protocol_fee_collector_addr: Addr,
+    protocol_fee_config: ProtocolFeeConfigV0_1_0,
+    default_fee_config: FeeConfigV0_1_0,
+    default_fee_share_config: FeeShareConfigV0_1_0,
+}
+
+#[cw_serde]
+struct MembershipIssuedByMeV0_1_0 {
+    membership_supply: Uint128,
+    member_count: Uint128,
+}
+
+#[cw_serde]
+struct UserV0_1_0 {
+    id: Uint64,
+    addr: Addr,
+    social_media_handle: Option<String>,
+    membership_issued_by_me: Option<MembershipIssuedByMeV0_1_0>,
+    user_member_count: Uint128,
+    fee_config: Option<FeeConfigV0_1_0>,
+    fee_share_config: Option<FeeShareConfigV0_1_0>,
+}
+
+struct UserIndexesV0_1_0<'a> {
+    id: UniqueIndex<'a, u64, UserV0_1_0>,
+}
+
+impl<'a> IndexList<UserV0_1_0> for UserIndexesV0_1_0<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<UserV0_1_0>> + '_> {
+        let v: Vec<&dyn Index<UserV0_1_0>> = vec![&self.id];
+        Box::new(v.into_iter())
+    }
+}
+
+#[allow(non_snake_case)]
+fn ALL_USERS_V0_1_0<'a>() -> IndexedMap<'a, &'a Addr, UserV0_1_0, UserIndexesV0_1_0<'a>> {
+    let indexes = UserIndexesV0_1_0 {
+        id: UniqueIndex::new(|user| user.id.u64(), "ALL_USERS_USER_ID"),
+    };
+    IndexedMap::new("ALL_USERS", indexes)
+}
+
+// Pretends to be the first release of member contract with 2 registered users
+// User 1 linked a twitter handle and issued a membership
+fn instantiate_as_first_release(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    _msg: Empty,
+) -> StdResult<Response> {
+    cw2::set_contract_version(deps.storage, format!("crates.io:{CONTRACT_NAME}"), "0.1.0")?;
+
+    Item::new("CONFIG").save(
+        deps.storage,
+        &ConfigV0_1_0 {
+            admin_addr: Addr::unchecked(ADMIN),
+            distribution_contract_addr: None,
+            enabled: true,
+            enable_open_registration: true,
+            registration_admin_addr: Addr::unchecked(REGISTRATION_ADMIN),
+            protocol_fee_collector_addr: Addr::unchecked(PROTOCOL_FEE_COLLECTOR),
+            protocol_fee_config: ProtocolFeeConfigV0_1_0 {
+                membership_trading_fee_percentage: Uint64::from(10_u64),
+            },
+            default_fee_config: FeeConfigV0_1_0 {
+                fee_denom: FEE_DENOM.to_string(),
+                trading_fee_percentage_of_membership: Uint64::from(5_u64),
+            },
+            default_fee_share_config: FeeShareConfigV0_1_0 {
+                share_to_issuer_percentage: Uint64::from(80_u64),
+                share_to_all_members_percentage: Uint64::from(20_u64),
+            },
+        },
+    )?;
+    Item::new("NEXT_USER_ID").save(deps.storage, &Uint64::from(3_u64))?;
+
+    ALL_USERS_V0_1_0().save(
+        deps.storage,
+        &Addr::unchecked(USER_1),
+        &UserV0_1_0 {
+            id: Uint64::one(),
+            addr: Addr::unchecked(USER_1),
+            social_media_handle: Some("@Alice".to_string()),
+            membership_issued_by_me: Some(MembershipIssuedByMeV0_1_0 {
+                membership_supply: Uint128::from(2_u64),
+                member_count: Uint128::from(2_u64),
+            }),
+            user_member_count: Uint128::one(),
+            fee_config: Some(FeeConfigV0_1_0 {
+                fee_denom: FEE_DENOM.to_string(),
+                trading_fee_percentage_of_membership: Uint64::from(8_u64),
+            }),
+            fee_share_config: None,
+        },
+    )?;
+    ALL_USERS_V0_1_0().save(
+        deps.storage,
+        &Addr::unchecked(USER_2),
+        &UserV0_1_0 {
+            id: Uint64::from(2_u64),
+            addr: Addr::unchecked(USER_2),
+            social_media_handle: None,
+            membership_issued_by_me: None,
+            user_member_count: Uint128::one(),
+            fee_config: None,
+            fee_share_config: None,
+        },
+    )?;
+
+    Ok(Response::new())
+}
+
+#[test]
+fn test_admin_can_migrate_contract_from_first_release() {
+    let (mut app, _, _, _, _, _, _) = proper_instantiate();
+    let admin_addr = Addr::unchecked(ADMIN);
+
+    let first_release_code_id = app.store_code(Box::new(ContractWrapper::new(
+        execute,
+        instantiate_as_first_release,
+        query,
+    )));
+    let cw_member_contract_addr = app
+        .instantiate_contract(
+            first_release_code_id,
+            admin_addr.clone(),
+            &Empty {},
+            &[],
+            "cw_member_first_release",
+            Some(admin_addr.to_string()),
+        )
+        .unwrap();
+
+    let new_code_id = app.store_code(contract_cw_thread());
+    app.migrate_contract(
+        admin_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &MigrateMsg {},
+        new_code_id,
+    )
+    .unwrap();
+    let contract_version =
+        cw2::query_contract_info(&app.wrap(), cw_member_contract_addr.clone()).unwrap();
+    assert_eq!(contract_version.version, CONTRACT_VERSION);
+
+    // Old config is kept with fees converted to bps, new fields get the same defaults as instantiate
+    let config = query_config(&app, &cw_member_contract_addr);
+    assert_eq!(config.admin_addr, admin_addr);
+    assert!(config.enabled);
+    assert!(config.enable_open_registration);
+    assert_eq!(
+        config.protocol_fee_config.membership_trading_fee_bps,
+        Uint64::from(1_000_u64)
+    );
+    assert_eq!(config.protocol_fee_config.referral_fee_bps, Uint64::zero());
+    assert_eq!(
+        config.default_fee_config,
+        FeeConfig {
+            fee_denom: FEE_DENOM.to_string(),
+            trading_fee_bps_of_membership: Uint64::from(500_u64),
+            holding_cap: None,
+        }
+    );
+    assert_eq!(
+        config.default_fee_share_config,
+        FeeShareConfig {
+            share_to_issuer_bps: Uint64::from(8_000_u64),
+            share_to_all_members_bps: Uint64::from(2_000_u64),
+        }
+    );
+    assert_eq!(config.default_bonding_curve, BondingCurve::default());
+    assert_eq!(config.sigmoid_max_trade_amount, Uint128::from(1_000_u64));
+    assert_eq!(config.fee_denom_allowlist, vec![FEE_DENOM.to_string()]);
+    assert!(config
+        .social_media_platform_allowlist
+        .contains(&SOCIAL_MEDIA_PLATFORM.to_string()));
+
+    // Addresses in config hold their roles
+    assert_roles(
+        &app,
+        &cw_member_contract_addr,
+        &admin_addr,
+        vec![Role::Admin],
+    );
+    assert_roles(
+        &app,
+        &cw_member_contract_addr,
+        &Addr::unchecked(REGISTRATION_ADMIN),
+        vec![Role::RegistrationOperator],
+    );
+
+    // Users are converted and can still be looked up by ID
+    let user_1: UserResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryUserByID(QueryUserByIDMsg {
+                user_id: Uint64::one(),
+            }),
+        )
+        .unwrap();
+    let block_time: Timestamp = app.block_info().time;
+    assert_eq!(user_1.user.addr, Addr::unchecked(USER_1));
+    assert_eq!(
+        user_1.user.social_links,
+        vec![SocialLink {
+            platform: SOCIAL_MEDIA_PLATFORM.to_string(),
+            handle: "@Alice".to_string(),
+            linked_at: block_time,
+        }]
+    );
+    assert_eq!(
+        user_1.user.membership_issued_by_me,
+        Some(MembershipIssuedByMe {
+            membership_supply: Uint128::from(2_u64),
+            member_count: Uint128::from(2_u64),
+            bonding_curve: BondingCurve::default(),
+            fee_denom: FEE_DENOM.to_string(),
+        })
+    );
+    assert_eq!(
+        user_1.user.fee_config,
+        UserFeeConfig {
+            trading_fee_bps_of_membership: Some(Uint64::from(800_u64)),
+            holding_cap: None,
+        }
+    );
+
+    // Linked handle can be resolved to the user
+    let user_1_by_handle: UserResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryUserBySocialMediaHandle(QueryUserBySocialMediaHandleMsg {
+                platform: SOCIAL_MEDIA_PLATFORM.to_string(),
+                social_media_handle: "alice".to_string(),
+            }),
+        )
+        .unwrap();
+    assert_eq!(user_1_by_handle.user.id, Uint64::one());
+
+    let user_2: UserResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryUserByAddr(QueryUserByAddrMsg {
+                user_addr: USER_2.to_string(),
+            }),
+        )
+        .unwrap();
+    assert_eq!(user_2.user.id, Uint64::from(2_u64));
+    assert!(user_2.user.social_links.is_empty());
+    assert_eq!(user_2.user.membership_issued_by_me, None);
+    assert_eq!(user_2.user.fee_config, UserFeeConfig::default());
+
+    // Migrating again to the same version doesn't run the migration a second time
+    app.migrate_contract(
+        admin_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &MigrateMsg {},
+        new_code_id,
+    )
+    .unwrap();
+    assert_eq!(query_config(&app, &cw_member_contract_addr), config);
+}
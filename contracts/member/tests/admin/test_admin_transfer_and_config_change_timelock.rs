@@ -0,0 +1,267 @@
+use cosmwasm_std::{Addr, Uint64};
+use cw_multi_test::Executor;
+use cw_utils::{Duration, Expiration};
+
+use member::ContractError;
+use member_pkg::msg::{ExecuteMsg, UpdateConfigMsg};
+use shared_pkg::{
+    admin_transfer::{AdminProposal, CancelAdminProposalMsg},
+    rbac::Role,
+    timelock::CancelPendingConfigChangeMsg,
+};
+
+use crate::helpers::{
+    accept_admin, assert_err, assert_roles, execute_pending_config_change, proper_instantiate,
+    propose_new_admin, query_config, query_pending_config_changes, USER_3,
+};
+
+#[test]
+fn test_admin_transfer_and_config_change_timelock() {
+    let (mut app, cw_member_contract_addr, admin_addr, _, _, user_1_addr, user_2_addr) =
+        proper_instantiate();
+    let user_3_addr = Addr::unchecked(USER_3);
+
+    // Only admin can propose new admin and only proposed admin can accept
+    assert_err(
+        propose_new_admin(
+            &mut app,
+            &cw_member_contract_addr,
+            &user_1_addr,
+            &user_1_addr,
+            None,
+        ),
+        ContractError::OnlyAdminCanProposeNewAdmin {},
+    );
+    assert_err(
+        accept_admin(&mut app, &cw_member_contract_addr, &user_1_addr),
+        ContractError::NoPendingAdminProposal {},
+    );
+    let expiration = Expiration::AtHeight(app.block_info().height + 10);
+    propose_new_admin(
+        &mut app,
+        &cw_member_contract_addr,
+        &admin_addr,
+        &user_1_addr,
+        Some(expiration),
+    )
+    .unwrap();
+    assert_eq!(
+        query_pending_config_changes(&app, &cw_member_contract_addr).pending_admin_proposal,
+        Some(AdminProposal {
+            new_admin_addr: user_1_addr.clone(),
+            expiration,
+        })
+    );
+    assert_err(
+        accept_admin(&mut app, &cw_member_contract_addr, &user_2_addr),
+        ContractError::OnlyProposedAdminCanAcceptAdmin {},
+    );
+
+    // Proposal cannot be accepted once expired
+    app.update_block(|block| {
+        block.height += 10;
+    });
+    assert_err(
+        accept_admin(&mut app, &cw_member_contract_addr, &user_1_addr),
+        ContractError::AdminProposalExpired {},
+    );
+
+    // Cancelled proposal cannot be accepted
+    propose_new_admin(
+        &mut app,
+        &cw_member_contract_addr,
+        &admin_addr,
+        &user_1_addr,
+        None,
+    )
+    .unwrap();
+    app.execute_contract(
+        admin_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::CancelAdminProposal(CancelAdminProposalMsg {}),
+        &[],
+    )
+    .unwrap();
+    assert_err(
+        accept_admin(&mut app, &cw_member_contract_addr, &user_1_addr),
+        ContractError::NoPendingAdminProposal {},
+    );
+
+    // Admin stays in control until proposed admin accepts
+    propose_new_admin(
+        &mut app,
+        &cw_member_contract_addr,
+        &admin_addr,
+        &user_3_addr,
+        None,
+    )
+    .unwrap();
+    assert_roles(
+        &app,
+        &cw_member_contract_addr,
+        &admin_addr,
+        vec![Role::Admin],
+    );
+    accept_admin(&mut app, &cw_member_contract_addr, &user_3_addr).unwrap();
+    assert_roles(&app, &cw_member_contract_addr, &admin_addr, vec![]);
+    assert_roles(
+        &app,
+        &cw_member_contract_addr,
+        &user_3_addr,
+        vec![Role::Admin],
+    );
+    assert_eq!(
+        query_pending_config_changes(&app, &cw_member_contract_addr).pending_admin_proposal,
+        None
+    );
+
+    // Enabling time-lock takes effect right away since there is no time-lock yet
+    let config_change_timelock = Duration::Time(86_400);
+    app.execute_contract(
+        user_3_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            config_change_timelock: Some(config_change_timelock),
+            ..UpdateConfigMsg::default()
+        }),
+        &[],
+    )
+    .unwrap();
+
+    // Fee change is queued while non fee change is applied right away
+    app.execute_contract(
+        user_3_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            protocol_fee_membership_trading_fee_bps: Some(Uint64::from(2_000_u32)),
+            only_verified_buyers: Some(true),
+            ..UpdateConfigMsg::default()
+        }),
+        &[],
+    )
+    .unwrap();
+    let config = query_config(&app, &cw_member_contract_addr);
+    assert!(config.only_verified_buyers);
+    assert_eq!(
+        config.protocol_fee_config.membership_trading_fee_bps,
+        Uint64::from(1_000_u32)
+    );
+
+    let pending = query_pending_config_changes(&app, &cw_member_contract_addr);
+    assert_eq!(pending.config_change_timelock, Some(config_change_timelock));
+    assert_eq!(pending.pending_config_changes.len(), 1);
+    let pending_config_change = &pending.pending_config_changes[0];
+    assert_eq!(pending_config_change.id, Uint64::one());
+    assert_eq!(pending_config_change.proposed_by, user_3_addr);
+    assert_eq!(
+        pending_config_change.changes,
+        UpdateConfigMsg {
+            protocol_fee_membership_trading_fee_bps: Some(Uint64::from(2_000_u32)),
+            ..UpdateConfigMsg::default()
+        }
+    );
+    assert_eq!(
+        pending_config_change.executable_at,
+        config_change_timelock.after(&app.block_info())
+    );
+
+    // Invalid change is rejected when proposed, not when executed
+    assert_err(
+        app.execute_contract(
+            user_3_addr.clone(),
+            cw_member_contract_addr.clone(),
+            &ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+                default_share_to_issuer_bps: Some(Uint64::from(9_000_u32)),
+                ..UpdateConfigMsg::default()
+            }),
+            &[],
+        ),
+        ContractError::MembershipTradingFeeShareBpsMustSumTo10000 {},
+    );
+
+    // Pending change cannot be executed before time-lock passes
+    assert_err(
+        execute_pending_config_change(
+            &mut app,
+            &cw_member_contract_addr,
+            &user_3_addr,
+            Uint64::one(),
+        ),
+        ContractError::PendingConfigChangeStillTimeLocked {
+            executable_at: pending_config_change.executable_at.to_string(),
+        },
+    );
+    assert_err(
+        execute_pending_config_change(
+            &mut app,
+            &cw_member_contract_addr,
+            &user_1_addr,
+            Uint64::one(),
+        ),
+        ContractError::OnlyAdminOrFeeManagerCanExecutePendingConfigChange {},
+    );
+
+    app.update_block(|block| {
+        block.time = block.time.plus_seconds(86_400);
+    });
+    execute_pending_config_change(
+        &mut app,
+        &cw_member_contract_addr,
+        &user_3_addr,
+        Uint64::one(),
+    )
+    .unwrap();
+    assert_eq!(
+        query_config(&app, &cw_member_contract_addr)
+            .protocol_fee_config
+            .membership_trading_fee_bps,
+        Uint64::from(2_000_u32)
+    );
+    assert_err(
+        execute_pending_config_change(
+            &mut app,
+            &cw_member_contract_addr,
+            &user_3_addr,
+            Uint64::one(),
+        ),
+        ContractError::PendingConfigChangeNotExist { id: Uint64::one() },
+    );
+
+    // Cancelled change is never applied
+    app.execute_contract(
+        user_3_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            protocol_fee_referral_fee_bps: Some(Uint64::from(5_000_u32)),
+            ..UpdateConfigMsg::default()
+        }),
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        user_3_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::CancelPendingConfigChange(CancelPendingConfigChangeMsg {
+            id: Uint64::from(2_u8),
+        }),
+        &[],
+    )
+    .unwrap();
+    assert!(query_pending_config_changes(&app, &cw_member_contract_addr)
+        .pending_config_changes
+        .is_empty());
+    app.update_block(|block| {
+        block.time = block.time.plus_seconds(86_400);
+    });
+    assert_err(
+        execute_pending_config_change(
+            &mut app,
+            &cw_member_contract_addr,
+            &user_3_addr,
+            Uint64::from(2_u8),
+        ),
+        ContractError::PendingConfigChangeNotExist {
+            id: Uint64::from(2_u8),
+        },
+    );
+}
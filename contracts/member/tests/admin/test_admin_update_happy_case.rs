@@ -1,30 +1,48 @@
-use cosmwasm_std::Uint64;
+use cosmwasm_std::{Uint128, Uint64};
+use cw_utils::Duration;
 
 use member_pkg::{
-    config::{Config, FeeConfig, ProtocolFeeConfig},
+    config::{
+        Config, FeeConfig, MembershipEligibility, PauseConfig, ProtocolFeeConfig, UserFeeRanges,
+    },
     msg::{ConfigResponse, QueryConfigMsg, QueryMsg},
+    trade::CandleInterval,
 };
-use shared_pkg::fee_share_config::FeeShareConfig;
+use shared_pkg::{fee_share_config::FeeShareConfig, pricing::BondingCurve};
 
-use crate::helpers::{proper_instantiate, update_config};
+use crate::helpers::{proper_instantiate, update_config, FEE_DENOM};
 
 #[test]
 fn test_only_admin_can_update_config() {
-    let (mut app, cw_member_contract_addr, admin_addr, _, _, user_1_addr, user_2_addr) =
-        proper_instantiate();
+    let (mut app, cw_member_contract_addr, admin_addr, _, _, _, user_2_addr) = proper_instantiate();
 
     update_config(
         &mut app,
         &cw_member_contract_addr,
         &admin_addr,
-        Some(user_1_addr.to_string()),
         Some(user_2_addr.to_string()),
         Some(user_2_addr.to_string()),
         Some(user_2_addr.to_string()),
-        Some(Uint64::from(20_u64)),
-        Some(Uint64::from(80_u64)),
-        Some(Uint64::from(70_u64)),
-        Some(Uint64::from(30_u64)),
+        Some(Uint64::from(2_000_u64)),
+        Some(Uint64::from(1_000_u64)),
+        Some(Uint64::from(8_000_u64)),
+        Some(Uint64::from(7_000_u64)),
+        Some(Uint64::from(3_000_u64)),
+        Some(BondingCurve::Linear {
+            base_price: Uint128::from(1_000_u64),
+            slope: Uint128::from(100_u64),
+        }),
+        Some(Uint128::from(500_u64)),
+        Some(Uint128::from(100_u64)),
+        Some(true),
+        Some(CandleInterval::Daily),
+        Some(MembershipEligibility::SpecificPlatform {
+            platform: "GitHub".to_string(),
+        }),
+        None,
+        None,
+        None,
+        None,
     )
     .unwrap();
 
@@ -39,23 +57,47 @@ fn test_only_admin_can_update_config() {
         config_res,
         ConfigResponse {
             config: Config {
-                admin_addr: user_1_addr,
+                admin_addr,
                 distribution_contract_addr: Some(user_2_addr.clone()),
-                enabled: false,
-                enable_open_registration: false,
+                enabled: true,
+                enable_open_registration: true,
+                pause_config: PauseConfig::default(),
                 registration_admin_addr: user_2_addr.clone(),
+                attestation_pubkey: None,
                 protocol_fee_collector_addr: user_2_addr.clone(),
                 default_fee_config: FeeConfig {
                     fee_denom: "uluna".to_string(),
-                    trading_fee_percentage_of_membership: Uint64::from(80_u64),
+                    trading_fee_bps_of_membership: Uint64::from(8_000_u64),
+                    holding_cap: Some(Uint128::from(100_u64)),
                 },
                 protocol_fee_config: ProtocolFeeConfig {
-                    membership_trading_fee_percentage: Uint64::from(20_u64),
+                    membership_trading_fee_bps: Uint64::from(2_000_u64),
+                    referral_fee_bps: Uint64::from(1_000_u64),
                 },
                 default_fee_share_config: FeeShareConfig {
-                    share_to_issuer_percentage: Uint64::from(70_u64),
-                    share_to_all_members_percentage: Uint64::from(30_u64),
-                }
+                    share_to_issuer_bps: Uint64::from(7_000_u64),
+                    share_to_all_members_bps: Uint64::from(3_000_u64),
+                },
+                default_bonding_curve: BondingCurve::Linear {
+                    base_price: Uint128::from(1_000_u64),
+                    slope: Uint128::from(100_u64),
+                },
+                sigmoid_max_trade_amount: Uint128::from(500_u64),
+                fee_denom_allowlist: vec![FEE_DENOM.to_string()],
+                only_verified_buyers: true,
+                price_candle_interval: CandleInterval::Daily,
+                social_media_platform_allowlist: vec![
+                    "twitter".to_string(),
+                    "github".to_string(),
+                    "farcaster".to_string(),
+                    "telegram".to_string(),
+                ],
+                membership_eligibility: MembershipEligibility::SpecificPlatform {
+                    platform: "github".to_string(),
+                },
+                config_change_timelock: None,
+                user_fee_change_timelock: Some(Duration::Time(86_400)),
+                user_fee_ranges: UserFeeRanges::default(),
             }
         }
     );
@@ -1,12 +1,16 @@
-use cosmwasm_std::Uint64;
+use cosmwasm_std::{Uint128, Uint64};
+use cw_utils::Duration;
 
 use member_pkg::{
-    config::{Config, FeeConfig, ProtocolFeeConfig},
+    config::{
+        Config, FeeConfig, MembershipEligibility, PauseConfig, ProtocolFeeConfig, UserFeeRanges,
+    },
     msg::{ConfigResponse, QueryConfigMsg, QueryMsg},
+    trade::CandleInterval,
 };
-use shared_pkg::fee_share_config::FeeShareConfig;
+use shared_pkg::{fee_range::BpsRange, fee_share_config::FeeShareConfig, pricing::BondingCurve};
 
-use crate::helpers::proper_instantiate;
+use crate::helpers::{get_distribution_contract_addr, proper_instantiate, FEE_DENOM};
 
 #[test]
 fn test_default_config() {
@@ -19,6 +23,8 @@ fn test_default_config() {
         _,
         _,
     ) = proper_instantiate();
+    let cw_distribution_contract_addr =
+        get_distribution_contract_addr(&app, &cw_member_contract_addr);
     let config_res: ConfigResponse = app
         .wrap()
         .query_wasm_smart(
@@ -31,22 +37,48 @@ fn test_default_config() {
         ConfigResponse {
             config: Config {
                 admin_addr,
-                distribution_contract_addr: None,
-                enabled: false,
-                enable_open_registration: false,
+                distribution_contract_addr: Some(cw_distribution_contract_addr),
+                enabled: true,
+                enable_open_registration: true,
+                pause_config: PauseConfig::default(),
                 registration_admin_addr,
+                attestation_pubkey: None,
                 protocol_fee_collector_addr,
                 default_fee_config: FeeConfig {
                     fee_denom: "uluna".to_string(),
-                    trading_fee_percentage_of_membership: Uint64::from(5_u64),
+                    trading_fee_bps_of_membership: Uint64::from(500_u64),
+                    holding_cap: None,
                 },
                 protocol_fee_config: ProtocolFeeConfig {
-                    membership_trading_fee_percentage: Uint64::from(10_u64),
+                    membership_trading_fee_bps: Uint64::from(1_000_u64),
+                    referral_fee_bps: Uint64::zero(),
                 },
                 default_fee_share_config: FeeShareConfig {
-                    share_to_issuer_percentage: Uint64::from(80_u64),
-                    share_to_all_members_percentage: Uint64::from(20_u64),
-                }
+                    share_to_issuer_bps: Uint64::from(8_000_u64),
+                    share_to_all_members_bps: Uint64::from(2_000_u64),
+                },
+                default_bonding_curve: BondingCurve::Quadratic {
+                    divisor: Uint128::from(16_000_u64),
+                },
+                sigmoid_max_trade_amount: Uint128::from(1_000_u64),
+                fee_denom_allowlist: vec![FEE_DENOM.to_string()],
+                only_verified_buyers: false,
+                price_candle_interval: CandleInterval::Hourly,
+                social_media_platform_allowlist: vec![
+                    "twitter".to_string(),
+                    "github".to_string(),
+                    "farcaster".to_string(),
+                    "telegram".to_string(),
+                ],
+                membership_eligibility: MembershipEligibility::AnyLinkedPlatform,
+                config_change_timelock: None,
+                user_fee_change_timelock: Some(Duration::Time(86_400)),
+                user_fee_ranges: UserFeeRanges {
+                    trading_fee_bps_of_membership: BpsRange {
+                        min: Uint64::zero(),
+                        max: Uint64::from(10_000_u32),
+                    },
+                },
             }
         }
     );
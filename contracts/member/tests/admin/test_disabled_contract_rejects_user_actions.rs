@@ -0,0 +1,82 @@
+use cosmwasm_std::{Coin, Uint128, Uint64};
+use cw_multi_test::Executor;
+
+use member::ContractError;
+use member_pkg::msg::{BuyMembershipMsg, DisableMsg, EnableMsg, ExecuteMsg};
+
+use crate::helpers::{
+    assert_err, enable_membership, get_fund_from_faucet, link_social_media, proper_instantiate,
+    register_user, FEE_DENOM, SOCIAL_MEDIA_HANDLE_1,
+};
+
+#[test]
+fn test_disabled_contract_rejects_user_actions() {
+    let (
+        mut app,
+        cw_member_contract_addr,
+        admin_addr,
+        registration_admin_addr,
+        _,
+        user_1_addr,
+        user_2_addr,
+    ) = proper_instantiate();
+
+    register_user(&mut app, &cw_member_contract_addr, &user_1_addr).unwrap();
+    let user_1_id = Uint64::one();
+    link_social_media(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+        SOCIAL_MEDIA_HANDLE_1,
+    )
+    .unwrap();
+    enable_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+    )
+    .unwrap();
+
+    app.execute_contract(
+        admin_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::Disable(DisableMsg {}),
+        &[],
+    )
+    .unwrap();
+
+    // User cannot register or trade while contract is disabled
+    assert_err(
+        register_user(&mut app, &cw_member_contract_addr, &user_2_addr),
+        ContractError::ContractDisabled {},
+    );
+    get_fund_from_faucet(&mut app, user_1_addr.clone(), Uint128::from(100_000_u32));
+    assert_err(
+        app.execute_contract(
+            user_1_addr.clone(),
+            cw_member_contract_addr.clone(),
+            &ExecuteMsg::BuyMembership(BuyMembershipMsg {
+                membership_issuer_user_id: user_1_id,
+                amount: Uint128::one(),
+                max_total_cost: None,
+            }),
+            &[Coin {
+                denom: FEE_DENOM.to_string(),
+                amount: Uint128::from(100_000_u32),
+            }],
+        ),
+        ContractError::ContractDisabled {},
+    );
+
+    // Admin turns contract back on
+    app.execute_contract(
+        admin_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::Enable(EnableMsg {}),
+        &[],
+    )
+    .unwrap();
+    register_user(&mut app, &cw_member_contract_addr, &user_2_addr).unwrap();
+}
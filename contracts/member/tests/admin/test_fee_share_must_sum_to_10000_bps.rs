@@ -19,9 +19,19 @@ fn test_only_admin_can_update_config() {
             None,
             None,
             None,
-            Some(Uint64::from(70_u64)),
+            Some(Uint64::from(7_000_u64)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
         ),
-        ContractError::MembershipTradingFeeSharePercentageMustSumTo100 {},
+        ContractError::MembershipTradingFeeShareBpsMustSumTo10000 {},
     );
 }
@@ -19,6 +19,16 @@ fn test_only_admin_can_update_config() {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         ),
         ContractError::OnlyAdminCanUpdateConfig {},
     );
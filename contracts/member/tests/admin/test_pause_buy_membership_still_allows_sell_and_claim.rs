@@ -0,0 +1,174 @@
+use cosmwasm_std::{Coin, Uint128, Uint64};
+use cw_multi_test::Executor;
+
+use member::ContractError;
+use member_pkg::{
+    config::PauseConfig,
+    msg::{
+        BuyMembershipMsg, ExecuteMsg, PauseStatusResponse, QueryMsg, QueryPauseStatusMsg,
+        SellMembershipMsg, UpdatePauseConfigMsg,
+    },
+};
+
+use crate::helpers::{
+    assert_err, claim_reward, enable_membership, get_distribution_contract_addr,
+    get_fund_from_faucet, link_social_media, proper_instantiate, register_user, FEE_DENOM,
+    SOCIAL_MEDIA_HANDLE_1,
+};
+
+#[test]
+fn test_pause_buy_membership_still_allows_sell_and_claim() {
+    let (
+        mut app,
+        cw_member_contract_addr,
+        admin_addr,
+        registration_admin_addr,
+        _,
+        user_1_addr,
+        user_2_addr,
+    ) = proper_instantiate();
+    let cw_distribution_contract_addr =
+        get_distribution_contract_addr(&app, &cw_member_contract_addr);
+
+    let uint_128_amount_5 = Uint128::from(5_u8);
+    let uint_128_amount_10 = Uint128::from(10_u8);
+    let buy_membership_msg = ExecuteMsg::BuyMembership(BuyMembershipMsg {
+        membership_issuer_user_id: Uint64::one(),
+        amount: uint_128_amount_10,
+        max_total_cost: None,
+    });
+    let pause_buy_membership_msg = ExecuteMsg::UpdatePauseConfig(UpdatePauseConfigMsg {
+        registration_paused: None,
+        buy_membership_paused: Some(true),
+        sell_membership_paused: None,
+    });
+
+    register_user(&mut app, &cw_member_contract_addr, &user_1_addr).unwrap();
+    register_user(&mut app, &cw_member_contract_addr, &user_2_addr).unwrap();
+    let user_1_id = Uint64::one();
+    let user_2_id = Uint64::from(2_u8);
+    link_social_media(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+        SOCIAL_MEDIA_HANDLE_1,
+    )
+    .unwrap();
+    enable_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+    )
+    .unwrap();
+
+    get_fund_from_faucet(&mut app, user_2_addr.clone(), Uint128::from(1_000_000_u32));
+    app.execute_contract(
+        user_2_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &buy_membership_msg,
+        &[Coin {
+            denom: FEE_DENOM.to_string(),
+            amount: Uint128::from(100_000_u32),
+        }],
+    )
+    .unwrap();
+
+    // Only admin can update pause config
+    assert_err(
+        app.execute_contract(
+            user_1_addr.clone(),
+            cw_member_contract_addr.clone(),
+            &pause_buy_membership_msg,
+            &[],
+        ),
+        ContractError::OnlyAdminCanUpdatePauseConfig {},
+    );
+    app.execute_contract(
+        admin_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &pause_buy_membership_msg,
+        &[],
+    )
+    .unwrap();
+
+    let query_pause_status_res: PauseStatusResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryPauseStatus(QueryPauseStatusMsg {}),
+        )
+        .unwrap();
+    assert_eq!(
+        query_pause_status_res,
+        PauseStatusResponse {
+            enabled: true,
+            pause_config: PauseConfig {
+                registration_paused: false,
+                buy_membership_paused: true,
+                sell_membership_paused: false,
+            },
+            paused_operations: vec!["buy_membership".to_string()],
+        }
+    );
+
+    // Buying is paused
+    assert_err(
+        app.execute_contract(
+            user_2_addr.clone(),
+            cw_member_contract_addr.clone(),
+            &buy_membership_msg,
+            &[Coin {
+                denom: FEE_DENOM.to_string(),
+                amount: Uint128::from(100_000_u32),
+            }],
+        ),
+        ContractError::BuyMembershipPaused {},
+    );
+
+    // Selling and claiming still work
+    app.execute_contract(
+        user_2_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::SellMembership(SellMembershipMsg {
+            membership_issuer_user_id: user_1_id,
+            amount: uint_128_amount_5,
+            min_proceeds: None,
+            deduct_fees_from_proceeds: Some(true),
+        }),
+        &[],
+    )
+    .unwrap();
+    claim_reward(
+        &mut app,
+        &cw_distribution_contract_addr,
+        &user_2_addr,
+        user_1_id,
+        user_2_id,
+    )
+    .unwrap();
+
+    // Admin unpauses buying
+    app.execute_contract(
+        admin_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::UpdatePauseConfig(UpdatePauseConfigMsg {
+            registration_paused: None,
+            buy_membership_paused: Some(false),
+            sell_membership_paused: None,
+        }),
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        user_2_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &buy_membership_msg,
+        &[Coin {
+            denom: FEE_DENOM.to_string(),
+            amount: Uint128::from(100_000_u32),
+        }],
+    )
+    .unwrap();
+}
@@ -1,20 +1,40 @@
 use anyhow::Result as AnyResult;
-use cosmwasm_std::{Addr, Coin, Empty, Uint128, Uint64};
+use cosmwasm_std::{Addr, Binary, Coin, Empty, Uint128, Uint64};
 use cw_multi_test::{App, AppBuilder, AppResponse, Contract, ContractWrapper, Executor};
+use cw_utils::{Duration, Expiration};
 
+use distribution::contract::{
+    execute as distribution_execute, instantiate as distribution_instantiate,
+    migrate as distribution_migrate, query as distribution_query,
+};
+use distribution_pkg::msg::{
+    ClaimRewardsMsg, EnableMsg as DistributionEnableMsg, ExecuteMsg as DistributionExecuteMsg,
+    InstantiateMsg as DistributionInstantiateMsg,
+};
 use member::{
-    contract::{execute, instantiate, query},
+    contract::{execute, instantiate, migrate, query},
     ContractError,
 };
 use member_pkg::{
+    config::{Config, MembershipEligibility, UserFeeRanges},
     msg::{
-        EnableMembershipMsg, ExecuteMsg, InstantiateMsg, LinkSocialMediaMsg, MemberCountResponse,
-        MembersResponse, MembershipSupplyResponse, MembershipsResponse, QueryMemberCountMsg,
+        AcceptMigrateUserMsg, AddToFeeDenomAllowlistMsg, ConfigResponse, EnableMembershipMsg,
+        EnableMsg, EnableOpenRegistrationMsg, ExecuteMsg, InstantiateMsg, LinkSocialMediaMsg,
+        MemberCountResponse, MembersResponse, MembershipSupplyResponse, MembershipsResponse,
+        MigrateUserMsg, PendingConfigChangesResponse, QueryConfigMsg, QueryMemberCountMsg,
         QueryMembersMsg, QueryMembershipSupplyMsg, QueryMembershipsMsg, QueryMsg, RegisterMsg,
+        RemoveFromFeeDenomAllowlistMsg, RemoveHotAddrMsg, SetHotAddrMsg, UnlinkSocialMediaMsg,
         UpdateConfigMsg,
     },
+    trade::CandleInterval,
     user::{Member, Membership},
 };
+use shared_pkg::{
+    admin_transfer::{AcceptAdminMsg, ProposeNewAdminMsg},
+    pricing::BondingCurve,
+    rbac::{GrantRoleMsg, QueryRolesMsg, RevokeRoleMsg, Role, RolesResponse},
+    timelock::{ExecutePendingConfigChangeMsg, QueryPendingConfigChangesMsg},
+};
 
 pub const FAUCET: &str = "faucet";
 
@@ -24,11 +44,19 @@ pub const PROTOCOL_FEE_COLLECTOR: &str = "terra3";
 
 pub const USER_1: &str = "terra4";
 pub const USER_2: &str = "terra5";
+// Not registered by default, used as new address when migrating user
+pub const USER_3: &str = "terra6";
+// Not registered by default, used as hot address
+pub const USER_1_HOT: &str = "terra7";
 
 pub const SOCIAL_MEDIA_HANDLE_1: &str = "twitter1";
-// pub const SOCIAL_MEDIA_HANDLE_2: &str = "twitter2";
+pub const SOCIAL_MEDIA_HANDLE_2: &str = "twitter2";
+// Platform link_social_media links handles on
+pub const SOCIAL_MEDIA_PLATFORM: &str = "twitter";
 
 pub const FEE_DENOM: &str = "uluna";
+// Not in fee denom allowlist by default
+pub const FEE_DENOM_2: &str = "uusdc";
 
 fn mock_app() -> App {
     AppBuilder::new().build(|router, _, storage| {
@@ -37,18 +65,34 @@ fn mock_app() -> App {
             .init_balance(
                 storage,
                 &Addr::unchecked(FAUCET),
-                vec![Coin {
-                    denom: FEE_DENOM.to_string(),
-                    // 1_000_000_000 uLuna i.e. 1k LUNA since 1 LUNA = 1_000_000 uLuna
-                    amount: Uint128::new(1_000_000_000),
-                }],
+                vec![
+                    Coin {
+                        denom: FEE_DENOM.to_string(),
+                        // 1_000_000_000 uLuna i.e. 1k LUNA since 1 LUNA = 1_000_000 uLuna
+                        amount: Uint128::new(1_000_000_000),
+                    },
+                    Coin {
+                        denom: FEE_DENOM_2.to_string(),
+                        amount: Uint128::new(1_000_000_000),
+                    },
+                ],
             )
             .unwrap();
     })
 }
 
-fn contract_cw_thread() -> Box<dyn Contract<Empty>> {
-    let contract = ContractWrapper::new(execute, instantiate, query);
+pub fn contract_cw_thread() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(execute, instantiate, query).with_migrate(migrate);
+    Box::new(contract)
+}
+
+pub fn contract_cw_distribution() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        distribution_execute,
+        distribution_instantiate,
+        distribution_query,
+    )
+    .with_migrate(distribution_migrate);
     Box::new(contract)
 }
 
@@ -61,10 +105,18 @@ pub fn proper_instantiate() -> (App, Addr, Addr, Addr, Addr, Addr, Addr) {
         registration_admin_addr: Some(REGISTRATION_ADMIN.to_string()),
         protocol_fee_collector_addr: Some(PROTOCOL_FEE_COLLECTOR.to_string()),
         fee_denom: Some(FEE_DENOM.to_string()),
-        protocol_fee_membership_trading_fee_percentage: None,
-        default_trading_fee_percentage_of_membership: None,
-        default_membership_trading_fee_membership_issuer_fee_percentage: None,
-        default_membership_trading_fee_membership_holder_fee_percentage: None,
+        protocol_fee_membership_trading_fee_bps: None,
+        protocol_fee_referral_fee_bps: None,
+        default_trading_fee_bps_of_membership: None,
+        default_membership_trading_fee_membership_issuer_fee_bps: None,
+        default_membership_trading_fee_membership_holder_fee_bps: None,
+        default_bonding_curve: None,
+        sigmoid_max_trade_amount: None,
+        default_holding_cap: None,
+        only_verified_buyers: None,
+        price_candle_interval: None,
+        user_fee_change_timelock: None,
+        user_fee_ranges: None,
     };
     let cw_member_contract_addr = app
         .instantiate_contract(
@@ -73,10 +125,60 @@ pub fn proper_instantiate() -> (App, Addr, Addr, Addr, Addr, Addr, Addr) {
             &msg,
             &[],
             "cw_thread",
-            None,
+            // Admin is able to migrate contract
+            Some(ADMIN.to_string()),
         )
         .unwrap();
 
+    // Distribution contract is deployed after membership contract then registered in membership config
+    let cw_distribution_contract_code_id = app.store_code(contract_cw_distribution());
+    let cw_distribution_contract_addr = app
+        .instantiate_contract(
+            cw_distribution_contract_code_id,
+            Addr::unchecked(ADMIN),
+            &DistributionInstantiateMsg {
+                member_contract_addr: cw_member_contract_addr.to_string(),
+                admin_addr: Some(ADMIN.to_string()),
+            },
+            &[],
+            "cw_distribution",
+            Some(ADMIN.to_string()),
+        )
+        .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            distribution_contract_addr: Some(cw_distribution_contract_addr.to_string()),
+            ..UpdateConfigMsg::default()
+        }),
+        &[],
+    )
+    .unwrap();
+
+    // Contracts are instantiated disabled, admin turns them on
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::Enable(EnableMsg {}),
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::EnableOpenRegistration(EnableOpenRegistrationMsg {}),
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        cw_distribution_contract_addr.clone(),
+        &DistributionExecuteMsg::Enable(DistributionEnableMsg {}),
+        &[],
+    )
+    .unwrap();
+
     let admin_addr = Addr::unchecked(ADMIN.to_string());
     let registration_admin_addr = Addr::unchecked(REGISTRATION_ADMIN.to_string());
     let protocol_fee_collector_addr = Addr::unchecked(PROTOCOL_FEE_COLLECTOR.to_string());
@@ -95,42 +197,192 @@ pub fn proper_instantiate() -> (App, Addr, Addr, Addr, Addr, Addr, Addr) {
 }
 
 pub fn get_fund_from_faucet(app: &mut App, addr: Addr, amount: Uint128) {
+    get_fund_from_faucet_in_denom(app, addr, amount, FEE_DENOM)
+}
+
+pub fn get_fund_from_faucet_in_denom(app: &mut App, addr: Addr, amount: Uint128, denom: &str) {
     app.send_tokens(
         Addr::unchecked(FAUCET),
         addr,
         &[Coin {
-            denom: FEE_DENOM.to_string(),
+            denom: denom.to_string(),
             amount,
         }],
     )
     .unwrap();
 }
 
+pub fn add_to_fee_denom_allowlist(
+    app: &mut App,
+    cw_member_contract_addr: &Addr,
+    sender_addr: &Addr,
+    denom: &str,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        sender_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::AddToFeeDenomAllowlist(AddToFeeDenomAllowlistMsg {
+            denom: denom.to_string(),
+        }),
+        &[],
+    )
+}
+
+pub fn remove_from_fee_denom_allowlist(
+    app: &mut App,
+    cw_member_contract_addr: &Addr,
+    sender_addr: &Addr,
+    denom: &str,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        sender_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::RemoveFromFeeDenomAllowlist(RemoveFromFeeDenomAllowlistMsg {
+            denom: denom.to_string(),
+        }),
+        &[],
+    )
+}
+
+pub fn grant_role(
+    app: &mut App,
+    cw_member_contract_addr: &Addr,
+    sender_addr: &Addr,
+    addr: &Addr,
+    role: Role,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        sender_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::GrantRole(GrantRoleMsg {
+            addr: addr.to_string(),
+            role,
+        }),
+        &[],
+    )
+}
+
+pub fn revoke_role(
+    app: &mut App,
+    cw_member_contract_addr: &Addr,
+    sender_addr: &Addr,
+    addr: &Addr,
+    role: Role,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        sender_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::RevokeRole(RevokeRoleMsg {
+            addr: addr.to_string(),
+            role,
+        }),
+        &[],
+    )
+}
+
+pub fn propose_new_admin(
+    app: &mut App,
+    cw_member_contract_addr: &Addr,
+    sender_addr: &Addr,
+    new_admin_addr: &Addr,
+    expiration: Option<Expiration>,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        sender_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::ProposeNewAdmin(ProposeNewAdminMsg {
+            new_admin_addr: new_admin_addr.to_string(),
+            expiration,
+        }),
+        &[],
+    )
+}
+
+pub fn accept_admin(
+    app: &mut App,
+    cw_member_contract_addr: &Addr,
+    sender_addr: &Addr,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        sender_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::AcceptAdmin(AcceptAdminMsg {}),
+        &[],
+    )
+}
+
+pub fn execute_pending_config_change(
+    app: &mut App,
+    cw_member_contract_addr: &Addr,
+    sender_addr: &Addr,
+    id: Uint64,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        sender_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::ExecutePendingConfigChange(ExecutePendingConfigChangeMsg { id }),
+        &[],
+    )
+}
+
+pub fn query_pending_config_changes(
+    app: &App,
+    cw_member_contract_addr: &Addr,
+) -> PendingConfigChangesResponse {
+    app.wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr,
+            &QueryMsg::QueryPendingConfigChanges(QueryPendingConfigChangesMsg {}),
+        )
+        .unwrap()
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn update_config(
     app: &mut App,
     cw_member_contract_addr: &Addr,
     sender_addr: &Addr,
-    admin_addr: Option<String>,
     distribution_contract_addr: Option<String>,
     registration_admin_addr: Option<String>,
     protocol_fee_collector_addr: Option<String>,
-    protocol_fee_membership_trading_fee_percentage: Option<Uint64>,
-    default_trading_fee_percentage_of_membership: Option<Uint64>,
-    default_share_to_issuer_percentage: Option<Uint64>,
-    default_share_to_all_members_percentage: Option<Uint64>,
+    protocol_fee_membership_trading_fee_bps: Option<Uint64>,
+    protocol_fee_referral_fee_bps: Option<Uint64>,
+    default_trading_fee_bps_of_membership: Option<Uint64>,
+    default_share_to_issuer_bps: Option<Uint64>,
+    default_share_to_all_members_bps: Option<Uint64>,
+    default_bonding_curve: Option<BondingCurve>,
+    sigmoid_max_trade_amount: Option<Uint128>,
+    default_holding_cap: Option<Uint128>,
+    only_verified_buyers: Option<bool>,
+    price_candle_interval: Option<CandleInterval>,
+    membership_eligibility: Option<MembershipEligibility>,
+    attestation_pubkey: Option<Binary>,
+    config_change_timelock: Option<Duration>,
+    user_fee_change_timelock: Option<Duration>,
+    user_fee_ranges: Option<UserFeeRanges>,
 ) -> AnyResult<AppResponse> {
     app.execute_contract(
         sender_addr.clone(),
         cw_member_contract_addr.clone(),
         &ExecuteMsg::UpdateConfig(UpdateConfigMsg {
-            admin_addr,
             distribution_contract_addr,
             registration_admin_addr,
             protocol_fee_collector_addr,
-            protocol_fee_membership_trading_fee_percentage,
-            default_trading_fee_percentage_of_membership,
-            default_share_to_issuer_percentage,
-            default_share_to_all_members_percentage,
+            protocol_fee_membership_trading_fee_bps,
+            protocol_fee_referral_fee_bps,
+            default_trading_fee_bps_of_membership,
+            default_share_to_issuer_bps,
+            default_share_to_all_members_bps,
+            default_bonding_curve,
+            sigmoid_max_trade_amount,
+            default_holding_cap,
+            only_verified_buyers,
+            price_candle_interval,
+            membership_eligibility,
+            attestation_pubkey,
+            config_change_timelock,
+            user_fee_change_timelock,
+            user_fee_ranges,
         }),
         &[],
     )
@@ -144,7 +396,27 @@ pub fn register_user(
     app.execute_contract(
         sender_addr.clone(),
         cw_member_contract_addr.clone(),
-        &ExecuteMsg::Register(RegisterMsg {}),
+        &ExecuteMsg::Register(RegisterMsg {
+            referrer_user_id: None,
+            user_addr: None,
+        }),
+        &[],
+    )
+}
+
+pub fn register_user_with_referrer(
+    app: &mut App,
+    cw_member_contract_addr: &Addr,
+    sender_addr: &Addr,
+    referrer_user_id: u64,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        sender_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::Register(RegisterMsg {
+            referrer_user_id: Some(Uint64::from(referrer_user_id)),
+            user_addr: None,
+        }),
         &[],
     )
 }
@@ -161,12 +433,31 @@ pub fn link_social_media(
         cw_member_contract_addr.clone(),
         &ExecuteMsg::LinkSocialMedia(LinkSocialMediaMsg {
             user_id,
+            platform: SOCIAL_MEDIA_PLATFORM.to_string(),
             social_media_handle: social_media_handle.to_string(),
         }),
         &[],
     )
 }
 
+pub fn unlink_social_media(
+    app: &mut App,
+    cw_member_contract_addr: &Addr,
+    sender_addr: &Addr,
+    user_id: Uint64,
+    platform: &str,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        sender_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::UnlinkSocialMedia(UnlinkSocialMediaMsg {
+            user_id,
+            platform: platform.to_string(),
+        }),
+        &[],
+    )
+}
+
 pub fn enable_membership(
     app: &mut App,
     cw_member_contract_addr: &Addr,
@@ -176,7 +467,148 @@ pub fn enable_membership(
     app.execute_contract(
         registration_admin_addr.clone(),
         cw_member_contract_addr.clone(),
-        &ExecuteMsg::EnableMembership(EnableMembershipMsg { user_id }),
+        &ExecuteMsg::EnableMembership(EnableMembershipMsg {
+            user_id,
+            bonding_curve: None,
+            fee_denom: None,
+        }),
+        &[],
+    )
+}
+
+pub fn enable_membership_with_bonding_curve(
+    app: &mut App,
+    cw_member_contract_addr: &Addr,
+    registration_admin_addr: &Addr,
+    user_id: Uint64,
+    bonding_curve: BondingCurve,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        registration_admin_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::EnableMembership(EnableMembershipMsg {
+            user_id,
+            bonding_curve: Some(bonding_curve),
+            fee_denom: None,
+        }),
+        &[],
+    )
+}
+
+pub fn enable_membership_with_fee_denom(
+    app: &mut App,
+    cw_member_contract_addr: &Addr,
+    registration_admin_addr: &Addr,
+    user_id: Uint64,
+    fee_denom: &str,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        registration_admin_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::EnableMembership(EnableMembershipMsg {
+            user_id,
+            bonding_curve: None,
+            fee_denom: Some(fee_denom.to_string()),
+        }),
+        &[],
+    )
+}
+
+pub fn migrate_user(
+    app: &mut App,
+    cw_member_contract_addr: &Addr,
+    sender_addr: &Addr,
+    new_user_addr: &Addr,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        sender_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::MigrateUser(MigrateUserMsg {
+            new_user_addr: new_user_addr.to_string(),
+        }),
+        &[],
+    )
+}
+
+pub fn accept_migrate_user(
+    app: &mut App,
+    cw_member_contract_addr: &Addr,
+    sender_addr: &Addr,
+    user_id: Uint64,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        sender_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::AcceptMigrateUser(AcceptMigrateUserMsg { user_id }),
+        &[],
+    )
+}
+
+pub fn set_hot_addr(
+    app: &mut App,
+    cw_member_contract_addr: &Addr,
+    sender_addr: &Addr,
+    hot_addr: &Addr,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        sender_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::SetHotAddr(SetHotAddrMsg {
+            hot_addr: hot_addr.to_string(),
+        }),
+        &[],
+    )
+}
+
+pub fn remove_hot_addr(
+    app: &mut App,
+    cw_member_contract_addr: &Addr,
+    sender_addr: &Addr,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        sender_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::RemoveHotAddr(RemoveHotAddrMsg {}),
+        &[],
+    )
+}
+
+pub fn query_config(app: &App, cw_member_contract_addr: &Addr) -> Config {
+    let config_res: ConfigResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr,
+            &QueryMsg::QueryConfig(QueryConfigMsg {}),
+        )
+        .unwrap();
+    config_res.config
+}
+
+pub fn get_distribution_contract_addr(app: &App, cw_member_contract_addr: &Addr) -> Addr {
+    let config_res: ConfigResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr,
+            &QueryMsg::QueryConfig(QueryConfigMsg {}),
+        )
+        .unwrap();
+    config_res.config.distribution_contract_addr.unwrap()
+}
+
+pub fn claim_reward(
+    app: &mut App,
+    cw_distribution_contract_addr: &Addr,
+    sender_addr: &Addr,
+    membership_issuer_user_id: Uint64,
+    user_id: Uint64,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        sender_addr.clone(),
+        cw_distribution_contract_addr.clone(),
+        &DistributionExecuteMsg::ClaimReward(ClaimRewardsMsg {
+            membership_issuer_user_id,
+            user_id,
+        }),
         &[],
     )
 }
@@ -216,6 +648,19 @@ pub fn assert_balance(app: &App, user_addr: &Addr, expected_balance: Uint128, de
     assert_eq!(balance.amount, expected_balance);
 }
 
+pub fn assert_roles(app: &App, cw_member_contract_addr: &Addr, addr: &Addr, expected: Vec<Role>) {
+    let res: RolesResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr,
+            &QueryMsg::QueryRoles(QueryRolesMsg {
+                addr: addr.to_string(),
+            }),
+        )
+        .unwrap();
+    assert_eq!(res.roles, expected);
+}
+
 pub fn assert_membership_supply(
     app: &App,
     contract_addr: &Addr,
@@ -1,7 +1,23 @@
 mod test_2_users_buy_and_sell_membership;
 mod test_buy_and_sell_membership;
+mod test_buy_and_sell_membership_emit_trade_events;
+mod test_buy_and_sell_membership_refund_overpayment;
+mod test_buy_and_sell_membership_slippage_protection;
 mod test_buy_membership_happy_case;
+mod test_buy_membership_holding_cap;
 mod test_buy_membership_insufficient_funds;
+mod test_buy_membership_of_missing_user_or_membership_fails;
+mod test_buy_membership_only_verified_buyers;
+mod test_issuer_can_pick_bonding_curve;
+mod test_issuer_can_pick_fee_denom;
+mod test_issuer_fee_change_is_time_locked;
+mod test_issuer_fee_config_follows_default_for_fees_not_set;
+mod test_issuer_fee_must_be_in_admin_set_range;
+mod test_pricing_round_trip;
 mod test_query_cost_to_buy_membership;
+mod test_query_twap;
 mod test_sell_membership_cannot_sell_last_membership;
+mod test_sell_membership_deduct_fees_from_proceeds;
 mod test_sell_membership_insufficient_funds;
+mod test_sigmoid_trade_amount_is_capped;
+mod test_trade_history_and_price_candles;
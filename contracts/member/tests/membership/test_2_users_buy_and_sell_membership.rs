@@ -11,8 +11,9 @@ use member_pkg::{
 
 use crate::helpers::{
     assert_balance, assert_member_count, assert_members, assert_membership_supply,
-    assert_memberships, enable_membership, get_fund_from_faucet, link_social_media,
-    proper_instantiate, register_user, FEE_DENOM, SOCIAL_MEDIA_HANDLE_1,
+    assert_memberships, claim_reward, enable_membership, get_distribution_contract_addr,
+    get_fund_from_faucet, link_social_media, proper_instantiate, register_user, FEE_DENOM,
+    SOCIAL_MEDIA_HANDLE_1,
 };
 
 #[test]
@@ -26,6 +27,8 @@ fn test_2_users_buy_and_sell_memberships() {
         user_1_addr,
         user_2_addr,
     ) = proper_instantiate();
+    let cw_distribution_contract_addr =
+        get_distribution_contract_addr(&app, &cw_member_contract_addr);
 
     let default_supply = Uint128::one();
     let uint_128_amount_30 = Uint128::from(30_u8);
@@ -78,6 +81,7 @@ fn test_2_users_buy_and_sell_memberships() {
         &ExecuteMsg::BuyMembership(BuyMembershipMsg {
             membership_issuer_user_id: user_1_id,
             amount: uint_128_amount_30,
+            max_total_cost: None,
         }),
         &[Coin {
             denom: FEE_DENOM.to_string(),
@@ -108,6 +112,7 @@ fn test_2_users_buy_and_sell_memberships() {
         &ExecuteMsg::BuyMembership(BuyMembershipMsg {
             membership_issuer_user_id: user_1_id,
             amount: uint_128_amount_25,
+            max_total_cost: None,
         }),
         &[Coin {
             denom: FEE_DENOM.to_string(),
@@ -138,6 +143,8 @@ fn test_2_users_buy_and_sell_memberships() {
         &ExecuteMsg::SellMembership(SellMembershipMsg {
             membership_issuer_user_id: user_1_id,
             amount: uint_128_amount_15,
+            min_proceeds: None,
+            deduct_fees_from_proceeds: None,
         }),
         &[Coin {
             denom: FEE_DENOM.to_string(),
@@ -168,6 +175,8 @@ fn test_2_users_buy_and_sell_memberships() {
         &ExecuteMsg::SellMembership(SellMembershipMsg {
             membership_issuer_user_id: user_1_id,
             amount: uint_128_amount_10,
+            min_proceeds: None,
+            deduct_fees_from_proceeds: None,
         }),
         &[Coin {
             denom: FEE_DENOM.to_string(),
@@ -247,14 +256,30 @@ fn test_2_users_buy_and_sell_memberships() {
     assert_balance(
         &app,
         &cw_member_contract_addr,
-        query_user_1_simulate_buy_membership_res.price + query_user_2_simulate_buy_membership_res.price
+        query_user_1_simulate_buy_membership_res.price
+            + query_user_2_simulate_buy_membership_res.price
             - query_user_2_simulate_sell_membership_res.price
-            - query_user_1_simulate_sell_membership_res.price
-            // TODO: P0: why do we nee to add 2? divide has rounding error?
-            + Uint128::from(2_u8),
+            - query_user_1_simulate_sell_membership_res.price,
         FEE_DENOM,
     );
 
+    claim_reward(
+        &mut app,
+        &cw_distribution_contract_addr,
+        &user_1_addr,
+        user_1_id,
+        user_1_id,
+    )
+    .unwrap();
+    claim_reward(
+        &mut app,
+        &cw_distribution_contract_addr,
+        &user_2_addr,
+        user_1_id,
+        user_2_id,
+    )
+    .unwrap();
+
     assert_balance(
         &app,
         &user_1_addr,
@@ -266,9 +291,9 @@ fn test_2_users_buy_and_sell_memberships() {
             + query_user_1_simulate_buy_membership_res.all_members_fee
             + query_user_2_simulate_buy_membership_res.all_members_fee
             + query_user_2_simulate_sell_membership_res.all_members_fee * Uint128::from(31_u8)
-                / Uint128::from(41_u8)
-            + query_user_1_simulate_sell_membership_res.all_members_fee * Uint128::from(21_u8)
-                / Uint128::from(31_u8),
+                / Uint128::from(56_u8)
+            + query_user_1_simulate_sell_membership_res.all_members_fee * Uint128::from(31_u8)
+                / Uint128::from(41_u8),
         FEE_DENOM,
     );
 
@@ -276,10 +301,10 @@ fn test_2_users_buy_and_sell_memberships() {
         &app,
         &user_2_addr,
         query_user_2_simulate_sell_membership_res.price
-            + query_user_2_simulate_sell_membership_res.all_members_fee * Uint128::from(10_u8)
-                / Uint128::from(41_u8)
+            + query_user_2_simulate_sell_membership_res.all_members_fee * Uint128::from(25_u8)
+                / Uint128::from(56_u8)
             + query_user_1_simulate_sell_membership_res.all_members_fee * Uint128::from(10_u8)
-                / Uint128::from(31_u8),
+                / Uint128::from(41_u8),
         FEE_DENOM,
     );
 }
@@ -11,8 +11,9 @@ use member_pkg::{
 
 use crate::helpers::{
     assert_balance, assert_member_count, assert_members, assert_membership_supply,
-    assert_memberships, enable_membership, get_fund_from_faucet, link_social_media, print_balance,
-    proper_instantiate, register_user, FEE_DENOM, SOCIAL_MEDIA_HANDLE_1,
+    assert_memberships, claim_reward, enable_membership, get_distribution_contract_addr,
+    get_fund_from_faucet, link_social_media, print_balance, proper_instantiate, register_user,
+    FEE_DENOM, SOCIAL_MEDIA_HANDLE_1,
 };
 
 #[test]
@@ -26,6 +27,8 @@ fn test_buy_and_sell_membership() {
         user_1_addr,
         user_2_addr,
     ) = proper_instantiate();
+    let cw_distribution_contract_addr =
+        get_distribution_contract_addr(&app, &cw_member_contract_addr);
 
     let default_supply = Uint128::one();
     let uint_128_amount_30 = Uint128::from(30_u8);
@@ -73,6 +76,7 @@ fn test_buy_and_sell_membership() {
         &ExecuteMsg::BuyMembership(BuyMembershipMsg {
             membership_issuer_user_id: user_1_id,
             amount: uint_128_amount_30,
+            max_total_cost: None,
         }),
         &[Coin {
             denom: FEE_DENOM.to_string(),
@@ -116,6 +120,8 @@ fn test_buy_and_sell_membership() {
         &ExecuteMsg::SellMembership(SellMembershipMsg {
             membership_issuer_user_id: user_1_id,
             amount: uint_128_amount_30,
+            min_proceeds: None,
+            deduct_fees_from_proceeds: None,
         }),
         &[Coin {
             denom: FEE_DENOM.to_string(),
@@ -126,6 +132,16 @@ fn test_buy_and_sell_membership() {
 
     // ========== Check balances, membership supply, user holdings, and membership holders ==========
 
+    // User 1 is the only member so it collects all of the member fee after claiming
+    claim_reward(
+        &mut app,
+        &cw_distribution_contract_addr,
+        &user_1_addr,
+        user_1_id,
+        user_1_id,
+    )
+    .unwrap();
+
     assert_balance(&app, &cw_member_contract_addr, Uint128::zero(), FEE_DENOM);
     assert_balance(
         &app,
@@ -134,7 +150,15 @@ fn test_buy_and_sell_membership() {
             + query_user_1_simulate_sell_membership_res.issuer_fee
             + query_user_1_simulate_buy_membership_res.issuer_fee
             + query_user_1_simulate_buy_membership_res.all_members_fee
-            + query_user_1_simulate_buy_membership_res.price,
+            + query_user_1_simulate_buy_membership_res.price
+            // Distribution index is rounded down, so 1 uluna of dust stays in distribution contract
+            - Uint128::one(),
+        FEE_DENOM,
+    );
+    assert_balance(
+        &app,
+        &cw_distribution_contract_addr,
+        Uint128::one(),
         FEE_DENOM,
     );
     assert_balance(
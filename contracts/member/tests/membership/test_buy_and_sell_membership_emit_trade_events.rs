@@ -0,0 +1,197 @@
+use cosmwasm_std::{Coin, Event, Uint128, Uint64};
+use cw_multi_test::{AppResponse, Executor};
+
+use member_pkg::msg::{
+    BuyMembershipMsg, CostToBuyMembershipResponse, CostToSellMembershipResponse, ExecuteMsg,
+    QueryCostToBuyMembershipMsg, QueryCostToSellMembershipMsg, QueryMsg, SellMembershipMsg,
+};
+
+use crate::helpers::{
+    claim_reward, enable_membership, get_distribution_contract_addr, get_fund_from_faucet,
+    link_social_media, proper_instantiate, register_user, FEE_DENOM, SOCIAL_MEDIA_HANDLE_1,
+};
+
+fn find_event(res: &AppResponse, ty: &str) -> Event {
+    res.events
+        .iter()
+        .find(|event| event.ty == ty)
+        .unwrap_or_else(|| panic!("event {ty} not emitted"))
+        .clone()
+}
+
+fn attribute(event: &Event, key: &str) -> String {
+    event
+        .attributes
+        .iter()
+        .find(|attr| attr.key == key)
+        .unwrap_or_else(|| panic!("attribute {key} not in event {}", event.ty))
+        .value
+        .clone()
+}
+
+#[test]
+fn test_buy_and_sell_membership_emit_trade_events() {
+    let (mut app, cw_member_contract_addr, _, registration_admin_addr, _, user_1_addr, user_2_addr) =
+        proper_instantiate();
+    let cw_distribution_contract_addr =
+        get_distribution_contract_addr(&app, &cw_member_contract_addr);
+
+    let uint_128_amount_5 = Uint128::from(5_u8);
+    let uint_128_amount_10 = Uint128::from(10_u8);
+
+    register_user(&mut app, &cw_member_contract_addr, &user_1_addr).unwrap();
+    register_user(&mut app, &cw_member_contract_addr, &user_2_addr).unwrap();
+    let user_1_id = Uint64::one();
+    let user_2_id = Uint64::from(2_u8);
+    link_social_media(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+        SOCIAL_MEDIA_HANDLE_1,
+    )
+    .unwrap();
+    enable_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+    )
+    .unwrap();
+
+    let query_user_2_simulate_buy_membership_res: CostToBuyMembershipResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryCostToBuyMembership(QueryCostToBuyMembershipMsg {
+                membership_issuer_user_id: user_1_id,
+                amount: uint_128_amount_10,
+            }),
+        )
+        .unwrap();
+    let overpaid_amount = Uint128::from(7_u8);
+    get_fund_from_faucet(&mut app, user_2_addr.clone(), Uint128::from(1_000_000_u32));
+    let buy_res = app
+        .execute_contract(
+            user_2_addr.clone(),
+            cw_member_contract_addr.clone(),
+            &ExecuteMsg::BuyMembership(BuyMembershipMsg {
+                membership_issuer_user_id: user_1_id,
+                amount: uint_128_amount_10,
+                max_total_cost: None,
+            }),
+            &[Coin {
+                denom: FEE_DENOM.to_string(),
+                amount: query_user_2_simulate_buy_membership_res.total_needed_from_user
+                    + overpaid_amount,
+            }],
+        )
+        .unwrap();
+
+    let buy_event = find_event(&buy_res, "wasm-cw_friend.trade");
+    assert_eq!(attribute(&buy_event, "side"), "buy");
+    assert_eq!(
+        attribute(&buy_event, "trader_user_id"),
+        user_2_id.to_string()
+    );
+    assert_eq!(
+        attribute(&buy_event, "trader_addr"),
+        user_2_addr.to_string()
+    );
+    assert_eq!(
+        attribute(&buy_event, "membership_issuer_user_id"),
+        user_1_id.to_string()
+    );
+    assert_eq!(attribute(&buy_event, "amount"), "10");
+    assert_eq!(
+        attribute(&buy_event, "price"),
+        query_user_2_simulate_buy_membership_res.price.to_string()
+    );
+    assert_eq!(
+        attribute(&buy_event, "protocol_fee"),
+        query_user_2_simulate_buy_membership_res
+            .protocol_fee
+            .to_string()
+    );
+    assert_eq!(
+        attribute(&buy_event, "issuer_fee"),
+        query_user_2_simulate_buy_membership_res
+            .issuer_fee
+            .to_string()
+    );
+    assert_eq!(
+        attribute(&buy_event, "all_members_fee"),
+        query_user_2_simulate_buy_membership_res
+            .all_members_fee
+            .to_string()
+    );
+    assert_eq!(
+        attribute(&buy_event, "total_paid"),
+        query_user_2_simulate_buy_membership_res
+            .total_needed_from_user
+            .to_string()
+    );
+    assert_eq!(
+        attribute(&buy_event, "refunded_amount"),
+        overpaid_amount.to_string()
+    );
+    assert_eq!(attribute(&buy_event, "fee_denom"), FEE_DENOM);
+    assert_eq!(attribute(&buy_event, "new_supply"), "11");
+    assert_eq!(attribute(&buy_event, "trader_new_hold_amount"), "10");
+
+    let query_user_2_simulate_sell_membership_res: CostToSellMembershipResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryCostToSellMembership(QueryCostToSellMembershipMsg {
+                membership_issuer_user_id: user_1_id,
+                amount: uint_128_amount_5,
+            }),
+        )
+        .unwrap();
+    let sell_res = app
+        .execute_contract(
+            user_2_addr.clone(),
+            cw_member_contract_addr.clone(),
+            &ExecuteMsg::SellMembership(SellMembershipMsg {
+                membership_issuer_user_id: user_1_id,
+                amount: uint_128_amount_5,
+                min_proceeds: None,
+                deduct_fees_from_proceeds: Some(true),
+            }),
+            &[],
+        )
+        .unwrap();
+
+    let sell_event = find_event(&sell_res, "wasm-cw_friend.trade");
+    assert_eq!(attribute(&sell_event, "side"), "sell");
+    assert_eq!(attribute(&sell_event, "amount"), "5");
+    assert_eq!(attribute(&sell_event, "total_paid"), "0");
+    assert_eq!(
+        attribute(&sell_event, "proceeds"),
+        query_user_2_simulate_sell_membership_res
+            .net_proceeds
+            .to_string()
+    );
+    assert_eq!(attribute(&sell_event, "new_supply"), "6");
+    assert_eq!(attribute(&sell_event, "trader_new_hold_amount"), "5");
+
+    let claim_res = claim_reward(
+        &mut app,
+        &cw_distribution_contract_addr,
+        &user_1_addr,
+        user_1_id,
+        user_1_id,
+    )
+    .unwrap();
+    let reward_claim_event = find_event(&claim_res, "wasm-cw_friend.reward_claim");
+    assert_eq!(
+        attribute(&reward_claim_event, "membership_issuer_user_id"),
+        user_1_id.to_string()
+    );
+    assert_eq!(
+        attribute(&reward_claim_event, "recipient_addr"),
+        user_1_addr.to_string()
+    );
+    assert!(attribute(&reward_claim_event, "rewards").ends_with(FEE_DENOM));
+}
@@ -0,0 +1,117 @@
+use cosmwasm_std::{Coin, Event, Uint128, Uint64};
+use cw_multi_test::Executor;
+
+use member_pkg::msg::{
+    BuyMembershipMsg, CostToBuyMembershipResponse, CostToSellMembershipResponse, ExecuteMsg,
+    QueryCostToBuyMembershipMsg, QueryCostToSellMembershipMsg, QueryMsg, SellMembershipMsg,
+};
+
+use crate::helpers::{
+    assert_balance, enable_membership, get_fund_from_faucet, link_social_media, proper_instantiate,
+    register_user, FEE_DENOM, SOCIAL_MEDIA_HANDLE_1,
+};
+
+#[test]
+fn test_buy_and_sell_membership_refund_overpayment() {
+    let (mut app, cw_member_contract_addr, _, registration_admin_addr, _, user_1_addr, user_2_addr) =
+        proper_instantiate();
+
+    let uint_128_amount_10 = Uint128::from(10_u8);
+    let overpaid_amount = Uint128::from(1_000_u32);
+
+    register_user(&mut app, &cw_member_contract_addr, &user_1_addr).unwrap();
+    register_user(&mut app, &cw_member_contract_addr, &user_2_addr).unwrap();
+    let user_1_id = Uint64::one();
+    link_social_media(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+        SOCIAL_MEDIA_HANDLE_1,
+    )
+    .unwrap();
+    enable_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+    )
+    .unwrap();
+
+    // User 2 overpays to buy 10 amount of user 1's memberships
+    let query_user_2_simulate_buy_membership_res: CostToBuyMembershipResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryCostToBuyMembership(QueryCostToBuyMembershipMsg {
+                membership_issuer_user_id: user_1_id,
+                amount: uint_128_amount_10,
+            }),
+        )
+        .unwrap();
+    get_fund_from_faucet(
+        &mut app,
+        user_2_addr.clone(),
+        query_user_2_simulate_buy_membership_res.total_needed_from_user + overpaid_amount,
+    );
+    let buy_res = app
+        .execute_contract(
+            user_2_addr.clone(),
+            cw_member_contract_addr.clone(),
+            &ExecuteMsg::BuyMembership(BuyMembershipMsg {
+                membership_issuer_user_id: user_1_id,
+                amount: uint_128_amount_10,
+                max_total_cost: None,
+            }),
+            &[Coin {
+                denom: FEE_DENOM.to_string(),
+                amount: query_user_2_simulate_buy_membership_res.total_needed_from_user
+                    + overpaid_amount,
+            }],
+        )
+        .unwrap();
+    assert!(buy_res.has_event(
+        &Event::new("wasm").add_attribute("refunded_amount", overpaid_amount.to_string())
+    ));
+    assert_balance(&app, &user_2_addr, overpaid_amount, FEE_DENOM);
+
+    // User 2 overpays fee to sell 10 amount of user 1's memberships
+    let query_user_2_simulate_sell_membership_res: CostToSellMembershipResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryCostToSellMembership(QueryCostToSellMembershipMsg {
+                membership_issuer_user_id: user_1_id,
+                amount: uint_128_amount_10,
+            }),
+        )
+        .unwrap();
+    get_fund_from_faucet(
+        &mut app,
+        user_2_addr.clone(),
+        query_user_2_simulate_sell_membership_res.total_needed_from_user,
+    );
+    app.execute_contract(
+        user_2_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::SellMembership(SellMembershipMsg {
+            membership_issuer_user_id: user_1_id,
+            amount: uint_128_amount_10,
+            min_proceeds: None,
+            deduct_fees_from_proceeds: None,
+        }),
+        &[Coin {
+            denom: FEE_DENOM.to_string(),
+            amount: query_user_2_simulate_sell_membership_res.total_needed_from_user
+                + overpaid_amount,
+        }],
+    )
+    .unwrap();
+    // User 2 gets sell price plus overpaid amount back
+    assert_balance(
+        &app,
+        &user_2_addr,
+        query_user_2_simulate_sell_membership_res.price + overpaid_amount,
+        FEE_DENOM,
+    );
+}
@@ -0,0 +1,141 @@
+use cosmwasm_std::{Coin, Uint128, Uint64};
+use cw_multi_test::Executor;
+
+use member::ContractError;
+use member_pkg::msg::{
+    BuyMembershipMsg, CostToBuyMembershipResponse, CostToSellMembershipResponse, ExecuteMsg,
+    QueryCostToBuyMembershipMsg, QueryCostToSellMembershipMsg, QueryMsg, SellMembershipMsg,
+};
+
+use crate::helpers::{
+    assert_err, enable_membership, get_fund_from_faucet, link_social_media, proper_instantiate,
+    register_user, FEE_DENOM, SOCIAL_MEDIA_HANDLE_1,
+};
+
+#[test]
+fn test_buy_and_sell_membership_slippage_protection() {
+    let (mut app, cw_member_contract_addr, _, registration_admin_addr, _, user_1_addr, user_2_addr) =
+        proper_instantiate();
+
+    let uint_128_amount_10 = Uint128::from(10_u8);
+    let uint_128_amount_5 = Uint128::from(5_u8);
+
+    register_user(&mut app, &cw_member_contract_addr, &user_1_addr).unwrap();
+    register_user(&mut app, &cw_member_contract_addr, &user_2_addr).unwrap();
+    let user_1_id = Uint64::one();
+    link_social_media(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+        SOCIAL_MEDIA_HANDLE_1,
+    )
+    .unwrap();
+    enable_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+    )
+    .unwrap();
+
+    // User 2 buy 10 amount of user 1's memberships
+    let query_user_2_simulate_buy_membership_res: CostToBuyMembershipResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryCostToBuyMembership(QueryCostToBuyMembershipMsg {
+                membership_issuer_user_id: user_1_id,
+                amount: uint_128_amount_10,
+            }),
+        )
+        .unwrap();
+    let total_cost = query_user_2_simulate_buy_membership_res.total_needed_from_user;
+    get_fund_from_faucet(&mut app, user_2_addr.clone(), total_cost);
+
+    // Max total cost is 1 below actual cost, e.g. someone else bought right before
+    assert_err(
+        app.execute_contract(
+            user_2_addr.clone(),
+            cw_member_contract_addr.clone(),
+            &ExecuteMsg::BuyMembership(BuyMembershipMsg {
+                membership_issuer_user_id: user_1_id,
+                amount: uint_128_amount_10,
+                max_total_cost: Some(total_cost - Uint128::one()),
+            }),
+            &[Coin {
+                denom: FEE_DENOM.to_string(),
+                amount: total_cost,
+            }],
+        ),
+        ContractError::BuyMembershipTotalCostExceedsMaxTotalCost {
+            total_cost,
+            max_total_cost: total_cost - Uint128::one(),
+        },
+    );
+    app.execute_contract(
+        user_2_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::BuyMembership(BuyMembershipMsg {
+            membership_issuer_user_id: user_1_id,
+            amount: uint_128_amount_10,
+            max_total_cost: Some(total_cost),
+        }),
+        &[Coin {
+            denom: FEE_DENOM.to_string(),
+            amount: total_cost,
+        }],
+    )
+    .unwrap();
+
+    // User 2 sell 5 amount of user 1's memberships
+    let query_user_2_simulate_sell_membership_res: CostToSellMembershipResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryCostToSellMembership(QueryCostToSellMembershipMsg {
+                membership_issuer_user_id: user_1_id,
+                amount: uint_128_amount_5,
+            }),
+        )
+        .unwrap();
+    let proceeds = query_user_2_simulate_sell_membership_res.price;
+    let fee = query_user_2_simulate_sell_membership_res.total_needed_from_user;
+    get_fund_from_faucet(&mut app, user_2_addr.clone(), fee);
+
+    assert_err(
+        app.execute_contract(
+            user_2_addr.clone(),
+            cw_member_contract_addr.clone(),
+            &ExecuteMsg::SellMembership(SellMembershipMsg {
+                membership_issuer_user_id: user_1_id,
+                amount: uint_128_amount_5,
+                min_proceeds: Some(proceeds + Uint128::one()),
+                deduct_fees_from_proceeds: None,
+            }),
+            &[Coin {
+                denom: FEE_DENOM.to_string(),
+                amount: fee,
+            }],
+        ),
+        ContractError::SellMembershipProceedsBelowMinProceeds {
+            proceeds,
+            min_proceeds: proceeds + Uint128::one(),
+        },
+    );
+    app.execute_contract(
+        user_2_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::SellMembership(SellMembershipMsg {
+            membership_issuer_user_id: user_1_id,
+            amount: uint_128_amount_5,
+            min_proceeds: Some(proceeds),
+            deduct_fees_from_proceeds: None,
+        }),
+        &[Coin {
+            denom: FEE_DENOM.to_string(),
+            amount: fee,
+        }],
+    )
+    .unwrap();
+}
@@ -11,8 +11,9 @@ use member_pkg::{
 
 use crate::helpers::{
     assert_balance, assert_member_count, assert_members, assert_membership_supply,
-    assert_memberships, enable_membership, get_fund_from_faucet, link_social_media, print_balance,
-    proper_instantiate, register_user, FEE_DENOM, SOCIAL_MEDIA_HANDLE_1,
+    assert_memberships, claim_reward, enable_membership, get_distribution_contract_addr,
+    get_fund_from_faucet, link_social_media, print_balance, proper_instantiate, register_user,
+    FEE_DENOM, SOCIAL_MEDIA_HANDLE_1,
 };
 
 #[test]
@@ -26,6 +27,8 @@ fn test_buy_membership_happy_case() {
         user_1_addr,
         user_2_addr,
     ) = proper_instantiate();
+    let cw_distribution_contract_addr =
+        get_distribution_contract_addr(&app, &cw_member_contract_addr);
 
     let default_supply: Uint128 = Uint128::one();
     let uint_128_amount_30: Uint128 = Uint128::from(30_u8);
@@ -92,6 +95,7 @@ fn test_buy_membership_happy_case() {
         &ExecuteMsg::BuyMembership(BuyMembershipMsg {
             membership_issuer_user_id: user_1_id,
             amount: uint_128_amount_30,
+            max_total_cost: None,
         }),
         &[Coin {
             denom: FEE_DENOM.to_string(),
@@ -111,6 +115,15 @@ fn test_buy_membership_happy_case() {
         &user_1_addr,
         &user_2_addr,
     );
+    // User 1 is the only member so it collects all of the member fee after claiming
+    claim_reward(
+        &mut app,
+        &cw_distribution_contract_addr,
+        &user_1_addr,
+        user_1_id,
+        user_1_id,
+    )
+    .unwrap();
     assert_balance(
         &app,
         &cw_member_contract_addr,
@@ -180,9 +193,12 @@ fn test_buy_membership_happy_case() {
         CostToBuyMembershipResponse {
             price: Uint128::from(2_091_875_u32),
             protocol_fee: Uint128::from(10_459_u32),
+            referral_fee: Uint128::zero(),
             issuer_fee: Uint128::from(83_674_u32),
             all_members_fee: Uint128::from(20_918_u32),
             total_needed_from_user: Uint128::from(2_206_926_u32),
+            fee_denom: FEE_DENOM.to_string(),
+            holding_cap: None,
         }
     );
     get_fund_from_faucet(
@@ -196,6 +212,7 @@ fn test_buy_membership_happy_case() {
         &ExecuteMsg::BuyMembership(BuyMembershipMsg {
             membership_issuer_user_id: user_1_id,
             amount: uint_128_amount_20,
+            max_total_cost: None,
         }),
         &[Coin {
             denom: FEE_DENOM.to_string(),
@@ -206,6 +223,15 @@ fn test_buy_membership_happy_case() {
 
     // ========== Check balances, membership supply, user holdings, and membership holders ==========
 
+    // User 1 is the only member so it held all memberships before user 2 bought, so it collects all of the member fee after claiming
+    claim_reward(
+        &mut app,
+        &cw_distribution_contract_addr,
+        &user_1_addr,
+        user_1_id,
+        user_1_id,
+    )
+    .unwrap();
     assert_balance(
         &app,
         &cw_member_contract_addr,
@@ -219,7 +245,15 @@ fn test_buy_membership_happy_case() {
         query_user_2_simulate_buy_membership_res.issuer_fee
             + query_user_2_simulate_buy_membership_res.all_members_fee
             + query_user_1_simulate_buy_membership_res.issuer_fee
-            + query_user_1_simulate_buy_membership_res.all_members_fee,
+            + query_user_1_simulate_buy_membership_res.all_members_fee
+            // Distribution index is rounded down, so 1 uluna of dust stays in distribution contract
+            - Uint128::one(),
+        FEE_DENOM,
+    );
+    assert_balance(
+        &app,
+        &cw_distribution_contract_addr,
+        Uint128::one(),
         FEE_DENOM,
     );
     assert_balance(&app, &user_2_addr, Uint128::zero(), FEE_DENOM);
@@ -0,0 +1,192 @@
+use cosmwasm_std::{Coin, Uint128, Uint64};
+use cw_multi_test::Executor;
+
+use member::ContractError;
+use member_pkg::msg::{
+    BuyMembershipMsg, CostToBuyMembershipResponse, ExecuteMsg, QueryCostToBuyMembershipMsg,
+    QueryMsg, UpdateUserConfigMsg,
+};
+
+use crate::helpers::{
+    assert_err, enable_membership, get_fund_from_faucet, link_social_media, proper_instantiate,
+    register_user, update_config, FEE_DENOM, SOCIAL_MEDIA_HANDLE_1,
+};
+
+#[test]
+fn test_buy_membership_holding_cap() {
+    let (
+        mut app,
+        cw_member_contract_addr,
+        admin_addr,
+        registration_admin_addr,
+        _,
+        user_1_addr,
+        user_2_addr,
+    ) = proper_instantiate();
+
+    let uint_128_amount_1 = Uint128::one();
+    let uint_128_amount_10 = Uint128::from(10_u8);
+    let uint_128_amount_11 = Uint128::from(11_u8);
+
+    // Admin caps every holder at 10 memberships by default
+    update_config(
+        &mut app,
+        &cw_member_contract_addr,
+        &admin_addr,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(uint_128_amount_10),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+
+    register_user(&mut app, &cw_member_contract_addr, &user_1_addr).unwrap();
+    register_user(&mut app, &cw_member_contract_addr, &user_2_addr).unwrap();
+    let user_1_id = Uint64::one();
+    link_social_media(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+        SOCIAL_MEDIA_HANDLE_1,
+    )
+    .unwrap();
+    enable_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+    )
+    .unwrap();
+
+    let query_user_2_simulate_buy_membership_res: CostToBuyMembershipResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryCostToBuyMembership(QueryCostToBuyMembershipMsg {
+                membership_issuer_user_id: user_1_id,
+                amount: uint_128_amount_11,
+            }),
+        )
+        .unwrap();
+    assert_eq!(
+        query_user_2_simulate_buy_membership_res.holding_cap,
+        Some(uint_128_amount_10)
+    );
+    get_fund_from_faucet(&mut app, user_2_addr.clone(), Uint128::from(1_000_000_u32));
+
+    // User 2 cannot buy more than the cap in one go
+    assert_err(
+        app.execute_contract(
+            user_2_addr.clone(),
+            cw_member_contract_addr.clone(),
+            &ExecuteMsg::BuyMembership(BuyMembershipMsg {
+                membership_issuer_user_id: user_1_id,
+                amount: uint_128_amount_11,
+                max_total_cost: None,
+            }),
+            &[Coin {
+                denom: FEE_DENOM.to_string(),
+                amount: query_user_2_simulate_buy_membership_res.total_needed_from_user,
+            }],
+        ),
+        ContractError::HoldingCapExceeded {
+            cap: uint_128_amount_10,
+            attempted: uint_128_amount_11,
+        },
+    );
+
+    // User 2 can buy up to the cap
+    app.execute_contract(
+        user_2_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::BuyMembership(BuyMembershipMsg {
+            membership_issuer_user_id: user_1_id,
+            amount: uint_128_amount_10,
+            max_total_cost: None,
+        }),
+        &[Coin {
+            denom: FEE_DENOM.to_string(),
+            amount: Uint128::from(100_000_u32),
+        }],
+    )
+    .unwrap();
+
+    // Cap counts what user 2 already holds
+    assert_err(
+        app.execute_contract(
+            user_2_addr.clone(),
+            cw_member_contract_addr.clone(),
+            &ExecuteMsg::BuyMembership(BuyMembershipMsg {
+                membership_issuer_user_id: user_1_id,
+                amount: uint_128_amount_1,
+                max_total_cost: None,
+            }),
+            &[Coin {
+                denom: FEE_DENOM.to_string(),
+                amount: Uint128::from(100_000_u32),
+            }],
+        ),
+        ContractError::HoldingCapExceeded {
+            cap: uint_128_amount_10,
+            attempted: uint_128_amount_11,
+        },
+    );
+
+    // User 1 removes the cap on its own membership
+    app.execute_contract(
+        user_1_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::UpdateUserConfig(UpdateUserConfigMsg {
+            user_id: user_1_id,
+            trading_fee_bps_of_membership: None,
+            share_to_issuer_bps: None,
+            share_to_all_members_bps: None,
+            holding_cap: Some(Uint128::zero()),
+            only_verified_buyers: None,
+        }),
+        &[],
+    )
+    .unwrap();
+
+    let query_user_2_simulate_buy_membership_res: CostToBuyMembershipResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryCostToBuyMembership(QueryCostToBuyMembershipMsg {
+                membership_issuer_user_id: user_1_id,
+                amount: uint_128_amount_1,
+            }),
+        )
+        .unwrap();
+    assert_eq!(query_user_2_simulate_buy_membership_res.holding_cap, None);
+
+    app.execute_contract(
+        user_2_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::BuyMembership(BuyMembershipMsg {
+            membership_issuer_user_id: user_1_id,
+            amount: uint_128_amount_1,
+            max_total_cost: None,
+        }),
+        &[Coin {
+            denom: FEE_DENOM.to_string(),
+            amount: query_user_2_simulate_buy_membership_res.total_needed_from_user,
+        }],
+    )
+    .unwrap();
+}
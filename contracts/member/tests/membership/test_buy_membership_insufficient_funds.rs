@@ -68,6 +68,7 @@ fn test_buy_membership_insufficient_funds() {
             &ExecuteMsg::BuyMembership(BuyMembershipMsg {
                 membership_issuer_user_id: user_1_id,
                 amount: uint_128_amount_30,
+                max_total_cost: None,
             }),
             &[Coin {
                 denom: FEE_DENOM.to_string(),
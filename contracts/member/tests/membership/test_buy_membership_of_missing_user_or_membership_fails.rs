@@ -0,0 +1,57 @@
+use cosmwasm_std::{Uint128, Uint64};
+use cw_multi_test::Executor;
+
+use member::ContractError;
+use member_pkg::msg::{BuyMembershipMsg, ExecuteMsg};
+
+use crate::helpers::{
+    assert_err, enable_membership, link_social_media, proper_instantiate, register_user,
+    SOCIAL_MEDIA_HANDLE_1,
+};
+
+#[test]
+fn test_buy_membership_of_missing_user_or_membership_fails() {
+    let (mut app, cw_member_contract_addr, _, registration_admin_addr, _, user_1_addr, user_2_addr) =
+        proper_instantiate();
+
+    register_user(&mut app, &cw_member_contract_addr, &user_1_addr).unwrap();
+    let user_1_id = Uint64::one();
+    link_social_media(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+        SOCIAL_MEDIA_HANDLE_1,
+    )
+    .unwrap();
+    enable_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+    )
+    .unwrap();
+
+    // User 2 is registered but hasn't enabled membership
+    register_user(&mut app, &cw_member_contract_addr, &user_2_addr).unwrap();
+    let user_2_id = Uint64::from(2_u8);
+
+    for (membership_issuer_user_id, err) in [
+        (Uint64::from(9_u8), ContractError::UserNotExist {}),
+        (user_2_id, ContractError::UserHasNotRegisteredMembership {}),
+    ] {
+        assert_err(
+            app.execute_contract(
+                user_1_addr.clone(),
+                cw_member_contract_addr.clone(),
+                &ExecuteMsg::BuyMembership(BuyMembershipMsg {
+                    membership_issuer_user_id,
+                    amount: Uint128::one(),
+                    max_total_cost: None,
+                }),
+                &[],
+            ),
+            err,
+        );
+    }
+}
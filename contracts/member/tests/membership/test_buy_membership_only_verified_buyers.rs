@@ -0,0 +1,158 @@
+use cosmwasm_std::{Coin, Uint128, Uint64};
+use cw_multi_test::Executor;
+
+use member::ContractError;
+use member_pkg::msg::{
+    BuyMembershipMsg, ExecuteMsg, QueryMsg, QueryUserByIDMsg, UpdateUserConfigMsg, UserResponse,
+};
+
+use crate::helpers::{
+    assert_err, enable_membership, get_fund_from_faucet, link_social_media, proper_instantiate,
+    register_user, update_config, FEE_DENOM, SOCIAL_MEDIA_HANDLE_1, SOCIAL_MEDIA_HANDLE_2,
+};
+
+#[test]
+fn test_buy_membership_only_verified_buyers() {
+    let (
+        mut app,
+        cw_member_contract_addr,
+        admin_addr,
+        registration_admin_addr,
+        _,
+        user_1_addr,
+        user_2_addr,
+    ) = proper_instantiate();
+
+    let uint_128_amount_10 = Uint128::from(10_u8);
+    let buy_msg = ExecuteMsg::BuyMembership(BuyMembershipMsg {
+        membership_issuer_user_id: Uint64::one(),
+        amount: uint_128_amount_10,
+        max_total_cost: None,
+    });
+    let funds = [Coin {
+        denom: FEE_DENOM.to_string(),
+        amount: Uint128::from(100_000_u32),
+    }];
+
+    register_user(&mut app, &cw_member_contract_addr, &user_1_addr).unwrap();
+    register_user(&mut app, &cw_member_contract_addr, &user_2_addr).unwrap();
+    let user_1_id = Uint64::one();
+    let user_2_id = Uint64::from(2_u64);
+    link_social_media(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+        SOCIAL_MEDIA_HANDLE_1,
+    )
+    .unwrap();
+    enable_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+    )
+    .unwrap();
+    get_fund_from_faucet(&mut app, user_2_addr.clone(), Uint128::from(1_000_000_u32));
+
+    // User 1 only allows verified users to buy its membership
+    app.execute_contract(
+        user_1_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::UpdateUserConfig(UpdateUserConfigMsg {
+            user_id: user_1_id,
+            trading_fee_bps_of_membership: None,
+            share_to_issuer_bps: None,
+            share_to_all_members_bps: None,
+            holding_cap: None,
+            only_verified_buyers: Some(true),
+        }),
+        &[],
+    )
+    .unwrap();
+    let user_1_res: UserResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryUserByID(QueryUserByIDMsg { user_id: user_1_id }),
+        )
+        .unwrap();
+    assert!(user_1_res.user.only_verified_buyers);
+
+    // User 2 has not linked social media
+    assert_err(
+        app.execute_contract(
+            user_2_addr.clone(),
+            cw_member_contract_addr.clone(),
+            &buy_msg,
+            &funds,
+        ),
+        ContractError::OnlyVerifiedUserCanBuyMembership {},
+    );
+
+    // User 1 turns it off but admin turns it on for all memberships
+    app.execute_contract(
+        user_1_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::UpdateUserConfig(UpdateUserConfigMsg {
+            user_id: user_1_id,
+            trading_fee_bps_of_membership: None,
+            share_to_issuer_bps: None,
+            share_to_all_members_bps: None,
+            holding_cap: None,
+            only_verified_buyers: Some(false),
+        }),
+        &[],
+    )
+    .unwrap();
+    update_config(
+        &mut app,
+        &cw_member_contract_addr,
+        &admin_addr,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(true),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+    assert_err(
+        app.execute_contract(
+            user_2_addr.clone(),
+            cw_member_contract_addr.clone(),
+            &buy_msg,
+            &funds,
+        ),
+        ContractError::OnlyVerifiedUserCanBuyMembership {},
+    );
+
+    // User 2 can buy after linking social media
+    link_social_media(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_2_id,
+        SOCIAL_MEDIA_HANDLE_2,
+    )
+    .unwrap();
+    app.execute_contract(
+        user_2_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &buy_msg,
+        &funds,
+    )
+    .unwrap();
+}
@@ -0,0 +1,124 @@
+use cosmwasm_std::{Coin, Uint128, Uint64};
+use cw_multi_test::Executor;
+
+use member::ContractError;
+use member_pkg::msg::{
+    BuyMembershipMsg, CostToBuyMembershipResponse, CostToSellMembershipResponse, ExecuteMsg,
+    QueryCostToBuyMembershipMsg, QueryCostToSellMembershipMsg, QueryMsg,
+};
+
+use shared_pkg::pricing::BondingCurve;
+
+use crate::helpers::{
+    assert_err, enable_membership_with_bonding_curve, get_fund_from_faucet, link_social_media,
+    proper_instantiate, register_user, FEE_DENOM, SOCIAL_MEDIA_HANDLE_1,
+};
+
+#[test]
+fn test_issuer_can_pick_bonding_curve() {
+    let (mut app, cw_member_contract_addr, _, registration_admin_addr, _, user_1_addr, user_2_addr) =
+        proper_instantiate();
+    let user_1_id = Uint64::one();
+    let uint_128_amount_10 = Uint128::from(10_u8);
+
+    register_user(&mut app, &cw_member_contract_addr, &user_1_addr).unwrap();
+    register_user(&mut app, &cw_member_contract_addr, &user_2_addr).unwrap();
+    link_social_media(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+        SOCIAL_MEDIA_HANDLE_1,
+    )
+    .unwrap();
+
+    assert_err(
+        enable_membership_with_bonding_curve(
+            &mut app,
+            &cw_member_contract_addr,
+            &registration_admin_addr,
+            user_1_id,
+            BondingCurve::Sigmoid {
+                max_price: Uint128::from(1_000_000_u64),
+                inflection_supply: Uint128::zero(),
+            },
+        ),
+        ContractError::BondingCurveInflectionSupplyCannotBeZero {},
+    );
+    assert_err(
+        enable_membership_with_bonding_curve(
+            &mut app,
+            &cw_member_contract_addr,
+            &registration_admin_addr,
+            user_1_id,
+            BondingCurve::Exponential {
+                base_price: Uint128::from(1_000_000_u64),
+                growth_bps: Uint64::zero(),
+            },
+        ),
+        ContractError::BondingCurveGrowthCannotBeZero {},
+    );
+    enable_membership_with_bonding_curve(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+        BondingCurve::Linear {
+            base_price: Uint128::from(1_000_000_u64),
+            slope: Uint128::from(100_000_u64),
+        },
+    )
+    .unwrap();
+
+    // Price of membership 1 to 10 is 1_100_000, 1_200_000, ..., 2_000_000
+    let query_user_2_simulate_buy_membership_res: CostToBuyMembershipResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryCostToBuyMembership(QueryCostToBuyMembershipMsg {
+                membership_issuer_user_id: user_1_id,
+                amount: uint_128_amount_10,
+            }),
+        )
+        .unwrap();
+    assert_eq!(
+        query_user_2_simulate_buy_membership_res.price,
+        Uint128::from(15_500_000_u64)
+    );
+
+    get_fund_from_faucet(
+        &mut app,
+        user_2_addr.clone(),
+        query_user_2_simulate_buy_membership_res.total_needed_from_user,
+    );
+    app.execute_contract(
+        user_2_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::BuyMembership(BuyMembershipMsg {
+            membership_issuer_user_id: user_1_id,
+            amount: uint_128_amount_10,
+            max_total_cost: None,
+        }),
+        &[Coin {
+            denom: FEE_DENOM.to_string(),
+            amount: query_user_2_simulate_buy_membership_res.total_needed_from_user,
+        }],
+    )
+    .unwrap();
+
+    // Selling the same amount back returns the same price
+    let query_user_2_simulate_sell_membership_res: CostToSellMembershipResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryCostToSellMembership(QueryCostToSellMembershipMsg {
+                membership_issuer_user_id: user_1_id,
+                amount: uint_128_amount_10,
+            }),
+        )
+        .unwrap();
+    assert_eq!(
+        query_user_2_simulate_sell_membership_res.price,
+        query_user_2_simulate_buy_membership_res.price
+    );
+}
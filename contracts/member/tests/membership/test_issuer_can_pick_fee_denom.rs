@@ -0,0 +1,186 @@
+use cosmwasm_std::{Coin, Uint128, Uint64};
+use cw_multi_test::Executor;
+
+use member::ContractError;
+use member_pkg::msg::{
+    BuyMembershipMsg, CostToBuyMembershipResponse, ExecuteMsg, QueryCostToBuyMembershipMsg,
+    QueryMsg,
+};
+
+use crate::helpers::{
+    add_to_fee_denom_allowlist, assert_balance, assert_err, claim_reward,
+    enable_membership_with_fee_denom, get_distribution_contract_addr,
+    get_fund_from_faucet_in_denom, link_social_media, proper_instantiate, register_user,
+    remove_from_fee_denom_allowlist, FEE_DENOM, FEE_DENOM_2, SOCIAL_MEDIA_HANDLE_1,
+};
+
+#[test]
+fn test_issuer_can_pick_fee_denom() {
+    let (
+        mut app,
+        cw_member_contract_addr,
+        admin_addr,
+        registration_admin_addr,
+        fee_collector_addr,
+        user_1_addr,
+        user_2_addr,
+    ) = proper_instantiate();
+    let cw_distribution_contract_addr =
+        get_distribution_contract_addr(&app, &cw_member_contract_addr);
+
+    let uint_128_amount_10 = Uint128::from(10_u8);
+
+    register_user(&mut app, &cw_member_contract_addr, &user_1_addr).unwrap();
+    register_user(&mut app, &cw_member_contract_addr, &user_2_addr).unwrap();
+    let user_1_id = Uint64::one();
+    link_social_media(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+        SOCIAL_MEDIA_HANDLE_1,
+    )
+    .unwrap();
+
+    // Denom must be in allowlist before issuer can pick it
+    assert_err(
+        enable_membership_with_fee_denom(
+            &mut app,
+            &cw_member_contract_addr,
+            &registration_admin_addr,
+            user_1_id,
+            FEE_DENOM_2,
+        ),
+        ContractError::FeeDenomNotInAllowlist {
+            denom: FEE_DENOM_2.to_string(),
+        },
+    );
+
+    // Only admin can update allowlist
+    assert_err(
+        add_to_fee_denom_allowlist(
+            &mut app,
+            &cw_member_contract_addr,
+            &registration_admin_addr,
+            FEE_DENOM_2,
+        ),
+        ContractError::OnlyAdminCanUpdateFeeDenomAllowlist {},
+    );
+    add_to_fee_denom_allowlist(&mut app, &cw_member_contract_addr, &admin_addr, FEE_DENOM_2)
+        .unwrap();
+
+    // Default fee denom always stays in allowlist
+    assert_err(
+        remove_from_fee_denom_allowlist(&mut app, &cw_member_contract_addr, &admin_addr, FEE_DENOM),
+        ContractError::CannotRemoveDefaultFeeDenomFromAllowlist {},
+    );
+
+    enable_membership_with_fee_denom(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+        FEE_DENOM_2,
+    )
+    .unwrap();
+
+    // Removing denom from allowlist doesn't affect memberships already using it
+    remove_from_fee_denom_allowlist(&mut app, &cw_member_contract_addr, &admin_addr, FEE_DENOM_2)
+        .unwrap();
+
+    let query_user_2_simulate_buy_membership_res: CostToBuyMembershipResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryCostToBuyMembership(QueryCostToBuyMembershipMsg {
+                membership_issuer_user_id: user_1_id,
+                amount: uint_128_amount_10,
+            }),
+        )
+        .unwrap();
+    assert_eq!(
+        query_user_2_simulate_buy_membership_res.fee_denom,
+        FEE_DENOM_2.to_string()
+    );
+    let total_needed_from_user = query_user_2_simulate_buy_membership_res.total_needed_from_user;
+
+    // User 2 cannot pay in default fee denom
+    get_fund_from_faucet_in_denom(
+        &mut app,
+        user_2_addr.clone(),
+        total_needed_from_user,
+        FEE_DENOM,
+    );
+    assert_err(
+        app.execute_contract(
+            user_2_addr.clone(),
+            cw_member_contract_addr.clone(),
+            &ExecuteMsg::BuyMembership(BuyMembershipMsg {
+                membership_issuer_user_id: user_1_id,
+                amount: uint_128_amount_10,
+                max_total_cost: None,
+            }),
+            &[Coin {
+                denom: FEE_DENOM.to_string(),
+                amount: total_needed_from_user,
+            }],
+        ),
+        ContractError::Payment(cw_utils::PaymentError::MissingDenom(
+            FEE_DENOM_2.to_string(),
+        )),
+    );
+
+    // User 2 pays in membership's fee denom
+    get_fund_from_faucet_in_denom(
+        &mut app,
+        user_2_addr.clone(),
+        total_needed_from_user,
+        FEE_DENOM_2,
+    );
+    app.execute_contract(
+        user_2_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::BuyMembership(BuyMembershipMsg {
+            membership_issuer_user_id: user_1_id,
+            amount: uint_128_amount_10,
+            max_total_cost: None,
+        }),
+        &[Coin {
+            denom: FEE_DENOM_2.to_string(),
+            amount: total_needed_from_user,
+        }],
+    )
+    .unwrap();
+    assert_balance(&app, &user_2_addr, Uint128::zero(), FEE_DENOM_2);
+    assert_balance(&app, &user_2_addr, total_needed_from_user, FEE_DENOM);
+
+    // User 1 is the only member before the buy so it collects all of the member fee in membership's fee denom
+    claim_reward(
+        &mut app,
+        &cw_distribution_contract_addr,
+        &user_1_addr,
+        user_1_id,
+        user_1_id,
+    )
+    .unwrap();
+    assert_balance(
+        &app,
+        &cw_member_contract_addr,
+        query_user_2_simulate_buy_membership_res.price,
+        FEE_DENOM_2,
+    );
+    assert_balance(
+        &app,
+        &user_1_addr,
+        query_user_2_simulate_buy_membership_res.issuer_fee
+            + query_user_2_simulate_buy_membership_res.all_members_fee,
+        FEE_DENOM_2,
+    );
+    assert_balance(
+        &app,
+        &fee_collector_addr,
+        query_user_2_simulate_buy_membership_res.protocol_fee,
+        FEE_DENOM_2,
+    );
+    assert_balance(&app, &user_1_addr, Uint128::zero(), FEE_DENOM);
+}
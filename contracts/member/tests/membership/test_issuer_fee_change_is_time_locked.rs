@@ -0,0 +1,176 @@
+use cosmwasm_std::{Addr, Uint128, Uint64};
+use cw_multi_test::{App, Executor};
+use cw_utils::Expiration;
+
+use member_pkg::{
+    msg::{
+        CostToBuyMembershipResponse, ExecuteMsg, QueryCostToBuyMembershipMsg, QueryMsg,
+        QueryUserByIDMsg, UpdateUserConfigMsg, UserResponse,
+    },
+    user::{PendingUserFeeChange, UserFeeConfig},
+};
+use shared_pkg::fee_share_config::FeeShareConfig;
+
+use crate::helpers::{
+    enable_membership, link_social_media, proper_instantiate, register_user, SOCIAL_MEDIA_HANDLE_1,
+};
+
+#[test]
+fn test_issuer_fee_change_is_time_locked() {
+    let (mut app, cw_member_contract_addr, _, registration_admin_addr, _, user_1_addr, _) =
+        proper_instantiate();
+
+    register_user(&mut app, &cw_member_contract_addr, &user_1_addr).unwrap();
+    let user_1_id = Uint64::one();
+    link_social_media(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+        SOCIAL_MEDIA_HANDLE_1,
+    )
+    .unwrap();
+    enable_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+    )
+    .unwrap();
+
+    let cost_before_fee_change =
+        query_cost_to_buy_membership(&app, &cw_member_contract_addr, user_1_id);
+    assert_eq!(cost_before_fee_change.issuer_fee, Uint128::from(23_636_u32));
+
+    // Fee hike waits for the time-lock while holding cap takes effect right away
+    app.execute_contract(
+        user_1_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::UpdateUserConfig(UpdateUserConfigMsg {
+            user_id: user_1_id,
+            trading_fee_bps_of_membership: Some(Uint64::from(1_000_u32)),
+            share_to_issuer_bps: None,
+            share_to_all_members_bps: None,
+            holding_cap: Some(Uint128::from(100_u8)),
+            only_verified_buyers: None,
+        }),
+        &[],
+    )
+    .unwrap();
+    let effective_at = Expiration::AtTime(app.block_info().time.plus_seconds(86_400));
+
+    let user_res = query_user(&app, &cw_member_contract_addr, user_1_id);
+    assert_eq!(
+        user_res.user.fee_config,
+        UserFeeConfig {
+            trading_fee_bps_of_membership: None,
+            holding_cap: Some(Uint128::from(100_u8)),
+        }
+    );
+    assert_eq!(
+        user_res.pending_fee_change,
+        Some(PendingUserFeeChange {
+            trading_fee_bps_of_membership: Some(Uint64::from(1_000_u32)),
+            fee_share_config: None,
+            effective_at,
+        })
+    );
+    assert_eq!(
+        query_cost_to_buy_membership(&app, &cw_member_contract_addr, user_1_id),
+        CostToBuyMembershipResponse {
+            holding_cap: Some(Uint128::from(100_u8)),
+            ..cost_before_fee_change.clone()
+        }
+    );
+
+    // Fee change takes effect once time-lock passes, without issuer doing anything
+    app.update_block(|block| {
+        block.time = block.time.plus_seconds(86_400);
+    });
+    let user_res = query_user(&app, &cw_member_contract_addr, user_1_id);
+    assert_eq!(
+        user_res.user.fee_config.trading_fee_bps_of_membership,
+        Some(Uint64::from(1_000_u32))
+    );
+    assert_eq!(user_res.pending_fee_change, None);
+    let cost_after_fee_change =
+        query_cost_to_buy_membership(&app, &cw_member_contract_addr, user_1_id);
+    assert_eq!(cost_after_fee_change.issuer_fee, Uint128::from(47_274_u32));
+    assert_eq!(
+        cost_after_fee_change.all_members_fee,
+        Uint128::from(11_818_u32)
+    );
+
+    // New fee change replaces pending one, keeping fees it leaves out, and restarts the time-lock
+    app.execute_contract(
+        user_1_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::UpdateUserConfig(UpdateUserConfigMsg {
+            user_id: user_1_id,
+            trading_fee_bps_of_membership: Some(Uint64::from(2_000_u32)),
+            share_to_issuer_bps: None,
+            share_to_all_members_bps: None,
+            holding_cap: None,
+            only_verified_buyers: None,
+        }),
+        &[],
+    )
+    .unwrap();
+    app.update_block(|block| {
+        block.time = block.time.plus_seconds(3_600);
+    });
+    app.execute_contract(
+        user_1_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::UpdateUserConfig(UpdateUserConfigMsg {
+            user_id: user_1_id,
+            trading_fee_bps_of_membership: None,
+            share_to_issuer_bps: Some(Uint64::from(10_000_u32)),
+            share_to_all_members_bps: Some(Uint64::zero()),
+            holding_cap: None,
+            only_verified_buyers: None,
+        }),
+        &[],
+    )
+    .unwrap();
+    assert_eq!(
+        query_user(&app, &cw_member_contract_addr, user_1_id).pending_fee_change,
+        Some(PendingUserFeeChange {
+            trading_fee_bps_of_membership: Some(Uint64::from(2_000_u32)),
+            fee_share_config: Some(FeeShareConfig {
+                share_to_issuer_bps: Uint64::from(10_000_u32),
+                share_to_all_members_bps: Uint64::zero(),
+            }),
+            effective_at: Expiration::AtTime(app.block_info().time.plus_seconds(86_400)),
+        })
+    );
+    assert_eq!(
+        query_cost_to_buy_membership(&app, &cw_member_contract_addr, user_1_id),
+        cost_after_fee_change
+    );
+}
+
+fn query_user(app: &App, cw_member_contract_addr: &Addr, user_id: Uint64) -> UserResponse {
+    app.wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr,
+            &QueryMsg::QueryUserByID(QueryUserByIDMsg { user_id }),
+        )
+        .unwrap()
+}
+
+fn query_cost_to_buy_membership(
+    app: &App,
+    cw_member_contract_addr: &Addr,
+    membership_issuer_user_id: Uint64,
+) -> CostToBuyMembershipResponse {
+    app.wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr,
+            &QueryMsg::QueryCostToBuyMembership(QueryCostToBuyMembershipMsg {
+                membership_issuer_user_id,
+                amount: Uint128::from(30_u8),
+            }),
+        )
+        .unwrap()
+}
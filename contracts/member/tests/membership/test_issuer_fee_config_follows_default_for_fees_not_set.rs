@@ -0,0 +1,131 @@
+use cosmwasm_std::{Addr, Uint128, Uint64};
+use cw_multi_test::{App, Executor};
+
+use member_pkg::msg::{
+    CostToBuyMembershipResponse, ExecuteMsg, QueryCostToBuyMembershipMsg, QueryMsg,
+    UpdateConfigMsg, UpdateUserConfigMsg,
+};
+
+use crate::helpers::{
+    enable_membership, link_social_media, proper_instantiate, register_user, SOCIAL_MEDIA_HANDLE_1,
+};
+
+#[test]
+fn test_issuer_fee_config_follows_default_for_fees_not_set() {
+    let (mut app, cw_member_contract_addr, admin_addr, registration_admin_addr, _, user_1_addr, _) =
+        proper_instantiate();
+
+    register_user(&mut app, &cw_member_contract_addr, &user_1_addr).unwrap();
+    let user_1_id = Uint64::one();
+    link_social_media(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+        SOCIAL_MEDIA_HANDLE_1,
+    )
+    .unwrap();
+    enable_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+    )
+    .unwrap();
+
+    // Issuer only sets its holding cap
+    update_holding_cap(
+        &mut app,
+        &cw_member_contract_addr,
+        &user_1_addr,
+        user_1_id,
+        Uint128::from(100_u8),
+    );
+    let cost_before_default_change =
+        query_cost_to_buy_membership(&app, &cw_member_contract_addr, user_1_id);
+    assert_eq!(
+        cost_before_default_change.holding_cap,
+        Some(Uint128::from(100_u8))
+    );
+    assert_eq!(
+        cost_before_default_change.issuer_fee,
+        Uint128::from(23_636_u32)
+    );
+
+    // Admin raises default trading fee and sets a default holding cap
+    app.execute_contract(
+        admin_addr,
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            default_trading_fee_bps_of_membership: Some(Uint64::from(1_000_u32)),
+            default_holding_cap: Some(Uint128::from(50_u8)),
+            ..UpdateConfigMsg::default()
+        }),
+        &[],
+    )
+    .unwrap();
+
+    // Issuer never set its trading fee so it follows the new default, its own holding cap is kept
+    let cost_after_default_change =
+        query_cost_to_buy_membership(&app, &cw_member_contract_addr, user_1_id);
+    assert_eq!(
+        cost_after_default_change.holding_cap,
+        Some(Uint128::from(100_u8))
+    );
+    assert_eq!(
+        cost_after_default_change.issuer_fee,
+        Uint128::from(47_274_u32)
+    );
+
+    // Holding cap of 0 removes the cap even though config has a default cap
+    update_holding_cap(
+        &mut app,
+        &cw_member_contract_addr,
+        &user_1_addr,
+        user_1_id,
+        Uint128::zero(),
+    );
+    assert_eq!(
+        query_cost_to_buy_membership(&app, &cw_member_contract_addr, user_1_id).holding_cap,
+        None
+    );
+}
+
+fn update_holding_cap(
+    app: &mut App,
+    cw_member_contract_addr: &Addr,
+    sender_addr: &Addr,
+    user_id: Uint64,
+    holding_cap: Uint128,
+) {
+    app.execute_contract(
+        sender_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::UpdateUserConfig(UpdateUserConfigMsg {
+            user_id,
+            trading_fee_bps_of_membership: None,
+            share_to_issuer_bps: None,
+            share_to_all_members_bps: None,
+            holding_cap: Some(holding_cap),
+            only_verified_buyers: None,
+        }),
+        &[],
+    )
+    .unwrap();
+}
+
+fn query_cost_to_buy_membership(
+    app: &App,
+    cw_member_contract_addr: &Addr,
+    membership_issuer_user_id: Uint64,
+) -> CostToBuyMembershipResponse {
+    app.wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr,
+            &QueryMsg::QueryCostToBuyMembership(QueryCostToBuyMembershipMsg {
+                membership_issuer_user_id,
+                amount: Uint128::from(30_u8),
+            }),
+        )
+        .unwrap()
+}
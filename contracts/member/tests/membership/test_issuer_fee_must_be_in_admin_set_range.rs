@@ -0,0 +1,223 @@
+use anyhow::Result as AnyResult;
+use cosmwasm_std::{Addr, Uint64};
+use cw_multi_test::{App, AppResponse, Executor};
+
+use member::ContractError;
+use member_pkg::{
+    config::UserFeeRanges,
+    msg::{
+        ExecuteMsg, QueryMsg, QueryUserByIDMsg, QueryUserFeeRangesMsg, UpdateConfigMsg,
+        UpdateUserConfigMsg, UserFeeRangesResponse, UserResponse,
+    },
+};
+use shared_pkg::fee_range::BpsRange;
+
+use crate::helpers::{assert_err, proper_instantiate, register_user};
+
+#[test]
+fn test_issuer_fee_must_be_in_admin_set_range() {
+    let (mut app, cw_member_contract_addr, admin_addr, _, _, user_1_addr, _) = proper_instantiate();
+
+    register_user(&mut app, &cw_member_contract_addr, &user_1_addr).unwrap();
+
+    // Issuer fee can go up to 1 membership price by default
+    assert_eq!(
+        query_user_fee_ranges(&app, &cw_member_contract_addr),
+        UserFeeRanges {
+            trading_fee_bps_of_membership: BpsRange {
+                min: Uint64::zero(),
+                max: Uint64::from(10_000_u32),
+            },
+        }
+    );
+    assert_err(
+        update_trading_fee(
+            &mut app,
+            &cw_member_contract_addr,
+            &user_1_addr,
+            Uint64::from(10_001_u32),
+        ),
+        ContractError::FeeBpsOutOfRange {
+            fee: "trading_fee_bps_of_membership".to_string(),
+            bps: Uint64::from(10_001_u32),
+            min: Uint64::zero(),
+            max: Uint64::from(10_000_u32),
+        },
+    );
+
+    // Range with min above max is rejected
+    assert_err(
+        update_user_fee_ranges(
+            &mut app,
+            &cw_member_contract_addr,
+            &admin_addr,
+            Uint64::from(2_000_u32),
+            Uint64::from(1_000_u32),
+        ),
+        ContractError::InvalidUserFeeRange {
+            fee: "trading_fee_bps_of_membership".to_string(),
+            min: Uint64::from(2_000_u32),
+            max: Uint64::from(1_000_u32),
+        },
+    );
+
+    // Range must keep default fee of 500 bps in it
+    assert_err(
+        update_user_fee_ranges(
+            &mut app,
+            &cw_member_contract_addr,
+            &admin_addr,
+            Uint64::from(100_u32),
+            Uint64::from(300_u32),
+        ),
+        ContractError::FeeBpsOutOfRange {
+            fee: "trading_fee_bps_of_membership".to_string(),
+            bps: Uint64::from(500_u32),
+            min: Uint64::from(100_u32),
+            max: Uint64::from(300_u32),
+        },
+    );
+
+    update_user_fee_ranges(
+        &mut app,
+        &cw_member_contract_addr,
+        &admin_addr,
+        Uint64::from(100_u32),
+        Uint64::from(1_000_u32),
+    )
+    .unwrap();
+    assert_eq!(
+        query_user_fee_ranges(&app, &cw_member_contract_addr).trading_fee_bps_of_membership,
+        BpsRange {
+            min: Uint64::from(100_u32),
+            max: Uint64::from(1_000_u32),
+        }
+    );
+
+    // Bounds are inclusive
+    assert_err(
+        update_trading_fee(
+            &mut app,
+            &cw_member_contract_addr,
+            &user_1_addr,
+            Uint64::from(1_001_u32),
+        ),
+        ContractError::FeeBpsOutOfRange {
+            fee: "trading_fee_bps_of_membership".to_string(),
+            bps: Uint64::from(1_001_u32),
+            min: Uint64::from(100_u32),
+            max: Uint64::from(1_000_u32),
+        },
+    );
+    assert_err(
+        update_trading_fee(
+            &mut app,
+            &cw_member_contract_addr,
+            &user_1_addr,
+            Uint64::from(99_u32),
+        ),
+        ContractError::FeeBpsOutOfRange {
+            fee: "trading_fee_bps_of_membership".to_string(),
+            bps: Uint64::from(99_u32),
+            min: Uint64::from(100_u32),
+            max: Uint64::from(1_000_u32),
+        },
+    );
+    update_trading_fee(
+        &mut app,
+        &cw_member_contract_addr,
+        &user_1_addr,
+        Uint64::from(1_000_u32),
+    )
+    .unwrap();
+    update_trading_fee(
+        &mut app,
+        &cw_member_contract_addr,
+        &user_1_addr,
+        Uint64::from(100_u32),
+    )
+    .unwrap();
+
+    // Pending fee change outside the range admin tightened afterwards never takes effect
+    update_trading_fee(
+        &mut app,
+        &cw_member_contract_addr,
+        &user_1_addr,
+        Uint64::from(1_000_u32),
+    )
+    .unwrap();
+    update_user_fee_ranges(
+        &mut app,
+        &cw_member_contract_addr,
+        &admin_addr,
+        Uint64::from(100_u32),
+        Uint64::from(500_u32),
+    )
+    .unwrap();
+    app.update_block(|block| {
+        block.time = block.time.plus_seconds(86_400);
+    });
+    let user_res: UserResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr,
+            &QueryMsg::QueryUserByID(QueryUserByIDMsg {
+                user_id: Uint64::one(),
+            }),
+        )
+        .unwrap();
+    assert_eq!(user_res.user.fee_config.trading_fee_bps_of_membership, None);
+    assert_eq!(user_res.pending_fee_change, None);
+}
+
+fn update_trading_fee(
+    app: &mut App,
+    cw_member_contract_addr: &Addr,
+    user_addr: &Addr,
+    trading_fee_bps_of_membership: Uint64,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        user_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::UpdateUserConfig(UpdateUserConfigMsg {
+            user_id: Uint64::one(),
+            trading_fee_bps_of_membership: Some(trading_fee_bps_of_membership),
+            share_to_issuer_bps: None,
+            share_to_all_members_bps: None,
+            holding_cap: None,
+            only_verified_buyers: None,
+        }),
+        &[],
+    )
+}
+
+fn update_user_fee_ranges(
+    app: &mut App,
+    cw_member_contract_addr: &Addr,
+    admin_addr: &Addr,
+    min: Uint64,
+    max: Uint64,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        admin_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            user_fee_ranges: Some(UserFeeRanges {
+                trading_fee_bps_of_membership: BpsRange { min, max },
+            }),
+            ..UpdateConfigMsg::default()
+        }),
+        &[],
+    )
+}
+
+fn query_user_fee_ranges(app: &App, cw_member_contract_addr: &Addr) -> UserFeeRanges {
+    let res: UserFeeRangesResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr,
+            &QueryMsg::QueryUserFeeRanges(QueryUserFeeRangesMsg {}),
+        )
+        .unwrap();
+    res.user_fee_ranges
+}
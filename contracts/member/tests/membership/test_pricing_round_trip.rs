@@ -0,0 +1,143 @@
+use cosmwasm_std::{Coin, Uint128, Uint64};
+use cw_multi_test::Executor;
+use proptest::prelude::*;
+
+use member_pkg::msg::{
+    BuyMembershipMsg, CostToBuyMembershipResponse, CostToSellMembershipResponse, ExecuteMsg,
+    QueryCostToBuyMembershipMsg, QueryCostToSellMembershipMsg, QueryMsg, SellMembershipMsg,
+};
+use shared_pkg::pricing::BondingCurve;
+
+use crate::helpers::{
+    assert_balance, enable_membership_with_bonding_curve, get_fund_from_faucet, link_social_media,
+    proper_instantiate, register_user, FEE_DENOM, SOCIAL_MEDIA_HANDLE_1,
+};
+
+// Prices from 0 up to what faucet can fund for 50 memberships
+// Small prices round some fees down to 0, trades must still go through
+fn affordable_bonding_curve() -> impl Strategy<Value = BondingCurve> {
+    prop_oneof![
+        (1_000..=1_000_000_000_u128).prop_map(|divisor| BondingCurve::Quadratic {
+            divisor: Uint128::new(divisor),
+        }),
+        (0..=1_000_000_u128, 0..=100_000_u128).prop_map(|(base_price, slope)| {
+            BondingCurve::Linear {
+                base_price: Uint128::new(base_price),
+                slope: Uint128::new(slope),
+            }
+        }),
+        (0..=1_000_000_u128, 1..=500_u64).prop_map(|(base_price, growth_bps)| {
+            BondingCurve::Exponential {
+                base_price: Uint128::new(base_price),
+                growth_bps: Uint64::new(growth_bps),
+            }
+        }),
+        (0..=1_000_000_u128, 1..=1_000_u128).prop_map(|(max_price, inflection_supply)| {
+            BondingCurve::Sigmoid {
+                max_price: Uint128::new(max_price),
+                inflection_supply: Uint128::new(inflection_supply),
+            }
+        }),
+    ]
+}
+
+proptest! {
+    // Each case spins up a fresh app, so fewer cases than the pure math tests in shared-pkg
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn test_buy_then_sell_same_amount_round_trips(
+        bonding_curve in affordable_bonding_curve(),
+        amount in 1..=50_u128,
+    ) {
+        let (mut app, cw_member_contract_addr, _, registration_admin_addr, _, user_1_addr, user_2_addr) =
+            proper_instantiate();
+        let user_1_id = Uint64::one();
+        let amount = Uint128::new(amount);
+
+        register_user(&mut app, &cw_member_contract_addr, &user_1_addr).unwrap();
+        register_user(&mut app, &cw_member_contract_addr, &user_2_addr).unwrap();
+        link_social_media(
+            &mut app,
+            &cw_member_contract_addr,
+            &registration_admin_addr,
+            user_1_id,
+            SOCIAL_MEDIA_HANDLE_1,
+        )
+        .unwrap();
+        enable_membership_with_bonding_curve(
+            &mut app,
+            &cw_member_contract_addr,
+            &registration_admin_addr,
+            user_1_id,
+            bonding_curve,
+        )
+        .unwrap();
+
+        let cost_to_buy: CostToBuyMembershipResponse = app
+            .wrap()
+            .query_wasm_smart(
+                cw_member_contract_addr.clone(),
+                &QueryMsg::QueryCostToBuyMembership(QueryCostToBuyMembershipMsg {
+                    membership_issuer_user_id: user_1_id,
+                    amount,
+                }),
+            )
+            .unwrap();
+        // Buying requires paying something, a free membership has nothing to round trip
+        prop_assume!(!cost_to_buy.total_needed_from_user.is_zero());
+        get_fund_from_faucet(&mut app, user_2_addr.clone(), cost_to_buy.total_needed_from_user);
+        app.execute_contract(
+            user_2_addr.clone(),
+            cw_member_contract_addr.clone(),
+            &ExecuteMsg::BuyMembership(BuyMembershipMsg {
+                membership_issuer_user_id: user_1_id,
+                amount,
+                max_total_cost: None,
+            }),
+            &[Coin {
+                denom: FEE_DENOM.to_string(),
+                amount: cost_to_buy.total_needed_from_user,
+            }],
+        )
+        .unwrap();
+
+        // Selling the same amount back prices it exactly as it was bought, fees included
+        let cost_to_sell: CostToSellMembershipResponse = app
+            .wrap()
+            .query_wasm_smart(
+                cw_member_contract_addr.clone(),
+                &QueryMsg::QueryCostToSellMembership(QueryCostToSellMembershipMsg {
+                    membership_issuer_user_id: user_1_id,
+                    amount,
+                }),
+            )
+            .unwrap();
+        prop_assert_eq!(cost_to_sell.price, cost_to_buy.price);
+        prop_assert_eq!(cost_to_sell.protocol_fee, cost_to_buy.protocol_fee);
+        prop_assert_eq!(cost_to_sell.referral_fee, cost_to_buy.referral_fee);
+        prop_assert_eq!(cost_to_sell.issuer_fee, cost_to_buy.issuer_fee);
+        prop_assert_eq!(cost_to_sell.all_members_fee, cost_to_buy.all_members_fee);
+
+        app.execute_contract(
+            user_2_addr.clone(),
+            cw_member_contract_addr.clone(),
+            &ExecuteMsg::SellMembership(SellMembershipMsg {
+                membership_issuer_user_id: user_1_id,
+                amount,
+                min_proceeds: None,
+                deduct_fees_from_proceeds: Some(true),
+            }),
+            &[],
+        )
+        .unwrap();
+
+        // Trader only loses the fees of both trades
+        assert_balance(&app, &user_2_addr, cost_to_sell.net_proceeds, FEE_DENOM);
+        prop_assert_eq!(
+            cost_to_buy.total_needed_from_user - cost_to_sell.net_proceeds,
+            (cost_to_buy.total_needed_from_user - cost_to_buy.price)
+                + cost_to_sell.total_needed_from_user.min(cost_to_sell.price)
+        );
+    }
+}
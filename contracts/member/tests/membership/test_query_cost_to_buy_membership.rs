@@ -4,7 +4,7 @@ use member_pkg::msg::{CostToBuyMembershipResponse, QueryCostToBuyMembershipMsg,
 
 use crate::helpers::{
     assert_member_count, assert_membership_supply, enable_membership, link_social_media,
-    print_balance, proper_instantiate, register_user, SOCIAL_MEDIA_HANDLE_1,
+    print_balance, proper_instantiate, register_user, FEE_DENOM, SOCIAL_MEDIA_HANDLE_1,
 };
 
 #[test]
@@ -70,9 +70,12 @@ fn test_query_cost_to_buy_membership() {
         CostToBuyMembershipResponse {
             price: Uint128::from(590_937_u32),
             protocol_fee: Uint128::from(2954_u32),
+            referral_fee: Uint128::zero(),
             issuer_fee: Uint128::from(23_636_u32),
             all_members_fee: Uint128::from(5_909_u32),
             total_needed_from_user: Uint128::from(623_436_u32),
+            fee_denom: FEE_DENOM.to_string(),
+            holding_cap: None,
         }
     );
 }
@@ -0,0 +1,144 @@
+use cosmwasm_std::{Addr, Coin, Uint128, Uint64};
+use cw_multi_test::{App, Executor};
+
+use member_pkg::msg::{
+    BuyMembershipMsg, CostToBuyMembershipResponse, ExecuteMsg, QueryCostToBuyMembershipMsg,
+    QueryMsg, QueryTwapMsg, TwapResponse,
+};
+
+use crate::helpers::{
+    enable_membership, get_fund_from_faucet, link_social_media, proper_instantiate, register_user,
+    FEE_DENOM, SOCIAL_MEDIA_HANDLE_1,
+};
+
+fn query_cost_to_buy(
+    app: &App,
+    cw_member_contract_addr: &Addr,
+    membership_issuer_user_id: Uint64,
+    amount: Uint128,
+) -> CostToBuyMembershipResponse {
+    app.wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryCostToBuyMembership(QueryCostToBuyMembershipMsg {
+                membership_issuer_user_id,
+                amount,
+            }),
+        )
+        .unwrap()
+}
+
+fn query_twap(
+    app: &App,
+    cw_member_contract_addr: &Addr,
+    membership_issuer_user_id: Uint64,
+    window_seconds: u64,
+) -> TwapResponse {
+    app.wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryTwap(QueryTwapMsg {
+                membership_issuer_user_id,
+                window_seconds: Uint64::from(window_seconds),
+            }),
+        )
+        .unwrap()
+}
+
+#[test]
+fn test_query_twap() {
+    let (mut app, cw_member_contract_addr, _, registration_admin_addr, _, user_1_addr, user_2_addr) =
+        proper_instantiate();
+
+    register_user(&mut app, &cw_member_contract_addr, &user_1_addr).unwrap();
+    register_user(&mut app, &cw_member_contract_addr, &user_2_addr).unwrap();
+    let user_1_id = Uint64::one();
+    link_social_media(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+        SOCIAL_MEDIA_HANDLE_1,
+    )
+    .unwrap();
+    enable_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+    )
+    .unwrap();
+
+    let initial_price =
+        query_cost_to_buy(&app, &cw_member_contract_addr, user_1_id, Uint128::one()).price;
+
+    // Only 1 observation so far, TWAP is the initial price
+    let twap_res = query_twap(&app, &cw_member_contract_addr, user_1_id, 3_600);
+    assert_eq!(twap_res.twap, initial_price);
+    assert_eq!(twap_res.spot_price, initial_price);
+    assert_eq!(twap_res.observed_seconds, Uint64::zero());
+
+    app.update_block(|block| {
+        block.height += 20;
+        block.time = block.time.plus_seconds(100);
+    });
+
+    let uint_128_amount_10 = Uint128::from(10_u8);
+    let query_user_2_simulate_buy_membership_res = query_cost_to_buy(
+        &app,
+        &cw_member_contract_addr,
+        user_1_id,
+        uint_128_amount_10,
+    );
+    get_fund_from_faucet(&mut app, user_2_addr.clone(), Uint128::from(1_000_000_u32));
+    app.execute_contract(
+        user_2_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::BuyMembership(BuyMembershipMsg {
+            membership_issuer_user_id: user_1_id,
+            amount: uint_128_amount_10,
+            max_total_cost: None,
+        }),
+        &[Coin {
+            denom: FEE_DENOM.to_string(),
+            amount: query_user_2_simulate_buy_membership_res.total_needed_from_user,
+        }],
+    )
+    .unwrap();
+
+    let price_after_buy =
+        query_cost_to_buy(&app, &cw_member_contract_addr, user_1_id, Uint128::one()).price;
+    assert!(price_after_buy > initial_price);
+
+    // Spot price jumps right after the trade but TWAP still reflects the old price
+    let twap_res = query_twap(&app, &cw_member_contract_addr, user_1_id, 100);
+    assert_eq!(twap_res.twap, initial_price);
+    assert_eq!(twap_res.spot_price, price_after_buy);
+    assert_eq!(twap_res.observed_seconds, Uint64::from(100_u8));
+
+    app.update_block(|block| {
+        block.height += 20;
+        block.time = block.time.plus_seconds(100);
+    });
+
+    // Half of the window at initial price, half at price after buy
+    let twap_res = query_twap(&app, &cw_member_contract_addr, user_1_id, 200);
+    assert_eq!(
+        twap_res.twap,
+        (initial_price + price_after_buy) / Uint128::from(2_u8)
+    );
+    assert_eq!(twap_res.observed_seconds, Uint64::from(200_u8));
+
+    // Window after the trade only sees price after buy
+    let twap_res = query_twap(&app, &cw_member_contract_addr, user_1_id, 50);
+    assert_eq!(twap_res.twap, price_after_buy);
+    assert_eq!(twap_res.observed_seconds, Uint64::from(50_u8));
+
+    // Window longer than price history is capped at when membership was enabled
+    let twap_res = query_twap(&app, &cw_member_contract_addr, user_1_id, 86_400);
+    assert_eq!(
+        twap_res.twap,
+        (initial_price + price_after_buy) / Uint128::from(2_u8)
+    );
+    assert_eq!(twap_res.observed_seconds, Uint64::from(200_u8));
+}
@@ -61,6 +61,8 @@ fn test_sell_membership_cannot_sell_last_key() {
             &ExecuteMsg::SellMembership(SellMembershipMsg {
                 membership_issuer_user_id: user_1_id,
                 amount: default_supply,
+                min_proceeds: None,
+                deduct_fees_from_proceeds: None,
             }),
             &[Coin {
                 denom: FEE_DENOM.to_string(),
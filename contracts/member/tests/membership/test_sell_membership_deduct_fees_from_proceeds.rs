@@ -0,0 +1,128 @@
+use cosmwasm_std::{Coin, Uint128, Uint64};
+use cw_multi_test::Executor;
+
+use member::ContractError;
+use member_pkg::msg::{
+    BuyMembershipMsg, CostToBuyMembershipResponse, CostToSellMembershipResponse, ExecuteMsg,
+    QueryCostToBuyMembershipMsg, QueryCostToSellMembershipMsg, QueryMsg, SellMembershipMsg,
+};
+
+use crate::helpers::{
+    assert_balance, assert_err, enable_membership, get_fund_from_faucet, link_social_media,
+    proper_instantiate, register_user, FEE_DENOM, SOCIAL_MEDIA_HANDLE_1,
+};
+
+#[test]
+fn test_sell_membership_deduct_fees_from_proceeds() {
+    let (mut app, cw_member_contract_addr, _, registration_admin_addr, _, user_1_addr, user_2_addr) =
+        proper_instantiate();
+
+    let uint_128_amount_10 = Uint128::from(10_u8);
+
+    register_user(&mut app, &cw_member_contract_addr, &user_1_addr).unwrap();
+    register_user(&mut app, &cw_member_contract_addr, &user_2_addr).unwrap();
+    let user_1_id = Uint64::one();
+    link_social_media(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+        SOCIAL_MEDIA_HANDLE_1,
+    )
+    .unwrap();
+    enable_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+    )
+    .unwrap();
+
+    // User 2 buy 10 amount of user 1's memberships and spends all its balance
+    let query_user_2_simulate_buy_membership_res: CostToBuyMembershipResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryCostToBuyMembership(QueryCostToBuyMembershipMsg {
+                membership_issuer_user_id: user_1_id,
+                amount: uint_128_amount_10,
+            }),
+        )
+        .unwrap();
+    get_fund_from_faucet(
+        &mut app,
+        user_2_addr.clone(),
+        query_user_2_simulate_buy_membership_res.total_needed_from_user,
+    );
+    app.execute_contract(
+        user_2_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::BuyMembership(BuyMembershipMsg {
+            membership_issuer_user_id: user_1_id,
+            amount: uint_128_amount_10,
+            max_total_cost: None,
+        }),
+        &[Coin {
+            denom: FEE_DENOM.to_string(),
+            amount: query_user_2_simulate_buy_membership_res.total_needed_from_user,
+        }],
+    )
+    .unwrap();
+    assert_balance(&app, &user_2_addr, Uint128::zero(), FEE_DENOM);
+
+    let query_user_2_simulate_sell_membership_res: CostToSellMembershipResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryCostToSellMembership(QueryCostToSellMembershipMsg {
+                membership_issuer_user_id: user_1_id,
+                amount: uint_128_amount_10,
+            }),
+        )
+        .unwrap();
+    assert_eq!(
+        query_user_2_simulate_sell_membership_res.net_proceeds,
+        query_user_2_simulate_sell_membership_res.price
+            - query_user_2_simulate_sell_membership_res.total_needed_from_user
+    );
+
+    // Seller cannot attach funds when fees are deducted from proceeds
+    get_fund_from_faucet(&mut app, user_2_addr.clone(), Uint128::one());
+    assert_err(
+        app.execute_contract(
+            user_2_addr.clone(),
+            cw_member_contract_addr.clone(),
+            &ExecuteMsg::SellMembership(SellMembershipMsg {
+                membership_issuer_user_id: user_1_id,
+                amount: uint_128_amount_10,
+                min_proceeds: None,
+                deduct_fees_from_proceeds: Some(true),
+            }),
+            &[Coin {
+                denom: FEE_DENOM.to_string(),
+                amount: Uint128::one(),
+            }],
+        ),
+        ContractError::Payment(cw_utils::PaymentError::NonPayable {}),
+    );
+
+    // User 2 sells without attaching any funds and receives net proceeds
+    app.execute_contract(
+        user_2_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::SellMembership(SellMembershipMsg {
+            membership_issuer_user_id: user_1_id,
+            amount: uint_128_amount_10,
+            min_proceeds: Some(query_user_2_simulate_sell_membership_res.net_proceeds),
+            deduct_fees_from_proceeds: Some(true),
+        }),
+        &[],
+    )
+    .unwrap();
+    assert_balance(
+        &app,
+        &user_2_addr,
+        query_user_2_simulate_sell_membership_res.net_proceeds + Uint128::one(),
+        FEE_DENOM,
+    );
+}
@@ -3,8 +3,8 @@ use cw_multi_test::Executor;
 
 use member::ContractError;
 use member_pkg::msg::{
-    BuyMembershipMsg, CostToBuyMembershipResponse, ExecuteMsg, QueryCostToBuyMembershipMsg,
-    QueryCostToSellMembershipMsg, QueryMsg, SellMembershipMsg,
+    BuyMembershipMsg, CostToBuyMembershipResponse, CostToSellMembershipResponse, ExecuteMsg,
+    QueryCostToBuyMembershipMsg, QueryCostToSellMembershipMsg, QueryMsg, SellMembershipMsg,
 };
 
 use crate::helpers::{
@@ -83,6 +83,7 @@ fn test_sell_membership_insufficient_funds() {
         &ExecuteMsg::BuyMembership(BuyMembershipMsg {
             membership_issuer_user_id: user_1_id,
             amount: uint_128_amount_30,
+            max_total_cost: None,
         }),
         &[Coin {
             denom: FEE_DENOM.to_string(),
@@ -102,7 +103,7 @@ fn test_sell_membership_insufficient_funds() {
     );
 
     // User 1 tries to sell 10 amount of its own memberships but fails because it didn't pay enough protocol fee
-    let query_user_1_simulate_sell_membership_res: CostToBuyMembershipResponse = app
+    let query_user_1_simulate_sell_membership_res: CostToSellMembershipResponse = app
         .wrap()
         .query_wasm_smart(
             cw_member_contract_addr.clone(),
@@ -122,6 +123,8 @@ fn test_sell_membership_insufficient_funds() {
             &ExecuteMsg::SellMembership(SellMembershipMsg {
                 membership_issuer_user_id: user_1_id,
                 amount: uint_128_amount_10,
+                min_proceeds: None,
+                deduct_fees_from_proceeds: None,
             }),
             &[Coin {
                 denom: FEE_DENOM.to_string(),
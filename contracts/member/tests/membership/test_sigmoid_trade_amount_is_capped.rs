@@ -0,0 +1,171 @@
+use cosmwasm_std::{Coin, Uint128, Uint64};
+use cw_multi_test::Executor;
+
+use member::ContractError;
+use member_pkg::msg::{BuyMembershipMsg, ExecuteMsg, SellMembershipMsg};
+use shared_pkg::pricing::BondingCurve;
+
+use crate::helpers::{
+    assert_err, enable_membership_with_bonding_curve, get_fund_from_faucet, link_social_media,
+    proper_instantiate, register_user, update_config, FEE_DENOM, SOCIAL_MEDIA_HANDLE_1,
+};
+
+#[test]
+fn test_sigmoid_trade_amount_is_capped() {
+    let (
+        mut app,
+        cw_member_contract_addr,
+        admin_addr,
+        registration_admin_addr,
+        _,
+        user_1_addr,
+        user_2_addr,
+    ) = proper_instantiate();
+    let user_1_id = Uint64::one();
+    let uint_128_amount_5 = Uint128::from(5_u8);
+    let uint_128_amount_6 = Uint128::from(6_u8);
+
+    assert_err(
+        update_config(
+            &mut app,
+            &cw_member_contract_addr,
+            &admin_addr,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Uint128::zero()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ),
+        ContractError::SigmoidMaxTradeAmountCannotBeZero {},
+    );
+    update_config(
+        &mut app,
+        &cw_member_contract_addr,
+        &admin_addr,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(uint_128_amount_5),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+
+    register_user(&mut app, &cw_member_contract_addr, &user_1_addr).unwrap();
+    register_user(&mut app, &cw_member_contract_addr, &user_2_addr).unwrap();
+    link_social_media(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+        SOCIAL_MEDIA_HANDLE_1,
+    )
+    .unwrap();
+    enable_membership_with_bonding_curve(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+        BondingCurve::Sigmoid {
+            max_price: Uint128::from(1_000_000_u64),
+            inflection_supply: Uint128::from(10_u8),
+        },
+    )
+    .unwrap();
+    get_fund_from_faucet(&mut app, user_2_addr.clone(), Uint128::from(10_000_000_u64));
+
+    // User 2 cannot buy more than the max trade amount in one trade
+    assert_err(
+        app.execute_contract(
+            user_2_addr.clone(),
+            cw_member_contract_addr.clone(),
+            &ExecuteMsg::BuyMembership(BuyMembershipMsg {
+                membership_issuer_user_id: user_1_id,
+                amount: uint_128_amount_6,
+                max_total_cost: None,
+            }),
+            &[Coin {
+                denom: FEE_DENOM.to_string(),
+                amount: Uint128::from(5_000_000_u64),
+            }],
+        ),
+        ContractError::ExceedSigmoidMaxTradeAmount {
+            amount: uint_128_amount_6,
+            max_amount: uint_128_amount_5,
+        },
+    );
+
+    // User 2 can buy more than the max trade amount in several trades
+    for _ in 0..2 {
+        app.execute_contract(
+            user_2_addr.clone(),
+            cw_member_contract_addr.clone(),
+            &ExecuteMsg::BuyMembership(BuyMembershipMsg {
+                membership_issuer_user_id: user_1_id,
+                amount: uint_128_amount_5,
+                max_total_cost: None,
+            }),
+            &[Coin {
+                denom: FEE_DENOM.to_string(),
+                amount: Uint128::from(5_000_000_u64),
+            }],
+        )
+        .unwrap();
+    }
+
+    // Selling is capped the same way
+    assert_err(
+        app.execute_contract(
+            user_2_addr.clone(),
+            cw_member_contract_addr.clone(),
+            &ExecuteMsg::SellMembership(SellMembershipMsg {
+                membership_issuer_user_id: user_1_id,
+                amount: uint_128_amount_6,
+                min_proceeds: None,
+                deduct_fees_from_proceeds: Some(true),
+            }),
+            &[],
+        ),
+        ContractError::ExceedSigmoidMaxTradeAmount {
+            amount: uint_128_amount_6,
+            max_amount: uint_128_amount_5,
+        },
+    );
+    app.execute_contract(
+        user_2_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::SellMembership(SellMembershipMsg {
+            membership_issuer_user_id: user_1_id,
+            amount: uint_128_amount_5,
+            min_proceeds: None,
+            deduct_fees_from_proceeds: Some(true),
+        }),
+        &[],
+    )
+    .unwrap();
+}
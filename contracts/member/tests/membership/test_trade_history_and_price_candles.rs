@@ -0,0 +1,199 @@
+use cosmwasm_std::{Coin, Timestamp, Uint128, Uint64};
+use cw_multi_test::Executor;
+
+use member_pkg::{
+    msg::{
+        BuyMembershipMsg, CostToBuyMembershipResponse, ExecuteMsg, PriceCandlesResponse,
+        QueryCostToBuyMembershipMsg, QueryMsg, QueryPriceCandlesMsg, QueryTradeHistoryMsg,
+        SellMembershipMsg, TradeHistoryResponse,
+    },
+    trade::CandleInterval,
+};
+use shared_pkg::event::TradeSide;
+
+use crate::helpers::{
+    enable_membership, get_fund_from_faucet, link_social_media, proper_instantiate, register_user,
+    FEE_DENOM, SOCIAL_MEDIA_HANDLE_1,
+};
+
+#[test]
+fn test_trade_history_and_price_candles() {
+    let (mut app, cw_member_contract_addr, _, registration_admin_addr, _, user_1_addr, user_2_addr) =
+        proper_instantiate();
+
+    let uint_128_amount_5 = Uint128::from(5_u8);
+    let uint_128_amount_10 = Uint128::from(10_u8);
+
+    register_user(&mut app, &cw_member_contract_addr, &user_1_addr).unwrap();
+    register_user(&mut app, &cw_member_contract_addr, &user_2_addr).unwrap();
+    let user_1_id = Uint64::one();
+    let user_2_id = Uint64::from(2_u8);
+    link_social_media(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+        SOCIAL_MEDIA_HANDLE_1,
+    )
+    .unwrap();
+    enable_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+    )
+    .unwrap();
+
+    // Move to the start of an hour so both buys land in the same candle
+    app.update_block(|block| {
+        block.time = Timestamp::from_seconds((block.time.seconds() / 3_600 + 1) * 3_600);
+    });
+    let first_candle_start_time = app.block_info().time;
+
+    get_fund_from_faucet(&mut app, user_2_addr.clone(), Uint128::from(1_000_000_u32));
+    for _ in 0..2 {
+        let query_user_2_simulate_buy_membership_res: CostToBuyMembershipResponse = app
+            .wrap()
+            .query_wasm_smart(
+                cw_member_contract_addr.clone(),
+                &QueryMsg::QueryCostToBuyMembership(QueryCostToBuyMembershipMsg {
+                    membership_issuer_user_id: user_1_id,
+                    amount: uint_128_amount_10,
+                }),
+            )
+            .unwrap();
+        app.execute_contract(
+            user_2_addr.clone(),
+            cw_member_contract_addr.clone(),
+            &ExecuteMsg::BuyMembership(BuyMembershipMsg {
+                membership_issuer_user_id: user_1_id,
+                amount: uint_128_amount_10,
+                max_total_cost: None,
+            }),
+            &[Coin {
+                denom: FEE_DENOM.to_string(),
+                amount: query_user_2_simulate_buy_membership_res.total_needed_from_user,
+            }],
+        )
+        .unwrap();
+        app.update_block(|block| {
+            block.height += 1;
+            block.time = block.time.plus_seconds(5);
+        });
+    }
+
+    // Sell in the next hour
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(3_600);
+    });
+    let second_candle_start_time = first_candle_start_time.plus_seconds(3_600);
+    app.execute_contract(
+        user_2_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::SellMembership(SellMembershipMsg {
+            membership_issuer_user_id: user_1_id,
+            amount: uint_128_amount_5,
+            min_proceeds: None,
+            deduct_fees_from_proceeds: Some(true),
+        }),
+        &[],
+    )
+    .unwrap();
+
+    let trade_history_res: TradeHistoryResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryTradeHistory(QueryTradeHistoryMsg {
+                membership_issuer_user_id: user_1_id,
+                start_after_trade_id: None,
+                limit: None,
+                include_start_after: None,
+            }),
+        )
+        .unwrap();
+    assert_eq!(trade_history_res.count, 3);
+    assert_eq!(trade_history_res.total_count, 3);
+    let trades = trade_history_res.trades;
+    assert_eq!(
+        trades.iter().map(|trade| trade.id).collect::<Vec<Uint64>>(),
+        vec![Uint64::one(), Uint64::from(2_u8), Uint64::from(3_u8)]
+    );
+    assert_eq!(
+        trades
+            .iter()
+            .map(|trade| trade.side.clone())
+            .collect::<Vec<TradeSide>>(),
+        vec![TradeSide::Buy, TradeSide::Buy, TradeSide::Sell]
+    );
+    assert!(trades.iter().all(|trade| trade.trader_user_id == user_2_id));
+    assert_eq!(trades[2].amount, uint_128_amount_5);
+    assert_eq!(trades[0].timestamp, first_candle_start_time);
+    assert!(trades[1].unit_price() > trades[0].unit_price());
+
+    // Paginate from trade 1
+    let paginated_trade_history_res: TradeHistoryResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryTradeHistory(QueryTradeHistoryMsg {
+                membership_issuer_user_id: user_1_id,
+                start_after_trade_id: Some(Uint64::one()),
+                limit: Some(1),
+                include_start_after: None,
+            }),
+        )
+        .unwrap();
+    assert_eq!(paginated_trade_history_res.count, 1);
+    assert_eq!(paginated_trade_history_res.total_count, 3);
+    assert_eq!(paginated_trade_history_res.trades[0], trades[1]);
+
+    let price_candles_res: PriceCandlesResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryPriceCandles(QueryPriceCandlesMsg {
+                membership_issuer_user_id: user_1_id,
+                interval: None,
+                start_after_start_time: None,
+                limit: None,
+                include_start_after: None,
+            }),
+        )
+        .unwrap();
+    assert_eq!(price_candles_res.interval, CandleInterval::Hourly);
+    assert_eq!(price_candles_res.count, 2);
+
+    let first_candle = &price_candles_res.candles[0];
+    assert_eq!(first_candle.start_time, first_candle_start_time);
+    assert_eq!(first_candle.open, trades[0].unit_price());
+    assert_eq!(first_candle.low, trades[0].unit_price());
+    assert_eq!(first_candle.high, trades[1].unit_price());
+    assert_eq!(first_candle.close, trades[1].unit_price());
+    assert_eq!(first_candle.volume, Uint128::from(20_u8));
+    assert_eq!(first_candle.trade_count, Uint64::from(2_u8));
+
+    let second_candle = &price_candles_res.candles[1];
+    assert_eq!(second_candle.start_time, second_candle_start_time);
+    assert_eq!(second_candle.open, trades[2].unit_price());
+    assert_eq!(second_candle.close, trades[2].unit_price());
+    assert_eq!(second_candle.volume, uint_128_amount_5);
+    assert_eq!(second_candle.trade_count, Uint64::one());
+
+    // Nothing was recorded for daily interval since config uses hourly
+    let daily_price_candles_res: PriceCandlesResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr,
+            &QueryMsg::QueryPriceCandles(QueryPriceCandlesMsg {
+                membership_issuer_user_id: user_1_id,
+                interval: Some(CandleInterval::Daily),
+                start_after_start_time: None,
+                limit: None,
+                include_start_after: None,
+            }),
+        )
+        .unwrap();
+    assert_eq!(daily_price_candles_res.count, 0);
+}
@@ -1 +1,4 @@
+mod test_link_multiple_social_media_platforms;
 mod test_registration_admin_can_enable_membership_on_behalf_of_user;
+mod test_registration_admin_can_register_on_behalf_of_user;
+mod test_social_media_handle_is_unique_and_searchable;
@@ -0,0 +1,261 @@
+use anyhow::Result as AnyResult;
+use cosmwasm_std::{Addr, Uint64};
+use cw_multi_test::{App, AppResponse, Executor};
+
+use member::ContractError;
+use member_pkg::{
+    config::MembershipEligibility,
+    msg::{
+        AddToSocialMediaPlatformAllowlistMsg, ExecuteMsg, LinkSocialMediaMsg, QueryMsg,
+        QueryUserByIDMsg, QueryUserBySocialMediaHandleMsg,
+        RemoveFromSocialMediaPlatformAllowlistMsg, UserResponse,
+    },
+};
+
+use crate::helpers::{
+    assert_err, enable_membership, link_social_media, proper_instantiate, register_user,
+    unlink_social_media, update_config, SOCIAL_MEDIA_HANDLE_1, SOCIAL_MEDIA_PLATFORM,
+};
+
+fn link_social_media_on_platform(
+    app: &mut App,
+    cw_member_contract_addr: &Addr,
+    registration_admin_addr: &Addr,
+    user_id: Uint64,
+    platform: &str,
+    social_media_handle: &str,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        registration_admin_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::LinkSocialMedia(LinkSocialMediaMsg {
+            user_id,
+            platform: platform.to_string(),
+            social_media_handle: social_media_handle.to_string(),
+        }),
+        &[],
+    )
+}
+
+#[test]
+fn test_link_multiple_social_media_platforms() {
+    let (
+        mut app,
+        cw_member_contract_addr,
+        admin_addr,
+        registration_admin_addr,
+        _,
+        user_1_addr,
+        user_2_addr,
+    ) = proper_instantiate();
+
+    register_user(&mut app, &cw_member_contract_addr, &user_1_addr).unwrap();
+    register_user(&mut app, &cw_member_contract_addr, &user_2_addr).unwrap();
+    let user_1_id = Uint64::one();
+    let user_2_id = Uint64::from(2_u8);
+
+    link_social_media(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+        SOCIAL_MEDIA_HANDLE_1,
+    )
+    .unwrap();
+    // Platform is normalized
+    link_social_media_on_platform(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+        "GitHub",
+        "github1",
+    )
+    .unwrap();
+    assert_err(
+        link_social_media_on_platform(
+            &mut app,
+            &cw_member_contract_addr,
+            &registration_admin_addr,
+            user_1_id,
+            "myspace",
+            "myspace1",
+        ),
+        ContractError::SocialMediaPlatformNotInAllowlist {
+            platform: "myspace".to_string(),
+        },
+    );
+    assert_err(
+        link_social_media_on_platform(
+            &mut app,
+            &cw_member_contract_addr,
+            &registration_admin_addr,
+            user_1_id,
+            SOCIAL_MEDIA_PLATFORM,
+            "another_twitter",
+        ),
+        ContractError::UserAlreadyLinkedSocialMedia {},
+    );
+
+    let query_user_1_res: UserResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryUserByID(QueryUserByIDMsg { user_id: user_1_id }),
+        )
+        .unwrap();
+    assert_eq!(
+        query_user_1_res
+            .user
+            .social_links
+            .iter()
+            .map(|social_link| social_link.platform.as_str())
+            .collect::<Vec<&str>>(),
+        vec![SOCIAL_MEDIA_PLATFORM, "github"]
+    );
+
+    // Admin requires farcaster for membership
+    update_config(
+        &mut app,
+        &cw_member_contract_addr,
+        &admin_addr,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(MembershipEligibility::SpecificPlatform {
+            platform: "farcaster".to_string(),
+        }),
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+    assert_err(
+        enable_membership(
+            &mut app,
+            &cw_member_contract_addr,
+            &registration_admin_addr,
+            user_1_id,
+        ),
+        ContractError::UserCannotRegisterMembershipBeforeLinkingSocialMediaPlatform {
+            platform: "farcaster".to_string(),
+        },
+    );
+    assert_err(
+        app.execute_contract(
+            admin_addr.clone(),
+            cw_member_contract_addr.clone(),
+            &ExecuteMsg::RemoveFromSocialMediaPlatformAllowlist(
+                RemoveFromSocialMediaPlatformAllowlistMsg {
+                    platform: "farcaster".to_string(),
+                },
+            ),
+            &[],
+        ),
+        ContractError::CannotRemoveMembershipEligibilityPlatformFromAllowlist {},
+    );
+
+    link_social_media_on_platform(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+        "farcaster",
+        "farcaster1",
+    )
+    .unwrap();
+    enable_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+    )
+    .unwrap();
+
+    // Only registration admin or user itself can unlink
+    assert_err(
+        unlink_social_media(
+            &mut app,
+            &cw_member_contract_addr,
+            &user_2_addr,
+            user_1_id,
+            SOCIAL_MEDIA_PLATFORM,
+        ),
+        ContractError::OnlyRegistrationAdminOrUserCanUnlinkSocialMedia {},
+    );
+    unlink_social_media(
+        &mut app,
+        &cw_member_contract_addr,
+        &user_1_addr,
+        user_1_id,
+        SOCIAL_MEDIA_PLATFORM,
+    )
+    .unwrap();
+    assert_err(
+        unlink_social_media(
+            &mut app,
+            &cw_member_contract_addr,
+            &registration_admin_addr,
+            user_1_id,
+            SOCIAL_MEDIA_PLATFORM,
+        ),
+        ContractError::UserHasNotLinkedSocialMedia {
+            platform: SOCIAL_MEDIA_PLATFORM.to_string(),
+        },
+    );
+
+    // Unlinked handle can be linked to another user
+    link_social_media(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_2_id,
+        SOCIAL_MEDIA_HANDLE_1,
+    )
+    .unwrap();
+    let query_user_by_handle_res: UserResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryUserBySocialMediaHandle(QueryUserBySocialMediaHandleMsg {
+                platform: SOCIAL_MEDIA_PLATFORM.to_string(),
+                social_media_handle: SOCIAL_MEDIA_HANDLE_1.to_string(),
+            }),
+        )
+        .unwrap();
+    assert_eq!(query_user_by_handle_res.user.id, user_2_id);
+
+    app.execute_contract(
+        admin_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::AddToSocialMediaPlatformAllowlist(AddToSocialMediaPlatformAllowlistMsg {
+            platform: "bluesky".to_string(),
+        }),
+        &[],
+    )
+    .unwrap();
+    assert_err(
+        app.execute_contract(
+            admin_addr,
+            cw_member_contract_addr,
+            &ExecuteMsg::AddToSocialMediaPlatformAllowlist(AddToSocialMediaPlatformAllowlistMsg {
+                platform: "BlueSky".to_string(),
+            }),
+            &[],
+        ),
+        ContractError::SocialMediaPlatformAlreadyInAllowlist {
+            platform: "bluesky".to_string(),
+        },
+    );
+}
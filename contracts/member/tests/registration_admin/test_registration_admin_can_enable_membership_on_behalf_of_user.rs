@@ -2,12 +2,15 @@ use cosmwasm_std::{Uint128, Uint64};
 
 use member_pkg::{
     msg::{QueryMsg, QueryUserByAddrMsg, UserResponse},
-    user::{Member, Membership, MembershipIssuedByMe, User},
+    user::{Member, Membership, MembershipIssuedByMe, SocialLink, User, UserFeeConfig},
 };
 
+use shared_pkg::pricing::BondingCurve;
+
 use crate::helpers::{
     assert_member_count, assert_members, assert_membership_supply, assert_memberships,
-    enable_membership, link_social_media, proper_instantiate, register_user, SOCIAL_MEDIA_HANDLE_1,
+    enable_membership, link_social_media, proper_instantiate, register_user, FEE_DENOM,
+    SOCIAL_MEDIA_HANDLE_1, SOCIAL_MEDIA_PLATFORM,
 };
 
 #[test]
@@ -48,15 +51,26 @@ fn test_registration_admin_can_enable_membership_on_behalf_of_user() {
             user: User {
                 id: user_1_id,
                 addr: user_1_addr.clone(),
-                social_media_handle: Some(SOCIAL_MEDIA_HANDLE_1.to_string()),
+                hot_addr: None,
+                social_links: vec![SocialLink {
+                    platform: SOCIAL_MEDIA_PLATFORM.to_string(),
+                    handle: SOCIAL_MEDIA_HANDLE_1.to_string(),
+                    linked_at: app.block_info().time,
+                }],
                 membership_issued_by_me: Some(MembershipIssuedByMe {
                     membership_supply: Uint128::one(),
-                    member_count: Uint128::one()
+                    member_count: Uint128::one(),
+                    bonding_curve: BondingCurve::default(),
+                    fee_denom: FEE_DENOM.to_string(),
                 }),
-                fee_config: None,
+                fee_config: UserFeeConfig::default(),
                 fee_share_config: None,
-                user_member_count: Uint128::one()
-            }
+                user_member_count: Uint128::one(),
+                referrer_user_id: None,
+                referee_count: Uint128::zero(),
+                only_verified_buyers: false,
+            },
+            pending_fee_change: None,
         }
     );
 
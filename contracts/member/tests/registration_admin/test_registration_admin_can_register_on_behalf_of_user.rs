@@ -0,0 +1,73 @@
+use cosmwasm_std::Uint64;
+use cw_multi_test::Executor;
+
+use member::ContractError;
+use member_pkg::msg::{
+    DisableOpenRegistrationMsg, ExecuteMsg, QueryMsg, QueryUserByAddrMsg, RegisterMsg, UserResponse,
+};
+
+use crate::helpers::{assert_err, proper_instantiate, register_user};
+
+#[test]
+fn test_registration_admin_can_register_on_behalf_of_user() {
+    let (
+        mut app,
+        cw_member_contract_addr,
+        admin_addr,
+        registration_admin_addr,
+        _,
+        user_1_addr,
+        user_2_addr,
+    ) = proper_instantiate();
+
+    app.execute_contract(
+        admin_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::DisableOpenRegistration(DisableOpenRegistrationMsg {}),
+        &[],
+    )
+    .unwrap();
+
+    // User cannot register itself when open registration is disabled
+    assert_err(
+        register_user(&mut app, &cw_member_contract_addr, &user_1_addr),
+        ContractError::OpenRegistrationDisabled {},
+    );
+
+    // Only registration admin can register on behalf of user
+    assert_err(
+        app.execute_contract(
+            user_2_addr.clone(),
+            cw_member_contract_addr.clone(),
+            &ExecuteMsg::Register(RegisterMsg {
+                referrer_user_id: None,
+                user_addr: Some(user_1_addr.to_string()),
+            }),
+            &[],
+        ),
+        ContractError::OnlyRegistrationAdminCanRegisterOnBehalfOfUser {},
+    );
+
+    app.execute_contract(
+        registration_admin_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::Register(RegisterMsg {
+            referrer_user_id: None,
+            user_addr: Some(user_1_addr.to_string()),
+        }),
+        &[],
+    )
+    .unwrap();
+
+    let query_user_1_res: UserResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryUserByAddr(QueryUserByAddrMsg {
+                user_addr: user_1_addr.to_string(),
+            }),
+        )
+        .unwrap();
+    assert_eq!(query_user_1_res.user.id, Uint64::one());
+    assert_eq!(query_user_1_res.user.addr, user_1_addr);
+}
@@ -0,0 +1,166 @@
+use cosmwasm_std::{Addr, Uint64};
+
+use member::ContractError;
+use member_pkg::msg::{
+    QueryMsg, QueryUserBySocialMediaHandleMsg, QueryUsersBySocialMediaHandlePrefixMsg,
+    UserResponse, UsersBySocialMediaHandlePrefixResponse,
+};
+
+use crate::helpers::{
+    assert_err, link_social_media, proper_instantiate, register_user, SOCIAL_MEDIA_HANDLE_1,
+    SOCIAL_MEDIA_HANDLE_2, SOCIAL_MEDIA_PLATFORM, USER_3,
+};
+
+#[test]
+fn test_social_media_handle_is_unique_and_searchable() {
+    let (mut app, cw_member_contract_addr, _, registration_admin_addr, _, user_1_addr, user_2_addr) =
+        proper_instantiate();
+    let user_3_addr = Addr::unchecked(USER_3);
+
+    register_user(&mut app, &cw_member_contract_addr, &user_1_addr).unwrap();
+    register_user(&mut app, &cw_member_contract_addr, &user_2_addr).unwrap();
+    register_user(&mut app, &cw_member_contract_addr, &user_3_addr).unwrap();
+    let user_1_id = Uint64::one();
+    let user_2_id = Uint64::from(2_u8);
+    let user_3_id = Uint64::from(3_u8);
+
+    link_social_media(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+        SOCIAL_MEDIA_HANDLE_1,
+    )
+    .unwrap();
+
+    // Same handle in different case and with leading @ is still the same handle
+    assert_err(
+        link_social_media(
+            &mut app,
+            &cw_member_contract_addr,
+            &registration_admin_addr,
+            user_2_id,
+            &format!("@{}", SOCIAL_MEDIA_HANDLE_1.to_uppercase()),
+        ),
+        ContractError::SocialMediaHandleAlreadyLinked {},
+    );
+    assert_err(
+        link_social_media(
+            &mut app,
+            &cw_member_contract_addr,
+            &registration_admin_addr,
+            user_2_id,
+            " @ ",
+        ),
+        ContractError::SocialMediaHandleCannotBeEmpty {},
+    );
+
+    link_social_media(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_2_id,
+        SOCIAL_MEDIA_HANDLE_2,
+    )
+    .unwrap();
+
+    let query_user_res: UserResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryUserBySocialMediaHandle(QueryUserBySocialMediaHandleMsg {
+                platform: SOCIAL_MEDIA_PLATFORM.to_string(),
+                social_media_handle: format!("@{}", SOCIAL_MEDIA_HANDLE_1.to_uppercase()),
+            }),
+        )
+        .unwrap();
+    assert_eq!(query_user_res.user.id, user_1_id);
+
+    // User 3 has not linked social media
+    let query_missing_user_res: Result<UserResponse, _> = app.wrap().query_wasm_smart(
+        cw_member_contract_addr.clone(),
+        &QueryMsg::QueryUserBySocialMediaHandle(QueryUserBySocialMediaHandleMsg {
+            platform: SOCIAL_MEDIA_PLATFORM.to_string(),
+            social_media_handle: format!("@{}", user_3_id),
+        }),
+    );
+    assert!(query_missing_user_res.is_err());
+
+    let query_prefix_res: UsersBySocialMediaHandlePrefixResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryUsersBySocialMediaHandlePrefix(
+                QueryUsersBySocialMediaHandlePrefixMsg {
+                    platform: SOCIAL_MEDIA_PLATFORM.to_string(),
+                    prefix: "@TWIT".to_string(),
+                    start_after_social_media_handle: None,
+                    limit: Some(1),
+                    include_start_after: None,
+                },
+            ),
+        )
+        .unwrap();
+    assert_eq!(query_prefix_res.count, 1);
+    assert_eq!(query_prefix_res.users[0].id, user_1_id);
+
+    let query_prefix_next_page_res: UsersBySocialMediaHandlePrefixResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryUsersBySocialMediaHandlePrefix(
+                QueryUsersBySocialMediaHandlePrefixMsg {
+                    platform: SOCIAL_MEDIA_PLATFORM.to_string(),
+                    prefix: "@TWIT".to_string(),
+                    start_after_social_media_handle: Some(SOCIAL_MEDIA_HANDLE_1.to_string()),
+                    limit: None,
+                    include_start_after: None,
+                },
+            ),
+        )
+        .unwrap();
+    assert_eq!(query_prefix_next_page_res.count, 1);
+    assert_eq!(query_prefix_next_page_res.users[0].id, user_2_id);
+
+    // Empty prefix matches every linked user, user 3 is skipped
+    let query_empty_prefix_res: UsersBySocialMediaHandlePrefixResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryUsersBySocialMediaHandlePrefix(
+                QueryUsersBySocialMediaHandlePrefixMsg {
+                    platform: SOCIAL_MEDIA_PLATFORM.to_string(),
+                    prefix: "".to_string(),
+                    start_after_social_media_handle: None,
+                    limit: None,
+                    include_start_after: None,
+                },
+            ),
+        )
+        .unwrap();
+    assert_eq!(
+        query_empty_prefix_res
+            .users
+            .iter()
+            .map(|user| user.id)
+            .collect::<Vec<Uint64>>(),
+        vec![user_1_id, user_2_id]
+    );
+
+    let query_no_match_res: UsersBySocialMediaHandlePrefixResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr,
+            &QueryMsg::QueryUsersBySocialMediaHandlePrefix(
+                QueryUsersBySocialMediaHandlePrefixMsg {
+                    platform: SOCIAL_MEDIA_PLATFORM.to_string(),
+                    prefix: "twitter3".to_string(),
+                    start_after_social_media_handle: None,
+                    limit: None,
+                    include_start_after: None,
+                },
+            ),
+        )
+        .unwrap();
+    assert_eq!(query_no_match_res.count, 0);
+}
@@ -1,2 +1,10 @@
+mod test_hot_addr_cannot_trade_membership;
+mod test_only_proposed_address_can_accept_user_migration;
+mod test_query_users_by_ids_and_addrs;
+mod test_referrer_earns_referral_fee;
+mod test_referrer_earns_referral_fee_per_denom;
+mod test_user_can_link_social_media_with_attestation;
+mod test_user_can_migrate_to_new_address;
 mod test_user_can_register_itself;
+mod test_user_can_set_and_remove_hot_addr;
 mod test_user_cannot_enable_membership_by_itself;
@@ -0,0 +1,159 @@
+use cosmwasm_std::{Addr, Coin, Uint128, Uint64};
+use cw_multi_test::Executor;
+
+use member::ContractError;
+use member_pkg::msg::{
+    BuyMembershipMsg, CostToBuyMembershipResponse, ExecuteMsg, QueryCostToBuyMembershipMsg,
+    QueryMsg, SellMembershipMsg, UpdateUserConfigMsg,
+};
+
+use crate::helpers::{
+    assert_balance, assert_err, claim_reward, enable_membership, get_distribution_contract_addr,
+    get_fund_from_faucet, link_social_media, proper_instantiate, register_user, set_hot_addr,
+    FEE_DENOM, SOCIAL_MEDIA_HANDLE_1, USER_1_HOT,
+};
+
+#[test]
+fn test_hot_addr_cannot_trade_membership() {
+    let (mut app, cw_member_contract_addr, _, registration_admin_addr, _, user_1_addr, _) =
+        proper_instantiate();
+    let cw_distribution_contract_addr =
+        get_distribution_contract_addr(&app, &cw_member_contract_addr);
+    let user_1_hot_addr = Addr::unchecked(USER_1_HOT);
+    let user_1_id = Uint64::one();
+    let uint_128_amount_10 = Uint128::from(10_u8);
+
+    register_user(&mut app, &cw_member_contract_addr, &user_1_addr).unwrap();
+    link_social_media(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+        SOCIAL_MEDIA_HANDLE_1,
+    )
+    .unwrap();
+    enable_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+    )
+    .unwrap();
+    set_hot_addr(
+        &mut app,
+        &cw_member_contract_addr,
+        &user_1_addr,
+        &user_1_hot_addr,
+    )
+    .unwrap();
+
+    let query_simulate_buy_membership_res: CostToBuyMembershipResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryCostToBuyMembership(QueryCostToBuyMembershipMsg {
+                membership_issuer_user_id: user_1_id,
+                amount: uint_128_amount_10,
+            }),
+        )
+        .unwrap();
+    get_fund_from_faucet(
+        &mut app,
+        user_1_hot_addr.clone(),
+        query_simulate_buy_membership_res.total_needed_from_user,
+    );
+    get_fund_from_faucet(
+        &mut app,
+        user_1_addr.clone(),
+        query_simulate_buy_membership_res.total_needed_from_user,
+    );
+
+    assert_err(
+        app.execute_contract(
+            user_1_hot_addr.clone(),
+            cw_member_contract_addr.clone(),
+            &ExecuteMsg::BuyMembership(BuyMembershipMsg {
+                membership_issuer_user_id: user_1_id,
+                amount: uint_128_amount_10,
+                max_total_cost: None,
+            }),
+            &[Coin {
+                denom: FEE_DENOM.to_string(),
+                amount: query_simulate_buy_membership_res.total_needed_from_user,
+            }],
+        ),
+        ContractError::OnlyColdAddrAllowed {},
+    );
+    assert_err(
+        app.execute_contract(
+            user_1_hot_addr.clone(),
+            cw_member_contract_addr.clone(),
+            &ExecuteMsg::SellMembership(SellMembershipMsg {
+                membership_issuer_user_id: user_1_id,
+                amount: Uint128::one(),
+                min_proceeds: None,
+                deduct_fees_from_proceeds: None,
+            }),
+            &[Coin {
+                denom: FEE_DENOM.to_string(),
+                amount: Uint128::one(),
+            }],
+        ),
+        ContractError::OnlyColdAddrAllowed {},
+    );
+    assert_err(
+        app.execute_contract(
+            user_1_hot_addr.clone(),
+            cw_member_contract_addr.clone(),
+            &ExecuteMsg::UpdateUserConfig(UpdateUserConfigMsg {
+                user_id: user_1_id,
+                trading_fee_bps_of_membership: None,
+                share_to_issuer_bps: None,
+                share_to_all_members_bps: None,
+                holding_cap: None,
+                only_verified_buyers: None,
+            }),
+            &[],
+        ),
+        ContractError::OnlyMembershipIssuerCanUpdateItsTradingFeePercentageOfMembership {},
+    );
+
+    // Cold address can still buy
+    app.execute_contract(
+        user_1_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::BuyMembership(BuyMembershipMsg {
+            membership_issuer_user_id: user_1_id,
+            amount: uint_128_amount_10,
+            max_total_cost: None,
+        }),
+        &[Coin {
+            denom: FEE_DENOM.to_string(),
+            amount: query_simulate_buy_membership_res.total_needed_from_user,
+        }],
+    )
+    .unwrap();
+
+    // Hot address can claim reward, reward goes to cold address
+    claim_reward(
+        &mut app,
+        &cw_distribution_contract_addr,
+        &user_1_hot_addr,
+        user_1_id,
+        user_1_id,
+    )
+    .unwrap();
+    assert_balance(
+        &app,
+        &user_1_addr,
+        query_simulate_buy_membership_res.issuer_fee
+            + query_simulate_buy_membership_res.all_members_fee,
+        FEE_DENOM,
+    );
+    assert_balance(
+        &app,
+        &user_1_hot_addr,
+        query_simulate_buy_membership_res.total_needed_from_user,
+        FEE_DENOM,
+    );
+}
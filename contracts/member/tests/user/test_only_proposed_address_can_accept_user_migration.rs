@@ -0,0 +1,89 @@
+use cosmwasm_std::{Addr, Uint64};
+
+use member::ContractError;
+
+use crate::helpers::{
+    accept_migrate_user, assert_err, migrate_user, proper_instantiate, register_user, USER_3,
+};
+
+#[test]
+fn test_only_proposed_address_can_accept_user_migration() {
+    let (mut app, cw_member_contract_addr, _, _, _, user_1_addr, user_2_addr) =
+        proper_instantiate();
+    let new_user_1_addr = Addr::unchecked(USER_3);
+    let user_1_id = Uint64::one();
+
+    register_user(&mut app, &cw_member_contract_addr, &user_1_addr).unwrap();
+
+    assert_err(
+        accept_migrate_user(
+            &mut app,
+            &cw_member_contract_addr,
+            &new_user_1_addr,
+            user_1_id,
+        ),
+        ContractError::UserHasNoPendingMigration {},
+    );
+
+    // Unregistered address has no user to migrate
+    assert_err(
+        migrate_user(
+            &mut app,
+            &cw_member_contract_addr,
+            &user_2_addr,
+            &new_user_1_addr,
+        ),
+        ContractError::UserNotExist {},
+    );
+
+    assert_err(
+        migrate_user(
+            &mut app,
+            &cw_member_contract_addr,
+            &user_1_addr,
+            &user_1_addr,
+        ),
+        ContractError::CannotMigrateUserToItsCurrentAddress {},
+    );
+
+    migrate_user(
+        &mut app,
+        &cw_member_contract_addr,
+        &user_1_addr,
+        &new_user_1_addr,
+    )
+    .unwrap();
+
+    assert_err(
+        accept_migrate_user(&mut app, &cw_member_contract_addr, &user_1_addr, user_1_id),
+        ContractError::OnlyProposedNewAddressCanAcceptUserMigration {},
+    );
+    assert_err(
+        accept_migrate_user(&mut app, &cw_member_contract_addr, &user_2_addr, user_1_id),
+        ContractError::OnlyProposedNewAddressCanAcceptUserMigration {},
+    );
+
+    // Proposed address registers itself before accepting, so it can no longer take over user 1
+    register_user(&mut app, &cw_member_contract_addr, &new_user_1_addr).unwrap();
+    assert_err(
+        accept_migrate_user(
+            &mut app,
+            &cw_member_contract_addr,
+            &new_user_1_addr,
+            user_1_id,
+        ),
+        ContractError::AddressAlreadyRegistered {},
+    );
+
+    // Cannot propose migrating to an address that is already registered
+    register_user(&mut app, &cw_member_contract_addr, &user_2_addr).unwrap();
+    assert_err(
+        migrate_user(
+            &mut app,
+            &cw_member_contract_addr,
+            &user_1_addr,
+            &user_2_addr,
+        ),
+        ContractError::AddressAlreadyRegistered {},
+    );
+}
@@ -0,0 +1,91 @@
+use cosmwasm_std::{Addr, StdResult, Uint64};
+
+use member_pkg::{
+    msg::{
+        QueryMsg, QueryUserByIDMsg, QueryUsersByAddrsMsg, QueryUsersByIDsMsg, UserResponse,
+        UsersByAddrsResponse, UsersByIDsResponse,
+    },
+    user::User,
+};
+
+use crate::helpers::{proper_instantiate, register_user, set_hot_addr, USER_1_HOT, USER_3};
+
+#[test]
+fn test_query_users_by_ids_and_addrs() {
+    let (mut app, cw_member_contract_addr, _, _, _, user_1_addr, user_2_addr) =
+        proper_instantiate();
+    let user_1_hot_addr = Addr::unchecked(USER_1_HOT);
+    let unregistered_addr = Addr::unchecked(USER_3);
+
+    register_user(&mut app, &cw_member_contract_addr, &user_1_addr).unwrap();
+    register_user(&mut app, &cw_member_contract_addr, &user_2_addr).unwrap();
+    set_hot_addr(
+        &mut app,
+        &cw_member_contract_addr,
+        &user_1_addr,
+        &user_1_hot_addr,
+    )
+    .unwrap();
+
+    // Users come back in the same order as requested, None for IDs without a user
+    let users_by_ids: UsersByIDsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &cw_member_contract_addr,
+            &QueryMsg::QueryUsersByIDs(QueryUsersByIDsMsg {
+                user_ids: vec![Uint64::from(2_u8), Uint64::from(99_u8), Uint64::one()],
+            }),
+        )
+        .unwrap();
+    assert_eq!(
+        user_addrs(users_by_ids.users),
+        vec![Some(user_2_addr.clone()), None, Some(user_1_addr.clone())]
+    );
+
+    // Hot address resolves to its user
+    let users_by_addrs: UsersByAddrsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &cw_member_contract_addr,
+            &QueryMsg::QueryUsersByAddrs(QueryUsersByAddrsMsg {
+                user_addrs: vec![
+                    user_1_hot_addr.to_string(),
+                    unregistered_addr.to_string(),
+                    user_2_addr.to_string(),
+                ],
+            }),
+        )
+        .unwrap();
+    assert_eq!(
+        user_addrs(users_by_addrs.users),
+        vec![Some(user_1_addr), None, Some(user_2_addr)]
+    );
+
+    // Batch size is capped
+    let res: StdResult<UsersByIDsResponse> = app.wrap().query_wasm_smart(
+        &cw_member_contract_addr,
+        &QueryMsg::QueryUsersByIDs(QueryUsersByIDsMsg {
+            user_ids: (1..=26_u64).map(Uint64::from).collect(),
+        }),
+    );
+    assert!(res
+        .unwrap_err()
+        .to_string()
+        .contains("Exceed query limit: given 26, limit 25"));
+
+    // Missing user is a readable error instead of a panic
+    let res: StdResult<UserResponse> = app.wrap().query_wasm_smart(
+        &cw_member_contract_addr,
+        &QueryMsg::QueryUserByID(QueryUserByIDMsg {
+            user_id: Uint64::from(99_u8),
+        }),
+    );
+    assert!(res.unwrap_err().to_string().contains("User not found"));
+}
+
+fn user_addrs(users: Vec<Option<User>>) -> Vec<Option<Addr>> {
+    users
+        .into_iter()
+        .map(|user| user.map(|user| user.addr))
+        .collect()
+}
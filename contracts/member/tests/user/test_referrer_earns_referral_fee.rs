@@ -0,0 +1,191 @@
+use cosmwasm_std::{Addr, Coin, Uint128, Uint64};
+use cw_multi_test::Executor;
+
+use member::ContractError;
+use member_pkg::{
+    msg::{
+        BuyMembershipMsg, CostToBuyMembershipResponse, ExecuteMsg, QueryCostToBuyMembershipMsg,
+        QueryMsg, QueryRefereesMsg, QueryUserByIDMsg, RefereesResponse, UserResponse,
+    },
+    user::Referee,
+};
+
+use crate::helpers::{
+    assert_balance, assert_err, enable_membership, get_fund_from_faucet, link_social_media,
+    proper_instantiate, register_user, register_user_with_referrer, update_config, FEE_DENOM,
+    SOCIAL_MEDIA_HANDLE_1, USER_3,
+};
+
+#[test]
+fn test_referrer_earns_referral_fee() {
+    let (
+        mut app,
+        cw_member_contract_addr,
+        admin_addr,
+        registration_admin_addr,
+        protocol_fee_collector_addr,
+        user_1_addr,
+        user_2_addr,
+    ) = proper_instantiate();
+    let user_3_addr = Addr::unchecked(USER_3);
+    let user_1_id = Uint64::one();
+    let user_3_id = Uint64::from(2_u8);
+    let user_2_id = Uint64::from(3_u8);
+    let uint_128_amount_10 = Uint128::from(10_u8);
+
+    // Half of protocol fee goes to referrer
+    update_config(
+        &mut app,
+        &cw_member_contract_addr,
+        &admin_addr,
+        None,
+        None,
+        None,
+        None,
+        Some(Uint64::from(5_000_u64)),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+
+    register_user(&mut app, &cw_member_contract_addr, &user_1_addr).unwrap();
+    link_social_media(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+        SOCIAL_MEDIA_HANDLE_1,
+    )
+    .unwrap();
+    enable_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+    )
+    .unwrap();
+    register_user(&mut app, &cw_member_contract_addr, &user_3_addr).unwrap();
+
+    assert_err(
+        register_user_with_referrer(&mut app, &cw_member_contract_addr, &user_2_addr, 100),
+        ContractError::ReferrerNotExist {},
+    );
+    register_user_with_referrer(
+        &mut app,
+        &cw_member_contract_addr,
+        &user_2_addr,
+        user_3_id.u64(),
+    )
+    .unwrap();
+
+    let query_user_2_res: UserResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryUserByID(QueryUserByIDMsg { user_id: user_2_id }),
+        )
+        .unwrap();
+    assert_eq!(query_user_2_res.user.referrer_user_id, Some(user_3_id));
+    let query_user_3_res: UserResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryUserByID(QueryUserByIDMsg { user_id: user_3_id }),
+        )
+        .unwrap();
+    assert_eq!(query_user_3_res.user.referee_count, Uint128::one());
+
+    let query_user_2_simulate_buy_membership_res: CostToBuyMembershipResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryCostToBuyMembership(QueryCostToBuyMembershipMsg {
+                membership_issuer_user_id: user_1_id,
+                amount: uint_128_amount_10,
+            }),
+        )
+        .unwrap();
+    assert_eq!(
+        query_user_2_simulate_buy_membership_res,
+        CostToBuyMembershipResponse {
+            price: Uint128::from(24_062_u32),
+            protocol_fee: Uint128::from(60_u32),
+            referral_fee: Uint128::from(60_u32),
+            issuer_fee: Uint128::from(962_u32),
+            all_members_fee: Uint128::from(240_u32),
+            total_needed_from_user: Uint128::from(25_384_u32),
+            fee_denom: FEE_DENOM.to_string(),
+            holding_cap: None,
+        }
+    );
+    get_fund_from_faucet(
+        &mut app,
+        user_2_addr.clone(),
+        query_user_2_simulate_buy_membership_res.total_needed_from_user,
+    );
+    app.execute_contract(
+        user_2_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::BuyMembership(BuyMembershipMsg {
+            membership_issuer_user_id: user_1_id,
+            amount: uint_128_amount_10,
+            max_total_cost: None,
+        }),
+        &[Coin {
+            denom: FEE_DENOM.to_string(),
+            amount: query_user_2_simulate_buy_membership_res.total_needed_from_user,
+        }],
+    )
+    .unwrap();
+
+    assert_balance(
+        &app,
+        &user_3_addr,
+        query_user_2_simulate_buy_membership_res.referral_fee,
+        FEE_DENOM,
+    );
+    assert_balance(
+        &app,
+        &protocol_fee_collector_addr,
+        query_user_2_simulate_buy_membership_res.protocol_fee,
+        FEE_DENOM,
+    );
+
+    let query_referees_res: RefereesResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryReferees(QueryRefereesMsg {
+                referrer_user_id: user_3_id,
+                start_after_referee_user_id: None,
+                limit: None,
+                include_start_after: None,
+            }),
+        )
+        .unwrap();
+    assert_eq!(
+        query_referees_res,
+        RefereesResponse {
+            referees: vec![Referee {
+                referee_user_id: user_2_id,
+                earned: vec![Coin {
+                    denom: FEE_DENOM.to_string(),
+                    amount: query_user_2_simulate_buy_membership_res.referral_fee,
+                }],
+            }],
+            count: 1,
+            total_count: 1,
+        }
+    );
+}
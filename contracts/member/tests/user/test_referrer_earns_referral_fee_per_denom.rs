@@ -0,0 +1,182 @@
+use cosmwasm_std::{Addr, Coin, Uint128, Uint64};
+use cw_multi_test::Executor;
+
+use member_pkg::{
+    msg::{
+        BuyMembershipMsg, CostToBuyMembershipResponse, ExecuteMsg, QueryCostToBuyMembershipMsg,
+        QueryMsg, QueryRefereesMsg, RefereesResponse,
+    },
+    user::Referee,
+};
+
+use crate::helpers::{
+    add_to_fee_denom_allowlist, enable_membership, enable_membership_with_fee_denom,
+    get_fund_from_faucet_in_denom, link_social_media, proper_instantiate, register_user,
+    register_user_with_referrer, update_config, FEE_DENOM, FEE_DENOM_2, SOCIAL_MEDIA_HANDLE_1,
+    SOCIAL_MEDIA_HANDLE_2, USER_3,
+};
+
+#[test]
+fn test_referrer_earns_referral_fee_per_denom() {
+    let (
+        mut app,
+        cw_member_contract_addr,
+        admin_addr,
+        registration_admin_addr,
+        _,
+        user_1_addr,
+        user_2_addr,
+    ) = proper_instantiate();
+    let user_3_addr = Addr::unchecked(USER_3);
+    let user_1_id = Uint64::one();
+    let user_3_id = Uint64::from(2_u8);
+    let user_2_id = Uint64::from(3_u8);
+    let uint_128_amount_10 = Uint128::from(10_u8);
+
+    // Half of protocol fee goes to referrer
+    update_config(
+        &mut app,
+        &cw_member_contract_addr,
+        &admin_addr,
+        None,
+        None,
+        None,
+        None,
+        Some(Uint64::from(5_000_u64)),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+    add_to_fee_denom_allowlist(&mut app, &cw_member_contract_addr, &admin_addr, FEE_DENOM_2)
+        .unwrap();
+
+    // User 1 membership is priced in FEE_DENOM, user 3 membership is priced in FEE_DENOM_2
+    register_user(&mut app, &cw_member_contract_addr, &user_1_addr).unwrap();
+    link_social_media(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+        SOCIAL_MEDIA_HANDLE_1,
+    )
+    .unwrap();
+    enable_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+    )
+    .unwrap();
+    register_user(&mut app, &cw_member_contract_addr, &user_3_addr).unwrap();
+    link_social_media(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_3_id,
+        SOCIAL_MEDIA_HANDLE_2,
+    )
+    .unwrap();
+    enable_membership_with_fee_denom(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_3_id,
+        FEE_DENOM_2,
+    )
+    .unwrap();
+    register_user_with_referrer(
+        &mut app,
+        &cw_member_contract_addr,
+        &user_2_addr,
+        user_3_id.u64(),
+    )
+    .unwrap();
+
+    // Referee has not paid any referral fee yet
+    let query_referees_msg = QueryMsg::QueryReferees(QueryRefereesMsg {
+        referrer_user_id: user_3_id,
+        start_after_referee_user_id: None,
+        limit: None,
+        include_start_after: None,
+    });
+    let query_referees_res: RefereesResponse = app
+        .wrap()
+        .query_wasm_smart(cw_member_contract_addr.clone(), &query_referees_msg)
+        .unwrap();
+    assert_eq!(
+        query_referees_res.referees,
+        vec![Referee {
+            referee_user_id: user_2_id,
+            earned: vec![],
+        }]
+    );
+
+    // ================ User 2 buys memberships priced in both denoms ================
+    let mut referral_fees = vec![];
+    for (membership_issuer_user_id, denom) in [(user_1_id, FEE_DENOM), (user_3_id, FEE_DENOM_2)] {
+        let query_simulate_buy_membership_res: CostToBuyMembershipResponse = app
+            .wrap()
+            .query_wasm_smart(
+                cw_member_contract_addr.clone(),
+                &QueryMsg::QueryCostToBuyMembership(QueryCostToBuyMembershipMsg {
+                    membership_issuer_user_id,
+                    amount: uint_128_amount_10,
+                }),
+            )
+            .unwrap();
+        assert_eq!(query_simulate_buy_membership_res.fee_denom, denom);
+        assert!(query_simulate_buy_membership_res.referral_fee > Uint128::zero());
+        get_fund_from_faucet_in_denom(
+            &mut app,
+            user_2_addr.clone(),
+            query_simulate_buy_membership_res.total_needed_from_user,
+            denom,
+        );
+        app.execute_contract(
+            user_2_addr.clone(),
+            cw_member_contract_addr.clone(),
+            &ExecuteMsg::BuyMembership(BuyMembershipMsg {
+                membership_issuer_user_id,
+                amount: uint_128_amount_10,
+                max_total_cost: None,
+            }),
+            &[Coin {
+                denom: denom.to_string(),
+                amount: query_simulate_buy_membership_res.total_needed_from_user,
+            }],
+        )
+        .unwrap();
+        referral_fees.push(Coin {
+            denom: denom.to_string(),
+            amount: query_simulate_buy_membership_res.referral_fee,
+        });
+    }
+
+    // Referral fees in different denoms are tracked separately, never added up
+    let query_referees_res: RefereesResponse = app
+        .wrap()
+        .query_wasm_smart(cw_member_contract_addr.clone(), &query_referees_msg)
+        .unwrap();
+    assert_eq!(
+        query_referees_res,
+        RefereesResponse {
+            referees: vec![Referee {
+                referee_user_id: user_2_id,
+                earned: referral_fees,
+            }],
+            count: 1,
+            total_count: 1,
+        }
+    );
+}
@@ -0,0 +1,223 @@
+use anyhow::Result as AnyResult;
+use cosmwasm_std::{Addr, Binary, Uint128, Uint64};
+use cw_multi_test::{App, AppResponse, Executor};
+use k256::ecdsa::{signature::Signer, Signature, SigningKey};
+
+use member::ContractError;
+use member_pkg::{
+    attestation::SocialMediaAttestation,
+    msg::{
+        ExecuteMsg, LinkSocialMediaWithAttestationMsg, QueryMsg, QueryUserByIDMsg, UserResponse,
+    },
+};
+
+use crate::helpers::{
+    assert_err, assert_membership_supply, proper_instantiate, register_user, update_config,
+    SOCIAL_MEDIA_HANDLE_1, SOCIAL_MEDIA_PLATFORM,
+};
+
+fn sign(signing_key: &SigningKey, attestation: &SocialMediaAttestation) -> Binary {
+    let signature: Signature = signing_key.sign(&attestation.sign_bytes().unwrap());
+    Binary::from(signature.as_ref())
+}
+
+fn link_social_media_with_attestation(
+    app: &mut App,
+    cw_member_contract_addr: &Addr,
+    sender_addr: &Addr,
+    attestation: SocialMediaAttestation,
+    signature: Binary,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        sender_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &ExecuteMsg::LinkSocialMediaWithAttestation(LinkSocialMediaWithAttestationMsg {
+            attestation,
+            signature,
+            bonding_curve: None,
+            fee_denom: None,
+        }),
+        &[],
+    )
+}
+
+fn set_attestation_pubkey(
+    app: &mut App,
+    cw_member_contract_addr: &Addr,
+    admin_addr: &Addr,
+    attestation_pubkey: Binary,
+) -> AnyResult<AppResponse> {
+    update_config(
+        app,
+        cw_member_contract_addr,
+        admin_addr,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(attestation_pubkey),
+        None,
+        None,
+        None,
+    )
+}
+
+#[test]
+fn test_user_can_link_social_media_with_attestation() {
+    let (mut app, cw_member_contract_addr, admin_addr, _, _, user_1_addr, user_2_addr) =
+        proper_instantiate();
+
+    register_user(&mut app, &cw_member_contract_addr, &user_1_addr).unwrap();
+    register_user(&mut app, &cw_member_contract_addr, &user_2_addr).unwrap();
+    let user_1_id = Uint64::one();
+
+    // Key is generated locally, registration admin keeps it off chain
+    let signing_key = SigningKey::from_bytes(&[7_u8; 32]).unwrap();
+    let attestation_pubkey = Binary::from(signing_key.verifying_key().to_bytes().as_slice());
+
+    let block = app.block_info();
+    let attestation = SocialMediaAttestation {
+        chain_id: block.chain_id,
+        contract_addr: cw_member_contract_addr.to_string(),
+        user_id: user_1_id,
+        platform: SOCIAL_MEDIA_PLATFORM.to_string(),
+        social_media_handle: SOCIAL_MEDIA_HANDLE_1.to_string(),
+        enable_membership: true,
+        expires_at: block.time.plus_seconds(600),
+        nonce: Uint64::from(42_u8),
+    };
+    let signature = sign(&signing_key, &attestation);
+
+    // Attestation is disabled by default
+    assert_err(
+        link_social_media_with_attestation(
+            &mut app,
+            &cw_member_contract_addr,
+            &user_1_addr,
+            attestation.clone(),
+            signature.clone(),
+        ),
+        ContractError::AttestationNotEnabled {},
+    );
+
+    assert_err(
+        set_attestation_pubkey(
+            &mut app,
+            &cw_member_contract_addr,
+            &admin_addr,
+            Binary::from(vec![2_u8; 20]),
+        ),
+        ContractError::InvalidAttestationPubkey {},
+    );
+    set_attestation_pubkey(
+        &mut app,
+        &cw_member_contract_addr,
+        &admin_addr,
+        attestation_pubkey,
+    )
+    .unwrap();
+
+    // Only attested user can submit
+    assert_err(
+        link_social_media_with_attestation(
+            &mut app,
+            &cw_member_contract_addr,
+            &user_2_addr,
+            attestation.clone(),
+            signature.clone(),
+        ),
+        ContractError::OnlyUserCanSubmitItsAttestation {},
+    );
+
+    // Signature doesn't cover a different handle
+    assert_err(
+        link_social_media_with_attestation(
+            &mut app,
+            &cw_member_contract_addr,
+            &user_1_addr,
+            SocialMediaAttestation {
+                social_media_handle: "someone_else".to_string(),
+                ..attestation.clone()
+            },
+            signature.clone(),
+        ),
+        ContractError::InvalidAttestationSignature {},
+    );
+
+    let other_contract_attestation = SocialMediaAttestation {
+        contract_addr: "other_contract".to_string(),
+        ..attestation.clone()
+    };
+    assert_err(
+        link_social_media_with_attestation(
+            &mut app,
+            &cw_member_contract_addr,
+            &user_1_addr,
+            other_contract_attestation.clone(),
+            sign(&signing_key, &other_contract_attestation),
+        ),
+        ContractError::AttestationNotForThisContract {},
+    );
+
+    let expired_attestation = SocialMediaAttestation {
+        expires_at: app.block_info().time,
+        ..attestation.clone()
+    };
+    assert_err(
+        link_social_media_with_attestation(
+            &mut app,
+            &cw_member_contract_addr,
+            &user_1_addr,
+            expired_attestation.clone(),
+            sign(&signing_key, &expired_attestation),
+        ),
+        ContractError::AttestationExpired {},
+    );
+
+    link_social_media_with_attestation(
+        &mut app,
+        &cw_member_contract_addr,
+        &user_1_addr,
+        attestation.clone(),
+        signature.clone(),
+    )
+    .unwrap();
+
+    let query_user_1_res: UserResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryUserByID(QueryUserByIDMsg { user_id: user_1_id }),
+        )
+        .unwrap();
+    assert_eq!(
+        query_user_1_res.user.social_links[0].handle,
+        SOCIAL_MEDIA_HANDLE_1
+    );
+    // Attestation also enabled membership
+    assert_membership_supply(&app, &cw_member_contract_addr, user_1_id, Uint128::one());
+
+    // Nonce cannot be replayed
+    assert_err(
+        link_social_media_with_attestation(
+            &mut app,
+            &cw_member_contract_addr,
+            &user_1_addr,
+            attestation,
+            signature,
+        ),
+        ContractError::AttestationNonceAlreadyUsed {
+            nonce: Uint64::from(42_u8),
+        },
+    );
+}
@@ -0,0 +1,195 @@
+use cosmwasm_std::{Addr, Uint128, Uint64};
+
+use member_pkg::{
+    msg::{
+        PendingUserMigrationResponse, QueryMsg, QueryPendingUserMigrationMsg, QueryUserByAddrMsg,
+        QueryUserByIDMsg, UserResponse,
+    },
+    user::{Member, Membership, MembershipIssuedByMe, SocialLink, User, UserFeeConfig},
+};
+
+use shared_pkg::pricing::BondingCurve;
+
+use crate::helpers::{
+    accept_migrate_user, assert_members, assert_memberships, enable_membership, link_social_media,
+    migrate_user, proper_instantiate, register_user, FEE_DENOM, SOCIAL_MEDIA_HANDLE_1,
+    SOCIAL_MEDIA_PLATFORM, USER_3,
+};
+
+#[test]
+fn test_user_can_migrate_to_new_address() {
+    let (mut app, cw_member_contract_addr, _, registration_admin_addr, _, user_1_addr, _) =
+        proper_instantiate();
+    let new_user_1_addr = Addr::unchecked(USER_3);
+
+    register_user(&mut app, &cw_member_contract_addr, &user_1_addr).unwrap();
+    let user_1_id = Uint64::one();
+    link_social_media(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+        SOCIAL_MEDIA_HANDLE_1,
+    )
+    .unwrap();
+    enable_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        user_1_id,
+    )
+    .unwrap();
+
+    migrate_user(
+        &mut app,
+        &cw_member_contract_addr,
+        &user_1_addr,
+        &new_user_1_addr,
+    )
+    .unwrap();
+
+    let query_pending_user_migration_res: PendingUserMigrationResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryPendingUserMigration(QueryPendingUserMigrationMsg {
+                user_id: user_1_id,
+            }),
+        )
+        .unwrap();
+    assert_eq!(
+        query_pending_user_migration_res,
+        PendingUserMigrationResponse {
+            new_user_addr: Some(new_user_1_addr.clone())
+        }
+    );
+
+    accept_migrate_user(
+        &mut app,
+        &cw_member_contract_addr,
+        &new_user_1_addr,
+        user_1_id,
+    )
+    .unwrap();
+
+    // ========== Check user is moved to new address with the same ID ==========
+
+    let expected_user_1 = User {
+        id: user_1_id,
+        addr: new_user_1_addr.clone(),
+        hot_addr: None,
+        social_links: vec![SocialLink {
+            platform: SOCIAL_MEDIA_PLATFORM.to_string(),
+            handle: SOCIAL_MEDIA_HANDLE_1.to_string(),
+            linked_at: app.block_info().time,
+        }],
+        membership_issued_by_me: Some(MembershipIssuedByMe {
+            membership_supply: Uint128::one(),
+            member_count: Uint128::one(),
+            bonding_curve: BondingCurve::default(),
+            fee_denom: FEE_DENOM.to_string(),
+        }),
+        fee_config: UserFeeConfig::default(),
+        fee_share_config: None,
+        user_member_count: Uint128::one(),
+        referrer_user_id: None,
+        referee_count: Uint128::zero(),
+        only_verified_buyers: false,
+    };
+
+    let query_user_1_by_id_res: UserResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryUserByID(QueryUserByIDMsg { user_id: user_1_id }),
+        )
+        .unwrap();
+    assert_eq!(
+        query_user_1_by_id_res,
+        UserResponse {
+            user: expected_user_1.clone(),
+            pending_fee_change: None,
+        }
+    );
+
+    let query_user_1_by_new_addr_res: UserResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryUserByAddr(QueryUserByAddrMsg {
+                user_addr: new_user_1_addr.to_string(),
+            }),
+        )
+        .unwrap();
+    assert_eq!(
+        query_user_1_by_new_addr_res,
+        UserResponse {
+            user: expected_user_1,
+            pending_fee_change: None,
+        }
+    );
+
+    // Old address is no longer associated with any user
+    assert!(app
+        .wrap()
+        .query_wasm_smart::<UserResponse>(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryUserByAddr(QueryUserByAddrMsg {
+                user_addr: user_1_addr.to_string(),
+            }),
+        )
+        .is_err());
+
+    let query_pending_user_migration_res: PendingUserMigrationResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryPendingUserMigration(QueryPendingUserMigrationMsg {
+                user_id: user_1_id,
+            }),
+        )
+        .unwrap();
+    assert_eq!(
+        query_pending_user_migration_res,
+        PendingUserMigrationResponse {
+            new_user_addr: None
+        }
+    );
+
+    // Memberships are keyed by user ID so they stay the same
+    assert_memberships(
+        &app,
+        &cw_member_contract_addr,
+        user_1_id,
+        vec![Membership {
+            issuer_user_id: user_1_id,
+            amount: Uint128::one(),
+        }],
+        1,
+        1,
+    );
+    assert_members(
+        &app,
+        &cw_member_contract_addr,
+        user_1_id,
+        vec![Member {
+            member_user_id: user_1_id,
+            amount: Uint128::one(),
+        }],
+        1,
+        1,
+    );
+
+    // Old address can register as a brand new user
+    register_user(&mut app, &cw_member_contract_addr, &user_1_addr).unwrap();
+    let query_old_addr_user_res: UserResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryUserByAddr(QueryUserByAddrMsg {
+                user_addr: user_1_addr.to_string(),
+            }),
+        )
+        .unwrap();
+    assert_eq!(query_old_addr_user_res.user.id, Uint64::from(2_u8));
+}
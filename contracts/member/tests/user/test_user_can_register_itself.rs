@@ -2,7 +2,7 @@ use cosmwasm_std::{Uint128, Uint64};
 
 use member_pkg::{
     msg::{QueryMsg, QueryUserByAddrMsg, UserResponse},
-    user::User,
+    user::{User, UserFeeConfig},
 };
 
 use crate::helpers::{proper_instantiate, register_user};
@@ -26,12 +26,17 @@ fn test_user_can_register_itself() {
             user: User {
                 id: Uint64::one(),
                 addr: user_1_addr.clone(),
-                social_media_handle: None,
+                hot_addr: None,
+                social_links: vec![],
                 membership_issued_by_me: None,
-                fee_config: None,
+                fee_config: UserFeeConfig::default(),
                 fee_share_config: None,
-                user_member_count: Uint128::zero()
-            }
+                user_member_count: Uint128::zero(),
+                referrer_user_id: None,
+                referee_count: Uint128::zero(),
+                only_verified_buyers: false,
+            },
+            pending_fee_change: None,
         }
     );
 }
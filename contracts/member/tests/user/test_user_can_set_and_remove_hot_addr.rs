@@ -0,0 +1,113 @@
+use cosmwasm_std::{Addr, Uint64};
+
+use member::ContractError;
+use member_pkg::msg::{QueryMsg, QueryUserByAddrMsg, UserResponse};
+
+use crate::helpers::{
+    assert_err, proper_instantiate, register_user, remove_hot_addr, set_hot_addr, USER_1_HOT,
+};
+
+#[test]
+fn test_user_can_set_and_remove_hot_addr() {
+    let (mut app, cw_member_contract_addr, _, _, _, user_1_addr, user_2_addr) =
+        proper_instantiate();
+    let user_1_hot_addr = Addr::unchecked(USER_1_HOT);
+    let user_1_id = Uint64::one();
+
+    register_user(&mut app, &cw_member_contract_addr, &user_1_addr).unwrap();
+    register_user(&mut app, &cw_member_contract_addr, &user_2_addr).unwrap();
+
+    assert_err(
+        set_hot_addr(
+            &mut app,
+            &cw_member_contract_addr,
+            &user_1_addr,
+            &user_1_addr,
+        ),
+        ContractError::HotAddrCannotBeSameAsColdAddr {},
+    );
+    assert_err(
+        set_hot_addr(
+            &mut app,
+            &cw_member_contract_addr,
+            &user_1_addr,
+            &user_2_addr,
+        ),
+        ContractError::AddressAlreadyRegistered {},
+    );
+    assert_err(
+        remove_hot_addr(&mut app, &cw_member_contract_addr, &user_1_addr),
+        ContractError::UserHasNoHotAddr {},
+    );
+
+    set_hot_addr(
+        &mut app,
+        &cw_member_contract_addr,
+        &user_1_addr,
+        &user_1_hot_addr,
+    )
+    .unwrap();
+
+    // Hot address resolves to the same user
+    let query_user_1_by_hot_addr_res: UserResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryUserByAddr(QueryUserByAddrMsg {
+                user_addr: user_1_hot_addr.to_string(),
+            }),
+        )
+        .unwrap();
+    assert_eq!(query_user_1_by_hot_addr_res.user.id, user_1_id);
+    assert_eq!(query_user_1_by_hot_addr_res.user.addr, user_1_addr);
+    assert_eq!(
+        query_user_1_by_hot_addr_res.user.hot_addr,
+        Some(user_1_hot_addr.clone())
+    );
+
+    // Hot address cannot register itself or be used by another user
+    assert_err(
+        register_user(&mut app, &cw_member_contract_addr, &user_1_hot_addr),
+        ContractError::AddressAlreadyRegistered {},
+    );
+    assert_err(
+        set_hot_addr(
+            &mut app,
+            &cw_member_contract_addr,
+            &user_2_addr,
+            &user_1_hot_addr,
+        ),
+        ContractError::AddressAlreadyRegistered {},
+    );
+
+    // Hot address cannot manage hot address
+    assert_err(
+        remove_hot_addr(&mut app, &cw_member_contract_addr, &user_1_hot_addr),
+        ContractError::OnlyColdAddrAllowed {},
+    );
+
+    remove_hot_addr(&mut app, &cw_member_contract_addr, &user_1_addr).unwrap();
+
+    assert!(app
+        .wrap()
+        .query_wasm_smart::<UserResponse>(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryUserByAddr(QueryUserByAddrMsg {
+                user_addr: user_1_hot_addr.to_string(),
+            }),
+        )
+        .is_err());
+    let query_user_1_res: UserResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr.clone(),
+            &QueryMsg::QueryUserByAddr(QueryUserByAddrMsg {
+                user_addr: user_1_addr.to_string(),
+            }),
+        )
+        .unwrap();
+    assert_eq!(query_user_1_res.user.hot_addr, None);
+
+    // Removed hot address is free to register as a new user
+    register_user(&mut app, &cw_member_contract_addr, &user_1_hot_addr).unwrap();
+}
@@ -4,7 +4,9 @@ use cw_multi_test::Executor;
 use member::ContractError;
 use member_pkg::msg::{EnableMembershipMsg, ExecuteMsg, LinkSocialMediaMsg};
 
-use crate::helpers::{assert_err, proper_instantiate, register_user, SOCIAL_MEDIA_HANDLE_1};
+use crate::helpers::{
+    assert_err, proper_instantiate, register_user, SOCIAL_MEDIA_HANDLE_1, SOCIAL_MEDIA_PLATFORM,
+};
 
 #[test]
 fn test_user_cannot_enable_membership_by_itself() {
@@ -16,6 +18,7 @@ fn test_user_cannot_enable_membership_by_itself() {
             cw_member_contract_addr.clone(),
             &ExecuteMsg::LinkSocialMedia(LinkSocialMediaMsg {
                 user_id: Uint64::one(),
+                platform: SOCIAL_MEDIA_PLATFORM.to_string(),
                 social_media_handle: SOCIAL_MEDIA_HANDLE_1.to_string(),
             }),
             &[],
@@ -28,6 +31,8 @@ fn test_user_cannot_enable_membership_by_itself() {
             cw_member_contract_addr.clone(),
             &ExecuteMsg::EnableMembership(EnableMembershipMsg {
                 user_id: Uint64::one(),
+                bonding_curve: None,
+                fee_denom: None,
             }),
             &[],
         ),
@@ -1,10 +1,11 @@
 use cosmwasm_schema::write_api;
-use thread_pkg::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use thread_pkg::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
 
 fn main() {
     write_api! {
         instantiate: InstantiateMsg,
         execute: ExecuteMsg,
         query: QueryMsg,
+        migrate: MigrateMsg,
     }
 }
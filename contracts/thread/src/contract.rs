@@ -4,13 +4,23 @@ use cosmwasm_std::{
 };
 use cw2::set_contract_version;
 
-use member_pkg::member_contract_querier::query_member_contract_config;
-use shared_pkg::fee_share_config::FeeShareConfig;
-use thread_pkg::config::{Config, FeeConfig, ProtocolFeeConfig, ThreadConfig};
-use thread_pkg::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use member_pkg::member_contract_querier::MemberContractQuerier;
+use shared_pkg::{
+    fee_share_config::FeeShareConfig,
+    migrate::{assert_can_migrate, migrate_state},
+    rbac::{grant_role, Role},
+    timelock::{normalize_timelock, DEFAULT_USER_FEE_CHANGE_TIMELOCK},
+};
+use thread_pkg::config::{Config, FeeConfig, PauseConfig, ProtocolFeeConfig, ThreadConfig};
+use thread_pkg::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
 
 use crate::state::{CONFIG, NEXT_THREAD_ID};
-use crate::util::fee_share::assert_config_fee_share_sum_to_100;
+use crate::util::config::assert_contract_enabled;
+use crate::util::fee_range::{assert_default_fee_config_in_ranges, assert_user_fee_ranges_valid};
+use crate::util::fee_share::{
+    assert_config_fee_share_sum_to_10000, assert_referral_fee_bps_within_10000,
+};
+use crate::util::migrate::STATE_MIGRATIONS;
 use crate::{execute, query, ContractError};
 
 pub const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
@@ -35,6 +45,8 @@ pub fn instantiate(
             .api
             .addr_validate(&msg.admin_addr.unwrap_or(info.sender.to_string()))?,
         enabled: false,
+        // Nothing is paused by default
+        pause_config: PauseConfig::default(),
         protocol_fee_collector_addr: deps.api.addr_validate(
             &msg.protocol_fee_collector_addr
                 .unwrap_or(info.sender.to_string()),
@@ -58,44 +70,60 @@ pub fn instantiate(
                 .protocol_fee_start_new_thread_fixed_cost
                 .unwrap_or(Uint128::from(10_000_u64)),
             // Default to 0%
-            ask_in_thread_fee_percentage: msg
-                .protocol_fee_ask_in_thread_fee_percentage
+            ask_in_thread_fee_bps: msg
+                .protocol_fee_ask_in_thread_fee_bps
                 .unwrap_or(Uint64::zero()),
             // Default to 0%
-            reply_in_thread_fee_percentage: msg
-                .protocol_fee_reply_in_thread_fee_percentage
+            reply_in_thread_fee_bps: msg
+                .protocol_fee_reply_in_thread_fee_bps
                 .unwrap_or(Uint64::zero()),
+            // Default to 0%, admin can turn on referral program later
+            referral_fee_bps: msg.protocol_fee_referral_fee_bps.unwrap_or(Uint64::zero()),
         },
         default_fee_config: FeeConfig {
             // By default, pay 5% of the price of a single membership to ask
-            ask_fee_percentage_of_membership: msg
-                .default_ask_fee_percentage_of_membership
-                .unwrap_or(Uint64::from(5_u64)),
+            ask_fee_bps_of_membership: msg
+                .default_ask_fee_bps_of_membership
+                .unwrap_or(Uint64::from(500_u64)),
             // By default, pay 1% of the price of a single membership to thread creator when someone ask in thread
-            ask_fee_to_thread_creator_percentage_of_membership: msg
-                .default_ask_fee_to_thread_creator_percentage_of_membership
-                .unwrap_or(Uint64::one()),
+            ask_fee_to_thread_creator_bps_of_membership: msg
+                .default_ask_fee_to_thread_creator_bps_of_membership
+                .unwrap_or(Uint64::from(100_u64)),
             // By default, pay 1% of the price of a single membership to reply
-            reply_fee_percentage_of_membership: msg
-                .default_reply_fee_percentage_of_membership
-                .unwrap_or(Uint64::one()),
+            reply_fee_bps_of_membership: msg
+                .default_reply_fee_bps_of_membership
+                .unwrap_or(Uint64::from(100_u64)),
             // By default, pay 1% of the price of a single membership to thread creator when someone reply in thread
-            reply_fee_to_thread_creator_percentage_of_membership: msg
-                .default_reply_fee_to_thread_creator_percentage_of_membership
-                .unwrap_or(Uint64::one()),
+            reply_fee_to_thread_creator_bps_of_membership: msg
+                .default_reply_fee_to_thread_creator_bps_of_membership
+                .unwrap_or(Uint64::from(100_u64)),
         },
         default_fee_share_config: FeeShareConfig {
-            share_to_issuer_percentage: msg
-                .default_share_to_issuer_percentage
-                .unwrap_or(Uint64::from(50_u64)),
-            share_to_all_members_percentage: msg
-                .default_share_to_all_members_percentage
-                .unwrap_or(Uint64::from(50_u64)),
+            share_to_issuer_bps: msg
+                .default_share_to_issuer_bps
+                .unwrap_or(Uint64::from(5_000_u64)),
+            share_to_all_members_bps: msg
+                .default_share_to_all_members_bps
+                .unwrap_or(Uint64::from(5_000_u64)),
         },
+        // Default to no time-lock, admin can set one after instantiation
+        config_change_timelock: None,
+        user_fee_change_timelock: normalize_timelock(
+            msg.user_fee_change_timelock
+                .unwrap_or(DEFAULT_USER_FEE_CHANGE_TIMELOCK),
+        ),
+        // Default to 0% to 100% for every issuer fee
+        user_fee_ranges: msg.user_fee_ranges.unwrap_or_default(),
     };
 
+    // Admin in config always holds admin role, it can grant roles to more addresses later
+    grant_role(deps.storage, &config.admin_addr, &Role::Admin)?;
+
     CONFIG.save(deps.storage, &config)?;
-    assert_config_fee_share_sum_to_100(deps.as_ref())?;
+    assert_config_fee_share_sum_to_10000(&config.default_fee_share_config)?;
+    assert_referral_fee_bps_within_10000(&config.protocol_fee_config)?;
+    assert_user_fee_ranges_valid(&config.user_fee_ranges)?;
+    assert_default_fee_config_in_ranges(&config.default_fee_config, &config.user_fee_ranges)?;
 
     NEXT_THREAD_ID.save(deps.storage, &Uint64::one())?;
 
@@ -105,23 +133,25 @@ pub fn instantiate(
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     let deps_ref = deps.as_ref();
     let config = CONFIG.load(deps_ref.storage)?;
     let membership_contract_config =
-        query_member_contract_config(deps_ref, config.clone().member_contract_addr.clone());
-    // TODO: P2: update this when we support user setting their own fee denom
+        MemberContractQuerier::new(deps_ref.querier, config.member_contract_addr.clone())
+            .config()?;
+    // Starting a new thread is paid in default fee denom
+    // Asking and replying are paid in the denom of the membership asked / replied to
     let fee_denom = membership_contract_config
         .default_fee_config
         .fee_denom
         .as_str();
+    // Only asking and replying send fees to distribution contract
     let distribution_contract_addr = membership_contract_config
         .distribution_contract_addr
-        .unwrap();
-    let member_contract_addr = config.clone().member_contract_addr;
+        .ok_or(ContractError::DistributionContractNotSet {});
 
     match msg {
         ExecuteMsg::Enable(_) => {
@@ -134,13 +164,50 @@ pub fn execute(
         }
         ExecuteMsg::UpdateConfig(data) => {
             cw_utils::nonpayable(&info)?;
-            execute::config::update_config(deps, info, data)
+            execute::config::update_config(deps, env, info, data)
+        }
+        ExecuteMsg::ExecutePendingConfigChange(data) => {
+            cw_utils::nonpayable(&info)?;
+            execute::config::execute_pending_config_change(deps, env, info, data)
+        }
+        ExecuteMsg::CancelPendingConfigChange(data) => {
+            cw_utils::nonpayable(&info)?;
+            execute::config::cancel_pending_config_change(deps, info, data)
+        }
+        ExecuteMsg::ProposeNewAdmin(data) => {
+            cw_utils::nonpayable(&info)?;
+            execute::config::propose_new_admin(deps, env, info, data)
+        }
+        ExecuteMsg::AcceptAdmin(_) => {
+            cw_utils::nonpayable(&info)?;
+            execute::config::accept_admin(deps, env, info)
+        }
+        ExecuteMsg::CancelAdminProposal(_) => {
+            cw_utils::nonpayable(&info)?;
+            execute::config::cancel_admin_proposal(deps, info)
+        }
+        ExecuteMsg::GrantRole(data) => {
+            cw_utils::nonpayable(&info)?;
+            execute::config::grant_role(deps, info, data)
+        }
+        ExecuteMsg::RevokeRole(data) => {
+            cw_utils::nonpayable(&info)?;
+            execute::config::revoke_role(deps, info, data, config)
+        }
+        ExecuteMsg::UpdatePauseConfig(data) => {
+            cw_utils::nonpayable(&info)?;
+            execute::config::update_pause_config(deps, info, data)
         }
         ExecuteMsg::UpdateUserConfig(data) => {
+            assert_contract_enabled(&config)?;
             cw_utils::nonpayable(&info)?;
-            execute::user_config::update_user_config(deps, info, data, member_contract_addr)
+            execute::user_config::update_user_config(deps, env, info, data, config)
         }
         ExecuteMsg::StartNewThread(data) => {
+            assert_contract_enabled(&config)?;
+            if config.pause_config.start_new_thread_paused {
+                return Err(ContractError::StartNewThreadPaused {});
+            }
             let user_paid_amount = cw_utils::must_pay(&info, fee_denom)?;
             execute::thread::start_new_thread(
                 deps,
@@ -152,52 +219,90 @@ pub fn execute(
             )
         }
         ExecuteMsg::AskInThread(data) => {
-            let user_paid_amount = cw_utils::must_pay(&info, fee_denom)?;
+            assert_contract_enabled(&config)?;
+            if config.pause_config.ask_in_thread_paused {
+                return Err(ContractError::AskInThreadPaused {});
+            }
+            // Payment is checked after we know which membership's denom to pay in
             execute::thread::ask_in_thread(
                 deps,
+                env,
                 info,
                 data,
                 config,
-                fee_denom.to_string(),
-                distribution_contract_addr,
-                user_paid_amount,
+                distribution_contract_addr?,
             )
         }
         ExecuteMsg::AnswerInThread(data) => {
+            assert_contract_enabled(&config)?;
+            if config.pause_config.answer_in_thread_paused {
+                return Err(ContractError::AnswerInThreadPaused {});
+            }
             cw_utils::nonpayable(&info)?;
             execute::thread::answer_in_thread(deps, info, data, config)
         }
         ExecuteMsg::ReplyInThread(data) => {
-            let user_paid_amount = cw_utils::must_pay(&info, fee_denom)?;
+            assert_contract_enabled(&config)?;
+            if config.pause_config.reply_in_thread_paused {
+                return Err(ContractError::ReplyInThreadPaused {});
+            }
+            // Payment is checked after we know which membership's denom to pay in
             execute::thread::reply_in_thread(
                 deps,
+                env,
                 info,
                 data,
                 config,
-                fee_denom.to_string(),
-                distribution_contract_addr,
-                user_paid_amount,
+                distribution_contract_addr?,
             )
         }
     }
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn migrate(mut deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let previous_version = assert_can_migrate(
+        deps.storage,
+        &format!("crates.io:{CONTRACT_NAME}"),
+        CONTRACT_VERSION,
+    )?;
+
+    migrate_state(deps.branch(), &env, &previous_version, STATE_MIGRATIONS)?;
+
+    set_contract_version(
+        deps.storage,
+        format!("crates.io:{CONTRACT_NAME}"),
+        CONTRACT_VERSION,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute("previous_version", previous_version.to_string())
+        .add_attribute("new_version", CONTRACT_VERSION))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     let config = CONFIG.load(deps.storage)?;
     match msg {
         QueryMsg::QueryConfig(_) => to_binary(&query::config::query_config(deps)?),
+        QueryMsg::QueryPauseStatus(_) => to_binary(&query::config::query_pause_status(deps)?),
+        QueryMsg::QueryRoles(data) => to_binary(&query::config::query_roles(deps, data)?),
+        QueryMsg::QueryUserFeeRanges(_) => to_binary(&query::config::query_user_fee_ranges(deps)?),
+        QueryMsg::QueryPendingConfigChanges(_) => {
+            to_binary(&query::config::query_pending_config_changes(deps)?)
+        }
         QueryMsg::QueryUserConfig(data) => {
-            to_binary(&query::user_config::query_user_config(deps, data)?)
+            to_binary(&query::user_config::query_user_config(deps, env, data)?)
         }
         QueryMsg::QueryCostToStartNewThread(_) => {
             to_binary(&query::thread::query_cost_to_start_new_thread(config)?)
         }
         QueryMsg::QueryCostToAskInThread(data) => to_binary(
-            &query::thread::query_cost_to_ask_in_thread(deps, data, config)?,
+            &query::thread::query_cost_to_ask_in_thread(deps, env, data, config)?,
         ),
         QueryMsg::QueryCostToReplyInThread(data) => to_binary(
-            &query::thread::query_cost_to_reply_in_thread(deps, data, config)?,
+            &query::thread::query_cost_to_reply_in_thread(deps, env, data, config)?,
         ),
         QueryMsg::QueryIDsOfAllThreadsUserParticipated(data) => to_binary(
             &query::thread::query_ids_of_all_threads_user_participated(deps, data)?,
@@ -214,5 +319,6 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::QueryThreadMsgsByIDs(data) => {
             to_binary(&query::thread::query_thread_msgs_by_ids(deps, data)?)
         }
+        QueryMsg::QueryReferees(data) => to_binary(&query::referral::query_referees(deps, data)?),
     }
 }
@@ -1,5 +1,6 @@
-use cosmwasm_std::{StdError, Uint128, Uint64};
+use cosmwasm_std::{OverflowError, StdError, Uint128, Uint64};
 use cw_utils::PaymentError;
+use shared_pkg::{migrate::MigrateError, pricing::PricingError};
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
@@ -10,6 +11,15 @@ pub enum ContractError {
     #[error("{0}")]
     Payment(#[from] PaymentError),
 
+    #[error("{0}")]
+    Migrate(#[from] MigrateError),
+
+    #[error("{0}")]
+    Overflow(#[from] OverflowError),
+
+    #[error("{0}")]
+    Pricing(#[from] PricingError),
+
     // ========================== ADMIN ==========================
     #[error("Unauthorized")]
     Unauthorized {},
@@ -23,6 +33,66 @@ pub enum ContractError {
     #[error("Only admin can update config")]
     OnlyAdminCanUpdateConfig {},
 
+    #[error("Only admin can grant role")]
+    OnlyAdminCanGrantRole {},
+
+    #[error("Only admin can revoke role")]
+    OnlyAdminCanRevokeRole {},
+
+    #[error("Address already has role {role}")]
+    AddressAlreadyHasRole { role: String },
+
+    #[error("Address does not have role {role}")]
+    AddressDoesNotHaveRole { role: String },
+
+    #[error("Cannot revoke role {role} from address set in config, update config instead")]
+    CannotRevokeRoleOfConfigAddress { role: String },
+
+    #[error("Only admin can propose new admin")]
+    OnlyAdminCanProposeNewAdmin {},
+
+    #[error("Only admin can cancel admin proposal")]
+    OnlyAdminCanCancelAdminProposal {},
+
+    #[error("Only proposed admin can accept admin")]
+    OnlyProposedAdminCanAcceptAdmin {},
+
+    #[error("No pending admin proposal")]
+    NoPendingAdminProposal {},
+
+    #[error("Admin proposal expired")]
+    AdminProposalExpired {},
+
+    #[error("Only admin, or fee manager for fee only changes, can execute pending config change")]
+    OnlyAdminOrFeeManagerCanExecutePendingConfigChange {},
+
+    #[error("Only admin, or fee manager for fee only changes, can cancel pending config change")]
+    OnlyAdminOrFeeManagerCanCancelPendingConfigChange {},
+
+    #[error("Pending config change {id} does not exist")]
+    PendingConfigChangeNotExist { id: Uint64 },
+
+    #[error("Pending config change is time-locked until {executable_at}")]
+    PendingConfigChangeStillTimeLocked { executable_at: String },
+
+    #[error("Only admin can update pause config")]
+    OnlyAdminCanUpdatePauseConfig {},
+
+    #[error("Contract disabled")]
+    ContractDisabled {},
+
+    #[error("Starting new thread is paused")]
+    StartNewThreadPaused {},
+
+    #[error("Asking in thread is paused")]
+    AskInThreadPaused {},
+
+    #[error("Answering in thread is paused")]
+    AnswerInThreadPaused {},
+
+    #[error("Replying in thread is paused")]
+    ReplyInThreadPaused {},
+
     // ========================== USER ==========================
     #[error("Only user can update its own config")]
     OnlyUserCanUpdateItsOwnConfig {},
@@ -42,6 +112,9 @@ pub enum ContractError {
     #[error("User not exist")]
     UserNotExist {},
 
+    #[error("Distribution contract not set")]
+    DistributionContractNotSet {},
+
     // ========================== THREAD ==========================
     #[error("User must hold ask to user membership to ask")]
     UserMustHoldAskToUserMembershipToAsk {},
@@ -99,8 +172,26 @@ pub enum ContractError {
     #[error("Cannot answer others question")]
     CannotAnswerOthersQuestion {},
 
-    #[error("Thread fee share percentage must sum to 100")]
-    ThreadFeeSharePercentageMustSumTo100 {},
+    #[error("Thread fee share bps must sum to 10000")]
+    ThreadFeeShareBpsMustSumTo10000 {},
+
+    #[error("Referral fee bps cannot exceed 10000")]
+    ReferralFeeBpsCannotExceed10000 {},
+
+    #[error("Min cannot exceed max in user fee range of {fee:?}: min {min:?}, max {max:?}")]
+    InvalidUserFeeRange {
+        fee: String,
+        min: Uint64,
+        max: Uint64,
+    },
+
+    #[error("Fee {fee:?} out of allowed range: bps {bps:?}, min {min:?}, max {max:?}")]
+    FeeBpsOutOfRange {
+        fee: String,
+        bps: Uint64,
+        min: Uint64,
+        max: Uint64,
+    },
 
     #[error("Exceed query limit: given {given:?}, limit {limit:?}")]
     ExceedQueryLimit { given: Uint64, limit: Uint64 },
@@ -1,15 +1,33 @@
-use cosmwasm_std::{DepsMut, MessageInfo, Response};
+use cosmwasm_std::{Deps, DepsMut, Env, MessageInfo, Response, Uint64};
+use shared_pkg::admin_transfer::{AdminProposal, ProposeNewAdminMsg};
 use shared_pkg::fee_share_config::FeeShareConfig;
+use shared_pkg::rbac::{
+    self, has_any_role, has_role, transfer_role, GrantRoleMsg, RevokeRoleMsg, Role,
+};
+use shared_pkg::timelock::{
+    normalize_timelock, CancelPendingConfigChangeMsg, ExecutePendingConfigChangeMsg,
+    PendingConfigChange,
+};
 
-use crate::{state::CONFIG, util::fee_share::assert_config_fee_share_sum_to_100, ContractError};
+use crate::{
+    state::{
+        CONFIG, NEXT_PENDING_CONFIG_CHANGE_ID, PENDING_ADMIN_PROPOSAL, PENDING_CONFIG_CHANGES,
+    },
+    util::{
+        config::{can_update_config, split_timelocked_config_changes},
+        fee_range::{assert_default_fee_config_in_ranges, assert_user_fee_ranges_valid},
+        fee_share::{assert_config_fee_share_sum_to_10000, assert_referral_fee_bps_within_10000},
+    },
+    ContractError,
+};
 
-use thread_pkg::config::{FeeConfig, ProtocolFeeConfig, ThreadConfig};
-use thread_pkg::msg::UpdateConfigMsg;
+use thread_pkg::config::{Config, FeeConfig, ProtocolFeeConfig, ThreadConfig};
+use thread_pkg::msg::{UpdateConfigMsg, UpdatePauseConfigMsg};
 
 pub fn enable(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
     let mut config = CONFIG.load(deps.storage)?;
 
-    if info.sender != config.admin_addr {
+    if !has_role(deps.storage, &info.sender, &Role::Admin)? {
         return Err(ContractError::OnlyAdminCanEnable {});
     }
 
@@ -23,7 +41,7 @@ pub fn enable(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractErro
 pub fn disable(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
     let mut config = CONFIG.load(deps.storage)?;
 
-    if info.sender != config.admin_addr {
+    if !has_role(deps.storage, &info.sender, &Role::Admin)? {
         return Err(ContractError::OnlyAdminCanDisable {});
     }
 
@@ -34,22 +52,204 @@ pub fn disable(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractErr
     Ok(Response::new().add_attribute("action", "disable"))
 }
 
+pub fn update_pause_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    data: UpdatePauseConfigMsg,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    if !has_any_role(deps.storage, &info.sender, &[Role::Admin, Role::Pauser])? {
+        return Err(ContractError::OnlyAdminCanUpdatePauseConfig {});
+    }
+
+    if let Some(start_new_thread_paused) = data.start_new_thread_paused {
+        config.pause_config.start_new_thread_paused = start_new_thread_paused;
+    }
+    if let Some(ask_in_thread_paused) = data.ask_in_thread_paused {
+        config.pause_config.ask_in_thread_paused = ask_in_thread_paused;
+    }
+    if let Some(answer_in_thread_paused) = data.answer_in_thread_paused {
+        config.pause_config.answer_in_thread_paused = answer_in_thread_paused;
+    }
+    if let Some(reply_in_thread_paused) = data.reply_in_thread_paused {
+        config.pause_config.reply_in_thread_paused = reply_in_thread_paused;
+    }
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attribute("action", "update_pause_config"))
+}
+
+pub fn grant_role(
+    deps: DepsMut,
+    info: MessageInfo,
+    data: GrantRoleMsg,
+) -> Result<Response, ContractError> {
+    if !has_role(deps.storage, &info.sender, &Role::Admin)? {
+        return Err(ContractError::OnlyAdminCanGrantRole {});
+    }
+
+    let addr = deps.api.addr_validate(data.addr.as_str())?;
+    if has_role(deps.storage, &addr, &data.role)? {
+        return Err(ContractError::AddressAlreadyHasRole {
+            role: data.role.as_str().to_string(),
+        });
+    }
+
+    rbac::grant_role(deps.storage, &addr, &data.role)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "grant_role")
+        .add_attribute("addr", addr)
+        .add_attribute("role", data.role.as_str()))
+}
+
+pub fn revoke_role(
+    deps: DepsMut,
+    info: MessageInfo,
+    data: RevokeRoleMsg,
+    config: Config,
+) -> Result<Response, ContractError> {
+    if !has_role(deps.storage, &info.sender, &Role::Admin)? {
+        return Err(ContractError::OnlyAdminCanRevokeRole {});
+    }
+
+    let addr = deps.api.addr_validate(data.addr.as_str())?;
+    // Otherwise config would point to an address without the role, and revoking the last admin would lock the contract
+    if data.role == Role::Admin && addr == config.admin_addr {
+        return Err(ContractError::CannotRevokeRoleOfConfigAddress {
+            role: data.role.as_str().to_string(),
+        });
+    }
+
+    if !has_role(deps.storage, &addr, &data.role)? {
+        return Err(ContractError::AddressDoesNotHaveRole {
+            role: data.role.as_str().to_string(),
+        });
+    }
+
+    rbac::revoke_role(deps.storage, &addr, &data.role);
+
+    Ok(Response::new()
+        .add_attribute("action", "revoke_role")
+        .add_attribute("addr", addr)
+        .add_attribute("role", data.role.as_str()))
+}
+
 pub fn update_config(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     data: UpdateConfigMsg,
 ) -> Result<Response, ContractError> {
-    let mut config = CONFIG.load(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
 
-    if info.sender != config.admin_addr {
+    if !can_update_config(deps.storage, &info.sender, &data)? {
         return Err(ContractError::OnlyAdminCanUpdateConfig {});
     }
 
-    config.admin_addr = match data.admin_addr {
-        None => config.admin_addr,
-        Some(data) => deps.api.addr_validate(data.as_str())?,
+    let (changes, timelocked_changes) = match config.config_change_timelock {
+        None => (data, None),
+        Some(config_change_timelock) => {
+            let (changes, timelocked_changes) = split_timelocked_config_changes(data);
+            (
+                changes,
+                timelocked_changes.map(|timelocked_changes| {
+                    (timelocked_changes, config_change_timelock.after(&env.block))
+                }),
+            )
+        }
     };
 
+    let new_config = apply_config_changes(deps.as_ref(), config, changes)?;
+    CONFIG.save(deps.storage, &new_config)?;
+
+    let mut response = Response::new().add_attribute("action", "update_config");
+
+    if let Some((timelocked_changes, executable_at)) = timelocked_changes {
+        // Reject invalid changes now instead of when the time-lock has passed
+        apply_config_changes(deps.as_ref(), new_config, timelocked_changes.clone())?;
+
+        let id = NEXT_PENDING_CONFIG_CHANGE_ID
+            .may_load(deps.storage)?
+            .unwrap_or(Uint64::one());
+        PENDING_CONFIG_CHANGES.save(
+            deps.storage,
+            id.u64(),
+            &PendingConfigChange {
+                id,
+                changes: timelocked_changes,
+                proposed_by: info.sender,
+                executable_at,
+            },
+        )?;
+        NEXT_PENDING_CONFIG_CHANGE_ID.save(deps.storage, &(id + Uint64::one()))?;
+
+        response = response
+            .add_attribute("pending_config_change_id", id)
+            .add_attribute("executable_at", executable_at.to_string());
+    }
+
+    Ok(response)
+}
+
+pub fn execute_pending_config_change(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    data: ExecutePendingConfigChangeMsg,
+) -> Result<Response, ContractError> {
+    let pending_config_change = PENDING_CONFIG_CHANGES
+        .may_load(deps.storage, data.id.u64())?
+        .ok_or(ContractError::PendingConfigChangeNotExist { id: data.id })?;
+
+    if !can_update_config(deps.storage, &info.sender, &pending_config_change.changes)? {
+        return Err(ContractError::OnlyAdminOrFeeManagerCanExecutePendingConfigChange {});
+    }
+
+    if !pending_config_change.executable_at.is_expired(&env.block) {
+        return Err(ContractError::PendingConfigChangeStillTimeLocked {
+            executable_at: pending_config_change.executable_at.to_string(),
+        });
+    }
+
+    let config = CONFIG.load(deps.storage)?;
+    let new_config = apply_config_changes(deps.as_ref(), config, pending_config_change.changes)?;
+    CONFIG.save(deps.storage, &new_config)?;
+    PENDING_CONFIG_CHANGES.remove(deps.storage, data.id.u64());
+
+    Ok(Response::new()
+        .add_attribute("action", "execute_pending_config_change")
+        .add_attribute("pending_config_change_id", data.id))
+}
+
+pub fn cancel_pending_config_change(
+    deps: DepsMut,
+    info: MessageInfo,
+    data: CancelPendingConfigChangeMsg,
+) -> Result<Response, ContractError> {
+    let pending_config_change = PENDING_CONFIG_CHANGES
+        .may_load(deps.storage, data.id.u64())?
+        .ok_or(ContractError::PendingConfigChangeNotExist { id: data.id })?;
+
+    if !can_update_config(deps.storage, &info.sender, &pending_config_change.changes)? {
+        return Err(ContractError::OnlyAdminOrFeeManagerCanCancelPendingConfigChange {});
+    }
+
+    PENDING_CONFIG_CHANGES.remove(deps.storage, data.id.u64());
+
+    Ok(Response::new()
+        .add_attribute("action", "cancel_pending_config_change")
+        .add_attribute("pending_config_change_id", data.id))
+}
+
+// Apply changes on top of config and validate the result, nothing is saved
+fn apply_config_changes(
+    deps: Deps,
+    mut config: Config,
+    data: UpdateConfigMsg,
+) -> Result<Config, ContractError> {
     config.protocol_fee_collector_addr = match data.protocol_fee_collector_addr {
         None => config.protocol_fee_collector_addr,
         Some(data) => deps.api.addr_validate(data.as_str())?,
@@ -82,50 +282,141 @@ pub fn update_config(
         start_new_thread_fixed_cost: data
             .protocol_fee_start_new_thread_fixed_cost
             .unwrap_or(config.protocol_fee_config.start_new_thread_fixed_cost),
-        ask_in_thread_fee_percentage: data
-            .protocol_fee_ask_in_thread_fee_percentage
-            .unwrap_or(config.protocol_fee_config.ask_in_thread_fee_percentage),
-        reply_in_thread_fee_percentage: data
-            .protocol_fee_reply_in_thread_fee_percentage
-            .unwrap_or(config.protocol_fee_config.reply_in_thread_fee_percentage),
+        ask_in_thread_fee_bps: data
+            .protocol_fee_ask_in_thread_fee_bps
+            .unwrap_or(config.protocol_fee_config.ask_in_thread_fee_bps),
+        reply_in_thread_fee_bps: data
+            .protocol_fee_reply_in_thread_fee_bps
+            .unwrap_or(config.protocol_fee_config.reply_in_thread_fee_bps),
+        referral_fee_bps: data
+            .protocol_fee_referral_fee_bps
+            .unwrap_or(config.protocol_fee_config.referral_fee_bps),
     };
 
     config.default_fee_config = FeeConfig {
-        ask_fee_percentage_of_membership: data
-            .default_ask_fee_percentage_of_membership
-            .unwrap_or(config.default_fee_config.ask_fee_percentage_of_membership),
-        ask_fee_to_thread_creator_percentage_of_membership: data
-            .default_ask_fee_to_thread_creator_percentage_of_membership
+        ask_fee_bps_of_membership: data
+            .default_ask_fee_bps_of_membership
+            .unwrap_or(config.default_fee_config.ask_fee_bps_of_membership),
+        ask_fee_to_thread_creator_bps_of_membership: data
+            .default_ask_fee_to_thread_creator_bps_of_membership
             .unwrap_or(
                 config
                     .default_fee_config
-                    .ask_fee_to_thread_creator_percentage_of_membership,
+                    .ask_fee_to_thread_creator_bps_of_membership,
             ),
-        reply_fee_percentage_of_membership: data
-            .default_reply_fee_percentage_of_membership
-            .unwrap_or(config.default_fee_config.reply_fee_percentage_of_membership),
-        reply_fee_to_thread_creator_percentage_of_membership: data
-            .default_reply_fee_to_thread_creator_percentage_of_membership
+        reply_fee_bps_of_membership: data
+            .default_reply_fee_bps_of_membership
+            .unwrap_or(config.default_fee_config.reply_fee_bps_of_membership),
+        reply_fee_to_thread_creator_bps_of_membership: data
+            .default_reply_fee_to_thread_creator_bps_of_membership
             .unwrap_or(
                 config
                     .default_fee_config
-                    .reply_fee_to_thread_creator_percentage_of_membership,
+                    .reply_fee_to_thread_creator_bps_of_membership,
             ),
     };
 
     config.default_fee_share_config = FeeShareConfig {
-        share_to_issuer_percentage: data
-            .default_share_to_issuer_percentage
-            .unwrap_or(config.default_fee_share_config.share_to_issuer_percentage),
-        share_to_all_members_percentage: data.default_share_to_all_members_percentage.unwrap_or(
-            config
-                .default_fee_share_config
-                .share_to_all_members_percentage,
-        ),
+        share_to_issuer_bps: data
+            .default_share_to_issuer_bps
+            .unwrap_or(config.default_fee_share_config.share_to_issuer_bps),
+        share_to_all_members_bps: data
+            .default_share_to_all_members_bps
+            .unwrap_or(config.default_fee_share_config.share_to_all_members_bps),
+    };
+
+    config.config_change_timelock = match data.config_change_timelock {
+        None => config.config_change_timelock,
+        Some(config_change_timelock) => normalize_timelock(config_change_timelock),
+    };
+
+    config.user_fee_change_timelock = match data.user_fee_change_timelock {
+        None => config.user_fee_change_timelock,
+        Some(user_fee_change_timelock) => normalize_timelock(user_fee_change_timelock),
     };
 
+    config.user_fee_ranges = data.user_fee_ranges.unwrap_or(config.user_fee_ranges);
+
+    assert_config_fee_share_sum_to_10000(&config.default_fee_share_config)?;
+    assert_referral_fee_bps_within_10000(&config.protocol_fee_config)?;
+    assert_user_fee_ranges_valid(&config.user_fee_ranges)?;
+    assert_default_fee_config_in_ranges(&config.default_fee_config, &config.user_fee_ranges)?;
+
+    Ok(config)
+}
+
+pub fn propose_new_admin(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    data: ProposeNewAdminMsg,
+) -> Result<Response, ContractError> {
+    if !has_role(deps.storage, &info.sender, &Role::Admin)? {
+        return Err(ContractError::OnlyAdminCanProposeNewAdmin {});
+    }
+
+    let new_admin_addr = deps.api.addr_validate(data.new_admin_addr.as_str())?;
+    let expiration = data.expiration.unwrap_or_default();
+    if expiration.is_expired(&env.block) {
+        return Err(ContractError::AdminProposalExpired {});
+    }
+
+    PENDING_ADMIN_PROPOSAL.save(
+        deps.storage,
+        &AdminProposal {
+            new_admin_addr: new_admin_addr.clone(),
+            expiration,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "propose_new_admin")
+        .add_attribute("new_admin_addr", new_admin_addr)
+        .add_attribute("expiration", expiration.to_string()))
+}
+
+pub fn accept_admin(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    let admin_proposal = PENDING_ADMIN_PROPOSAL
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoPendingAdminProposal {})?;
+
+    if info.sender != admin_proposal.new_admin_addr {
+        return Err(ContractError::OnlyProposedAdminCanAcceptAdmin {});
+    }
+
+    if admin_proposal.expiration.is_expired(&env.block) {
+        return Err(ContractError::AdminProposalExpired {});
+    }
+
+    let mut config = CONFIG.load(deps.storage)?;
+    let previous_admin_addr = config.admin_addr;
+    transfer_role(
+        deps.storage,
+        &previous_admin_addr,
+        &admin_proposal.new_admin_addr,
+        &Role::Admin,
+    )?;
+    config.admin_addr = admin_proposal.new_admin_addr;
+
     CONFIG.save(deps.storage, &config)?;
-    assert_config_fee_share_sum_to_100(deps.as_ref())?;
+    PENDING_ADMIN_PROPOSAL.remove(deps.storage);
+
+    Ok(Response::new()
+        .add_attribute("action", "accept_admin")
+        .add_attribute("previous_admin_addr", previous_admin_addr)
+        .add_attribute("new_admin_addr", config.admin_addr))
+}
+
+pub fn cancel_admin_proposal(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    if !has_role(deps.storage, &info.sender, &Role::Admin)? {
+        return Err(ContractError::OnlyAdminCanCancelAdminProposal {});
+    }
+
+    if PENDING_ADMIN_PROPOSAL.may_load(deps.storage)?.is_none() {
+        return Err(ContractError::NoPendingAdminProposal {});
+    }
+
+    PENDING_ADMIN_PROPOSAL.remove(deps.storage);
 
-    Ok(Response::new().add_attribute("action", "update_config"))
+    Ok(Response::new().add_attribute("action", "cancel_admin_proposal"))
 }
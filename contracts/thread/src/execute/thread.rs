@@ -1,12 +1,13 @@
 use cosmwasm_std::{
-    to_binary, Addr, BankMsg, Coin, CosmosMsg, Decimal, DepsMut, MessageInfo, Response, Uint128,
-    Uint64, WasmMsg,
+    to_binary, Addr, BankMsg, Coin, CosmosMsg, Decimal, DepsMut, Env, MessageInfo, Response,
+    StdResult, Uint128, Uint64, WasmMsg,
 };
 
 use distribution_pkg::msg::{DistributeMsg, ExecuteMsg};
-use member_pkg::member_contract_querier::{
-    query_is_user_a_member_and_membership_amount, query_membership_supply, query_user_by_addr,
-    query_user_by_id,
+use member_pkg::member_contract_querier::MemberContractQuerier;
+use shared_pkg::{
+    event::{ThreadEvent, ThreadFees, ThreadMsgEvent, ThreadMsgFees, ThreadMsgKind},
+    refund::build_refund_msg,
 };
 use thread_pkg::{
     config::Config,
@@ -27,6 +28,7 @@ use crate::{
         ALL_USERS_PARTICIPATED_THREADS, ALL_USERS_THREAD_STATS, ALL_USERS_UNANSWERED_QUESTIONS,
         NEXT_THREAD_ID, NEXT_THREAD_MSG_ID,
     },
+    util::referral::pay_referral_fee,
     ContractError,
 };
 
@@ -41,8 +43,12 @@ pub fn start_new_thread(
     let config_copy = config.clone();
     let thread_config = config.thread_config;
     let member_contract_addr = config.member_contract_addr;
+    let member_contract_querier =
+        MemberContractQuerier::new(deps.querier, member_contract_addr.clone());
 
-    let thread_creator = query_user_by_addr(deps.as_ref(), member_contract_addr, info.sender);
+    let thread_creator = member_contract_querier
+        .user_by_addr(&info.sender)?
+        .ok_or(ContractError::UserNotExist {})?;
     let thread_creator_user_id = thread_creator.id.u64();
 
     // TODO: P1: allow user to start thread without having issued membership, maybe a thread only itself can interact with
@@ -69,9 +75,12 @@ pub fn start_new_thread(
     let cost_to_start_new_thread_response: CostToStartNewThreadResponse =
         query_cost_to_start_new_thread(config_copy)?;
 
-    if cost_to_start_new_thread_response.protocol_fee > user_paid_amount {
+    let total_needed_from_user = cost_to_start_new_thread_response
+        .protocol_fee
+        .checked_add(cost_to_start_new_thread_response.referral_fee)?;
+    if total_needed_from_user > user_paid_amount {
         return Err(ContractError::InsufficientFundsToPayDuringAsk {
-            needed: cost_to_start_new_thread_response.protocol_fee,
+            needed: total_needed_from_user,
             available: user_paid_amount,
         });
     }
@@ -130,35 +139,83 @@ pub fn start_new_thread(
 
     // Bump next_available_thread_id
     NEXT_THREAD_ID.save(deps.storage, &(thread_id + Uint64::one()))?;
+    // New thread has no message yet, so replies in it start from thread msg ID 1
+    NEXT_THREAD_MSG_ID.save(deps.storage, thread_id.u64(), &Uint64::one())?;
+    ALL_THREADS_MSGS_COUNT.save(deps.storage, thread_id.u64(), &Uint128::zero())?;
 
-    let msgs_vec = vec![
-        // Send protocol fee to fee collector
-        CosmosMsg::Bank(BankMsg::Send {
-            to_address: config.protocol_fee_collector_addr.to_string(),
-            amount: vec![Coin {
-                denom: fee_denom,
-                amount: cost_to_start_new_thread_response.protocol_fee,
-            }],
-        }),
-    ];
-
-    Ok(Response::new().add_messages(msgs_vec))
+    let mut msgs_vec = vec![];
+    if cost_to_start_new_thread_response.protocol_fee > Uint128::zero() {
+        msgs_vec.push(
+            // Send protocol fee to fee collector
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: config.protocol_fee_collector_addr.to_string(),
+                amount: vec![Coin {
+                    denom: fee_denom.clone(),
+                    amount: cost_to_start_new_thread_response.protocol_fee,
+                }],
+            }),
+        );
+    }
+    // Send referral fee to thread creator's referrer
+    if let Some(msg) = pay_referral_fee(
+        deps,
+        member_contract_addr,
+        &config.protocol_fee_collector_addr,
+        &thread_creator,
+        cost_to_start_new_thread_response.referral_fee,
+        &fee_denom,
+    )? {
+        msgs_vec.push(msg);
+    }
+
+    // Refund what user paid on top of what is needed
+    let (refund_amount, refund_msg) = build_refund_msg(
+        &info.sender,
+        user_paid_amount,
+        total_needed_from_user,
+        &fee_denom,
+    );
+    if let Some(msg) = refund_msg {
+        msgs_vec.push(msg);
+    }
+
+    Ok(Response::new()
+        .add_messages(msgs_vec)
+        .add_attribute("action", "start_new_thread")
+        .add_attribute("refunded_amount", refund_amount)
+        .add_event(
+            ThreadEvent {
+                thread_id,
+                creator_user_id: Uint64::from(thread_creator_user_id),
+                fees: ThreadFees {
+                    protocol_fee: cost_to_start_new_thread_response.protocol_fee,
+                    referral_fee: cost_to_start_new_thread_response.referral_fee,
+                    total_paid: total_needed_from_user,
+                    refunded_amount: refund_amount,
+                    fee_denom,
+                },
+            }
+            .into(),
+        ))
 }
 
 pub fn ask_in_thread(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     data: AskInThreadMsg,
     config: Config,
-    fee_denom: String,
     distribution_contract_addr: Addr,
-    user_paid_amount: Uint128,
 ) -> Result<Response, ContractError> {
     let config_copy = config.clone();
     let thread_config = config.thread_config;
     let member_contract_addr = config.member_contract_addr;
+    let member_contract_querier =
+        MemberContractQuerier::new(deps.querier, member_contract_addr.clone());
 
-    let asker = query_user_by_addr(deps.as_ref(), member_contract_addr.clone(), info.sender);
+    let asker = member_contract_querier
+        .user_by_addr(&info.sender)?
+        .ok_or(ContractError::UserNotExist {})?;
     let asker_user_id = asker.id.u64();
 
     if asker.membership_issued_by_me.is_none() {
@@ -166,43 +223,38 @@ pub fn ask_in_thread(
     }
 
     let (thread_creator_user_id, thread_creator) = if data.start_new_thread.unwrap_or(false) {
-        (asker_user_id, asker)
+        (asker_user_id, asker.clone())
     } else {
-        let thread = ALL_THREADS.load(deps.storage, data.thread_id.unwrap().u64())?;
-        let thread_creator = query_user_by_id(
-            deps.as_ref(),
-            member_contract_addr.clone(),
-            thread.creator_user_id.u64(),
-        );
+        let thread = ALL_THREADS
+            .may_load(
+                deps.storage,
+                data.thread_id
+                    .ok_or(ContractError::ThreadNotExist {})?
+                    .u64(),
+            )?
+            .ok_or(ContractError::ThreadNotExist {})?;
+        let thread_creator = member_contract_querier
+            .user_by_id(thread.creator_user_id.u64())?
+            .ok_or(ContractError::UserNotExist {})?;
         (thread_creator.id.u64(), thread_creator)
     };
 
-    let ask_to_user = query_user_by_id(
-        deps.as_ref(),
-        member_contract_addr.clone(),
-        data.ask_to_user_id.u64(),
-    );
+    let ask_to_user = member_contract_querier
+        .user_by_id(data.ask_to_user_id.u64())?
+        .ok_or(ContractError::UserNotExist {})?;
     let ask_to_user_id = ask_to_user.id.u64();
 
-    if !query_is_user_a_member_and_membership_amount(
-        deps.as_ref(),
-        member_contract_addr.clone(),
-        ask_to_user_id,
-        asker_user_id,
-    )
-    .0
+    if !member_contract_querier
+        .is_member_and_membership_amount(ask_to_user_id, asker_user_id)?
+        .0
     {
         return Err(ContractError::UserMustHoldAskToUserMembershipToAsk {});
     }
 
     if thread_creator_user_id != asker_user_id
-        && !query_is_user_a_member_and_membership_amount(
-            deps.as_ref(),
-            member_contract_addr.clone(),
-            thread_creator_user_id,
-            asker_user_id,
-        )
-        .0
+        && !member_contract_querier
+            .is_member_and_membership_amount(thread_creator_user_id, asker_user_id)?
+            .0
     {
         return Err(ContractError::UserMustHoldThreadCreatorMembershipToAskInItsThread {});
     }
@@ -230,6 +282,7 @@ pub fn ask_in_thread(
 
     let cost_to_ask_response: CostToAskInThreadResponse = query_cost_to_ask_in_thread(
         deps.as_ref(),
+        env,
         QueryCostToAskInThreadMsg {
             asker_user_id: Uint64::from(asker_user_id),
             ask_to_user_id: Uint64::from(ask_to_user_id),
@@ -239,6 +292,10 @@ pub fn ask_in_thread(
         config_copy,
     )?;
 
+    // Fees are paid in the denom of the membership asked / replied to
+    let fee_denom = cost_to_ask_response.fee_denom.clone();
+    let user_paid_amount = cw_utils::must_pay(&info, &fee_denom)?;
+
     if cost_to_ask_response.total_needed_from_user > user_paid_amount {
         return Err(ContractError::InsufficientFundsToPayDuringAsk {
             needed: cost_to_ask_response.total_needed_from_user,
@@ -249,9 +306,10 @@ pub fn ask_in_thread(
     let (thread_id, thread_msg_id) = if data.start_new_thread.unwrap_or(false) {
         (NEXT_THREAD_ID.load(deps.storage)?, Uint64::one())
     } else {
+        let thread_id = data.thread_id.ok_or(ContractError::ThreadNotExist {})?;
         (
-            data.thread_id.unwrap(),
-            NEXT_THREAD_MSG_ID.load(deps.storage, data.thread_id.unwrap().u64())?,
+            thread_id,
+            NEXT_THREAD_MSG_ID.load(deps.storage, thread_id.u64())?,
         )
     };
 
@@ -261,8 +319,8 @@ pub fn ask_in_thread(
             None => {
                 let thread = Thread {
                     id: thread_id,
-                    title: data.thread_title.unwrap(),
-                    description: data.thread_description.unwrap(),
+                    title: data.thread_title.unwrap_or_default(),
+                    description: data.thread_description.unwrap_or_default(),
                     labels: data.thread_labels.unwrap_or(vec![]),
                     creator_user_id: Uint64::from(asker_user_id),
                     updatable: false,
@@ -304,13 +362,11 @@ pub fn ask_in_thread(
                 }
             },
         )?;
+        // Asker may already be in the thread, e.g. asked before
         ALL_USERS_PARTICIPATED_THREADS.update(
             deps.storage,
             (asker_user_id, thread_id.u64()),
-            |thread| match thread {
-                None => Ok(true),
-                Some(_) => Err(ContractError::ThreadAlreadyExist {}),
-            },
+            |thread| -> StdResult<bool> { Ok(thread.unwrap_or(true)) },
         )?;
         ALL_USERS_THREAD_STATS.update(deps.storage, thread_creator_user_id, |thread_stats| {
             match thread_stats {
@@ -322,7 +378,7 @@ pub fn ask_in_thread(
         })?;
     }
 
-    if ALL_USERS_THREAD_STATS.has(deps.storage, ask_to_user_id) {
+    if ALL_USERS_THREAD_STATS.has(deps.storage, asker_user_id) {
         ALL_USERS_THREAD_STATS.update(deps.storage, asker_user_id, |thread_stats| {
             match thread_stats {
                 None => Err(ContractError::UserNotExist {}),
@@ -341,13 +397,11 @@ pub fn ask_in_thread(
 
     // Add to ask to's list of threads they belong to
     // ALL_THREADS_USERS_BELONG_TO.save(deps.storage, (&info.sender, thread_id.u64()), &true)?;
+    // Ask to user may already be in the thread, e.g. it is the thread creator
     ALL_USERS_PARTICIPATED_THREADS.update(
         deps.storage,
         (ask_to_user_id, thread_id.u64()),
-        |thread| match thread {
-            None => Ok(false),
-            Some(_) => Err(ContractError::ThreadAlreadyExist {}),
-        },
+        |thread| -> StdResult<bool> { Ok(thread.unwrap_or(false)) },
     )?;
 
     // Add to unanswered question list
@@ -383,53 +437,69 @@ pub fn ask_in_thread(
         ALL_THREADS_MSGS_COUNT.save(deps.storage, thread_id.u64(), &Uint128::one())?;
     }
 
-    let ask_to_membership_supply =
-        query_membership_supply(deps.as_ref(), member_contract_addr.clone(), ask_to_user_id);
+    let ask_to_membership_supply = member_contract_querier.membership_supply(ask_to_user_id)?;
     let thread_creator_membership_supply =
-        query_membership_supply(deps.as_ref(), member_contract_addr, thread_creator_user_id);
+        member_contract_querier.membership_supply(thread_creator_user_id)?;
 
     // TODO: P1: do not send membership issuer fee to membership issuer until question is answered
     // TODO: P1: decide if we want to hold payout to membership holders as well, i think we should, give it more pressure to answer
     // We can do those fancy trick later, as now if i ask a question and not get answer, i won't ask again
 
     let mut msgs_vec = vec![];
-    msgs_vec.push(
-        // Send all member fee to distribution contract
-        CosmosMsg::Wasm(WasmMsg::Execute {
-            contract_addr: distribution_contract_addr.to_string(),
-            msg: to_binary(&ExecuteMsg::Distribute(DistributeMsg {
-                membership_issuer_user_id: Uint64::from(ask_to_user_id),
-                index_increment: Decimal::from_ratio(
-                    cost_to_ask_response.ask_to_membership_all_members_fee,
-                    ask_to_membership_supply,
-                ),
-            }))?,
-            funds: vec![Coin {
-                denom: fee_denom.clone(),
-                amount: cost_to_ask_response.ask_to_membership_all_members_fee,
-            }],
-        }),
-    );
-    msgs_vec.push(
-        // Send membership issuer fee to membership issuer
-        CosmosMsg::Bank(BankMsg::Send {
-            to_address: ask_to_user.addr.to_string(),
-            amount: vec![Coin {
-                denom: fee_denom.clone(),
-                amount: cost_to_ask_response.ask_to_membership_issuer_fee,
-            }],
-        }),
-    );
-    msgs_vec.push(
-        // Send protocol fee to fee collector
-        CosmosMsg::Bank(BankMsg::Send {
-            to_address: config.protocol_fee_collector_addr.to_string(),
-            amount: vec![Coin {
-                denom: fee_denom.clone(),
-                amount: cost_to_ask_response.protocol_fee,
-            }],
-        }),
-    );
+    if cost_to_ask_response.ask_to_membership_all_members_fee > Uint128::zero() {
+        msgs_vec.push(
+            // Send all member fee to distribution contract
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: distribution_contract_addr.to_string(),
+                msg: to_binary(&ExecuteMsg::Distribute(DistributeMsg {
+                    membership_issuer_user_id: Uint64::from(ask_to_user_id),
+                    index_increment: Decimal::from_ratio(
+                        cost_to_ask_response.ask_to_membership_all_members_fee,
+                        ask_to_membership_supply,
+                    ),
+                }))?,
+                funds: vec![Coin {
+                    denom: fee_denom.clone(),
+                    amount: cost_to_ask_response.ask_to_membership_all_members_fee,
+                }],
+            }),
+        );
+    }
+    if cost_to_ask_response.ask_to_membership_issuer_fee > Uint128::zero() {
+        msgs_vec.push(
+            // Send membership issuer fee to membership issuer
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: ask_to_user.addr.to_string(),
+                amount: vec![Coin {
+                    denom: fee_denom.clone(),
+                    amount: cost_to_ask_response.ask_to_membership_issuer_fee,
+                }],
+            }),
+        );
+    }
+    if cost_to_ask_response.protocol_fee > Uint128::zero() {
+        msgs_vec.push(
+            // Send protocol fee to fee collector
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: config.protocol_fee_collector_addr.to_string(),
+                amount: vec![Coin {
+                    denom: fee_denom.clone(),
+                    amount: cost_to_ask_response.protocol_fee,
+                }],
+            }),
+        );
+    }
+    // Send referral fee to asker's referrer
+    if let Some(msg) = pay_referral_fee(
+        deps,
+        member_contract_addr,
+        &config.protocol_fee_collector_addr,
+        &asker,
+        cost_to_ask_response.referral_fee,
+        &fee_denom,
+    )? {
+        msgs_vec.push(msg);
+    }
 
     // Send asker's question fee to thread creator if thread creator is not the asker
     if cost_to_ask_response.thread_creator_membership_all_members_fee > Uint128::zero() {
@@ -467,7 +537,61 @@ pub fn ask_in_thread(
 
     // TODO: P0: send tip to answerer
 
-    Ok(Response::new().add_messages(msgs_vec))
+    // Refund what user paid on top of what is needed
+    let (refund_amount, refund_msg) = build_refund_msg(
+        &info.sender,
+        user_paid_amount,
+        cost_to_ask_response.total_needed_from_user,
+        &fee_denom,
+    );
+    if let Some(msg) = refund_msg {
+        msgs_vec.push(msg);
+    }
+
+    let mut resp = Response::new()
+        .add_messages(msgs_vec)
+        .add_attribute("action", "ask_in_thread")
+        .add_attribute("refunded_amount", refund_amount);
+    if data.start_new_thread.unwrap_or(false) {
+        resp = resp.add_event(
+            ThreadEvent {
+                thread_id,
+                creator_user_id: Uint64::from(asker_user_id),
+                fees: ThreadFees {
+                    fee_denom: fee_denom.clone(),
+                    ..ThreadFees::default()
+                },
+            }
+            .into(),
+        );
+    }
+
+    Ok(resp.add_event(
+        ThreadMsgEvent {
+            kind: ThreadMsgKind::Question,
+            thread_id,
+            thread_msg_id,
+            creator_user_id: Uint64::from(asker_user_id),
+            thread_creator_user_id: Uint64::from(thread_creator_user_id),
+            to_user_id: Uint64::from(ask_to_user_id),
+            to_thread_msg_id: Uint64::zero(),
+            fees: ThreadMsgFees {
+                protocol_fee: cost_to_ask_response.protocol_fee,
+                referral_fee: cost_to_ask_response.referral_fee,
+                to_membership_issuer_fee: cost_to_ask_response.ask_to_membership_issuer_fee,
+                to_membership_all_members_fee: cost_to_ask_response
+                    .ask_to_membership_all_members_fee,
+                thread_creator_membership_issuer_fee: cost_to_ask_response
+                    .thread_creator_membership_issuer_fee,
+                thread_creator_membership_all_members_fee: cost_to_ask_response
+                    .thread_creator_membership_all_members_fee,
+                total_paid: cost_to_ask_response.total_needed_from_user,
+                refunded_amount: refund_amount,
+                fee_denom,
+            },
+        }
+        .into(),
+    ))
 }
 
 pub fn answer_in_thread(
@@ -477,17 +601,22 @@ pub fn answer_in_thread(
     config: Config,
 ) -> Result<Response, ContractError> {
     let thread_config = config.thread_config;
-    let member_contract_addr = config.member_contract_addr;
+    let member_contract_querier =
+        MemberContractQuerier::new(deps.querier, config.member_contract_addr);
 
     let thread_id = data.thread_id.u64();
     let question_id = data.question_id.u64();
 
-    let answerer = query_user_by_addr(deps.as_ref(), member_contract_addr, info.sender);
+    let answerer = member_contract_querier
+        .user_by_addr(&info.sender)?
+        .ok_or(ContractError::UserNotExist {})?;
     let answerer_user_id = answerer.id.u64();
 
-    if answerer.membership_issued_by_me.is_none() {
-        return Err(ContractError::UserMustHaveIssuedMembershipToAnswer {});
-    }
+    // Answer is free, fee denom of answerer's membership is only used in thread msg event
+    let answerer_fee_denom = match answerer.membership_issued_by_me {
+        None => return Err(ContractError::UserMustHaveIssuedMembershipToAnswer {}),
+        Some(membership_issued_by_me) => membership_issued_by_me.fee_denom,
+    };
 
     let question = ALL_THREADS_MSGS.load(deps.storage, (thread_id, question_id))?;
 
@@ -505,17 +634,17 @@ pub fn answer_in_thread(
         return Err(ContractError::CannotAnswerOthersQuestion {});
     }
 
+    let thread = ALL_THREADS.load(deps.storage, thread_id)?;
+
     let thread_msg_id = NEXT_THREAD_MSG_ID.load(deps.storage, thread_id)?;
 
     // Bump next_available_thread_msg_id
-    NEXT_THREAD_MSG_ID.update(
-        deps.storage,
-        question.id.u64(),
-        |next_available_thread_msg_id| match next_available_thread_msg_id {
+    NEXT_THREAD_MSG_ID.update(deps.storage, thread_id, |next_available_thread_msg_id| {
+        match next_available_thread_msg_id {
             None => Err(ContractError::ThreadNotExist {}),
             Some(next_available_thread_msg_id) => Ok(next_available_thread_msg_id + Uint64::one()),
-        },
-    )?;
+        }
+    })?;
 
     if data.content.chars().count() > thread_config.max_thread_msg_length.u64() as usize {
         return Err(ContractError::ThreadMsgContentTooLong {
@@ -526,7 +655,7 @@ pub fn answer_in_thread(
 
     ALL_THREADS_MSGS.update(
         deps.storage,
-        (data.thread_id.u64(), data.question_id.u64()),
+        (thread_id, thread_msg_id.u64()),
         |thread_msg| match thread_msg {
             None => {
                 let new_answer = ThreadMsg::ThreadAnswerMsg(ThreadAnswerMsg {
@@ -538,7 +667,7 @@ pub fn answer_in_thread(
                 });
                 Ok(new_answer)
             }
-            Some(_) => Err(ContractError::ThreadMsgNotExist {}),
+            Some(_) => Err(ContractError::ThreadMsgAlreadyExist {}),
         },
     )?;
 
@@ -581,25 +710,45 @@ pub fn answer_in_thread(
         Some(count) => Ok(count + Uint128::one()),
     })?;
 
-    Ok(Response::new())
+    Ok(Response::new()
+        .add_attribute("action", "answer_in_thread")
+        .add_event(
+            ThreadMsgEvent {
+                kind: ThreadMsgKind::Answer,
+                thread_id: data.thread_id,
+                thread_msg_id,
+                creator_user_id: Uint64::from(answerer_user_id),
+                thread_creator_user_id: thread.creator_user_id,
+                to_user_id: question.creator_user_id,
+                to_thread_msg_id: data.question_id,
+                fees: ThreadMsgFees {
+                    fee_denom: answerer_fee_denom,
+                    ..ThreadMsgFees::default()
+                },
+            }
+            .into(),
+        ))
 }
 
 pub fn reply_in_thread(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     data: ReplyInThreadMsg,
     config: Config,
-    fee_denom: String,
     distribution_contract_addr: Addr,
-    user_paid_amount: Uint128,
 ) -> Result<Response, ContractError> {
     let config_copy = config.clone();
     let thread_config = config.thread_config;
     let member_contract_addr = config.member_contract_addr;
+    let member_contract_querier =
+        MemberContractQuerier::new(deps.querier, member_contract_addr.clone());
 
     let thread_id = data.thread_id.u64();
 
-    let replier = query_user_by_addr(deps.as_ref(), member_contract_addr.clone(), info.sender);
+    let replier = member_contract_querier
+        .user_by_addr(&info.sender)?
+        .ok_or(ContractError::UserNotExist {})?;
     let replier_user_id = replier.id.u64();
 
     if replier.membership_issued_by_me.is_none() {
@@ -607,61 +756,48 @@ pub fn reply_in_thread(
     }
 
     let thread = ALL_THREADS.load(deps.storage, thread_id)?;
-    let thread_creator = query_user_by_id(
-        deps.as_ref(),
-        member_contract_addr.clone(),
-        thread.creator_user_id.u64(),
-    );
+    let thread_creator = member_contract_querier
+        .user_by_id(thread.creator_user_id.u64())?
+        .ok_or(ContractError::UserNotExist {})?;
     let thread_creator_user_id = thread_creator.id.u64();
 
-    let (_, reply_to_user, reply_to_user_id) = if data.reply_to_thread_msg_id.is_some() {
-        let reply_to_thread_msg = ALL_THREADS_MSGS.load(
-            deps.storage,
+    let (_, reply_to_user, reply_to_user_id) =
+        if let Some(reply_to_thread_msg_id) = data.reply_to_thread_msg_id {
+            let reply_to_thread_msg = ALL_THREADS_MSGS.load(
+                deps.storage,
+                (data.thread_id.u64(), reply_to_thread_msg_id.u64()),
+            )?;
+            let reply_to_user_id = match reply_to_thread_msg.clone() {
+                ThreadMsg::ThreadAnswerMsg(answer) => answer.creator_user_id,
+                ThreadMsg::ThreadQuestionMsg(question) => question.creator_user_id,
+                ThreadMsg::ThreadReplyMsg(reply) => reply.creator_user_id,
+            };
+            let reply_to_user = member_contract_querier
+                .user_by_id(reply_to_user_id.u64())?
+                .ok_or(ContractError::UserNotExist {})?;
             (
-                data.thread_id.u64(),
-                data.reply_to_thread_msg_id.unwrap().u64(),
-            ),
-        )?;
-        let reply_to_user_id = match reply_to_thread_msg.clone() {
-            ThreadMsg::ThreadAnswerMsg(answer) => answer.creator_user_id,
-            ThreadMsg::ThreadQuestionMsg(question) => question.creator_user_id,
-            ThreadMsg::ThreadReplyMsg(reply) => reply.creator_user_id,
+                Some(reply_to_thread_msg),
+                Some(reply_to_user),
+                Some(reply_to_user_id.u64()),
+            )
+        } else {
+            (None, None, None)
         };
-        let reply_to_user = query_user_by_id(
-            deps.as_ref(),
-            member_contract_addr.clone(),
-            reply_to_user_id.u64(),
-        );
-        (
-            Some(reply_to_thread_msg),
-            Some(reply_to_user),
-            Some(reply_to_user_id.u64()),
-        )
-    } else {
-        (None, None, None)
-    };
 
-    if !query_is_user_a_member_and_membership_amount(
-        deps.as_ref(),
-        member_contract_addr.clone(),
-        thread_creator_user_id,
-        replier_user_id,
-    )
-    .0
+    if !member_contract_querier
+        .is_member_and_membership_amount(thread_creator_user_id, replier_user_id)?
+        .0
     {
         return Err(ContractError::UserMustHoldThreadCreatorMembershipToReply {});
     }
 
-    if reply_to_user_id.is_some()
-        && !query_is_user_a_member_and_membership_amount(
-            deps.as_ref(),
-            member_contract_addr.clone(),
-            reply_to_user_id.unwrap(),
-            replier_user_id,
-        )
-        .0
-    {
-        return Err(ContractError::UserMustHoldThreadReplyToUserMembershipToReply {});
+    if let Some(reply_to_user_id) = reply_to_user_id {
+        if !member_contract_querier
+            .is_member_and_membership_amount(reply_to_user_id, replier_user_id)?
+            .0
+        {
+            return Err(ContractError::UserMustHoldThreadReplyToUserMembershipToReply {});
+        }
     }
 
     let title_len = data.content.chars().count() as u64;
@@ -681,19 +817,20 @@ pub fn reply_in_thread(
     }
     let cost_to_reply_response: CostToReplyInThreadResponse = query_cost_to_reply_in_thread(
         deps.as_ref(),
+        env,
         QueryCostToReplyInThreadMsg {
             replier_user_id: Uint64::from(replier_user_id),
-            reply_to_user_id: Uint64::from(if reply_to_user_id.is_some() {
-                reply_to_user_id.unwrap()
-            } else {
-                thread_creator_user_id
-            }),
+            reply_to_user_id: Uint64::from(reply_to_user_id.unwrap_or(thread_creator_user_id)),
             thread_creator_user_id: Uint64::from(thread_creator_user_id),
             content_len: Uint64::from(content_len),
         },
         config_copy,
     )?;
 
+    // Fees are paid in the denom of the membership asked / replied to
+    let fee_denom = cost_to_reply_response.fee_denom.clone();
+    let user_paid_amount = cw_utils::must_pay(&info, &fee_denom)?;
+
     if cost_to_reply_response.total_needed_from_user > user_paid_amount {
         return Err(ContractError::InsufficientFundsToPayDuringAsk {
             needed: cost_to_reply_response.total_needed_from_user,
@@ -760,43 +897,26 @@ pub fn reply_in_thread(
         Some(count) => Ok(count + Uint128::one()),
     })?;
 
-    let (reply_to_membership_supply, thread_creator_membership_supply) =
-        if let Some(reply_to_user_id) = reply_to_user_id {
-            (
-                query_membership_supply(
-                    deps.as_ref(),
-                    member_contract_addr.clone(),
-                    reply_to_user_id,
-                ),
-                query_membership_supply(
-                    deps.as_ref(),
-                    member_contract_addr,
-                    thread_creator_user_id,
-                ),
-            )
-        } else {
-            let thread_creator_membership_supply = query_membership_supply(
-                deps.as_ref(),
-                member_contract_addr,
-                thread_creator_user_id,
-            );
-            (
-                thread_creator_membership_supply,
-                thread_creator_membership_supply,
-            )
-        };
+    // Replying to the thread itself pays reply fee to thread creator
+    let (fee_to_user, fee_to_user_id) = match (reply_to_user, reply_to_user_id) {
+        (Some(reply_to_user), Some(reply_to_user_id)) => (reply_to_user, reply_to_user_id),
+        _ => (thread_creator.clone(), thread_creator_user_id),
+    };
+    let fee_to_membership_supply = member_contract_querier.membership_supply(fee_to_user_id)?;
+    let thread_creator_membership_supply =
+        member_contract_querier.membership_supply(thread_creator_user_id)?;
 
     let mut msgs_vec = vec![];
-    if data.reply_to_thread_msg_id.is_some() {
+    if cost_to_reply_response.reply_to_membership_all_members_fee > Uint128::zero() {
         msgs_vec.push(
             // Send all member fee to distribution contract
             CosmosMsg::Wasm(WasmMsg::Execute {
                 contract_addr: distribution_contract_addr.to_string(),
                 msg: to_binary(&ExecuteMsg::Distribute(DistributeMsg {
-                    membership_issuer_user_id: Uint64::from(reply_to_user_id.unwrap()),
+                    membership_issuer_user_id: Uint64::from(fee_to_user_id),
                     index_increment: Decimal::from_ratio(
                         cost_to_reply_response.reply_to_membership_all_members_fee,
-                        reply_to_membership_supply,
+                        fee_to_membership_supply,
                     ),
                 }))?,
                 funds: vec![Coin {
@@ -805,16 +925,20 @@ pub fn reply_in_thread(
                 }],
             }),
         );
+    }
+    if cost_to_reply_response.reply_to_membership_issuer_fee > Uint128::zero() {
         msgs_vec.push(
             // Send membership issuer fee to membership issuer
             CosmosMsg::Bank(BankMsg::Send {
-                to_address: reply_to_user.unwrap().addr.to_string(),
+                to_address: fee_to_user.addr.to_string(),
                 amount: vec![Coin {
                     denom: fee_denom.clone(),
                     amount: cost_to_reply_response.reply_to_membership_issuer_fee,
                 }],
             }),
         );
+    }
+    if cost_to_reply_response.protocol_fee > Uint128::zero() {
         msgs_vec.push(
             // Send protocol fee to fee collector
             CosmosMsg::Bank(BankMsg::Send {
@@ -826,6 +950,17 @@ pub fn reply_in_thread(
             }),
         );
     }
+    // Send referral fee to replier's referrer
+    if let Some(msg) = pay_referral_fee(
+        deps,
+        member_contract_addr,
+        &config.protocol_fee_collector_addr,
+        &replier,
+        cost_to_reply_response.referral_fee,
+        &fee_denom,
+    )? {
+        msgs_vec.push(msg);
+    }
 
     // Send asker's question fee to thread creator if thread creator is not the asker
     if cost_to_reply_response.thread_creator_membership_all_members_fee > Uint128::zero() {
@@ -861,5 +996,45 @@ pub fn reply_in_thread(
         );
     }
 
-    Ok(Response::new().add_messages(msgs_vec))
+    // Refund what user paid on top of what is needed
+    let (refund_amount, refund_msg) = build_refund_msg(
+        &info.sender,
+        user_paid_amount,
+        cost_to_reply_response.total_needed_from_user,
+        &fee_denom,
+    );
+    if let Some(msg) = refund_msg {
+        msgs_vec.push(msg);
+    }
+
+    Ok(Response::new()
+        .add_messages(msgs_vec)
+        .add_attribute("action", "reply_in_thread")
+        .add_attribute("refunded_amount", refund_amount)
+        .add_event(
+            ThreadMsgEvent {
+                kind: ThreadMsgKind::Reply,
+                thread_id: data.thread_id,
+                thread_msg_id,
+                creator_user_id: Uint64::from(replier_user_id),
+                thread_creator_user_id: Uint64::from(thread_creator_user_id),
+                to_user_id: Uint64::from(fee_to_user_id),
+                to_thread_msg_id: data.reply_to_thread_msg_id.unwrap_or_default(),
+                fees: ThreadMsgFees {
+                    protocol_fee: cost_to_reply_response.protocol_fee,
+                    referral_fee: cost_to_reply_response.referral_fee,
+                    to_membership_issuer_fee: cost_to_reply_response.reply_to_membership_issuer_fee,
+                    to_membership_all_members_fee: cost_to_reply_response
+                        .reply_to_membership_all_members_fee,
+                    thread_creator_membership_issuer_fee: cost_to_reply_response
+                        .thread_creator_membership_issuer_fee,
+                    thread_creator_membership_all_members_fee: cost_to_reply_response
+                        .thread_creator_membership_all_members_fee,
+                    total_paid: cost_to_reply_response.total_needed_from_user,
+                    refunded_amount: refund_amount,
+                    fee_denom,
+                },
+            }
+            .into(),
+        ))
 }
@@ -1,107 +1,116 @@
-use cosmwasm_std::{Addr, DepsMut, MessageInfo, Response};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use cw_utils::Expiration;
 
-use member_pkg::member_contract_querier::query_user_by_id;
+use member_pkg::member_contract_querier::MemberContractQuerier;
 use shared_pkg::fee_share_config::FeeShareConfig;
-use thread_pkg::{config::FeeConfig, msg::UpdateUserConfigMsg, user_config::UserConfig};
+use thread_pkg::{config::Config, msg::UpdateUserConfigMsg, user_config::PendingUserFeeChange};
 
 use crate::{
-    state::ALL_USER_CONFIGS, util::fee_share::assert_user_fee_share_sum_to_100, ContractError,
+    state::{ALL_PENDING_USER_FEE_CHANGES, ALL_USER_CONFIGS},
+    util::{
+        fee_range::assert_user_fees_in_ranges,
+        fee_share::assert_user_fee_share_sum_to_10000,
+        user_config::{apply_user_fee_change, load_effective_user_config},
+    },
+    ContractError,
 };
 
 pub fn update_user_config(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     data: UpdateUserConfigMsg,
-    member_contract_addr: Addr,
+    config: Config,
 ) -> Result<Response, ContractError> {
     let user_id = data.user_id.u64();
-    let user = query_user_by_id(deps.as_ref(), member_contract_addr, user_id);
+    let user = MemberContractQuerier::new(deps.querier, config.member_contract_addr.clone())
+        .user_by_id(user_id)?
+        .ok_or(ContractError::UserNotExist {})?;
 
     if info.sender != user.addr {
         return Err(ContractError::OnlyUserCanUpdateItsOwnConfig {});
     }
 
-    ALL_USER_CONFIGS.update(deps.storage, user_id, |user| match user {
-        // User should exist in ALL_USER_CONFIGS as it should be registered
-        None => Err(ContractError::UserNotExist {}),
-        Some(user) => {
-            let user_fee_config = user.fee_config;
-            let updated_user = UserConfig {
-                fee_config: if data.ask_fee_percentage_of_membership.is_some()
-                    || data
-                        .ask_fee_to_thread_creator_percentage_of_membership
-                        .is_some()
-                    || data.reply_fee_percentage_of_membership.is_some()
-                    || data
-                        .reply_fee_to_thread_creator_percentage_of_membership
-                        .is_some()
-                {
-                    Some(FeeConfig {
-                        ask_fee_percentage_of_membership: match data
-                            .ask_fee_percentage_of_membership
-                        {
-                            None => {
-                                user_fee_config
-                                    .clone()
-                                    .unwrap()
-                                    .ask_fee_percentage_of_membership
-                            }
-                            Some(data) => data,
-                        },
-                        ask_fee_to_thread_creator_percentage_of_membership: match data
-                            .ask_fee_to_thread_creator_percentage_of_membership
-                        {
-                            None => {
-                                user_fee_config
-                                    .clone()
-                                    .unwrap()
-                                    .ask_fee_to_thread_creator_percentage_of_membership
-                            }
-                            Some(data) => data,
-                        },
-                        reply_fee_percentage_of_membership: match data
-                            .reply_fee_percentage_of_membership
-                        {
-                            None => {
-                                user_fee_config
-                                    .clone()
-                                    .unwrap()
-                                    .reply_fee_percentage_of_membership
-                            }
-                            Some(data) => data,
-                        },
-                        reply_fee_to_thread_creator_percentage_of_membership: match data
-                            .reply_fee_to_thread_creator_percentage_of_membership
-                        {
-                            None => {
-                                user_fee_config
-                                    .unwrap()
-                                    .reply_fee_to_thread_creator_percentage_of_membership
-                            }
-                            Some(data) => data,
-                        },
-                    })
-                } else {
-                    user_fee_config
-                },
-                fee_share_config: if data.share_to_all_members_percentage.is_some() {
-                    Some(FeeShareConfig {
-                        share_to_all_members_percentage: data
-                            .share_to_all_members_percentage
-                            .unwrap(),
-                        share_to_issuer_percentage: data.share_to_issuer_percentage.unwrap(),
-                    })
-                } else {
-                    user.fee_share_config
-                },
-            };
-            Ok(updated_user)
-        }
-    })?;
+    assert_user_fees_in_ranges(
+        data.ask_fee_bps_of_membership,
+        data.ask_fee_to_thread_creator_bps_of_membership,
+        data.reply_fee_bps_of_membership,
+        data.reply_fee_to_thread_creator_bps_of_membership,
+        &config.user_fee_ranges,
+    )?;
+
+    // Both shares are set together, one alone cannot sum to 10000
+    let fee_share_config = match (data.share_to_issuer_bps, data.share_to_all_members_bps) {
+        (Some(share_to_issuer_bps), Some(share_to_all_members_bps)) => Some(FeeShareConfig {
+            share_to_issuer_bps,
+            share_to_all_members_bps,
+        }),
+        (None, None) => None,
+        _ => return Err(ContractError::ThreadFeeShareBpsMustSumTo10000 {}),
+    };
+    if let Some(fee_share_config) = &fee_share_config {
+        assert_user_fee_share_sum_to_10000(fee_share_config)?;
+    }
 
-    assert_user_fee_share_sum_to_100(deps.as_ref(), user_id)?;
+    // Write fee change that has taken effect to user config before it gets replaced
+    let (mut user_config, pending_fee_change) =
+        load_effective_user_config(deps.storage, &env.block, &config.user_fee_ranges, user_id)?;
+    ALL_PENDING_USER_FEE_CHANGES.remove(deps.storage, user_id);
 
-    Ok(Response::new()
+    let mut fee_change = PendingUserFeeChange {
+        ask_fee_bps_of_membership: data.ask_fee_bps_of_membership,
+        ask_fee_to_thread_creator_bps_of_membership: data
+            .ask_fee_to_thread_creator_bps_of_membership,
+        reply_fee_bps_of_membership: data.reply_fee_bps_of_membership,
+        reply_fee_to_thread_creator_bps_of_membership: data
+            .reply_fee_to_thread_creator_bps_of_membership,
+        fee_share_config,
+        effective_at: match config.user_fee_change_timelock {
+            None => Expiration::AtHeight(env.block.height),
+            Some(user_fee_change_timelock) => user_fee_change_timelock.after(&env.block),
+        },
+    };
+
+    let mut response = Response::new()
         .add_attribute("action", "update_user_config")
-        .add_attribute("user_id", data.user_id))
+        .add_attribute("user_id", data.user_id);
+
+    if fee_change.is_empty() {
+        // Nothing to replace, keep fee change still waiting for the time-lock
+        if let Some(pending_fee_change) = pending_fee_change {
+            ALL_PENDING_USER_FEE_CHANGES.save(deps.storage, user_id, &pending_fee_change)?;
+        }
+    } else if config.user_fee_change_timelock.is_none() {
+        // Fee change left from when time-lock was set is replaced as well
+        apply_user_fee_change(&mut user_config, fee_change);
+    } else {
+        // New fee change replaces the pending one, fees it leaves out keep their pending value
+        // Time-lock restarts so askers and repliers always get the full delay to react
+        if let Some(pending_fee_change) = pending_fee_change {
+            fee_change.ask_fee_bps_of_membership = fee_change
+                .ask_fee_bps_of_membership
+                .or(pending_fee_change.ask_fee_bps_of_membership);
+            fee_change.ask_fee_to_thread_creator_bps_of_membership = fee_change
+                .ask_fee_to_thread_creator_bps_of_membership
+                .or(pending_fee_change.ask_fee_to_thread_creator_bps_of_membership);
+            fee_change.reply_fee_bps_of_membership = fee_change
+                .reply_fee_bps_of_membership
+                .or(pending_fee_change.reply_fee_bps_of_membership);
+            fee_change.reply_fee_to_thread_creator_bps_of_membership = fee_change
+                .reply_fee_to_thread_creator_bps_of_membership
+                .or(pending_fee_change.reply_fee_to_thread_creator_bps_of_membership);
+            fee_change.fee_share_config = fee_change
+                .fee_share_config
+                .or(pending_fee_change.fee_share_config);
+        }
+        response = response.add_attribute(
+            "fee_change_effective_at",
+            fee_change.effective_at.to_string(),
+        );
+        ALL_PENDING_USER_FEE_CHANGES.save(deps.storage, user_id, &fee_change)?;
+    }
+
+    ALL_USER_CONFIGS.save(deps.storage, user_id, &user_config)?;
+
+    Ok(response)
 }
@@ -1,3 +1,4 @@
 pub(crate) mod config;
+pub(crate) mod referral;
 pub(crate) mod thread;
 pub(crate) mod user_config;
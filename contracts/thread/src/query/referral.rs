@@ -0,0 +1,57 @@
+use cosmwasm_std::{Coin, Deps, Order, StdResult, Uint64};
+use cw_storage_plus::Bound;
+
+use member_pkg::user::Referee;
+use thread_pkg::msg::{QueryRefereesMsg, RefereesResponse};
+
+use crate::state::{ALL_REFERRERS_REFEREES, DEFAULT_QUERY_LIMIT, MAX_QUERY_LIMIT};
+
+pub fn query_referees(deps: Deps, data: QueryRefereesMsg) -> StdResult<RefereesResponse> {
+    let limit = data
+        .limit
+        .unwrap_or(DEFAULT_QUERY_LIMIT)
+        .min(MAX_QUERY_LIMIT) as usize;
+
+    let include_start_after = data.include_start_after.unwrap_or(false);
+    // Keys are (referee's user ID, fee denom), empty string sorts before any denom of a referee
+    let min = data
+        .start_after_referee_user_id
+        .map(|start_after_referee_user_id| {
+            Bound::inclusive((start_after_referee_user_id.u64(), ""))
+        });
+
+    let mut referees: Vec<Referee> = vec![];
+    for item in ALL_REFERRERS_REFEREES
+        .sub_prefix(data.referrer_user_id.u64())
+        .range(deps.storage, min, None, Order::Ascending)
+    {
+        let ((referee_user_id, denom), earned) = item?;
+        let referee_user_id = Uint64::from(referee_user_id);
+        if !include_start_after && Some(referee_user_id) == data.start_after_referee_user_id {
+            continue;
+        }
+        let earned = (!earned.is_zero()).then_some(Coin {
+            denom,
+            amount: earned,
+        });
+        match referees.last_mut() {
+            Some(referee) if referee.referee_user_id == referee_user_id => {
+                referee.earned.extend(earned)
+            }
+            _ => {
+                if referees.len() == limit {
+                    break;
+                }
+                referees.push(Referee {
+                    referee_user_id,
+                    earned: earned.into_iter().collect(),
+                })
+            }
+        }
+    }
+
+    Ok(RefereesResponse {
+        count: referees.len(),
+        referees,
+    })
+}
@@ -1,7 +1,8 @@
-use cosmwasm_std::{Deps, Order, StdResult, Uint128, Uint64};
+use cosmwasm_std::{Deps, Env, Order, StdResult, Uint128, Uint64};
 
 use cw_storage_plus::{Bound, PrefixBound};
-use member_pkg::member_contract_querier::query_membership_supply;
+use member_pkg::member_contract_querier::MemberContractQuerier;
+use shared_pkg::pricing::checked_multiply_basis_points;
 use thread_pkg::{
     config::Config,
     msg::{
@@ -17,62 +18,81 @@ use thread_pkg::{
 use crate::{
     state::{
         ALL_THREADS, ALL_THREADS_MSGS, ALL_THREADS_MSGS_COUNT, ALL_USERS_CREATED_THREADS,
-        ALL_USERS_PARTICIPATED_THREADS, ALL_USERS_THREAD_STATS, ALL_USER_CONFIGS,
-        DEFAULT_QUERY_LIMIT, MAX_QUERY_LIMIT,
+        ALL_USERS_PARTICIPATED_THREADS, ALL_USERS_THREAD_STATS, DEFAULT_QUERY_LIMIT,
+        MAX_QUERY_LIMIT,
     },
-    util::price::{calculate_price, multiply_percentage},
+    util::{referral::split_referral_fee, user_config::load_effective_user_config},
 };
 
 pub fn query_cost_to_start_new_thread(config: Config) -> StdResult<CostToStartNewThreadResponse> {
     // TODO: P0: benchmark length
     let fixed_protocol_fee = config.protocol_fee_config.start_new_thread_fixed_cost;
+    let (protocol_fee, referral_fee) = split_referral_fee(
+        fixed_protocol_fee,
+        config.protocol_fee_config.referral_fee_bps,
+    )?;
 
     Ok(CostToStartNewThreadResponse {
-        protocol_fee: fixed_protocol_fee,
+        protocol_fee,
+        referral_fee,
     })
 }
 
 pub fn query_cost_to_ask_in_thread(
     deps: Deps,
+    env: Env,
     data: QueryCostToAskInThreadMsg,
     config: Config,
 ) -> StdResult<CostToAskInThreadResponse> {
     let ask_to_user_id = data.ask_to_user_id.u64();
     let thread_creator_user_id = data.thread_creator_user_id.u64();
-    let thread_creator_config = ALL_USER_CONFIGS
-        .load(deps.storage, thread_creator_user_id)
-        .unwrap();
-
-    let supply = query_membership_supply(deps, config.member_contract_addr, ask_to_user_id);
-
-    let price_for_single_membership = calculate_price(supply, Uint128::one());
+    // Fee change applies from the block it takes effect, even if nobody has written it to user config yet
+    let (thread_creator_config, _) = load_effective_user_config(
+        deps.storage,
+        &env.block,
+        &config.user_fee_ranges,
+        thread_creator_user_id,
+    )?;
+
+    // Fee is based on price of 1 membership on the issuer's own bonding curve
+    let membership_issued_by_me =
+        MemberContractQuerier::new(deps.querier, config.member_contract_addr)
+            .membership_issued_by(ask_to_user_id)?;
+
+    let price_for_single_membership = membership_issued_by_me
+        .bonding_curve
+        .calculate_price(membership_issued_by_me.membership_supply, Uint128::one())?;
 
     let fee_share_config = thread_creator_config
         .fee_share_config
         .unwrap_or(config.default_fee_share_config);
-    let membership_issuer_fee_percentage = fee_share_config.share_to_issuer_percentage;
-    let all_members_percentage = fee_share_config.share_to_all_members_percentage;
+    let membership_issuer_fee_bps = fee_share_config.share_to_issuer_bps;
+    let all_members_bps = fee_share_config.share_to_all_members_bps;
 
     let fee_config = thread_creator_config
         .fee_config
-        .unwrap_or(config.default_fee_config);
+        .resolve(&config.default_fee_config);
 
-    let ask_fee = multiply_percentage(
+    let ask_fee = checked_multiply_basis_points(
         price_for_single_membership,
-        fee_config.ask_fee_percentage_of_membership,
-    );
+        fee_config.ask_fee_bps_of_membership,
+    )?;
 
     let ask_to_membership_issuer_fee =
-        multiply_percentage(ask_fee, membership_issuer_fee_percentage);
-    let ask_to_membership_all_members_fee = multiply_percentage(ask_fee, all_members_percentage);
+        checked_multiply_basis_points(ask_fee, membership_issuer_fee_bps)?;
+    let ask_to_membership_all_members_fee =
+        checked_multiply_basis_points(ask_fee, all_members_bps)?;
 
-    let protocol_fee_percentage = config.protocol_fee_config.ask_in_thread_fee_percentage;
-    let protocol_fee: Uint128 = multiply_percentage(ask_fee, protocol_fee_percentage);
+    let protocol_fee_bps = config.protocol_fee_config.ask_in_thread_fee_bps;
+    let (protocol_fee, referral_fee) = split_referral_fee(
+        checked_multiply_basis_points(ask_fee, protocol_fee_bps)?,
+        config.protocol_fee_config.referral_fee_bps,
+    )?;
 
-    let thread_creator_fee = multiply_percentage(
+    let thread_creator_fee = checked_multiply_basis_points(
         price_for_single_membership,
-        fee_config.ask_fee_to_thread_creator_percentage_of_membership,
-    );
+        fee_config.ask_fee_to_thread_creator_bps_of_membership,
+    )?;
 
     // 0 if thread creator is membership issuer
     let (thread_creator_membership_issuer_fee, thread_creator_membership_all_members_fee) =
@@ -80,69 +100,85 @@ pub fn query_cost_to_ask_in_thread(
             (Uint128::zero(), Uint128::zero())
         } else {
             (
-                multiply_percentage(thread_creator_fee, membership_issuer_fee_percentage),
-                multiply_percentage(thread_creator_fee, all_members_percentage),
+                checked_multiply_basis_points(thread_creator_fee, membership_issuer_fee_bps)?,
+                checked_multiply_basis_points(thread_creator_fee, all_members_bps)?,
             )
         };
 
     let total_needed_from_user = protocol_fee
-        + ask_to_membership_issuer_fee
-        + ask_to_membership_all_members_fee
-        + thread_creator_membership_issuer_fee
-        + thread_creator_membership_all_members_fee;
+        .checked_add(referral_fee)?
+        .checked_add(ask_to_membership_issuer_fee)?
+        .checked_add(ask_to_membership_all_members_fee)?
+        .checked_add(thread_creator_membership_issuer_fee)?
+        .checked_add(thread_creator_membership_all_members_fee)?;
 
     Ok(CostToAskInThreadResponse {
         protocol_fee,
+        referral_fee,
         ask_to_membership_issuer_fee,
         ask_to_membership_all_members_fee,
         thread_creator_membership_issuer_fee,
         thread_creator_membership_all_members_fee,
         total_needed_from_user,
+        fee_denom: membership_issued_by_me.fee_denom,
     })
 }
 
 pub fn query_cost_to_reply_in_thread(
     deps: Deps,
+    env: Env,
     data: QueryCostToReplyInThreadMsg,
     config: Config,
 ) -> StdResult<CostToReplyInThreadResponse> {
     let reply_to_user_id = data.reply_to_user_id.u64();
     let thread_creator_user_id = data.thread_creator_user_id.u64();
-    let thread_creator_config = ALL_USER_CONFIGS
-        .load(deps.storage, thread_creator_user_id)
-        .unwrap();
-
-    let supply = query_membership_supply(deps, config.member_contract_addr, reply_to_user_id);
-
-    let price_for_single_membership = calculate_price(supply, Uint128::one());
+    // Fee change applies from the block it takes effect, even if nobody has written it to user config yet
+    let (thread_creator_config, _) = load_effective_user_config(
+        deps.storage,
+        &env.block,
+        &config.user_fee_ranges,
+        thread_creator_user_id,
+    )?;
+
+    // Fee is based on price of 1 membership on the issuer's own bonding curve
+    let membership_issued_by_me =
+        MemberContractQuerier::new(deps.querier, config.member_contract_addr)
+            .membership_issued_by(reply_to_user_id)?;
+
+    let price_for_single_membership = membership_issued_by_me
+        .bonding_curve
+        .calculate_price(membership_issued_by_me.membership_supply, Uint128::one())?;
 
     let fee_share_config = thread_creator_config
         .fee_share_config
         .unwrap_or(config.default_fee_share_config);
-    let membership_issuer_fee_percentage = fee_share_config.share_to_issuer_percentage;
-    let all_members_percentage = fee_share_config.share_to_all_members_percentage;
+    let membership_issuer_fee_bps = fee_share_config.share_to_issuer_bps;
+    let all_members_bps = fee_share_config.share_to_all_members_bps;
 
     let fee_config = thread_creator_config
         .fee_config
-        .unwrap_or(config.default_fee_config);
+        .resolve(&config.default_fee_config);
 
-    let reply_fee = multiply_percentage(
+    let reply_fee = checked_multiply_basis_points(
         price_for_single_membership,
-        fee_config.reply_fee_percentage_of_membership,
-    );
+        fee_config.reply_fee_bps_of_membership,
+    )?;
 
     let reply_to_membership_issuer_fee =
-        multiply_percentage(reply_fee, membership_issuer_fee_percentage);
+        checked_multiply_basis_points(reply_fee, membership_issuer_fee_bps)?;
     let reply_to_membership_all_members_fee =
-        multiply_percentage(reply_fee, all_members_percentage);
+        checked_multiply_basis_points(reply_fee, all_members_bps)?;
 
-    let protocol_fee_percentage = config.protocol_fee_config.reply_in_thread_fee_percentage;
-    let protocol_fee = multiply_percentage(reply_fee, protocol_fee_percentage);
+    let protocol_fee_bps = config.protocol_fee_config.reply_in_thread_fee_bps;
+    let (protocol_fee, referral_fee) = split_referral_fee(
+        checked_multiply_basis_points(reply_fee, protocol_fee_bps)?,
+        config.protocol_fee_config.referral_fee_bps,
+    )?;
 
-    let thread_creator_fee = multiply_percentage(
+    let thread_creator_fee = checked_multiply_basis_points(
         price_for_single_membership,
-        fee_config.reply_fee_to_thread_creator_percentage_of_membership,
-    );
+        fee_config.reply_fee_to_thread_creator_bps_of_membership,
+    )?;
 
     // 0 if thread creator is membership issuer
     let (thread_creator_membership_issuer_fee, thread_creator_membership_all_members_fee) =
@@ -150,24 +186,27 @@ pub fn query_cost_to_reply_in_thread(
             (Uint128::zero(), Uint128::zero())
         } else {
             (
-                multiply_percentage(thread_creator_fee, membership_issuer_fee_percentage),
-                multiply_percentage(thread_creator_fee, all_members_percentage),
+                checked_multiply_basis_points(thread_creator_fee, membership_issuer_fee_bps)?,
+                checked_multiply_basis_points(thread_creator_fee, all_members_bps)?,
             )
         };
 
     let total_needed_from_user = protocol_fee
-        + reply_to_membership_issuer_fee
-        + reply_to_membership_all_members_fee
-        + thread_creator_membership_issuer_fee
-        + thread_creator_membership_all_members_fee;
+        .checked_add(referral_fee)?
+        .checked_add(reply_to_membership_issuer_fee)?
+        .checked_add(reply_to_membership_all_members_fee)?
+        .checked_add(thread_creator_membership_issuer_fee)?
+        .checked_add(thread_creator_membership_all_members_fee)?;
 
     Ok(CostToReplyInThreadResponse {
         protocol_fee,
+        referral_fee,
         reply_to_membership_issuer_fee,
         reply_to_membership_all_members_fee,
         thread_creator_membership_issuer_fee,
         thread_creator_membership_all_members_fee,
         total_needed_from_user,
+        fee_denom: membership_issued_by_me.fee_denom,
     })
 }
 
@@ -296,8 +335,8 @@ pub fn query_threads_by_ids(deps: Deps, data: QueryThreadsByIDsMsg) -> StdResult
     let threads = data
         .thread_ids
         .iter()
-        .map(|thread_id| ALL_THREADS.load(deps.storage, thread_id.u64()).unwrap())
-        .collect();
+        .map(|thread_id| ALL_THREADS.load(deps.storage, thread_id.u64()))
+        .collect::<StdResult<Vec<_>>>()?;
 
     Ok(ThreadsResponse { threads })
 }
@@ -310,11 +349,9 @@ pub fn query_thread_msgs_by_ids(
         .thread_and_thread_msg_ids
         .iter()
         .map(|(thread_id, thread_msg_id)| {
-            ALL_THREADS_MSGS
-                .load(deps.storage, (thread_id.u64(), thread_msg_id.u64()))
-                .unwrap()
+            ALL_THREADS_MSGS.load(deps.storage, (thread_id.u64(), thread_msg_id.u64()))
         })
-        .collect();
+        .collect::<StdResult<Vec<_>>>()?;
 
     Ok(ThreadMsgsResponse { thread_msgs })
 }
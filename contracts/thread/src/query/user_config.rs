@@ -1,10 +1,23 @@
-use cosmwasm_std::{Deps, StdResult};
+use cosmwasm_std::{Deps, Env, StdResult};
 
 use thread_pkg::msg::{QueryUserConfigMsg, UserConfigResponse};
 
-use crate::state::ALL_USER_CONFIGS;
+use crate::{state::CONFIG, util::user_config::load_effective_user_config};
 
-pub fn query_user_config(deps: Deps, data: QueryUserConfigMsg) -> StdResult<UserConfigResponse> {
-    let user_config = ALL_USER_CONFIGS.load(deps.storage, data.user_id.u64())?;
-    Ok(UserConfigResponse { user_config })
+pub fn query_user_config(
+    deps: Deps,
+    env: Env,
+    data: QueryUserConfigMsg,
+) -> StdResult<UserConfigResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let (user_config, pending_fee_change) = load_effective_user_config(
+        deps.storage,
+        &env.block,
+        &config.user_fee_ranges,
+        data.user_id.u64(),
+    )?;
+    Ok(UserConfigResponse {
+        user_config,
+        pending_fee_change,
+    })
 }
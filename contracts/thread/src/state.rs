@@ -1,10 +1,12 @@
 use cosmwasm_std::{Uint128, Uint64};
 use cw_storage_plus::{Item, Map};
 
+use shared_pkg::{admin_transfer::AdminProposal, timelock::PendingConfigChange};
 use thread_pkg::{
     config::Config,
+    msg::UpdateConfigMsg,
     thread::{Thread, ThreadMsg},
-    user_config::UserConfig,
+    user_config::{PendingUserFeeChange, UserConfig},
 };
 
 pub const DEFAULT_QUERY_LIMIT: u32 = 5;
@@ -12,6 +14,17 @@ pub const MAX_QUERY_LIMIT: u32 = 25;
 
 pub const CONFIG: Item<Config> = Item::new("CONFIG");
 
+// New admin proposed by current admin, removed once accepted or cancelled
+pub const PENDING_ADMIN_PROPOSAL: Item<AdminProposal> = Item::new("PENDING_ADMIN_PROPOSAL");
+
+// Next available ID of pending config change, start from 1
+pub const NEXT_PENDING_CONFIG_CHANGE_ID: Item<Uint64> = Item::new("NEXT_PENDING_CONFIG_CHANGE_ID");
+
+// Key is pending config change ID, value is config changes waiting for the time-lock
+// Removed once executed or cancelled
+pub const PENDING_CONFIG_CHANGES: Map<u64, PendingConfigChange<UpdateConfigMsg>> =
+    Map::new("PENDING_CONFIG_CHANGES");
+
 // Next available monotonically increasing global unique ID to identify each thread
 // Start from 1
 pub const NEXT_THREAD_ID: Item<Uint64> = Item::new("NEXT_THREAD_ID");
@@ -23,6 +36,11 @@ pub const NEXT_THREAD_MSG_ID: Map<u64, Uint64> = Map::new("NEXT_THREAD_MSG_ID");
 // Key is user ID in membership contract, value is user struct which contains fee config
 pub const ALL_USER_CONFIGS: Map<u64, UserConfig> = Map::new("ALL_USER_CONFIGS");
 
+// Key is user ID in membership contract, value is fee change waiting for user fee change time-lock
+// Applied on the fly by fee lookups once it takes effect, written to user config on user's next fee update
+pub const ALL_PENDING_USER_FEE_CHANGES: Map<u64, PendingUserFeeChange> =
+    Map::new("ALL_PENDING_USER_FEE_CHANGES");
+
 /*
     requests:
     1. get all conversations that are asked to me
@@ -67,3 +85,8 @@ pub const ALL_THREADS_MSGS: Map<(u64, u64), ThreadMsg> = Map::new("ALL_THREADS_M
 // TODO: P2: decide should we store this onchain or in indexer
 pub const ALL_USERS_UNANSWERED_QUESTIONS: Map<(u64, u64, u64), bool> =
     Map::new("ALL_USERS_UNANSWERED_QUESTIONS");
+
+// Key is (referrer's user ID in membership contract, referee's user ID in membership contract, fee denom)
+// Value is total referral fee referrer earned from referee's thread fees in that denom
+pub const ALL_REFERRERS_REFEREES: Map<(u64, u64, &str), Uint128> =
+    Map::new("ALL_REFERRERS_REFEREES");
@@ -0,0 +1,125 @@
+use cosmwasm_std::{Addr, StdResult, Storage};
+use shared_pkg::rbac::{has_role, Role};
+use thread_pkg::{config::Config, msg::UpdateConfigMsg};
+
+use crate::ContractError;
+
+// All user facing functions are disabled when contract is disabled
+// Admin functions stay available so admin can turn it back on
+pub fn assert_contract_enabled(config: &Config) -> Result<(), ContractError> {
+    if !config.enabled {
+        return Err(ContractError::ContractDisabled {});
+    }
+
+    Ok(())
+}
+
+// Admin can make any config change, fee manager only fee changes
+// Also applies to executing and cancelling pending config changes so fee manager cannot touch queued admin changes
+pub fn can_update_config(
+    storage: &dyn Storage,
+    addr: &Addr,
+    data: &UpdateConfigMsg,
+) -> StdResult<bool> {
+    Ok(has_role(storage, addr, &Role::Admin)?
+        || (is_fee_only_update(data) && has_role(storage, addr, &Role::FeeManager)?))
+}
+
+// Fee manager can only update fees, their ranges and fixed cost, everything else needs admin
+// Destructure without .. so a new field must be classified here before it compiles
+pub fn is_fee_only_update(data: &UpdateConfigMsg) -> bool {
+    let UpdateConfigMsg {
+        protocol_fee_collector_addr,
+        member_contract_addr,
+        max_thread_title_length,
+        max_thread_description_length,
+        max_thread_label_length,
+        max_number_of_thread_labels,
+        max_thread_msg_length,
+        protocol_fee_start_new_thread_fixed_cost: _,
+        protocol_fee_ask_in_thread_fee_bps: _,
+        protocol_fee_reply_in_thread_fee_bps: _,
+        protocol_fee_referral_fee_bps: _,
+        default_ask_fee_bps_of_membership: _,
+        default_ask_fee_to_thread_creator_bps_of_membership: _,
+        default_reply_fee_bps_of_membership: _,
+        default_reply_fee_to_thread_creator_bps_of_membership: _,
+        default_share_to_issuer_bps: _,
+        default_share_to_all_members_bps: _,
+        config_change_timelock,
+        user_fee_change_timelock,
+        user_fee_ranges: _,
+    } = data;
+
+    protocol_fee_collector_addr.is_none()
+        && member_contract_addr.is_none()
+        && max_thread_title_length.is_none()
+        && max_thread_description_length.is_none()
+        && max_thread_label_length.is_none()
+        && max_number_of_thread_labels.is_none()
+        && max_thread_msg_length.is_none()
+        && config_change_timelock.is_none()
+        && user_fee_change_timelock.is_none()
+}
+
+// Changes to where fees go, how much they are, member contract and the time-locks wait for the time-lock
+// Returns changes to apply right away, and changes to queue or None if there is nothing to queue
+pub fn split_timelocked_config_changes(
+    data: UpdateConfigMsg,
+) -> (UpdateConfigMsg, Option<UpdateConfigMsg>) {
+    let UpdateConfigMsg {
+        protocol_fee_collector_addr,
+        member_contract_addr,
+        max_thread_title_length,
+        max_thread_description_length,
+        max_thread_label_length,
+        max_number_of_thread_labels,
+        max_thread_msg_length,
+        protocol_fee_start_new_thread_fixed_cost,
+        protocol_fee_ask_in_thread_fee_bps,
+        protocol_fee_reply_in_thread_fee_bps,
+        protocol_fee_referral_fee_bps,
+        default_ask_fee_bps_of_membership,
+        default_ask_fee_to_thread_creator_bps_of_membership,
+        default_reply_fee_bps_of_membership,
+        default_reply_fee_to_thread_creator_bps_of_membership,
+        default_share_to_issuer_bps,
+        default_share_to_all_members_bps,
+        config_change_timelock,
+        user_fee_change_timelock,
+        user_fee_ranges,
+    } = data;
+
+    let changes = UpdateConfigMsg {
+        max_thread_title_length,
+        max_thread_description_length,
+        max_thread_label_length,
+        max_number_of_thread_labels,
+        max_thread_msg_length,
+        ..UpdateConfigMsg::default()
+    };
+    let timelocked_changes = UpdateConfigMsg {
+        protocol_fee_collector_addr,
+        member_contract_addr,
+        protocol_fee_start_new_thread_fixed_cost,
+        protocol_fee_ask_in_thread_fee_bps,
+        protocol_fee_reply_in_thread_fee_bps,
+        protocol_fee_referral_fee_bps,
+        default_ask_fee_bps_of_membership,
+        default_ask_fee_to_thread_creator_bps_of_membership,
+        default_reply_fee_bps_of_membership,
+        default_reply_fee_to_thread_creator_bps_of_membership,
+        default_share_to_issuer_bps,
+        default_share_to_all_members_bps,
+        config_change_timelock,
+        user_fee_change_timelock,
+        user_fee_ranges,
+        ..UpdateConfigMsg::default()
+    };
+
+    if timelocked_changes == UpdateConfigMsg::default() {
+        (changes, None)
+    } else {
+        (changes, Some(timelocked_changes))
+    }
+}
@@ -0,0 +1,95 @@
+use cosmwasm_std::Uint64;
+use shared_pkg::fee_range::BpsRange;
+use thread_pkg::config::{FeeConfig, UserFeeRanges};
+
+use crate::ContractError;
+
+// Name of each issuer fee paired with its range
+fn named_ranges(user_fee_ranges: &UserFeeRanges) -> [(&'static str, &BpsRange); 4] {
+    [
+        (
+            "ask_fee_bps_of_membership",
+            &user_fee_ranges.ask_fee_bps_of_membership,
+        ),
+        (
+            "ask_fee_to_thread_creator_bps_of_membership",
+            &user_fee_ranges.ask_fee_to_thread_creator_bps_of_membership,
+        ),
+        (
+            "reply_fee_bps_of_membership",
+            &user_fee_ranges.reply_fee_bps_of_membership,
+        ),
+        (
+            "reply_fee_to_thread_creator_bps_of_membership",
+            &user_fee_ranges.reply_fee_to_thread_creator_bps_of_membership,
+        ),
+    ]
+}
+
+pub fn assert_user_fee_ranges_valid(user_fee_ranges: &UserFeeRanges) -> Result<(), ContractError> {
+    for (fee, range) in named_ranges(user_fee_ranges) {
+        if !range.is_valid() {
+            return Err(ContractError::InvalidUserFeeRange {
+                fee: fee.to_string(),
+                min: range.min,
+                max: range.max,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+pub fn assert_fee_bps_in_range(
+    fee: &str,
+    bps: Uint64,
+    range: &BpsRange,
+) -> Result<(), ContractError> {
+    if !range.contains(bps) {
+        return Err(ContractError::FeeBpsOutOfRange {
+            fee: fee.to_string(),
+            bps,
+            min: range.min,
+            max: range.max,
+        });
+    }
+
+    Ok(())
+}
+
+// Fees not set are skipped, issuer only updates the fees it passes
+pub fn assert_user_fees_in_ranges(
+    ask_fee_bps_of_membership: Option<Uint64>,
+    ask_fee_to_thread_creator_bps_of_membership: Option<Uint64>,
+    reply_fee_bps_of_membership: Option<Uint64>,
+    reply_fee_to_thread_creator_bps_of_membership: Option<Uint64>,
+    user_fee_ranges: &UserFeeRanges,
+) -> Result<(), ContractError> {
+    let all_bps = [
+        ask_fee_bps_of_membership,
+        ask_fee_to_thread_creator_bps_of_membership,
+        reply_fee_bps_of_membership,
+        reply_fee_to_thread_creator_bps_of_membership,
+    ];
+    for ((fee, range), bps) in named_ranges(user_fee_ranges).into_iter().zip(all_bps) {
+        if let Some(bps) = bps {
+            assert_fee_bps_in_range(fee, bps, range)?;
+        }
+    }
+
+    Ok(())
+}
+
+// Users without their own fees pay the default fees, so default fees must be in the ranges too
+pub fn assert_default_fee_config_in_ranges(
+    default_fee_config: &FeeConfig,
+    user_fee_ranges: &UserFeeRanges,
+) -> Result<(), ContractError> {
+    assert_user_fees_in_ranges(
+        Some(default_fee_config.ask_fee_bps_of_membership),
+        Some(default_fee_config.ask_fee_to_thread_creator_bps_of_membership),
+        Some(default_fee_config.reply_fee_bps_of_membership),
+        Some(default_fee_config.reply_fee_to_thread_creator_bps_of_membership),
+        user_fee_ranges,
+    )
+}
@@ -1,35 +1,43 @@
-use cosmwasm_std::{Deps, Uint64};
-
-use crate::{
-    state::{ALL_USER_CONFIGS, CONFIG},
-    ContractError,
-};
-
-pub fn assert_config_fee_share_sum_to_100(deps: Deps) -> Result<(), ContractError> {
-    let config = CONFIG.load(deps.storage)?;
-    let share_to_issuer_percentage = config.default_fee_share_config.share_to_issuer_percentage;
-    let share_to_all_members_percentage = config
-        .default_fee_share_config
-        .share_to_all_members_percentage;
-
-    if share_to_issuer_percentage + share_to_all_members_percentage != Uint64::from(100_u64) {
-        return Err(ContractError::ThreadFeeSharePercentageMustSumTo100 {});
+use cosmwasm_std::Uint64;
+use shared_pkg::{fee_share_config::FeeShareConfig, pricing::ONE_HUNDRED_PERCENT_IN_BASIS_POINTS};
+use thread_pkg::config::ProtocolFeeConfig;
+
+use crate::ContractError;
+
+pub fn assert_config_fee_share_sum_to_10000(
+    default_fee_share_config: &FeeShareConfig,
+) -> Result<(), ContractError> {
+    let share_to_issuer_bps = default_fee_share_config.share_to_issuer_bps;
+    let share_to_all_members_bps = default_fee_share_config.share_to_all_members_bps;
+
+    if share_to_issuer_bps.checked_add(share_to_all_members_bps)
+        != Ok(Uint64::new(ONE_HUNDRED_PERCENT_IN_BASIS_POINTS))
+    {
+        return Err(ContractError::ThreadFeeShareBpsMustSumTo10000 {});
+    }
+
+    Ok(())
+}
+
+pub fn assert_user_fee_share_sum_to_10000(
+    user_fee_share_config: &FeeShareConfig,
+) -> Result<(), ContractError> {
+    if user_fee_share_config
+        .share_to_issuer_bps
+        .checked_add(user_fee_share_config.share_to_all_members_bps)
+        != Ok(Uint64::new(ONE_HUNDRED_PERCENT_IN_BASIS_POINTS))
+    {
+        return Err(ContractError::ThreadFeeShareBpsMustSumTo10000 {});
     }
 
     Ok(())
 }
 
-pub fn assert_user_fee_share_sum_to_100(deps: Deps, user_id: u64) -> Result<(), ContractError> {
-    let user_config = ALL_USER_CONFIGS.load(deps.storage, user_id)?;
-
-    if user_config.fee_share_config.is_some() {
-        let user_fee_share_config = user_config.fee_share_config.unwrap();
-        if user_fee_share_config.share_to_issuer_percentage
-            + user_fee_share_config.share_to_all_members_percentage
-            != Uint64::from(100_u64)
-        {
-            return Err(ContractError::ThreadFeeSharePercentageMustSumTo100 {});
-        }
+pub fn assert_referral_fee_bps_within_10000(
+    protocol_fee_config: &ProtocolFeeConfig,
+) -> Result<(), ContractError> {
+    if protocol_fee_config.referral_fee_bps > Uint64::new(ONE_HUNDRED_PERCENT_IN_BASIS_POINTS) {
+        return Err(ContractError::ReferralFeeBpsCannotExceed10000 {});
     }
 
     Ok(())
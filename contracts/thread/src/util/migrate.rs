@@ -0,0 +1,191 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, DepsMut, Env, Order, StdResult, Uint128, Uint64};
+use cw_storage_plus::{Item, Map};
+
+use shared_pkg::{
+    fee_share_config::FeeShareConfig,
+    migrate::StateMigration,
+    pricing::checked_percentage_to_basis_points,
+    rbac::{grant_role, Role},
+    timelock::{normalize_timelock, DEFAULT_USER_FEE_CHANGE_TIMELOCK},
+};
+use thread_pkg::{
+    config::{Config, FeeConfig, PauseConfig, ProtocolFeeConfig, ThreadConfig, UserFeeRanges},
+    user_config::{UserConfig, UserFeeConfig},
+};
+
+use crate::state::{
+    ALL_THREADS, ALL_THREADS_MSGS_COUNT, ALL_USER_CONFIGS, CONFIG, NEXT_THREAD_MSG_ID,
+};
+use crate::ContractError;
+
+// Registered state migrations, sorted by version
+// When a release changes how config, user configs, threads or thread msgs are stored:
+// keep the old struct in this file, add a function that loads data in old schema and saves it in new schema,
+// then register the function with the version of that release
+pub const STATE_MIGRATIONS: &[(&str, StateMigration<ContractError>)] =
+    &[("0.2.0", migrate_state_to_v0_2_0)];
+
+// ========== 0.1.0 ==========
+
+#[cw_serde]
+struct ProtocolFeeConfigV0_1_0 {
+    start_new_thread_fixed_cost: Uint128,
+    ask_in_thread_fee_percentage: Uint64,
+    reply_in_thread_fee_percentage: Uint64,
+}
+
+#[cw_serde]
+struct FeeConfigV0_1_0 {
+    ask_fee_percentage_of_membership: Uint64,
+    ask_fee_to_thread_creator_percentage_of_membership: Uint64,
+    reply_fee_percentage_of_membership: Uint64,
+    reply_fee_to_thread_creator_percentage_of_membership: Uint64,
+}
+
+impl FeeConfigV0_1_0 {
+    fn into_bps(self) -> Result<FeeConfig, ContractError> {
+        Ok(FeeConfig {
+            ask_fee_bps_of_membership: checked_percentage_to_basis_points(
+                self.ask_fee_percentage_of_membership,
+            )?,
+            ask_fee_to_thread_creator_bps_of_membership: checked_percentage_to_basis_points(
+                self.ask_fee_to_thread_creator_percentage_of_membership,
+            )?,
+            reply_fee_bps_of_membership: checked_percentage_to_basis_points(
+                self.reply_fee_percentage_of_membership,
+            )?,
+            reply_fee_to_thread_creator_bps_of_membership: checked_percentage_to_basis_points(
+                self.reply_fee_to_thread_creator_percentage_of_membership,
+            )?,
+        })
+    }
+}
+
+#[cw_serde]
+struct FeeShareConfigV0_1_0 {
+    share_to_issuer_percentage: Uint64,
+    share_to_all_members_percentage: Uint64,
+}
+
+impl FeeShareConfigV0_1_0 {
+    fn into_bps(self) -> Result<FeeShareConfig, ContractError> {
+        Ok(FeeShareConfig {
+            share_to_issuer_bps: checked_percentage_to_basis_points(
+                self.share_to_issuer_percentage,
+            )?,
+            share_to_all_members_bps: checked_percentage_to_basis_points(
+                self.share_to_all_members_percentage,
+            )?,
+        })
+    }
+}
+
+#[cw_serde]
+struct ConfigV0_1_0 {
+    member_contract_addr: Addr,
+    admin_addr: Addr,
+    enabled: bool,
+    protocol_fee_collector_addr: Addr,
+    thread_config: ThreadConfig,
+    protocol_fee_config: ProtocolFeeConfigV0_1_0,
+    default_fee_config: FeeConfigV0_1_0,
+    default_fee_share_config: FeeShareConfigV0_1_0,
+}
+
+const CONFIG_V0_1_0: Item<ConfigV0_1_0> = Item::new("CONFIG");
+
+#[cw_serde]
+struct UserConfigV0_1_0 {
+    fee_config: Option<FeeConfigV0_1_0>,
+    fee_share_config: Option<FeeShareConfigV0_1_0>,
+}
+
+// Same namespace as ALL_USER_CONFIGS
+const ALL_USER_CONFIGS_V0_1_0: Map<u64, UserConfigV0_1_0> = Map::new("ALL_USER_CONFIGS");
+
+// ========== 0.2.0 ==========
+
+// Adds everything introduced since 0.1.0 with the same defaults as instantiate
+// Fees were whole percentages, they are converted to basis points
+// Admin in config is granted admin role since roles were introduced
+fn migrate_state_to_v0_2_0(deps: DepsMut, _env: &Env) -> Result<(), ContractError> {
+    let old_config = CONFIG_V0_1_0.load(deps.storage)?;
+
+    let config = Config {
+        member_contract_addr: old_config.member_contract_addr,
+        admin_addr: old_config.admin_addr,
+        enabled: old_config.enabled,
+        pause_config: PauseConfig::default(),
+        protocol_fee_collector_addr: old_config.protocol_fee_collector_addr,
+        thread_config: old_config.thread_config,
+        protocol_fee_config: ProtocolFeeConfig {
+            start_new_thread_fixed_cost: old_config.protocol_fee_config.start_new_thread_fixed_cost,
+            ask_in_thread_fee_bps: checked_percentage_to_basis_points(
+                old_config.protocol_fee_config.ask_in_thread_fee_percentage,
+            )?,
+            reply_in_thread_fee_bps: checked_percentage_to_basis_points(
+                old_config
+                    .protocol_fee_config
+                    .reply_in_thread_fee_percentage,
+            )?,
+            referral_fee_bps: Uint64::zero(),
+        },
+        default_fee_config: old_config.default_fee_config.into_bps()?,
+        default_fee_share_config: old_config.default_fee_share_config.into_bps()?,
+        config_change_timelock: None,
+        user_fee_change_timelock: normalize_timelock(DEFAULT_USER_FEE_CHANGE_TIMELOCK),
+        user_fee_ranges: UserFeeRanges::default(),
+    };
+    CONFIG.save(deps.storage, &config)?;
+
+    grant_role(deps.storage, &config.admin_addr, &Role::Admin)?;
+
+    // 0.1.0 didn't initialize msg ID and msg count of threads started with start new thread
+    // so nobody could reply in them, initialize them the same way start new thread does now
+    let thread_ids = ALL_THREADS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<u64>>>()?;
+    for thread_id in thread_ids {
+        if !NEXT_THREAD_MSG_ID.has(deps.storage, thread_id) {
+            NEXT_THREAD_MSG_ID.save(deps.storage, thread_id, &Uint64::one())?;
+            ALL_THREADS_MSGS_COUNT.save(deps.storage, thread_id, &Uint128::zero())?;
+        }
+    }
+
+    // 0.1.0 stored all fees of issuers who customized them, keep them all as set by the issuer
+    let user_configs = ALL_USER_CONFIGS_V0_1_0
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for (user_id, old_user_config) in user_configs {
+        let fee_config = match old_user_config.fee_config {
+            None => UserFeeConfig::default(),
+            Some(fee_config) => {
+                let fee_config = fee_config.into_bps()?;
+                UserFeeConfig {
+                    ask_fee_bps_of_membership: Some(fee_config.ask_fee_bps_of_membership),
+                    ask_fee_to_thread_creator_bps_of_membership: Some(
+                        fee_config.ask_fee_to_thread_creator_bps_of_membership,
+                    ),
+                    reply_fee_bps_of_membership: Some(fee_config.reply_fee_bps_of_membership),
+                    reply_fee_to_thread_creator_bps_of_membership: Some(
+                        fee_config.reply_fee_to_thread_creator_bps_of_membership,
+                    ),
+                }
+            }
+        };
+        ALL_USER_CONFIGS.save(
+            deps.storage,
+            user_id,
+            &UserConfig {
+                fee_config,
+                fee_share_config: old_user_config
+                    .fee_share_config
+                    .map(FeeShareConfigV0_1_0::into_bps)
+                    .transpose()?,
+            },
+        )?;
+    }
+
+    Ok(())
+}
@@ -1,2 +1,6 @@
+pub(crate) mod config;
+pub(crate) mod fee_range;
 pub(crate) mod fee_share;
-pub(crate) mod price;
+pub(crate) mod migrate;
+pub(crate) mod referral;
+pub(crate) mod user_config;
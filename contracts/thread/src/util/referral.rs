@@ -0,0 +1,57 @@
+use cosmwasm_std::{Addr, BankMsg, Coin, CosmosMsg, DepsMut, OverflowError, Uint128, Uint64};
+use member_pkg::{member_contract_querier::MemberContractQuerier, user::User};
+use shared_pkg::pricing::checked_multiply_basis_points;
+
+use crate::{state::ALL_REFERRERS_REFEREES, ContractError};
+
+// Split referral fee out of protocol fee
+// Returns (protocol fee, referral fee)
+pub fn split_referral_fee(
+    total_protocol_fee: Uint128,
+    referral_fee_bps: Uint64,
+) -> Result<(Uint128, Uint128), OverflowError> {
+    let referral_fee = checked_multiply_basis_points(total_protocol_fee, referral_fee_bps)?;
+    Ok((total_protocol_fee.checked_sub(referral_fee)?, referral_fee))
+}
+
+// Build msg to pay referral fee to the referrer of the user paying thread fee
+// And record how much referrer earned from the user
+// If user has no referrer then referral fee goes to protocol fee collector
+// Returns None if there is no referral fee to pay
+pub fn pay_referral_fee(
+    deps: DepsMut,
+    member_contract_addr: Addr,
+    protocol_fee_collector_addr: &Addr,
+    payer: &User,
+    referral_fee: Uint128,
+    fee_denom: &str,
+) -> Result<Option<CosmosMsg>, ContractError> {
+    if referral_fee.is_zero() {
+        return Ok(None);
+    }
+
+    let to_address = match payer.referrer_user_id {
+        None => protocol_fee_collector_addr.to_string(),
+        Some(referrer_user_id) => {
+            let referrer = MemberContractQuerier::new(deps.querier, member_contract_addr)
+                .user_by_id(referrer_user_id.u64())?
+                .ok_or(ContractError::UserNotExist {})?;
+            ALL_REFERRERS_REFEREES.update(
+                deps.storage,
+                (referrer_user_id.u64(), payer.id.u64(), fee_denom),
+                |earned| -> Result<Uint128, ContractError> {
+                    Ok(earned.unwrap_or_default().checked_add(referral_fee)?)
+                },
+            )?;
+            referrer.addr.to_string()
+        }
+    };
+
+    Ok(Some(CosmosMsg::Bank(BankMsg::Send {
+        to_address,
+        amount: vec![Coin {
+            denom: fee_denom.to_string(),
+            amount: referral_fee,
+        }],
+    })))
+}
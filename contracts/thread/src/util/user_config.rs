@@ -0,0 +1,85 @@
+use cosmwasm_std::{BlockInfo, StdResult, Storage};
+use thread_pkg::{
+    config::UserFeeRanges,
+    user_config::{PendingUserFeeChange, UserConfig},
+};
+
+use crate::state::{ALL_PENDING_USER_FEE_CHANGES, ALL_USER_CONFIGS};
+
+// User doesn't sign up with thread contract, user without config uses config's default fees
+pub fn load_user_config(storage: &dyn Storage, user_id: u64) -> StdResult<UserConfig> {
+    Ok(ALL_USER_CONFIGS
+        .may_load(storage, user_id)?
+        .unwrap_or_default())
+}
+
+// Write fee change onto user config, fees not in the change are kept
+pub fn apply_user_fee_change(user_config: &mut UserConfig, fee_change: PendingUserFeeChange) {
+    let fee_config = &mut user_config.fee_config;
+    fee_config.ask_fee_bps_of_membership = fee_change
+        .ask_fee_bps_of_membership
+        .or(fee_config.ask_fee_bps_of_membership);
+    fee_config.ask_fee_to_thread_creator_bps_of_membership = fee_change
+        .ask_fee_to_thread_creator_bps_of_membership
+        .or(fee_config.ask_fee_to_thread_creator_bps_of_membership);
+    fee_config.reply_fee_bps_of_membership = fee_change
+        .reply_fee_bps_of_membership
+        .or(fee_config.reply_fee_bps_of_membership);
+    fee_config.reply_fee_to_thread_creator_bps_of_membership = fee_change
+        .reply_fee_to_thread_creator_bps_of_membership
+        .or(fee_config.reply_fee_to_thread_creator_bps_of_membership);
+
+    if let Some(fee_share_config) = fee_change.fee_share_config {
+        user_config.fee_share_config = Some(fee_share_config);
+    }
+}
+
+// Admin may have tightened the ranges after fee change was made, fees now out of range are dropped
+fn drop_fees_out_of_range(
+    mut fee_change: PendingUserFeeChange,
+    user_fee_ranges: &UserFeeRanges,
+) -> PendingUserFeeChange {
+    fee_change.ask_fee_bps_of_membership = fee_change
+        .ask_fee_bps_of_membership
+        .filter(|bps| user_fee_ranges.ask_fee_bps_of_membership.contains(*bps));
+    fee_change.ask_fee_to_thread_creator_bps_of_membership = fee_change
+        .ask_fee_to_thread_creator_bps_of_membership
+        .filter(|bps| {
+            user_fee_ranges
+                .ask_fee_to_thread_creator_bps_of_membership
+                .contains(*bps)
+        });
+    fee_change.reply_fee_bps_of_membership = fee_change
+        .reply_fee_bps_of_membership
+        .filter(|bps| user_fee_ranges.reply_fee_bps_of_membership.contains(*bps));
+    fee_change.reply_fee_to_thread_creator_bps_of_membership = fee_change
+        .reply_fee_to_thread_creator_bps_of_membership
+        .filter(|bps| {
+            user_fee_ranges
+                .reply_fee_to_thread_creator_bps_of_membership
+                .contains(*bps)
+        });
+    fee_change
+}
+
+// User config with the fees in effect at block, pending fee change is applied once it takes effect
+// Also returns pending fee change that has not taken effect yet
+pub fn load_effective_user_config(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    user_fee_ranges: &UserFeeRanges,
+    user_id: u64,
+) -> StdResult<(UserConfig, Option<PendingUserFeeChange>)> {
+    let mut user_config = load_user_config(storage, user_id)?;
+    let pending_fee_change = ALL_PENDING_USER_FEE_CHANGES
+        .may_load(storage, user_id)?
+        .map(|fee_change| drop_fees_out_of_range(fee_change, user_fee_ranges));
+    match pending_fee_change {
+        Some(fee_change) if fee_change.is_empty() => Ok((user_config, None)),
+        Some(fee_change) if fee_change.effective_at.is_expired(block) => {
+            apply_user_fee_change(&mut user_config, fee_change);
+            Ok((user_config, None))
+        }
+        pending_fee_change => Ok((user_config, pending_fee_change)),
+    }
+}
@@ -0,0 +1,2 @@
+mod test_admin_can_migrate_contract_from_first_release;
+mod test_fee_manager_can_only_execute_fee_only_pending_config_changes;
@@ -0,0 +1,313 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response, StdResult, Uint128, Uint64};
+use cw_multi_test::{ContractWrapper, Executor};
+use cw_storage_plus::{Item, Map};
+
+use distribution_pkg::msg::ExecuteMsg as DistributionExecuteMsg;
+use shared_pkg::{
+    fee_share_config::FeeShareConfig,
+    rbac::{GrantRoleMsg, QueryRolesMsg, Role, RolesResponse},
+};
+use thread::contract::{execute, query, CONTRACT_NAME, CONTRACT_VERSION};
+use thread_pkg::{
+    config::ThreadConfig,
+    msg::{
+        ConfigResponse, MigrateMsg, QueryConfigMsg, QueryMsg, QueryUserConfigMsg,
+        UserConfigResponse,
+    },
+    thread::Thread,
+    user_config::{UserConfig, UserFeeConfig},
+};
+
+use crate::helpers::{
+    assert_balance, buy_membership, contract_cw_thread, get_distribution_contract_addr,
+    proper_instantiate, query_cost_to_reply_in_thread, register_user_and_enable_membership,
+    reply_in_thread, FEE_DENOM, PROTOCOL_FEE_COLLECTOR, SOCIAL_MEDIA_HANDLE_1,
+    SOCIAL_MEDIA_HANDLE_2,
+};
+
+// Config as stored by the first release of thread contract
+
+#[cw_serde]
+struct ProtocolFeeConfigV0_1_0 {
+    start_new_thread_fixed_cost: Uint128,
+    ask_in_thread_fee_percentage: Uint64,
+    reply_in_thread_fee_percentage: Uint64,
+}
+
+#[cw_serde]
+struct FeeConfigV0_1_0 {
+    ask_fee_percentage_of_membership: Uint64,
+    ask_fee_to_thread_creator_percentage_of_membership: Uint64,
+    reply_fee_percentage_of_membership: Uint64,
+    reply_fee_to_thread_creator_percentage_of_membership: Uint64,
+}
+
+#[cw_serde]
+struct FeeShareConfigV0_1_0 {
+    share_to_issuer_percentage: Uint64,
+    share_to_all_members_percentage: Uint64,
+}
+
+#[cw_serde]
+struct ConfigV0_1_0 {
+    member_contract_addr: Addr,
+    admin_addr: Addr,
+    enabled: bool,
+    protocol_fee_collector_addr: Addr,
+    thread_config: ThreadConfig,
+    protocol_fee_config: ProtocolFeeConfigV0_1_0,
+    default_fee_config: FeeConfigV0_1_0,
+    default_fee_share_config: FeeShareConfigV0_1_0,
+}
+
+#[cw_serde]
+struct UserConfigV0_1_0 {
+    fee_config: Option<FeeConfigV0_1_0>,
+    fee_share_config: Option<FeeShareConfigV0_1_0>,
+}
+
+#[cw_serde]
+struct FirstReleaseInstantiateMsg {
+    member_contract_addr: String,
+}
+
+// Pretends to be the first release of thread contract
+// User 1 started thread 1 with start new thread, which didn't initialize msg ID of the thread
+// User 2 customized its fees, first release stored all fees of the fee config
+fn instantiate_as_first_release(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: FirstReleaseInstantiateMsg,
+) -> StdResult<Response> {
+    cw2::set_contract_version(deps.storage, format!("crates.io:{CONTRACT_NAME}"), "0.1.0")?;
+
+    Item::new("CONFIG").save(
+        deps.storage,
+        &ConfigV0_1_0 {
+            member_contract_addr: deps.api.addr_validate(&msg.member_contract_addr)?,
+            admin_addr: info.sender,
+            enabled: true,
+            protocol_fee_collector_addr: Addr::unchecked(PROTOCOL_FEE_COLLECTOR),
+            thread_config: ThreadConfig {
+                max_thread_title_length: Uint64::from(100_u64),
+                max_thread_description_length: Uint64::from(500_u64),
+                max_thread_label_length: Uint64::from(10_u64),
+                max_number_of_thread_labels: Uint64::from(5_u64),
+                max_thread_msg_length: Uint64::from(500_u64),
+            },
+            protocol_fee_config: ProtocolFeeConfigV0_1_0 {
+                start_new_thread_fixed_cost: Uint128::from(10_000_u64),
+                ask_in_thread_fee_percentage: Uint64::zero(),
+                reply_in_thread_fee_percentage: Uint64::zero(),
+            },
+            default_fee_config: FeeConfigV0_1_0 {
+                ask_fee_percentage_of_membership: Uint64::from(5_u64),
+                ask_fee_to_thread_creator_percentage_of_membership: Uint64::one(),
+                reply_fee_percentage_of_membership: Uint64::one(),
+                reply_fee_to_thread_creator_percentage_of_membership: Uint64::one(),
+            },
+            default_fee_share_config: FeeShareConfigV0_1_0 {
+                share_to_issuer_percentage: Uint64::from(50_u64),
+                share_to_all_members_percentage: Uint64::from(50_u64),
+            },
+        },
+    )?;
+    Item::new("NEXT_THREAD_ID").save(deps.storage, &Uint64::from(2_u64))?;
+    Map::new("ALL_THREADS").save(
+        deps.storage,
+        1_u64,
+        &Thread {
+            id: Uint64::one(),
+            title: "title".to_string(),
+            description: "description".to_string(),
+            labels: vec![],
+            creator_user_id: Uint64::one(),
+            updatable: false,
+            deletable: false,
+        },
+    )?;
+
+    Map::new("ALL_USER_CONFIGS").save(
+        deps.storage,
+        2_u64,
+        &UserConfigV0_1_0 {
+            fee_config: Some(FeeConfigV0_1_0 {
+                ask_fee_percentage_of_membership: Uint64::from(10_u64),
+                ask_fee_to_thread_creator_percentage_of_membership: Uint64::from(2_u64),
+                reply_fee_percentage_of_membership: Uint64::from(3_u64),
+                reply_fee_to_thread_creator_percentage_of_membership: Uint64::from(4_u64),
+            }),
+            fee_share_config: None,
+        },
+    )?;
+
+    Ok(Response::new())
+}
+
+#[test]
+fn test_admin_can_migrate_contract_from_first_release() {
+    let (
+        mut app,
+        _,
+        cw_member_contract_addr,
+        admin_addr,
+        registration_admin_addr,
+        _,
+        user_1_addr,
+        user_2_addr,
+    ) = proper_instantiate();
+    let user_1_id = Uint64::one();
+    let user_2_id = Uint64::from(2_u64);
+    let thread_id = Uint64::one();
+
+    register_user_and_enable_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        &user_1_addr,
+        user_1_id,
+        SOCIAL_MEDIA_HANDLE_1,
+    );
+    register_user_and_enable_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        &user_2_addr,
+        user_2_id,
+        SOCIAL_MEDIA_HANDLE_2,
+    );
+    buy_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &user_2_addr,
+        user_1_id,
+        Uint128::one(),
+    )
+    .unwrap();
+
+    let first_release_code_id = app.store_code(Box::new(ContractWrapper::new(
+        execute,
+        instantiate_as_first_release,
+        query,
+    )));
+    let cw_thread_contract_addr = app
+        .instantiate_contract(
+            first_release_code_id,
+            admin_addr.clone(),
+            &FirstReleaseInstantiateMsg {
+                member_contract_addr: cw_member_contract_addr.to_string(),
+            },
+            &[],
+            "cw_thread_first_release",
+            Some(admin_addr.to_string()),
+        )
+        .unwrap();
+    app.execute_contract(
+        admin_addr.clone(),
+        get_distribution_contract_addr(&app, &cw_member_contract_addr),
+        &DistributionExecuteMsg::GrantRole(GrantRoleMsg {
+            addr: cw_thread_contract_addr.to_string(),
+            role: Role::Distributor,
+        }),
+        &[],
+    )
+    .unwrap();
+
+    let new_code_id = app.store_code(contract_cw_thread());
+    app.migrate_contract(
+        admin_addr.clone(),
+        cw_thread_contract_addr.clone(),
+        &MigrateMsg {},
+        new_code_id,
+    )
+    .unwrap();
+    let contract_version =
+        cw2::query_contract_info(&app.wrap(), cw_thread_contract_addr.clone()).unwrap();
+    assert_eq!(contract_version.version, CONTRACT_VERSION);
+
+    // Old config is kept with fees converted to bps, new fields get the same defaults as instantiate
+    let config: ConfigResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_thread_contract_addr.clone(),
+            &QueryMsg::QueryConfig(QueryConfigMsg {}),
+        )
+        .unwrap();
+    assert_eq!(config.config.admin_addr, admin_addr);
+    assert!(config.config.enabled);
+    assert_eq!(
+        config
+            .config
+            .protocol_fee_config
+            .start_new_thread_fixed_cost,
+        Uint128::from(10_000_u64)
+    );
+    assert_eq!(
+        config.config.protocol_fee_config.referral_fee_bps,
+        Uint64::zero()
+    );
+    assert_eq!(
+        config.config.default_fee_share_config,
+        FeeShareConfig {
+            share_to_issuer_bps: Uint64::from(5_000_u64),
+            share_to_all_members_bps: Uint64::from(5_000_u64),
+        }
+    );
+    assert_eq!(config.config.config_change_timelock, None);
+
+    let roles: RolesResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_thread_contract_addr.clone(),
+            &QueryMsg::QueryRoles(QueryRolesMsg {
+                addr: admin_addr.to_string(),
+            }),
+        )
+        .unwrap();
+    assert_eq!(roles.roles, vec![Role::Admin]);
+
+    // Fees user customized are kept
+    let user_2_config: UserConfigResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_thread_contract_addr.clone(),
+            &QueryMsg::QueryUserConfig(QueryUserConfigMsg { user_id: user_2_id }),
+        )
+        .unwrap();
+    assert_eq!(
+        user_2_config.user_config,
+        UserConfig {
+            fee_config: UserFeeConfig {
+                ask_fee_bps_of_membership: Some(Uint64::from(1_000_u64)),
+                ask_fee_to_thread_creator_bps_of_membership: Some(Uint64::from(200_u64)),
+                reply_fee_bps_of_membership: Some(Uint64::from(300_u64)),
+                reply_fee_to_thread_creator_bps_of_membership: Some(Uint64::from(400_u64)),
+            },
+            fee_share_config: None,
+        }
+    );
+
+    // Thread started before the migration can be replied in
+    let content = "reply";
+    let cost = query_cost_to_reply_in_thread(
+        &app,
+        &cw_thread_contract_addr,
+        user_2_id,
+        user_1_id,
+        user_1_id,
+        Uint64::from(content.chars().count() as u64),
+    );
+    reply_in_thread(
+        &mut app,
+        &cw_thread_contract_addr,
+        &user_2_addr,
+        thread_id,
+        None,
+        content,
+        cost.total_needed_from_user,
+    )
+    .unwrap();
+    assert_balance(&app, &cw_thread_contract_addr, Uint128::zero(), FEE_DENOM);
+}
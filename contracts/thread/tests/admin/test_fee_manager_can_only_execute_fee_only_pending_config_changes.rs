@@ -0,0 +1,136 @@
+use cosmwasm_std::Uint64;
+use cw_utils::Duration;
+
+use shared_pkg::rbac::Role;
+use thread::ContractError;
+use thread_pkg::msg::UpdateConfigMsg;
+
+use crate::helpers::{
+    assert_err, cancel_pending_config_change, execute_pending_config_change, grant_role,
+    proper_instantiate, query_config, update_config,
+};
+
+#[test]
+fn test_fee_manager_can_only_execute_fee_only_pending_config_changes() {
+    let (mut app, cw_thread_contract_addr, _, admin_addr, _, _, user_1_addr, user_2_addr) =
+        proper_instantiate();
+    let fee_manager_addr = user_1_addr;
+    let new_protocol_fee_collector_addr = user_2_addr;
+
+    update_config(
+        &mut app,
+        &cw_thread_contract_addr,
+        &admin_addr,
+        UpdateConfigMsg {
+            config_change_timelock: Some(Duration::Time(86_400)),
+            ..UpdateConfigMsg::default()
+        },
+    )
+    .unwrap();
+    grant_role(
+        &mut app,
+        &cw_thread_contract_addr,
+        &admin_addr,
+        &fee_manager_addr,
+        Role::FeeManager,
+    )
+    .unwrap();
+
+    // Admin queues a change of where fees go, fee manager queues a fee change
+    update_config(
+        &mut app,
+        &cw_thread_contract_addr,
+        &admin_addr,
+        UpdateConfigMsg {
+            protocol_fee_collector_addr: Some(new_protocol_fee_collector_addr.to_string()),
+            ..UpdateConfigMsg::default()
+        },
+    )
+    .unwrap();
+    update_config(
+        &mut app,
+        &cw_thread_contract_addr,
+        &fee_manager_addr,
+        UpdateConfigMsg {
+            protocol_fee_ask_in_thread_fee_bps: Some(Uint64::from(2_000_u32)),
+            ..UpdateConfigMsg::default()
+        },
+    )
+    .unwrap();
+
+    // Fee manager cannot queue a non fee change itself either
+    assert_err(
+        update_config(
+            &mut app,
+            &cw_thread_contract_addr,
+            &fee_manager_addr,
+            UpdateConfigMsg {
+                protocol_fee_collector_addr: Some(fee_manager_addr.to_string()),
+                ..UpdateConfigMsg::default()
+            },
+        ),
+        ContractError::OnlyAdminCanUpdateConfig {},
+    );
+
+    // Time-lock applies to fee manager too
+    assert_err(
+        execute_pending_config_change(
+            &mut app,
+            &cw_thread_contract_addr,
+            &fee_manager_addr,
+            Uint64::from(2_u8),
+        ),
+        ContractError::PendingConfigChangeStillTimeLocked {
+            executable_at: Duration::Time(86_400).after(&app.block_info()).to_string(),
+        },
+    );
+
+    app.update_block(|block| {
+        block.time = block.time.plus_seconds(86_400);
+    });
+
+    // Fee manager can neither execute nor cancel the change queued by admin
+    assert_err(
+        execute_pending_config_change(
+            &mut app,
+            &cw_thread_contract_addr,
+            &fee_manager_addr,
+            Uint64::one(),
+        ),
+        ContractError::OnlyAdminOrFeeManagerCanExecutePendingConfigChange {},
+    );
+    assert_err(
+        cancel_pending_config_change(
+            &mut app,
+            &cw_thread_contract_addr,
+            &fee_manager_addr,
+            Uint64::one(),
+        ),
+        ContractError::OnlyAdminOrFeeManagerCanCancelPendingConfigChange {},
+    );
+
+    execute_pending_config_change(
+        &mut app,
+        &cw_thread_contract_addr,
+        &fee_manager_addr,
+        Uint64::from(2_u8),
+    )
+    .unwrap();
+    execute_pending_config_change(
+        &mut app,
+        &cw_thread_contract_addr,
+        &admin_addr,
+        Uint64::one(),
+    )
+    .unwrap();
+
+    let config = query_config(&app, &cw_thread_contract_addr);
+    assert_eq!(
+        config.protocol_fee_config.ask_in_thread_fee_bps,
+        Uint64::from(2_000_u32)
+    );
+    assert_eq!(
+        config.protocol_fee_collector_addr,
+        new_protocol_fee_collector_addr
+    );
+}
@@ -0,0 +1,612 @@
+use anyhow::Result as AnyResult;
+use cosmwasm_std::{Addr, Coin, Empty, Event, Uint128, Uint64};
+use cw_multi_test::{App, AppBuilder, AppResponse, Contract, ContractWrapper, Executor};
+
+use distribution::contract::{
+    execute as distribution_execute, instantiate as distribution_instantiate,
+    migrate as distribution_migrate, query as distribution_query,
+};
+use distribution_pkg::msg::{
+    EnableMsg as DistributionEnableMsg, ExecuteMsg as DistributionExecuteMsg,
+    InstantiateMsg as DistributionInstantiateMsg,
+};
+use member::contract::{
+    execute as member_execute, instantiate as member_instantiate, migrate as member_migrate,
+    query as member_query,
+};
+use member_pkg::msg::{
+    BuyMembershipMsg, ConfigResponse as MemberConfigResponse, CostToBuyMembershipResponse,
+    EnableMembershipMsg, EnableMsg as MemberEnableMsg, EnableOpenRegistrationMsg,
+    ExecuteMsg as MemberExecuteMsg, InstantiateMsg as MemberInstantiateMsg, LinkSocialMediaMsg,
+    QueryConfigMsg as MemberQueryConfigMsg, QueryCostToBuyMembershipMsg,
+    QueryMsg as MemberQueryMsg, RegisterMsg, UpdateConfigMsg as MemberUpdateConfigMsg,
+};
+use shared_pkg::{
+    pricing::BondingCurve,
+    rbac::{GrantRoleMsg, Role},
+    timelock::{CancelPendingConfigChangeMsg, ExecutePendingConfigChangeMsg},
+};
+use thread::{
+    contract::{execute, instantiate, migrate, query},
+    ContractError,
+};
+use thread_pkg::{
+    config::Config,
+    msg::{
+        AnswerInThreadMsg, AskInThreadMsg, ConfigResponse, CostToAskInThreadResponse,
+        CostToReplyInThreadResponse, EnableMsg, ExecuteMsg, InstantiateMsg, QueryConfigMsg,
+        QueryCostToAskInThreadMsg, QueryCostToReplyInThreadMsg, QueryMsg, QueryUserConfigMsg,
+        ReplyInThreadMsg, StartNewThreadMsg, UpdateConfigMsg, UserConfigResponse,
+    },
+};
+
+pub const FAUCET: &str = "faucet";
+
+pub const ADMIN: &str = "terra1";
+pub const REGISTRATION_ADMIN: &str = "terra2";
+pub const PROTOCOL_FEE_COLLECTOR: &str = "terra3";
+
+pub const USER_1: &str = "terra4";
+pub const USER_2: &str = "terra5";
+
+pub const SOCIAL_MEDIA_HANDLE_1: &str = "twitter1";
+pub const SOCIAL_MEDIA_HANDLE_2: &str = "twitter2";
+// Platform link_social_media links handles on
+pub const SOCIAL_MEDIA_PLATFORM: &str = "twitter";
+
+pub const FEE_DENOM: &str = "uluna";
+
+// Thread fees are bps of 1 membership price
+// Default quadratic curve prices early memberships below 1 uluna so every fee would round down to 0
+pub const BONDING_CURVE: BondingCurve = BondingCurve::Linear {
+    base_price: Uint128::new(1_000_000),
+    slope: Uint128::new(10_000),
+};
+
+fn mock_app() -> App {
+    AppBuilder::new().build(|router, _, storage| {
+        router
+            .bank
+            .init_balance(
+                storage,
+                &Addr::unchecked(FAUCET),
+                vec![Coin {
+                    denom: FEE_DENOM.to_string(),
+                    // 1_000_000_000 uLuna i.e. 1k LUNA since 1 LUNA = 1_000_000 uLuna
+                    amount: Uint128::new(1_000_000_000),
+                }],
+            )
+            .unwrap();
+    })
+}
+
+pub fn contract_cw_thread() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(execute, instantiate, query).with_migrate(migrate);
+    Box::new(contract)
+}
+
+pub fn contract_cw_member() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(member_execute, member_instantiate, member_query)
+        .with_migrate(member_migrate);
+    Box::new(contract)
+}
+
+pub fn contract_cw_distribution() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        distribution_execute,
+        distribution_instantiate,
+        distribution_query,
+    )
+    .with_migrate(distribution_migrate);
+    Box::new(contract)
+}
+
+// Returns (app, thread contract, member contract, admin, registration admin, protocol fee collector, user 1, user 2)
+pub fn proper_instantiate() -> (App, Addr, Addr, Addr, Addr, Addr, Addr, Addr) {
+    let mut app = mock_app();
+
+    let cw_member_contract_code_id = app.store_code(contract_cw_member());
+    let cw_member_contract_addr = app
+        .instantiate_contract(
+            cw_member_contract_code_id,
+            Addr::unchecked(ADMIN),
+            &MemberInstantiateMsg {
+                admin_addr: Some(ADMIN.to_string()),
+                registration_admin_addr: Some(REGISTRATION_ADMIN.to_string()),
+                protocol_fee_collector_addr: Some(PROTOCOL_FEE_COLLECTOR.to_string()),
+                fee_denom: Some(FEE_DENOM.to_string()),
+                protocol_fee_membership_trading_fee_bps: None,
+                protocol_fee_referral_fee_bps: None,
+                default_trading_fee_bps_of_membership: None,
+                default_membership_trading_fee_membership_issuer_fee_bps: None,
+                default_membership_trading_fee_membership_holder_fee_bps: None,
+                default_bonding_curve: None,
+                sigmoid_max_trade_amount: None,
+                default_holding_cap: None,
+                only_verified_buyers: None,
+                price_candle_interval: None,
+                user_fee_change_timelock: None,
+                user_fee_ranges: None,
+            },
+            &[],
+            "cw_member",
+            Some(ADMIN.to_string()),
+        )
+        .unwrap();
+
+    let cw_distribution_contract_code_id = app.store_code(contract_cw_distribution());
+    let cw_distribution_contract_addr = app
+        .instantiate_contract(
+            cw_distribution_contract_code_id,
+            Addr::unchecked(ADMIN),
+            &DistributionInstantiateMsg {
+                member_contract_addr: cw_member_contract_addr.to_string(),
+                admin_addr: Some(ADMIN.to_string()),
+            },
+            &[],
+            "cw_distribution",
+            Some(ADMIN.to_string()),
+        )
+        .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        cw_member_contract_addr.clone(),
+        &MemberExecuteMsg::UpdateConfig(MemberUpdateConfigMsg {
+            distribution_contract_addr: Some(cw_distribution_contract_addr.to_string()),
+            ..MemberUpdateConfigMsg::default()
+        }),
+        &[],
+    )
+    .unwrap();
+
+    let cw_thread_contract_code_id = app.store_code(contract_cw_thread());
+    let cw_thread_contract_addr = app
+        .instantiate_contract(
+            cw_thread_contract_code_id,
+            Addr::unchecked(ADMIN),
+            &InstantiateMsg {
+                member_contract_addr: cw_member_contract_addr.to_string(),
+                admin_addr: Some(ADMIN.to_string()),
+                protocol_fee_collector_addr: Some(PROTOCOL_FEE_COLLECTOR.to_string()),
+                max_thread_title_length: None,
+                max_thread_description_length: None,
+                max_thread_label_length: None,
+                max_number_of_thread_labels: None,
+                max_thread_msg_length: None,
+                protocol_fee_start_new_thread_fixed_cost: None,
+                protocol_fee_ask_in_thread_fee_bps: None,
+                protocol_fee_reply_in_thread_fee_bps: None,
+                protocol_fee_referral_fee_bps: None,
+                default_ask_fee_bps_of_membership: None,
+                default_ask_fee_to_thread_creator_bps_of_membership: None,
+                default_reply_fee_bps_of_membership: None,
+                default_reply_fee_to_thread_creator_bps_of_membership: None,
+                default_share_to_issuer_bps: None,
+                default_share_to_all_members_bps: None,
+                user_fee_change_timelock: None,
+                user_fee_ranges: None,
+            },
+            &[],
+            "cw_thread",
+            // Admin is able to migrate contract
+            Some(ADMIN.to_string()),
+        )
+        .unwrap();
+    // Thread contract sends thread fees to membership holders through distribution contract
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        cw_distribution_contract_addr.clone(),
+        &DistributionExecuteMsg::GrantRole(GrantRoleMsg {
+            addr: cw_thread_contract_addr.to_string(),
+            role: Role::Distributor,
+        }),
+        &[],
+    )
+    .unwrap();
+
+    // Contracts are instantiated disabled, admin turns them on
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        cw_member_contract_addr.clone(),
+        &MemberExecuteMsg::Enable(MemberEnableMsg {}),
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        cw_member_contract_addr.clone(),
+        &MemberExecuteMsg::EnableOpenRegistration(EnableOpenRegistrationMsg {}),
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        cw_distribution_contract_addr,
+        &DistributionExecuteMsg::Enable(DistributionEnableMsg {}),
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        cw_thread_contract_addr.clone(),
+        &ExecuteMsg::Enable(EnableMsg {}),
+        &[],
+    )
+    .unwrap();
+
+    (
+        app,
+        cw_thread_contract_addr,
+        cw_member_contract_addr,
+        Addr::unchecked(ADMIN),
+        Addr::unchecked(REGISTRATION_ADMIN),
+        Addr::unchecked(PROTOCOL_FEE_COLLECTOR),
+        Addr::unchecked(USER_1),
+        Addr::unchecked(USER_2),
+    )
+}
+
+pub fn get_fund_from_faucet(app: &mut App, addr: Addr, amount: Uint128) {
+    app.send_tokens(
+        Addr::unchecked(FAUCET),
+        addr,
+        &[Coin {
+            denom: FEE_DENOM.to_string(),
+            amount,
+        }],
+    )
+    .unwrap();
+}
+
+pub fn get_distribution_contract_addr(app: &App, cw_member_contract_addr: &Addr) -> Addr {
+    let config_res: MemberConfigResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr,
+            &MemberQueryMsg::QueryConfig(MemberQueryConfigMsg {}),
+        )
+        .unwrap();
+    config_res.config.distribution_contract_addr.unwrap()
+}
+
+// Register user, link its social media and enable its membership priced on BONDING_CURVE
+pub fn register_user_and_enable_membership(
+    app: &mut App,
+    cw_member_contract_addr: &Addr,
+    registration_admin_addr: &Addr,
+    user_addr: &Addr,
+    user_id: Uint64,
+    social_media_handle: &str,
+) {
+    app.execute_contract(
+        user_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &MemberExecuteMsg::Register(RegisterMsg {
+            referrer_user_id: None,
+            user_addr: None,
+        }),
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        registration_admin_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &MemberExecuteMsg::LinkSocialMedia(LinkSocialMediaMsg {
+            user_id,
+            platform: SOCIAL_MEDIA_PLATFORM.to_string(),
+            social_media_handle: social_media_handle.to_string(),
+        }),
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        registration_admin_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &MemberExecuteMsg::EnableMembership(EnableMembershipMsg {
+            user_id,
+            bonding_curve: Some(BONDING_CURVE),
+            fee_denom: None,
+        }),
+        &[],
+    )
+    .unwrap();
+}
+
+pub fn buy_membership(
+    app: &mut App,
+    cw_member_contract_addr: &Addr,
+    sender_addr: &Addr,
+    membership_issuer_user_id: Uint64,
+    amount: Uint128,
+) -> AnyResult<AppResponse> {
+    let cost: CostToBuyMembershipResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_member_contract_addr,
+            &MemberQueryMsg::QueryCostToBuyMembership(QueryCostToBuyMembershipMsg {
+                membership_issuer_user_id,
+                amount,
+            }),
+        )
+        .unwrap();
+    get_fund_from_faucet(app, sender_addr.clone(), cost.total_needed_from_user);
+    app.execute_contract(
+        sender_addr.clone(),
+        cw_member_contract_addr.clone(),
+        &MemberExecuteMsg::BuyMembership(BuyMembershipMsg {
+            membership_issuer_user_id,
+            amount,
+            max_total_cost: None,
+        }),
+        &[Coin {
+            denom: FEE_DENOM.to_string(),
+            amount: cost.total_needed_from_user,
+        }],
+    )
+}
+
+pub fn start_new_thread(
+    app: &mut App,
+    cw_thread_contract_addr: &Addr,
+    sender_addr: &Addr,
+    fee: Uint128,
+) -> AnyResult<AppResponse> {
+    get_fund_from_faucet(app, sender_addr.clone(), fee);
+    app.execute_contract(
+        sender_addr.clone(),
+        cw_thread_contract_addr.clone(),
+        &ExecuteMsg::StartNewThread(StartNewThreadMsg {
+            title: "title".to_string(),
+            description: "description".to_string(),
+            labels: vec![],
+        }),
+        &[Coin {
+            denom: FEE_DENOM.to_string(),
+            amount: fee,
+        }],
+    )
+}
+
+pub fn query_cost_to_ask_in_thread(
+    app: &App,
+    cw_thread_contract_addr: &Addr,
+    asker_user_id: Uint64,
+    ask_to_user_id: Uint64,
+    thread_creator_user_id: Uint64,
+    content_len: Uint64,
+) -> CostToAskInThreadResponse {
+    app.wrap()
+        .query_wasm_smart(
+            cw_thread_contract_addr,
+            &QueryMsg::QueryCostToAskInThread(QueryCostToAskInThreadMsg {
+                asker_user_id,
+                ask_to_user_id,
+                thread_creator_user_id,
+                content_len,
+            }),
+        )
+        .unwrap()
+}
+
+// Ask in an existing thread
+pub fn ask_in_thread(
+    app: &mut App,
+    cw_thread_contract_addr: &Addr,
+    sender_addr: &Addr,
+    thread_id: Uint64,
+    ask_to_user_id: Uint64,
+    content: &str,
+    paid_amount: Uint128,
+) -> AnyResult<AppResponse> {
+    get_fund_from_faucet(app, sender_addr.clone(), paid_amount);
+    app.execute_contract(
+        sender_addr.clone(),
+        cw_thread_contract_addr.clone(),
+        &ExecuteMsg::AskInThread(AskInThreadMsg {
+            start_new_thread: None,
+            thread_title: None,
+            thread_description: None,
+            thread_labels: None,
+            thread_id: Some(thread_id),
+            ask_to_user_id,
+            content: content.to_string(),
+        }),
+        &[Coin {
+            denom: FEE_DENOM.to_string(),
+            amount: paid_amount,
+        }],
+    )
+}
+
+pub fn answer_in_thread(
+    app: &mut App,
+    cw_thread_contract_addr: &Addr,
+    sender_addr: &Addr,
+    thread_id: Uint64,
+    question_id: Uint64,
+    content: &str,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        sender_addr.clone(),
+        cw_thread_contract_addr.clone(),
+        &ExecuteMsg::AnswerInThread(AnswerInThreadMsg {
+            thread_id,
+            question_id,
+            content: content.to_string(),
+        }),
+        &[],
+    )
+}
+
+pub fn query_cost_to_reply_in_thread(
+    app: &App,
+    cw_thread_contract_addr: &Addr,
+    replier_user_id: Uint64,
+    reply_to_user_id: Uint64,
+    thread_creator_user_id: Uint64,
+    content_len: Uint64,
+) -> CostToReplyInThreadResponse {
+    app.wrap()
+        .query_wasm_smart(
+            cw_thread_contract_addr,
+            &QueryMsg::QueryCostToReplyInThread(QueryCostToReplyInThreadMsg {
+                replier_user_id,
+                reply_to_user_id,
+                thread_creator_user_id,
+                content_len,
+            }),
+        )
+        .unwrap()
+}
+
+pub fn reply_in_thread(
+    app: &mut App,
+    cw_thread_contract_addr: &Addr,
+    sender_addr: &Addr,
+    thread_id: Uint64,
+    reply_to_thread_msg_id: Option<Uint64>,
+    content: &str,
+    paid_amount: Uint128,
+) -> AnyResult<AppResponse> {
+    get_fund_from_faucet(app, sender_addr.clone(), paid_amount);
+    app.execute_contract(
+        sender_addr.clone(),
+        cw_thread_contract_addr.clone(),
+        &ExecuteMsg::ReplyInThread(ReplyInThreadMsg {
+            thread_id,
+            reply_to_thread_msg_id,
+            content: content.to_string(),
+        }),
+        &[Coin {
+            denom: FEE_DENOM.to_string(),
+            amount: paid_amount,
+        }],
+    )
+}
+
+pub fn grant_role(
+    app: &mut App,
+    cw_thread_contract_addr: &Addr,
+    sender_addr: &Addr,
+    addr: &Addr,
+    role: Role,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        sender_addr.clone(),
+        cw_thread_contract_addr.clone(),
+        &ExecuteMsg::GrantRole(GrantRoleMsg {
+            addr: addr.to_string(),
+            role,
+        }),
+        &[],
+    )
+}
+
+pub fn update_config(
+    app: &mut App,
+    cw_thread_contract_addr: &Addr,
+    sender_addr: &Addr,
+    data: UpdateConfigMsg,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        sender_addr.clone(),
+        cw_thread_contract_addr.clone(),
+        &ExecuteMsg::UpdateConfig(data),
+        &[],
+    )
+}
+
+pub fn execute_pending_config_change(
+    app: &mut App,
+    cw_thread_contract_addr: &Addr,
+    sender_addr: &Addr,
+    id: Uint64,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        sender_addr.clone(),
+        cw_thread_contract_addr.clone(),
+        &ExecuteMsg::ExecutePendingConfigChange(ExecutePendingConfigChangeMsg { id }),
+        &[],
+    )
+}
+
+pub fn cancel_pending_config_change(
+    app: &mut App,
+    cw_thread_contract_addr: &Addr,
+    sender_addr: &Addr,
+    id: Uint64,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        sender_addr.clone(),
+        cw_thread_contract_addr.clone(),
+        &ExecuteMsg::CancelPendingConfigChange(CancelPendingConfigChangeMsg { id }),
+        &[],
+    )
+}
+
+pub fn query_config(app: &App, cw_thread_contract_addr: &Addr) -> Config {
+    let config_res: ConfigResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw_thread_contract_addr,
+            &QueryMsg::QueryConfig(QueryConfigMsg {}),
+        )
+        .unwrap();
+    config_res.config
+}
+
+pub fn query_user_config(
+    app: &App,
+    cw_thread_contract_addr: &Addr,
+    user_id: Uint64,
+) -> UserConfigResponse {
+    app.wrap()
+        .query_wasm_smart(
+            cw_thread_contract_addr,
+            &QueryMsg::QueryUserConfig(QueryUserConfigMsg { user_id }),
+        )
+        .unwrap()
+}
+
+pub fn assert_err(res: AnyResult<AppResponse>, err: ContractError) {
+    match res {
+        Ok(_) => panic!("Result was not an error"),
+        Err(generic_err) => {
+            let contract_err: ContractError = generic_err.downcast().unwrap();
+            assert_eq!(contract_err, err);
+        }
+    }
+}
+
+pub fn assert_balance(app: &App, user_addr: &Addr, expected_balance: Uint128, denom: &str) {
+    let balance = app.wrap().query_balance(user_addr, denom).unwrap();
+    assert_eq!(balance.amount, expected_balance);
+}
+
+pub fn find_event(res: &AppResponse, ty: &str) -> Event {
+    res.events
+        .iter()
+        .find(|event| event.ty == ty)
+        .unwrap_or_else(|| panic!("event {ty} not emitted"))
+        .clone()
+}
+
+pub fn attribute(event: &Event, key: &str) -> String {
+    event
+        .attributes
+        .iter()
+        .find(|attr| attr.key == key)
+        .unwrap_or_else(|| panic!("attribute {key} not in event {}", event.ty))
+        .value
+        .clone()
+}
+
+// Attribute keys in order, without the contract address wasm module adds
+pub fn attribute_keys(event: &Event) -> Vec<&str> {
+    event
+        .attributes
+        .iter()
+        .map(|attr| attr.key.as_str())
+        .filter(|key| *key != "_contract_addr")
+        .collect()
+}
@@ -0,0 +1,4 @@
+mod admin;
+mod helpers;
+mod thread;
+mod user;
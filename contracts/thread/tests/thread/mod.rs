@@ -0,0 +1,4 @@
+mod test_ask_and_answer_in_thread_emit_events;
+mod test_ask_in_thread_to_missing_user_or_thread_fails;
+mod test_reply_in_thread_without_reply_to_pays_all_fees;
+mod test_start_new_thread_and_reply_emit_events;
@@ -0,0 +1,171 @@
+use cosmwasm_std::{Uint128, Uint64};
+
+use crate::helpers::{
+    answer_in_thread, ask_in_thread, attribute, attribute_keys, buy_membership, find_event,
+    proper_instantiate, query_cost_to_ask_in_thread, register_user_and_enable_membership,
+    start_new_thread, FEE_DENOM, SOCIAL_MEDIA_HANDLE_1, SOCIAL_MEDIA_HANDLE_2,
+};
+
+const THREAD_MSG_EVENT_ATTRIBUTE_KEYS: [&str; 16] = [
+    "kind",
+    "thread_id",
+    "thread_msg_id",
+    "creator_user_id",
+    "thread_creator_user_id",
+    "to_user_id",
+    "to_thread_msg_id",
+    "protocol_fee",
+    "referral_fee",
+    "to_membership_issuer_fee",
+    "to_membership_all_members_fee",
+    "thread_creator_membership_issuer_fee",
+    "thread_creator_membership_all_members_fee",
+    "total_paid",
+    "refunded_amount",
+    "fee_denom",
+];
+
+#[test]
+fn test_ask_and_answer_in_thread_emit_events() {
+    let (
+        mut app,
+        cw_thread_contract_addr,
+        cw_member_contract_addr,
+        _,
+        registration_admin_addr,
+        _,
+        user_1_addr,
+        user_2_addr,
+    ) = proper_instantiate();
+    let user_1_id = Uint64::one();
+    let user_2_id = Uint64::from(2_u8);
+    let thread_id = Uint64::one();
+
+    register_user_and_enable_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        &user_1_addr,
+        user_1_id,
+        SOCIAL_MEDIA_HANDLE_1,
+    );
+    register_user_and_enable_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        &user_2_addr,
+        user_2_id,
+        SOCIAL_MEDIA_HANDLE_2,
+    );
+    buy_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &user_2_addr,
+        user_1_id,
+        Uint128::one(),
+    )
+    .unwrap();
+    start_new_thread(
+        &mut app,
+        &cw_thread_contract_addr,
+        &user_1_addr,
+        Uint128::from(10_000_u64),
+    )
+    .unwrap();
+
+    // ================ User 2 asks thread creator in its thread ================
+    let question = "question";
+    let cost = query_cost_to_ask_in_thread(
+        &app,
+        &cw_thread_contract_addr,
+        user_2_id,
+        user_1_id,
+        user_1_id,
+        Uint64::from(question.chars().count() as u64),
+    );
+    let res = ask_in_thread(
+        &mut app,
+        &cw_thread_contract_addr,
+        &user_2_addr,
+        thread_id,
+        user_1_id,
+        question,
+        cost.total_needed_from_user,
+    )
+    .unwrap();
+    let question_event = find_event(&res, "wasm-cw_friend.thread_msg");
+    assert_eq!(
+        attribute_keys(&question_event),
+        THREAD_MSG_EVENT_ATTRIBUTE_KEYS
+    );
+    assert_eq!(attribute(&question_event, "kind"), "question");
+    assert_eq!(
+        attribute(&question_event, "thread_id"),
+        thread_id.to_string()
+    );
+    let question_id = attribute(&question_event, "thread_msg_id");
+    assert_eq!(question_id, "1");
+    assert_eq!(
+        attribute(&question_event, "to_user_id"),
+        user_1_id.to_string()
+    );
+    assert_eq!(
+        attribute(&question_event, "to_membership_issuer_fee"),
+        cost.ask_to_membership_issuer_fee.to_string()
+    );
+    assert_eq!(
+        attribute(&question_event, "total_paid"),
+        cost.total_needed_from_user.to_string()
+    );
+    assert_eq!(attribute(&question_event, "fee_denom"), FEE_DENOM);
+
+    // ================ User 1 answers the question ================
+    let res = answer_in_thread(
+        &mut app,
+        &cw_thread_contract_addr,
+        &user_1_addr,
+        thread_id,
+        Uint64::one(),
+        "answer",
+    )
+    .unwrap();
+    let answer_event = find_event(&res, "wasm-cw_friend.thread_msg");
+    assert_eq!(
+        attribute_keys(&answer_event),
+        THREAD_MSG_EVENT_ATTRIBUTE_KEYS
+    );
+    assert_eq!(attribute(&answer_event, "kind"), "answer");
+    assert_eq!(attribute(&answer_event, "thread_id"), thread_id.to_string());
+    // Answer is a new msg in the thread, question is kept
+    assert_eq!(attribute(&answer_event, "thread_msg_id"), "2");
+    assert_eq!(
+        attribute(&answer_event, "creator_user_id"),
+        user_1_id.to_string()
+    );
+    assert_eq!(
+        attribute(&answer_event, "to_user_id"),
+        user_2_id.to_string()
+    );
+    assert_eq!(attribute(&answer_event, "to_thread_msg_id"), question_id);
+    // Answer is free
+    assert_eq!(attribute(&answer_event, "total_paid"), "0");
+    assert_eq!(attribute(&answer_event, "fee_denom"), FEE_DENOM);
+
+    // Answering the same question again adds another answer
+    let res = answer_in_thread(
+        &mut app,
+        &cw_thread_contract_addr,
+        &user_1_addr,
+        thread_id,
+        Uint64::one(),
+        "another answer",
+    )
+    .unwrap();
+    assert_eq!(
+        attribute(
+            &find_event(&res, "wasm-cw_friend.thread_msg"),
+            "thread_msg_id"
+        ),
+        "3"
+    );
+}
@@ -0,0 +1,74 @@
+use cosmwasm_std::Uint64;
+use cw_multi_test::Executor;
+
+use thread::ContractError;
+use thread_pkg::msg::{AskInThreadMsg, ExecuteMsg};
+
+use crate::helpers::{
+    assert_err, proper_instantiate, register_user_and_enable_membership, SOCIAL_MEDIA_HANDLE_1,
+};
+
+#[test]
+fn test_ask_in_thread_to_missing_user_or_thread_fails() {
+    let (
+        mut app,
+        cw_thread_contract_addr,
+        cw_member_contract_addr,
+        _,
+        registration_admin_addr,
+        _,
+        user_1_addr,
+        _,
+    ) = proper_instantiate();
+    let user_1_id = Uint64::one();
+    let missing_id = Uint64::from(9_u8);
+
+    register_user_and_enable_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        &user_1_addr,
+        user_1_id,
+        SOCIAL_MEDIA_HANDLE_1,
+    );
+
+    // Asking to a user that doesn't exist
+    assert_err(
+        app.execute_contract(
+            user_1_addr.clone(),
+            cw_thread_contract_addr.clone(),
+            &ExecuteMsg::AskInThread(AskInThreadMsg {
+                start_new_thread: Some(true),
+                thread_title: Some("title".to_string()),
+                thread_description: Some("description".to_string()),
+                thread_labels: None,
+                thread_id: None,
+                ask_to_user_id: missing_id,
+                content: "question".to_string(),
+            }),
+            &[],
+        ),
+        ContractError::UserNotExist {},
+    );
+
+    // Asking in a thread that doesn't exist, or without a thread ID
+    for thread_id in [Some(missing_id), None] {
+        assert_err(
+            app.execute_contract(
+                user_1_addr.clone(),
+                cw_thread_contract_addr.clone(),
+                &ExecuteMsg::AskInThread(AskInThreadMsg {
+                    start_new_thread: None,
+                    thread_title: None,
+                    thread_description: None,
+                    thread_labels: None,
+                    thread_id,
+                    ask_to_user_id: user_1_id,
+                    content: "question".to_string(),
+                }),
+                &[],
+            ),
+            ContractError::ThreadNotExist {},
+        );
+    }
+}
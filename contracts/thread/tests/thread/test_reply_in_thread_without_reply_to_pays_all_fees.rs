@@ -0,0 +1,124 @@
+use cosmwasm_std::{Uint128, Uint64};
+
+use crate::helpers::{
+    assert_balance, buy_membership, get_distribution_contract_addr, proper_instantiate,
+    query_cost_to_reply_in_thread, register_user_and_enable_membership, reply_in_thread,
+    start_new_thread, FEE_DENOM, SOCIAL_MEDIA_HANDLE_1, SOCIAL_MEDIA_HANDLE_2,
+};
+
+#[test]
+fn test_reply_in_thread_without_reply_to_pays_all_fees() {
+    let (
+        mut app,
+        cw_thread_contract_addr,
+        cw_member_contract_addr,
+        _,
+        registration_admin_addr,
+        _,
+        user_1_addr,
+        user_2_addr,
+    ) = proper_instantiate();
+    let cw_distribution_contract_addr =
+        get_distribution_contract_addr(&app, &cw_member_contract_addr);
+
+    let user_1_id = Uint64::one();
+    let user_2_id = Uint64::from(2_u8);
+    let thread_id = Uint64::one();
+
+    register_user_and_enable_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        &user_1_addr,
+        user_1_id,
+        SOCIAL_MEDIA_HANDLE_1,
+    );
+    register_user_and_enable_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        &user_2_addr,
+        user_2_id,
+        SOCIAL_MEDIA_HANDLE_2,
+    );
+
+    // User 1 starts a thread, user 2 needs user 1's membership to reply in it
+    start_new_thread(
+        &mut app,
+        &cw_thread_contract_addr,
+        &user_1_addr,
+        Uint128::from(10_000_u64),
+    )
+    .unwrap();
+    buy_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &user_2_addr,
+        user_1_id,
+        Uint128::one(),
+    )
+    .unwrap();
+
+    let user_1_balance = app
+        .wrap()
+        .query_balance(&user_1_addr, FEE_DENOM)
+        .unwrap()
+        .amount;
+    let distribution_balance = app
+        .wrap()
+        .query_balance(&cw_distribution_contract_addr, FEE_DENOM)
+        .unwrap()
+        .amount;
+    let user_2_balance = app
+        .wrap()
+        .query_balance(&user_2_addr, FEE_DENOM)
+        .unwrap()
+        .amount;
+
+    // ================ User 2 replies to the thread itself ================
+    let content = "reply";
+    let cost = query_cost_to_reply_in_thread(
+        &app,
+        &cw_thread_contract_addr,
+        user_2_id,
+        user_1_id,
+        user_1_id,
+        Uint64::from(content.chars().count() as u64),
+    );
+    assert!(cost.reply_to_membership_issuer_fee > Uint128::zero());
+    assert!(cost.reply_to_membership_all_members_fee > Uint128::zero());
+
+    // Overpay so refund is covered too
+    let overpaid_amount = Uint128::from(1_000_u64);
+    reply_in_thread(
+        &mut app,
+        &cw_thread_contract_addr,
+        &user_2_addr,
+        thread_id,
+        None,
+        content,
+        cost.total_needed_from_user + overpaid_amount,
+    )
+    .unwrap();
+
+    // Nothing is left in thread contract, reply fee goes to thread creator and its membership holders
+    assert_balance(&app, &cw_thread_contract_addr, Uint128::zero(), FEE_DENOM);
+    assert_balance(
+        &app,
+        &user_1_addr,
+        user_1_balance + cost.reply_to_membership_issuer_fee,
+        FEE_DENOM,
+    );
+    assert_balance(
+        &app,
+        &cw_distribution_contract_addr,
+        distribution_balance + cost.reply_to_membership_all_members_fee,
+        FEE_DENOM,
+    );
+    assert_balance(
+        &app,
+        &user_2_addr,
+        user_2_balance + overpaid_amount,
+        FEE_DENOM,
+    );
+}
@@ -0,0 +1,133 @@
+use cosmwasm_std::{Uint128, Uint64};
+
+use crate::helpers::{
+    attribute, attribute_keys, buy_membership, find_event, proper_instantiate,
+    query_cost_to_reply_in_thread, register_user_and_enable_membership, reply_in_thread,
+    start_new_thread, FEE_DENOM, SOCIAL_MEDIA_HANDLE_1, SOCIAL_MEDIA_HANDLE_2,
+};
+
+#[test]
+fn test_start_new_thread_and_reply_emit_events() {
+    let (
+        mut app,
+        cw_thread_contract_addr,
+        cw_member_contract_addr,
+        _,
+        registration_admin_addr,
+        _,
+        user_1_addr,
+        user_2_addr,
+    ) = proper_instantiate();
+    let user_1_id = Uint64::one();
+    let user_2_id = Uint64::from(2_u8);
+    let thread_id = Uint64::one();
+
+    register_user_and_enable_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        &user_1_addr,
+        user_1_id,
+        SOCIAL_MEDIA_HANDLE_1,
+    );
+    register_user_and_enable_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        &user_2_addr,
+        user_2_id,
+        SOCIAL_MEDIA_HANDLE_2,
+    );
+    buy_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &user_2_addr,
+        user_1_id,
+        Uint128::one(),
+    )
+    .unwrap();
+
+    // ================ Start new thread ================
+    let res = start_new_thread(
+        &mut app,
+        &cw_thread_contract_addr,
+        &user_1_addr,
+        Uint128::from(10_000_u64),
+    )
+    .unwrap();
+    let thread_event = find_event(&res, "wasm-cw_friend.thread");
+    assert_eq!(
+        attribute_keys(&thread_event),
+        vec![
+            "thread_id",
+            "creator_user_id",
+            "protocol_fee",
+            "referral_fee",
+            "total_paid",
+            "refunded_amount",
+            "fee_denom",
+        ]
+    );
+    assert_eq!(attribute(&thread_event, "thread_id"), thread_id.to_string());
+    assert_eq!(attribute(&thread_event, "protocol_fee"), "10000");
+    assert_eq!(attribute(&thread_event, "fee_denom"), FEE_DENOM);
+
+    // ================ Reply to the thread itself ================
+    let content = "reply";
+    let cost = query_cost_to_reply_in_thread(
+        &app,
+        &cw_thread_contract_addr,
+        user_2_id,
+        user_1_id,
+        user_1_id,
+        Uint64::from(content.chars().count() as u64),
+    );
+    let res = reply_in_thread(
+        &mut app,
+        &cw_thread_contract_addr,
+        &user_2_addr,
+        thread_id,
+        None,
+        content,
+        cost.total_needed_from_user,
+    )
+    .unwrap();
+    let thread_msg_event = find_event(&res, "wasm-cw_friend.thread_msg");
+    assert_eq!(
+        attribute_keys(&thread_msg_event),
+        vec![
+            "kind",
+            "thread_id",
+            "thread_msg_id",
+            "creator_user_id",
+            "thread_creator_user_id",
+            "to_user_id",
+            "to_thread_msg_id",
+            "protocol_fee",
+            "referral_fee",
+            "to_membership_issuer_fee",
+            "to_membership_all_members_fee",
+            "thread_creator_membership_issuer_fee",
+            "thread_creator_membership_all_members_fee",
+            "total_paid",
+            "refunded_amount",
+            "fee_denom",
+        ]
+    );
+    assert_eq!(attribute(&thread_msg_event, "kind"), "reply");
+    assert_eq!(attribute(&thread_msg_event, "thread_msg_id"), "1");
+    // Replying to the thread itself pays thread creator
+    assert_eq!(
+        attribute(&thread_msg_event, "to_user_id"),
+        user_1_id.to_string()
+    );
+    assert_eq!(attribute(&thread_msg_event, "to_thread_msg_id"), "0");
+    assert_eq!(
+        attribute(&thread_msg_event, "to_membership_issuer_fee"),
+        cost.reply_to_membership_issuer_fee.to_string()
+    );
+    assert_eq!(
+        attribute(&thread_msg_event, "total_paid"),
+        cost.total_needed_from_user.to_string()
+    );
+}
@@ -0,0 +1,2 @@
+mod test_issuer_fee_change_is_time_locked;
+mod test_pending_issuer_fee_change_out_of_tightened_range_is_dropped;
@@ -0,0 +1,185 @@
+use cosmwasm_std::{Addr, Uint128, Uint64};
+use cw_multi_test::{App, Executor};
+use cw_utils::Expiration;
+
+use thread_pkg::{
+    msg::{
+        CostToAskInThreadResponse, ExecuteMsg, QueryCostToAskInThreadMsg, QueryMsg,
+        UpdateConfigMsg, UpdateUserConfigMsg,
+    },
+    user_config::{PendingUserFeeChange, UserFeeConfig},
+};
+
+use crate::helpers::{
+    proper_instantiate, query_cost_to_reply_in_thread, query_user_config,
+    register_user_and_enable_membership, update_config, SOCIAL_MEDIA_HANDLE_1,
+    SOCIAL_MEDIA_HANDLE_2,
+};
+
+#[test]
+fn test_issuer_fee_change_is_time_locked() {
+    let (
+        mut app,
+        cw_thread_contract_addr,
+        cw_member_contract_addr,
+        admin_addr,
+        registration_admin_addr,
+        _,
+        user_1_addr,
+        user_2_addr,
+    ) = proper_instantiate();
+    let user_1_id = Uint64::one();
+    let user_2_id = Uint64::from(2_u8);
+
+    register_user_and_enable_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        &user_1_addr,
+        user_1_id,
+        SOCIAL_MEDIA_HANDLE_1,
+    );
+    register_user_and_enable_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        &user_2_addr,
+        user_2_id,
+        SOCIAL_MEDIA_HANDLE_2,
+    );
+
+    let reply_cost_before_fee_change = query_cost_to_reply_in_thread(
+        &app,
+        &cw_thread_contract_addr,
+        user_2_id,
+        user_1_id,
+        user_1_id,
+        Uint64::from(5_u8),
+    );
+    assert_eq!(
+        reply_cost_before_fee_change.reply_to_membership_issuer_fee,
+        Uint128::from(5_050_u32)
+    );
+
+    // Fee hike waits for the time-lock
+    app.execute_contract(
+        user_1_addr.clone(),
+        cw_thread_contract_addr.clone(),
+        &ExecuteMsg::UpdateUserConfig(UpdateUserConfigMsg {
+            user_id: user_1_id,
+            ask_fee_bps_of_membership: None,
+            ask_fee_to_thread_creator_bps_of_membership: None,
+            reply_fee_bps_of_membership: Some(Uint64::from(300_u32)),
+            reply_fee_to_thread_creator_bps_of_membership: None,
+            share_to_issuer_bps: None,
+            share_to_all_members_bps: None,
+        }),
+        &[],
+    )
+    .unwrap();
+
+    let user_config = query_user_config(&app, &cw_thread_contract_addr, user_1_id);
+    assert_eq!(user_config.user_config.fee_config, UserFeeConfig::default());
+    assert_eq!(
+        user_config.pending_fee_change,
+        Some(PendingUserFeeChange {
+            ask_fee_bps_of_membership: None,
+            ask_fee_to_thread_creator_bps_of_membership: None,
+            reply_fee_bps_of_membership: Some(Uint64::from(300_u32)),
+            reply_fee_to_thread_creator_bps_of_membership: None,
+            fee_share_config: None,
+            effective_at: Expiration::AtTime(app.block_info().time.plus_seconds(86_400)),
+        })
+    );
+    assert_eq!(
+        query_cost_to_reply_in_thread(
+            &app,
+            &cw_thread_contract_addr,
+            user_2_id,
+            user_1_id,
+            user_1_id,
+            Uint64::from(5_u8),
+        ),
+        reply_cost_before_fee_change
+    );
+
+    // Fee change takes effect once time-lock passes, without issuer doing anything
+    app.update_block(|block| {
+        block.time = block.time.plus_seconds(86_400);
+    });
+    let user_config = query_user_config(&app, &cw_thread_contract_addr, user_1_id);
+    assert_eq!(
+        user_config.user_config.fee_config,
+        UserFeeConfig {
+            reply_fee_bps_of_membership: Some(Uint64::from(300_u32)),
+            ..UserFeeConfig::default()
+        }
+    );
+    assert_eq!(user_config.pending_fee_change, None);
+    assert_eq!(
+        query_cost_to_reply_in_thread(
+            &app,
+            &cw_thread_contract_addr,
+            user_2_id,
+            user_1_id,
+            user_1_id,
+            Uint64::from(5_u8),
+        )
+        .reply_to_membership_issuer_fee,
+        Uint128::from(15_150_u32)
+    );
+
+    // Issuer never set its ask fee so it follows a later change of the default
+    assert_eq!(
+        query_cost_to_ask_in_thread(&app, &cw_thread_contract_addr, user_2_id, user_1_id)
+            .ask_to_membership_issuer_fee,
+        Uint128::from(25_250_u32)
+    );
+    update_config(
+        &mut app,
+        &cw_thread_contract_addr,
+        &admin_addr,
+        UpdateConfigMsg {
+            default_ask_fee_bps_of_membership: Some(Uint64::from(1_000_u32)),
+            default_reply_fee_bps_of_membership: Some(Uint64::from(1_000_u32)),
+            ..UpdateConfigMsg::default()
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        query_cost_to_ask_in_thread(&app, &cw_thread_contract_addr, user_2_id, user_1_id)
+            .ask_to_membership_issuer_fee,
+        Uint128::from(50_500_u32)
+    );
+    assert_eq!(
+        query_cost_to_reply_in_thread(
+            &app,
+            &cw_thread_contract_addr,
+            user_2_id,
+            user_1_id,
+            user_1_id,
+            Uint64::from(5_u8),
+        )
+        .reply_to_membership_issuer_fee,
+        Uint128::from(15_150_u32)
+    );
+}
+
+fn query_cost_to_ask_in_thread(
+    app: &App,
+    cw_thread_contract_addr: &Addr,
+    asker_user_id: Uint64,
+    ask_to_user_id: Uint64,
+) -> CostToAskInThreadResponse {
+    app.wrap()
+        .query_wasm_smart(
+            cw_thread_contract_addr,
+            &QueryMsg::QueryCostToAskInThread(QueryCostToAskInThreadMsg {
+                asker_user_id,
+                ask_to_user_id,
+                thread_creator_user_id: ask_to_user_id,
+                content_len: Uint64::from(5_u8),
+            }),
+        )
+        .unwrap()
+}
@@ -0,0 +1,95 @@
+use cosmwasm_std::Uint64;
+use cw_multi_test::Executor;
+
+use shared_pkg::fee_range::BpsRange;
+use thread_pkg::{
+    config::UserFeeRanges,
+    msg::{ExecuteMsg, UpdateConfigMsg, UpdateUserConfigMsg},
+    user_config::UserFeeConfig,
+};
+
+use crate::helpers::{
+    proper_instantiate, query_user_config, register_user_and_enable_membership, update_config,
+    SOCIAL_MEDIA_HANDLE_1,
+};
+
+#[test]
+fn test_pending_issuer_fee_change_out_of_tightened_range_is_dropped() {
+    let (
+        mut app,
+        cw_thread_contract_addr,
+        cw_member_contract_addr,
+        admin_addr,
+        registration_admin_addr,
+        _,
+        user_1_addr,
+        _,
+    ) = proper_instantiate();
+    let user_1_id = Uint64::one();
+
+    register_user_and_enable_membership(
+        &mut app,
+        &cw_member_contract_addr,
+        &registration_admin_addr,
+        &user_1_addr,
+        user_1_id,
+        SOCIAL_MEDIA_HANDLE_1,
+    );
+
+    // Issuer queues a higher ask fee and reply fee while they are still in range
+    app.execute_contract(
+        user_1_addr.clone(),
+        cw_thread_contract_addr.clone(),
+        &ExecuteMsg::UpdateUserConfig(UpdateUserConfigMsg {
+            user_id: user_1_id,
+            ask_fee_bps_of_membership: Some(Uint64::from(5_000_u32)),
+            ask_fee_to_thread_creator_bps_of_membership: None,
+            reply_fee_bps_of_membership: Some(Uint64::from(500_u32)),
+            reply_fee_to_thread_creator_bps_of_membership: None,
+            share_to_issuer_bps: None,
+            share_to_all_members_bps: None,
+        }),
+        &[],
+    )
+    .unwrap();
+
+    // Admin tightens ask fee range before the fee change takes effect
+    update_config(
+        &mut app,
+        &cw_thread_contract_addr,
+        &admin_addr,
+        UpdateConfigMsg {
+            user_fee_ranges: Some(UserFeeRanges {
+                ask_fee_bps_of_membership: BpsRange {
+                    min: Uint64::zero(),
+                    max: Uint64::from(1_000_u32),
+                },
+                ..UserFeeRanges::default()
+            }),
+            ..UpdateConfigMsg::default()
+        },
+    )
+    .unwrap();
+
+    // Only the fee still in range is pending and takes effect
+    let user_config = query_user_config(&app, &cw_thread_contract_addr, user_1_id);
+    let pending_fee_change = user_config.pending_fee_change.unwrap();
+    assert_eq!(pending_fee_change.ask_fee_bps_of_membership, None);
+    assert_eq!(
+        pending_fee_change.reply_fee_bps_of_membership,
+        Some(Uint64::from(500_u32))
+    );
+
+    app.update_block(|block| {
+        block.time = block.time.plus_seconds(86_400);
+    });
+    let user_config = query_user_config(&app, &cw_thread_contract_addr, user_1_id);
+    assert_eq!(
+        user_config.user_config.fee_config,
+        UserFeeConfig {
+            reply_fee_bps_of_membership: Some(Uint64::from(500_u32)),
+            ..UserFeeConfig::default()
+        }
+    );
+    assert_eq!(user_config.pending_fee_change, None);
+}
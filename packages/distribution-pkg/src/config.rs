@@ -1,9 +1,34 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::Addr;
+use cw_utils::Duration;
+
+// Each operation can be paused independently on top of the contract wide enabled switch
+#[cw_serde]
+#[derive(Default)]
+pub struct PauseConfig {
+    // Membership trading and paid thread actions keep distributing fees to members while paused
+    // Fees are held by the contract, once distribute is unpaused anyone can release them to members' rewards
+    pub distribute_paused: bool,
+    pub claim_reward_paused: bool,
+}
+
+impl PauseConfig {
+    // Names of all paused operations
+    pub fn paused_operations(&self) -> Vec<String> {
+        [
+            ("distribute", self.distribute_paused),
+            ("claim_reward", self.claim_reward_paused),
+        ]
+        .iter()
+        .filter(|(_, paused)| *paused)
+        .map(|(operation, _)| operation.to_string())
+        .collect()
+    }
+}
 
 #[cw_serde]
 pub struct Config {
-    // Contract admin, able to upgrade contract
+    // Contract admin, able to upgrade contract, always holds admin role
     pub admin_addr: Addr,
     // Membership contract address, membership contract stores all user infos
     // Thread contract can be seen as an extension of membership contract that provides thread functionality
@@ -12,9 +37,11 @@ pub struct Config {
     pub member_contract_addr: Addr,
     // Enable or disable all user posting thread / ask / reply / answer
     pub enabled: bool,
-    // List of addresses that are allowed to call distribute function
-    // Currently it should only be membership contract and thread contract
-    pub distribute_caller_allowlist: Vec<Addr>,
+    // Pause individual operations, only matters when contract is enabled
+    pub pause_config: PauseConfig,
+    // Delay before changes to member contract and the delay itself take effect
+    // None means config changes take effect right away
+    pub config_change_timelock: Option<Duration>,
     // /// Minimal weight that the member must have to be eligible for member fee distributions
     // pub minimum_eligible_weight: Uint128,
 }
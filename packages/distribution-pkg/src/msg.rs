@@ -1,7 +1,16 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Decimal, Uint128, Uint64};
-
-use crate::config::Config;
+use cosmwasm_std::{Coin, Decimal, Uint128, Uint64};
+use cw_utils::Duration;
+use shared_pkg::{
+    admin_transfer::{AcceptAdminMsg, AdminProposal, CancelAdminProposalMsg, ProposeNewAdminMsg},
+    rbac::{GrantRoleMsg, QueryRolesMsg, RevokeRoleMsg, RolesResponse},
+    timelock::{
+        CancelPendingConfigChangeMsg, ExecutePendingConfigChangeMsg, PendingConfigChange,
+        QueryPendingConfigChangesMsg,
+    },
+};
+
+use crate::config::{Config, PauseConfig};
 
 // ========== instantiate ==========
 
@@ -19,9 +28,24 @@ pub struct InstantiateMsg {
 pub enum ExecuteMsg {
     Enable(EnableMsg),
     Disable(DisableMsg),
+    // When config change time-lock is set, all changes are queued as a pending config change
     UpdateConfig(UpdateConfigMsg),
-    AddToDistributeCallerAllowlist(AddToDistributeCallerAllowlistMsg),
-    RemoveFromDistributeCallerAllowlist(RemoveFromDistributeCallerAllowlistMsg),
+    // Only admin can execute a pending config change once its time-lock has passed
+    ExecutePendingConfigChange(ExecutePendingConfigChangeMsg),
+    // Only admin can cancel a pending config change
+    CancelPendingConfigChange(CancelPendingConfigChangeMsg),
+    // Only admin can propose a new admin, replacing any previous proposal
+    ProposeNewAdmin(ProposeNewAdminMsg),
+    // Only the proposed address can accept before the proposal expires
+    AcceptAdmin(AcceptAdminMsg),
+    // Only admin can cancel the pending admin proposal
+    CancelAdminProposal(CancelAdminProposalMsg),
+    // Only admin can grant a role to an address, a role can be held by multiple addresses
+    GrantRole(GrantRoleMsg),
+    // Only admin can revoke a role from an address, admin in config cannot lose its role this way
+    RevokeRole(RevokeRoleMsg),
+    // Only admin or pauser can pause or unpause individual operations
+    UpdatePauseConfig(UpdatePauseConfigMsg),
 
     // Called only by membership contract when an user enabled its membership program
     // i.e. when EnabledMembershipMsg is call in membership contract
@@ -34,10 +58,16 @@ pub enum ExecuteMsg {
     // Called by membership contract when user buys / sells membership
     UpdateUserPendingReward(UpdateUserPendingRewardMsg),
 
-    // Called by membership contract or thread contract whenever there's a pay out to all members
+    // Anyone can call this once distribute is unpaused, releases fees held while paused to members' rewards
+    // Releases up to limit memberships at a time, call again until nothing is released
+    ReleasePausedDistributions(ReleasePausedDistributionsMsg),
+
+    // Called by addresses with distributor role, i.e. membership contract and thread contract, whenever there's a pay out to all members
+    // Denom is taken from the funds attached, it must be the membership's fee denom
     Distribute(DistributeMsg),
 
-    // Anyone can call this to claim reward for a user
+    // Anyone can call this to claim reward for a user, e.g. user's hot address
+    // Reward is always sent to user's cold address, in all denoms the membership has distributed
     // TODO: P1: use warp job to do it so users don't have to call it manually
     // TODO: P0: add batch claim rewards that claim many members of same membership issuer
     ClaimReward(ClaimRewardsMsg),
@@ -49,20 +79,25 @@ pub struct EnableMsg {}
 #[cw_serde]
 pub struct DisableMsg {}
 
+// Admin is replaced with ProposeNewAdmin and AcceptAdmin so a typo cannot lock the contract
 #[cw_serde]
+#[derive(Default)]
 pub struct UpdateConfigMsg {
-    pub admin_addr: Option<String>,
     pub member_contract_addr: Option<String>,
+    // Set to 0 blocks or 0 seconds to remove the time-lock
+    pub config_change_timelock: Option<Duration>,
 }
 
 #[cw_serde]
-pub struct AddToDistributeCallerAllowlistMsg {
-    pub added_addr: String,
+pub struct UpdatePauseConfigMsg {
+    pub distribute_paused: Option<bool>,
+    pub claim_reward_paused: Option<bool>,
 }
 
 #[cw_serde]
-pub struct RemoveFromDistributeCallerAllowlistMsg {
-    pub remove_addr: String,
+pub struct ReleasePausedDistributionsMsg {
+    // Default to 10, max 30
+    pub limit: Option<u32>,
 }
 
 #[cw_serde]
@@ -75,6 +110,8 @@ pub struct UpdateUserPendingRewardMsg {
 #[cw_serde]
 pub struct SetupDistributionForNewMembershipMsg {
     pub membership_issuer_user_id: Uint64,
+    // Denom membership issuer picked, all member fees of the membership are distributed in this denom
+    pub fee_denom: String,
 }
 
 #[cw_serde]
@@ -95,6 +132,11 @@ pub struct ClaimRewardsMsg {
     pub user_id: Uint64,
 }
 
+// ========== migrate ==========
+
+#[cw_serde]
+pub struct MigrateMsg {}
+
 // ========== query ==========
 
 #[derive(QueryResponses)]
@@ -102,6 +144,15 @@ pub struct ClaimRewardsMsg {
 pub enum QueryMsg {
     #[returns(ConfigResponse)]
     QueryConfig(QueryConfigMsg),
+    // Returns which operations are paused
+    #[returns(PauseStatusResponse)]
+    QueryPauseStatus(QueryPauseStatusMsg),
+    // Returns roles granted to an address
+    #[returns(RolesResponse)]
+    QueryRoles(QueryRolesMsg),
+    // Returns pending admin proposal and config changes waiting for the time-lock
+    #[returns(PendingConfigChangesResponse)]
+    QueryPendingConfigChanges(QueryPendingConfigChangesMsg),
     #[returns(UserRewardResponse)]
     QueryUserReward(QueryUserRewardMsg),
     // TODO: P0: pagination query all users reward
@@ -115,6 +166,26 @@ pub struct ConfigResponse {
     pub config: Config,
 }
 
+#[cw_serde]
+pub struct QueryPauseStatusMsg {}
+
+#[cw_serde]
+pub struct PauseStatusResponse {
+    // When contract is disabled all user facing operations are unavailable regardless of pause config
+    pub enabled: bool,
+    pub pause_config: PauseConfig,
+    // Names of paused operations, e.g. claim_reward
+    pub paused_operations: Vec<String>,
+}
+
+#[cw_serde]
+pub struct PendingConfigChangesResponse {
+    pub pending_admin_proposal: Option<AdminProposal>,
+    pub config_change_timelock: Option<Duration>,
+    // Sorted by ID, i.e. in the order they were proposed
+    pub pending_config_changes: Vec<PendingConfigChange<UpdateConfigMsg>>,
+}
+
 #[cw_serde]
 pub struct QueryUserRewardMsg {
     pub membership_issuer_user_id: Uint64,
@@ -123,5 +194,6 @@ pub struct QueryUserRewardMsg {
 
 #[cw_serde]
 pub struct UserRewardResponse {
-    pub amount: Uint128,
+    // Reward in each denom membership has distributed, including zero amounts
+    pub rewards: Vec<Coin>,
 }
@@ -0,0 +1,26 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{to_vec, StdResult, Timestamp, Uint64};
+
+// Payload registration admin signs off chain so user can link social media without waiting for an admin tx
+// Signature is secp256k1 over sha256 of sign_bytes, verified against attestation pubkey in config
+#[cw_serde]
+pub struct SocialMediaAttestation {
+    // Chain and member contract the attestation is for, so it cannot be replayed on another deployment
+    pub chain_id: String,
+    pub contract_addr: String,
+    pub user_id: Uint64,
+    pub platform: String,
+    pub social_media_handle: String,
+    // If true then user can also enable its membership in the same tx
+    pub enable_membership: bool,
+    pub expires_at: Timestamp,
+    // Each nonce can only be used once across all users
+    pub nonce: Uint64,
+}
+
+impl SocialMediaAttestation {
+    // JSON of the attestation with fields in the order above, e.g. {"chain_id":"phoenix-1",...}
+    pub fn sign_bytes(&self) -> StdResult<Vec<u8>> {
+        to_vec(self)
+    }
+}
@@ -1,24 +1,79 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint64};
+use cosmwasm_std::{Addr, Binary, Uint128, Uint64};
+use cw_utils::Duration;
 
-use shared_pkg::fee_share_config::FeeShareConfig;
+use shared_pkg::{fee_range::BpsRange, fee_share_config::FeeShareConfig, pricing::BondingCurve};
+
+use crate::trade::CandleInterval;
 
 #[cw_serde]
 pub struct ProtocolFeeConfig {
-    pub membership_trading_fee_percentage: Uint64,
+    pub membership_trading_fee_bps: Uint64,
+    // Bps of protocol fee that goes to the referrer of the trader
+    // If trader has no referrer then it stays with protocol fee collector
+    pub referral_fee_bps: Uint64,
 }
 
 #[cw_serde]
 pub struct FeeConfig {
-    // Denom of fee, e.g. uluna, once set cannot change
+    // Denom of fee, e.g. uluna
+    // In config this is the default fee denom, memberships can pick another denom from fee denom allowlist
     pub fee_denom: String,
-    // Default membership trading fee in my 1 membership price percentage
-    pub trading_fee_percentage_of_membership: Uint64,
+    // Default membership trading fee in bps of my 1 membership price
+    pub trading_fee_bps_of_membership: Uint64,
+    // Max amount of the membership a single holder can own, None means no cap
+    // Prevents one holder from buying up a large share of a small membership supply
+    pub holding_cap: Option<Uint128>,
+}
+
+// Ranges membership issuer can set its own fees in, checked when issuer updates its fees
+#[cw_serde]
+#[derive(Default)]
+pub struct UserFeeRanges {
+    pub trading_fee_bps_of_membership: BpsRange,
+}
+
+// Each operation can be paused independently on top of the contract wide enabled switch
+// e.g. during an incident admin can pause buying while still letting people sell
+#[cw_serde]
+#[derive(Default)]
+pub struct PauseConfig {
+    pub registration_paused: bool,
+    pub buy_membership_paused: bool,
+    pub sell_membership_paused: bool,
+}
+
+impl PauseConfig {
+    // Names of all paused operations
+    pub fn paused_operations(&self) -> Vec<String> {
+        [
+            ("registration", self.registration_paused),
+            ("buy_membership", self.buy_membership_paused),
+            ("sell_membership", self.sell_membership_paused),
+        ]
+        .iter()
+        .filter(|(_, paused)| *paused)
+        .map(|(operation, _)| operation.to_string())
+        .collect()
+    }
+}
+
+// Which social media links make a user eligible to enable membership
+#[cw_serde]
+#[derive(Default)]
+pub enum MembershipEligibility {
+    // User has linked any platform in the allowlist
+    #[default]
+    AnyLinkedPlatform,
+    // User has linked this platform
+    SpecificPlatform {
+        platform: String,
+    },
 }
 
 #[cw_serde]
 pub struct Config {
-    // Contract admin, able to upgrade contract
+    // Contract admin, able to upgrade contract, always holds admin role
     pub admin_addr: Addr,
     // Distribution contract address, used to distribute fees to all members
     // This should be set right after distribution contract is deployed
@@ -29,15 +84,43 @@ pub struct Config {
     pub enabled: bool,
     // If true then anyone can sign up, but link social media and register membership still needs registration admin
     // If false then only registration admin can sign up for new users
-    // TODO: P1: add new referral
-    // TODO: P1: support transfer ownership
     // TODO: P0: separate membership and thread to 2 contracts
     pub enable_open_registration: bool,
-    // Registration admin, able to register membership for existing users
+    // Pause individual operations, only matters when contract is enabled
+    pub pause_config: PauseConfig,
+    // Registration admin, always holds registration operator role
     pub registration_admin_addr: Addr,
+    // Secp256k1 pubkey of registration admin's attestation key, compressed or uncompressed
+    // If set then users can link social media themselves with an attestation signed by this key
+    pub attestation_pubkey: Option<Binary>,
     // Protocol fee collector, collects protocol fee
     pub protocol_fee_collector_addr: Addr,
     pub protocol_fee_config: ProtocolFeeConfig,
     pub default_fee_config: FeeConfig,
     pub default_fee_share_config: FeeShareConfig,
+    // Bonding curve used by membership issuer that doesn't pick one when membership is enabled
+    pub default_bonding_curve: BondingCurve,
+    // Sigmoid curve has no closed form, its price is summed one membership at a time
+    // so amount bought or sold in one trade is capped to keep gas bounded
+    pub sigmoid_max_trade_amount: Uint128,
+    // Denoms membership issuer can pick as its membership fee denom, always contains the default fee denom
+    pub fee_denom_allowlist: Vec<String>,
+    // If true then only verified users, i.e. users with linked social media, can buy any membership
+    // Membership issuer can also turn this on for its own membership only
+    pub only_verified_buyers: bool,
+    // Platforms registration admin can link for users, e.g. twitter, github
+    pub social_media_platform_allowlist: Vec<String>,
+    // Social media links required before registration admin can enable membership for a user
+    pub membership_eligibility: MembershipEligibility,
+    // Interval of price candles recorded on every trade
+    // Candles recorded with previous interval are kept when admin changes it
+    pub price_candle_interval: CandleInterval,
+    // Delay before changes to fee collector, distribution contract, fees, time-locks and the delay itself take effect
+    // None means config changes take effect right away
+    pub config_change_timelock: Option<Duration>,
+    // Delay before membership issuer's own fee changes take effect, protects holders from sudden fee hikes
+    // None means issuer fee changes take effect right away
+    pub user_fee_change_timelock: Option<Duration>,
+    // Default fees must also be in these ranges
+    pub user_fee_ranges: UserFeeRanges,
 }
@@ -1,4 +1,6 @@
+pub mod attestation;
 pub mod config;
 pub mod member_contract_querier;
 pub mod msg;
+pub mod trade;
 pub mod user;
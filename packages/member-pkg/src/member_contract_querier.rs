@@ -1,84 +1,101 @@
-use cosmwasm_std::{Addr, Deps, Uint128, Uint64};
+use std::slice;
+
+use cosmwasm_schema::serde::de::DeserializeOwned;
+use cosmwasm_std::{Addr, QuerierWrapper, StdError, StdResult, Uint128, Uint64};
 
 use crate::{
     config::Config,
     msg::{
         ConfigResponse, IsMemberResponse, MembershipSupplyResponse, QueryConfigMsg,
-        QueryIsMemberMsg, QueryMembershipSupplyMsg, QueryMsg, QueryUserByAddrMsg, QueryUserByIDMsg,
-        UserResponse,
+        QueryIsMemberMsg, QueryMembershipSupplyMsg, QueryMsg, QueryUsersByAddrsMsg,
+        QueryUsersByIDsMsg, UsersByAddrsResponse, UsersByIDsResponse,
     },
-    user::User,
+    user::{MembershipIssuedByMe, User},
 };
 
-pub fn query_member_contract_config(deps: Deps, member_contract_addr: Addr) -> Config {
-    let resp: ConfigResponse = deps
-        .querier
-        .query_wasm_smart(
-            member_contract_addr,
-            &QueryMsg::QueryConfig(QueryConfigMsg {}),
-        )
-        .unwrap();
-    resp.config
+// Queries member contract for contracts built on top of it, e.g. thread and distribution
+// Every method returns an error instead of panicking so callers can turn it into their own error
+// Lookups return None when user doesn't exist so callers can tell it apart from a failed query
+pub struct MemberContractQuerier<'a> {
+    querier: QuerierWrapper<'a>,
+    member_contract_addr: Addr,
 }
 
-pub fn query_user_by_id(deps: Deps, member_contract_addr: Addr, user_id: u64) -> User {
-    let resp: UserResponse = deps
-        .querier
-        .query_wasm_smart(
+impl<'a> MemberContractQuerier<'a> {
+    pub fn new(querier: QuerierWrapper<'a>, member_contract_addr: Addr) -> Self {
+        Self {
+            querier,
             member_contract_addr,
-            &QueryMsg::QueryUserByID(QueryUserByIDMsg {
-                user_id: Uint64::from(user_id),
-            }),
-        )
-        .unwrap();
-    resp.user
-}
+        }
+    }
 
-pub fn query_user_by_addr(deps: Deps, member_contract_addr: Addr, user_addr: Addr) -> User {
-    let resp: UserResponse = deps
-        .querier
-        .query_wasm_smart(
-            member_contract_addr,
-            &QueryMsg::QueryUserByAddr(QueryUserByAddrMsg {
-                user_addr: user_addr.to_string(),
-            }),
-        )
-        .unwrap();
-    resp.user
-}
+    fn query<T: DeserializeOwned>(&self, msg: &QueryMsg) -> StdResult<T> {
+        self.querier
+            .query_wasm_smart(self.member_contract_addr.clone(), msg)
+    }
 
-pub fn query_is_user_a_member_and_membership_amount(
-    deps: Deps,
-    member_contract_addr: Addr,
-    membership_issuer_user_id: u64,
-    user_id: u64,
-) -> (bool, Uint128) {
-    let resp: IsMemberResponse = deps
-        .querier
-        .query_wasm_smart(
-            member_contract_addr,
-            &QueryMsg::QueryIsMember(QueryIsMemberMsg {
-                membership_issuer_user_id: Uint64::from(membership_issuer_user_id),
-                user_id: Uint64::from(user_id),
-            }),
-        )
-        .unwrap();
-    (resp.is_member, resp.amount)
-}
+    pub fn config(&self) -> StdResult<Config> {
+        let resp: ConfigResponse = self.query(&QueryMsg::QueryConfig(QueryConfigMsg {}))?;
+        Ok(resp.config)
+    }
 
-pub fn query_membership_supply(
-    deps: Deps,
-    member_contract_addr: Addr,
-    membership_issuer_user_id: u64,
-) -> Uint128 {
-    let resp: MembershipSupplyResponse = deps
-        .querier
-        .query_wasm_smart(
-            member_contract_addr,
-            &QueryMsg::QueryMembershipSupply(QueryMembershipSupplyMsg {
+    pub fn user_by_id(&self, user_id: u64) -> StdResult<Option<User>> {
+        Ok(self.users_by_ids(&[user_id])?.pop().flatten())
+    }
+
+    // Errors if user doesn't exist or hasn't enabled membership
+    pub fn membership_issued_by(&self, user_id: u64) -> StdResult<MembershipIssuedByMe> {
+        self.user_by_id(user_id)?
+            .ok_or_else(|| StdError::not_found("User"))?
+            .membership_issued_by_me
+            .ok_or_else(|| StdError::generic_err("Membership not enabled"))
+    }
+
+    // Users in the same order as IDs, at most 25 IDs per call
+    pub fn users_by_ids(&self, user_ids: &[u64]) -> StdResult<Vec<Option<User>>> {
+        let resp: UsersByIDsResponse =
+            self.query(&QueryMsg::QueryUsersByIDs(QueryUsersByIDsMsg {
+                user_ids: user_ids.iter().map(|id| Uint64::from(*id)).collect(),
+            }))?;
+        Ok(resp.users)
+    }
+
+    // user_addr can be either user's cold address or hot address
+    pub fn user_by_addr(&self, user_addr: &Addr) -> StdResult<Option<User>> {
+        Ok(self
+            .users_by_addrs(slice::from_ref(user_addr))?
+            .pop()
+            .flatten())
+    }
+
+    // Users in the same order as addresses, at most 25 addresses per call
+    pub fn users_by_addrs(&self, user_addrs: &[Addr]) -> StdResult<Vec<Option<User>>> {
+        let resp: UsersByAddrsResponse =
+            self.query(&QueryMsg::QueryUsersByAddrs(QueryUsersByAddrsMsg {
+                user_addrs: user_addrs.iter().map(|addr| addr.to_string()).collect(),
+            }))?;
+        Ok(resp.users)
+    }
+
+    // Returns whether user holds issuer's membership and how many it holds
+    pub fn is_member_and_membership_amount(
+        &self,
+        membership_issuer_user_id: u64,
+        user_id: u64,
+    ) -> StdResult<(bool, Uint128)> {
+        let resp: IsMemberResponse = self.query(&QueryMsg::QueryIsMember(QueryIsMemberMsg {
+            membership_issuer_user_id: Uint64::from(membership_issuer_user_id),
+            user_id: Uint64::from(user_id),
+        }))?;
+        Ok((resp.is_member, resp.amount))
+    }
+
+    // Errors if issuer doesn't exist or hasn't enabled membership
+    pub fn membership_supply(&self, membership_issuer_user_id: u64) -> StdResult<Uint128> {
+        let resp: MembershipSupplyResponse =
+            self.query(&QueryMsg::QueryMembershipSupply(QueryMembershipSupplyMsg {
                 membership_issuer_user_id: Uint64::from(membership_issuer_user_id),
-            }),
-        )
-        .unwrap();
-    resp.supply
+            }))?;
+        Ok(resp.supply)
+    }
 }
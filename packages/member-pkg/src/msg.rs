@@ -1,9 +1,21 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Uint128, Uint64};
+use cosmwasm_std::{Addr, Binary, Uint128, Uint64};
+use cw_utils::Duration;
+use shared_pkg::{
+    admin_transfer::{AcceptAdminMsg, AdminProposal, CancelAdminProposalMsg, ProposeNewAdminMsg},
+    pricing::BondingCurve,
+    rbac::{GrantRoleMsg, QueryRolesMsg, RevokeRoleMsg, RolesResponse},
+    timelock::{
+        CancelPendingConfigChangeMsg, ExecutePendingConfigChangeMsg, PendingConfigChange,
+        QueryPendingConfigChangesMsg,
+    },
+};
 
 use crate::{
-    config::Config,
-    user::{Member, Membership, User},
+    attestation::SocialMediaAttestation,
+    config::{Config, MembershipEligibility, PauseConfig, UserFeeRanges},
+    trade::{Candle, CandleInterval, Trade},
+    user::{Member, Membership, PendingUserFeeChange, Referee, User},
 };
 
 // TODO: P0: add a proxy contract that can charge custom fee so people can build tailored frontend
@@ -19,21 +31,43 @@ pub struct InstantiateMsg {
     // Default to sender
     pub protocol_fee_collector_addr: Option<String>,
     // Default to uluna
-    // TODO: P1: use noble USDC?
+    // This is the default fee denom and is always in the fee denom allowlist
     pub fee_denom: Option<String>,
 
-    // Protocol fee percentage for membership trading
-    pub protocol_fee_membership_trading_fee_percentage: Option<Uint64>,
+    // Protocol fee in bps for membership trading
+    pub protocol_fee_membership_trading_fee_bps: Option<Uint64>,
+    // Bps of protocol fee that goes to trader's referrer, default to 0
+    pub protocol_fee_referral_fee_bps: Option<Uint64>,
+
+    // Default membership trading fee in bps of my 1 membership price
+    pub default_trading_fee_bps_of_membership: Option<Uint64>,
+
+    // Default membership trading fee to membership issuer fee in bps
+    pub default_membership_trading_fee_membership_issuer_fee_bps: Option<Uint64>,
+    // Default membership trading fee to membership holder fee in bps
+    pub default_membership_trading_fee_membership_holder_fee_bps: Option<Uint64>,
+
+    // Default bonding curve for membership issuer that doesn't pick one, default to quadratic curve
+    pub default_bonding_curve: Option<BondingCurve>,
+
+    // Max amount of a sigmoid curve membership bought or sold in one trade, default to 1_000
+    pub sigmoid_max_trade_amount: Option<Uint128>,
+
+    // Default max amount of a membership a single holder can own, default to no cap
+    pub default_holding_cap: Option<Uint128>,
 
-    // Default membership trading fee in my 1 membership price percentage
-    pub default_trading_fee_percentage_of_membership: Option<Uint64>,
+    // Only allow users with linked social media to buy membership, default to false
+    pub only_verified_buyers: Option<bool>,
 
-    // Default membership trading fee to membership issuer fee percentage
-    pub default_membership_trading_fee_membership_issuer_fee_percentage: Option<Uint64>,
-    // Default membership trading fee to membership holder fee percentage
-    pub default_membership_trading_fee_membership_holder_fee_percentage: Option<Uint64>,
-    // TODO: P0: add new default param on how many membership each member can own
-    // TODO: P0: add new default param on whether only allow verified user to buy membership
+    // Interval of price candles, default to hourly
+    pub price_candle_interval: Option<CandleInterval>,
+
+    // Delay before membership issuer's fee changes take effect, default to 1 day
+    // Set to 0 blocks or 0 seconds to let issuer fee changes take effect right away
+    pub user_fee_change_timelock: Option<Duration>,
+
+    // Ranges membership issuer can set its own fees in, default to 0% to 100%
+    pub user_fee_ranges: Option<UserFeeRanges>,
     // TODO: P1: setup fee grant to cover onboarding fee, enough to register, post and ask
 }
 
@@ -47,25 +81,90 @@ pub enum ExecuteMsg {
     EnableOpenRegistration(EnableOpenRegistrationMsg),
     DisableOpenRegistration(DisableOpenRegistrationMsg),
 
+    // Admin can update everything, fee manager can update fees only
+    // When config change time-lock is set, changes to fee collector, distribution contract, fees
+    // and the time-lock itself are queued as a pending config change, other changes take effect right away
     UpdateConfig(UpdateConfigMsg),
 
-    // Anyone can register an account
+    // Only admin or fee manager can execute a pending config change once its time-lock has passed
+    ExecutePendingConfigChange(ExecutePendingConfigChangeMsg),
+
+    // Only admin or fee manager can cancel a pending config change
+    CancelPendingConfigChange(CancelPendingConfigChangeMsg),
+
+    // Only admin can propose a new admin, replacing any previous proposal
+    ProposeNewAdmin(ProposeNewAdminMsg),
+
+    // Only the proposed address can accept before the proposal expires
+    // Admin role moves from the current admin to the new admin
+    AcceptAdmin(AcceptAdminMsg),
+
+    // Only admin can cancel the pending admin proposal
+    CancelAdminProposal(CancelAdminProposalMsg),
+
+    // Only admin can grant a role to an address, a role can be held by multiple addresses
+    GrantRole(GrantRoleMsg),
+
+    // Only admin can revoke a role from an address
+    // Admin and registration admin in config cannot lose their role this way, update config to replace them
+    RevokeRole(RevokeRoleMsg),
+
+    // Only admin or pauser can pause or unpause individual operations
+    UpdatePauseConfig(UpdatePauseConfigMsg),
+
+    // Only admin or fee manager can add a denom that membership issuers can pick as their membership fee denom
+    AddToFeeDenomAllowlist(AddToFeeDenomAllowlistMsg),
+
+    // Only admin or fee manager can remove a denom from fee denom allowlist
+    // Memberships already enabled with the denom keep using it
+    RemoveFromFeeDenomAllowlist(RemoveFromFeeDenomAllowlistMsg),
+
+    // Anyone can register an account, optionally with a referrer
     // But without registering a membership they can only buy and sell other people's memberships but not issue their own memberships
+    // When open registration is disabled, only registration operator can register on behalf of an address
     Register(RegisterMsg),
 
-    // Only register admin can link social media for user
+    // Only admin can add a platform that registration operator can link for users
+    AddToSocialMediaPlatformAllowlist(AddToSocialMediaPlatformAllowlistMsg),
+
+    // Only admin can remove a platform from social media platform allowlist
+    // Social links already linked on the platform are kept
+    RemoveFromSocialMediaPlatformAllowlist(RemoveFromSocialMediaPlatformAllowlistMsg),
+
+    // Only registration operator can link social media for user, 1 handle per platform
     LinkSocialMedia(LinkSocialMediaMsg),
 
-    // Only register admin can register membership for user
+    // Only the attested user can link social media with an attestation signed by registration admin's attestation key
+    // Optionally enables membership too if the attestation allows it
+    LinkSocialMediaWithAttestation(LinkSocialMediaWithAttestationMsg),
+
+    // Registration operator, moderator or user itself can unlink social media of a platform
+    // Membership already enabled stays enabled
+    UnlinkSocialMedia(UnlinkSocialMediaMsg),
+
+    // Only registration operator can register membership for user
     // User must link social media first to be eligible for membership registration to prevent impersonation
     // This will initialize the user's membership and set the supply to 1 owned by the user
     // After that anyone can buy / sell user's membership
     EnableMembership(EnableMembershipMsg),
 
-    // TODO: P0: support transferring user ownership to another address
-    // MigrateUser(MigrateUserMsg),
+    // Only user itself can propose to migrate its user to a new address
+    // User ID stays the same so memberships, threads and rewards all move along with the user
+    MigrateUser(MigrateUserMsg),
+
+    // Only the new address proposed in MigrateUser can accept the migration
+    // Migration completes after this, old address is no longer associated with the user
+    AcceptMigrateUser(AcceptMigrateUserMsg),
+
+    // Only user's cold address can set its hot address
+    // Hot address can post in thread contract and claim rewards, but cannot trade memberships or update fee config
+    // Setting a new hot address replaces the previous one
+    SetHotAddr(SetHotAddrMsg),
 
-    // // Only membership issuer can update its membership trading fee percentage
+    // Only user's cold address can remove its hot address
+    RemoveHotAddr(RemoveHotAddrMsg),
+
+    // // Only membership issuer can update its membership trading fee in bps
     // UpdateTradingFeePercentageOfMembership(UpdateTradingFeePercentageOfMembershipMsg),
 
     // // Only membership issuer can update its membership trading fee config
@@ -77,8 +176,6 @@ pub enum ExecuteMsg {
 
     // Anyone can sell membership if they have it
     SellMembership(SellMembershipMsg),
-    // TODO: P1: add new default param on how much membership each holder can own, is this a good idea? hard to prevent bot as long as buy / sell membership is permissionless
-    // TODO: P0: add new default param on whether only allow verified user to buy membership
     // TODO: P1: setup fee grant to cover onboarding fee, enough to register, post and ask
 }
 
@@ -95,53 +192,169 @@ pub struct EnableOpenRegistrationMsg {}
 pub struct DisableOpenRegistrationMsg {}
 
 #[cw_serde]
+pub struct UpdatePauseConfigMsg {
+    pub registration_paused: Option<bool>,
+    pub buy_membership_paused: Option<bool>,
+    pub sell_membership_paused: Option<bool>,
+}
+
+// Admin is replaced with ProposeNewAdmin and AcceptAdmin so a typo cannot lock the contract
+#[cw_serde]
+#[derive(Default)]
 pub struct UpdateConfigMsg {
-    pub admin_addr: Option<String>,
     pub distribution_contract_addr: Option<String>,
     pub registration_admin_addr: Option<String>,
     pub protocol_fee_collector_addr: Option<String>,
-    pub protocol_fee_membership_trading_fee_percentage: Option<Uint64>,
-    pub default_trading_fee_percentage_of_membership: Option<Uint64>,
-    pub default_share_to_issuer_percentage: Option<Uint64>,
-    pub default_share_to_all_members_percentage: Option<Uint64>,
+    pub protocol_fee_membership_trading_fee_bps: Option<Uint64>,
+    pub protocol_fee_referral_fee_bps: Option<Uint64>,
+    pub default_trading_fee_bps_of_membership: Option<Uint64>,
+    pub default_share_to_issuer_bps: Option<Uint64>,
+    pub default_share_to_all_members_bps: Option<Uint64>,
+    // Only applies to memberships enabled after the update
+    pub default_bonding_curve: Option<BondingCurve>,
+    pub sigmoid_max_trade_amount: Option<Uint128>,
+    // Set to 0 to remove the cap
+    pub default_holding_cap: Option<Uint128>,
+    pub only_verified_buyers: Option<bool>,
+    // Only applies to trades after the update
+    pub price_candle_interval: Option<CandleInterval>,
+    // Only applies to memberships enabled after the update
+    pub membership_eligibility: Option<MembershipEligibility>,
+    // Set to empty binary to disable attestation
+    pub attestation_pubkey: Option<Binary>,
+    // Set to 0 blocks or 0 seconds to remove the time-lock
+    pub config_change_timelock: Option<Duration>,
+    // Set to 0 blocks or 0 seconds to let issuer fee changes take effect right away
+    pub user_fee_change_timelock: Option<Duration>,
+    // Only applies to later issuer fee updates, fees issuers already set are kept
+    // Pending issuer fee changes outside the new ranges are dropped instead of taking effect
+    pub user_fee_ranges: Option<UserFeeRanges>,
+}
+
+#[cw_serde]
+pub struct AddToFeeDenomAllowlistMsg {
+    pub denom: String,
 }
 
 #[cw_serde]
-pub struct RegisterMsg {}
+pub struct RemoveFromFeeDenomAllowlistMsg {
+    pub denom: String,
+}
+
+#[cw_serde]
+pub struct AddToSocialMediaPlatformAllowlistMsg {
+    pub platform: String,
+}
+
+#[cw_serde]
+pub struct RemoveFromSocialMediaPlatformAllowlistMsg {
+    pub platform: String,
+}
+
+#[cw_serde]
+pub struct RegisterMsg {
+    // User ID of the referrer, must be an existing user
+    pub referrer_user_id: Option<Uint64>,
+    // Address to register, only registration operator can register on behalf of another address
+    // Default to sender
+    pub user_addr: Option<String>,
+}
 
 #[cw_serde]
 pub struct LinkSocialMediaMsg {
     pub user_id: Uint64,
+    // Must be in social media platform allowlist
+    pub platform: String,
     pub social_media_handle: String,
 }
 
+#[cw_serde]
+pub struct LinkSocialMediaWithAttestationMsg {
+    pub attestation: SocialMediaAttestation,
+    // 64 bytes secp256k1 signature (r, s) over sha256 of attestation sign bytes
+    pub signature: Binary,
+    // Only used when attestation enables membership, same as in EnableMembershipMsg
+    pub bonding_curve: Option<BondingCurve>,
+    pub fee_denom: Option<String>,
+}
+
+#[cw_serde]
+pub struct UnlinkSocialMediaMsg {
+    pub user_id: Uint64,
+    pub platform: String,
+}
+
 #[cw_serde]
 pub struct EnableMembershipMsg {
     pub user_id: Uint64,
+    // Bonding curve of the membership, default to config's default_bonding_curve
+    // Cannot change once membership is enabled
+    pub bonding_curve: Option<BondingCurve>,
+    // Denom used to buy / sell the membership and pay fees to the issuer and members, must be in fee denom allowlist
+    // Default to config's default fee denom, cannot change once membership is enabled
+    pub fee_denom: Option<String>,
 }
 
+#[cw_serde]
+pub struct MigrateUserMsg {
+    pub new_user_addr: String,
+}
+
+#[cw_serde]
+pub struct AcceptMigrateUserMsg {
+    pub user_id: Uint64,
+}
+
+#[cw_serde]
+pub struct SetHotAddrMsg {
+    pub hot_addr: String,
+}
+
+#[cw_serde]
+pub struct RemoveHotAddrMsg {}
+
 #[cw_serde]
 pub struct UpdateUserConfigMsg {
     pub user_id: Uint64,
-    pub trading_fee_percentage_of_membership: Option<Uint64>,
-    // Revenue share percentage for membership issuer
-    pub share_to_issuer_percentage: Option<Uint64>,
-    // Revenue share percentage for all members
-    pub share_to_all_members_percentage: Option<Uint64>,
+    // Fee changes wait for user fee change time-lock in config, other changes take effect right away
+    pub trading_fee_bps_of_membership: Option<Uint64>,
+    // Revenue share in bps for membership issuer
+    pub share_to_issuer_bps: Option<Uint64>,
+    // Revenue share in bps for all members
+    pub share_to_all_members_bps: Option<Uint64>,
+    // Max amount of the membership a single holder can own, overrides config's default holding cap
+    // Set to 0 to remove the cap
+    pub holding_cap: Option<Uint128>,
+    // Only allow users with linked social media to buy the membership
+    pub only_verified_buyers: Option<bool>,
 }
 
 #[cw_serde]
 pub struct BuyMembershipMsg {
     pub membership_issuer_user_id: Uint64,
     pub amount: Uint128,
+    // Slippage protection, buy fails if total needed from user exceeds this
+    // Price can move if other trades land before this one
+    pub max_total_cost: Option<Uint128>,
 }
 
 #[cw_serde]
 pub struct SellMembershipMsg {
     pub membership_issuer_user_id: Uint64,
     pub amount: Uint128,
+    // Slippage protection, sell fails if amount paid to seller is below this
+    // Price can move if other trades land before this one
+    pub min_proceeds: Option<Uint128>,
+    // If true, seller attaches no funds and fees are deducted from proceeds, seller receives net proceeds
+    // If false, seller attaches funds to cover fees and receives full price, default to false
+    pub deduct_fees_from_proceeds: Option<bool>,
 }
 
+// ========== migrate ==========
+
+#[cw_serde]
+pub struct MigrateMsg {}
+
 // ========== query ==========
 
 #[derive(QueryResponses)]
@@ -150,6 +363,23 @@ pub enum QueryMsg {
     #[returns(ConfigResponse)]
     QueryConfig(QueryConfigMsg),
 
+    // Returns which operations are paused
+    #[returns(PauseStatusResponse)]
+    QueryPauseStatus(QueryPauseStatusMsg),
+
+    // Returns roles granted to an address
+    #[returns(RolesResponse)]
+    QueryRoles(QueryRolesMsg),
+
+    // Returns pending admin proposal and config changes waiting for the time-lock
+    #[returns(PendingConfigChangesResponse)]
+    QueryPendingConfigChanges(QueryPendingConfigChangesMsg),
+
+    // Returns ranges membership issuer can set its own fees in
+    #[returns(UserFeeRangesResponse)]
+    QueryUserFeeRanges(QueryUserFeeRangesMsg),
+
+    // Resolves user by either its cold address or hot address
     #[returns(UserResponse)]
     QueryUserByAddr(QueryUserByAddrMsg),
 
@@ -162,6 +392,31 @@ pub enum QueryMsg {
     #[returns(UsersResponse)]
     QueryUsersPaginatedByID(QueryUsersPaginatedByIDMsg),
 
+    // Batch lookup for contracts built on top of member contract
+    // Returns users in the same order as IDs, None for IDs without a user
+    #[returns(UsersByIDsResponse)]
+    QueryUsersByIDs(QueryUsersByIDsMsg),
+
+    // Same as QueryUsersByIDs, each address can be either user's cold address or hot address
+    #[returns(UsersByAddrsResponse)]
+    QueryUsersByAddrs(QueryUsersByAddrsMsg),
+
+    // Resolve user from social media handle on a platform, e.g. @handle in URL
+    #[returns(UserResponse)]
+    QueryUserBySocialMediaHandle(QueryUserBySocialMediaHandleMsg),
+
+    // Returns users whose social media handle on the platform starts with the prefix, ordered by normalized handle
+    #[returns(UsersBySocialMediaHandlePrefixResponse)]
+    QueryUsersBySocialMediaHandlePrefix(QueryUsersBySocialMediaHandlePrefixMsg),
+
+    // Returns the new address the user proposed to migrate to, if any
+    #[returns(PendingUserMigrationResponse)]
+    QueryPendingUserMigration(QueryPendingUserMigrationMsg),
+
+    // Returns all users referred by the referrer and referral fee earned from each, with pagination
+    #[returns(RefereesResponse)]
+    QueryReferees(QueryRefereesMsg),
+
     // Get total number of memberships issued by the membership issuer
     #[returns(MembershipSupplyResponse)]
     QueryMembershipSupply(QueryMembershipSupplyMsg),
@@ -190,6 +445,18 @@ pub enum QueryMsg {
     // QueryCostToSellMembership calculates the price and fee
     #[returns(CostToSellMembershipResponse)]
     QueryCostToSellMembership(QueryCostToSellMembershipMsg),
+
+    // Returns trades of the membership from oldest to newest, with pagination
+    #[returns(TradeHistoryResponse)]
+    QueryTradeHistory(QueryTradeHistoryMsg),
+
+    // Returns price candles of the membership from oldest to newest, with pagination
+    #[returns(PriceCandlesResponse)]
+    QueryPriceCandles(QueryPriceCandlesMsg),
+
+    // Returns time weighted average price of 1 membership over the last window_seconds
+    #[returns(TwapResponse)]
+    QueryTwap(QueryTwapMsg),
 }
 
 #[cw_serde]
@@ -200,6 +467,34 @@ pub struct ConfigResponse {
     pub config: Config,
 }
 
+#[cw_serde]
+pub struct QueryPauseStatusMsg {}
+
+#[cw_serde]
+pub struct PauseStatusResponse {
+    // When contract is disabled all user facing operations are unavailable regardless of pause config
+    pub enabled: bool,
+    pub pause_config: PauseConfig,
+    // Names of paused operations, e.g. buy_membership
+    pub paused_operations: Vec<String>,
+}
+
+#[cw_serde]
+pub struct PendingConfigChangesResponse {
+    pub pending_admin_proposal: Option<AdminProposal>,
+    pub config_change_timelock: Option<Duration>,
+    // Sorted by ID, i.e. in the order they were proposed
+    pub pending_config_changes: Vec<PendingConfigChange<UpdateConfigMsg>>,
+}
+
+#[cw_serde]
+pub struct QueryUserFeeRangesMsg {}
+
+#[cw_serde]
+pub struct UserFeeRangesResponse {
+    pub user_fee_ranges: UserFeeRanges,
+}
+
 #[cw_serde]
 pub struct QueryUserByAddrMsg {
     pub user_addr: String,
@@ -212,7 +507,10 @@ pub struct QueryUserByIDMsg {
 
 #[cw_serde]
 pub struct UserResponse {
+    // Fee config already reflects pending fee change that has taken effect
     pub user: User,
+    // Issuer fee change that has not taken effect yet
+    pub pending_fee_change: Option<PendingUserFeeChange>,
 }
 
 #[cw_serde]
@@ -236,6 +534,77 @@ pub struct UsersResponse {
     pub total_count: usize,
 }
 
+#[cw_serde]
+pub struct QueryUsersByIDsMsg {
+    // At most 25 IDs
+    pub user_ids: Vec<Uint64>,
+}
+
+#[cw_serde]
+pub struct UsersByIDsResponse {
+    pub users: Vec<Option<User>>,
+}
+
+#[cw_serde]
+pub struct QueryUsersByAddrsMsg {
+    // At most 25 addresses
+    pub user_addrs: Vec<String>,
+}
+
+#[cw_serde]
+pub struct UsersByAddrsResponse {
+    pub users: Vec<Option<User>>,
+}
+
+#[cw_serde]
+pub struct QueryUserBySocialMediaHandleMsg {
+    pub platform: String,
+    // Matched case insensitively and with or without leading @
+    pub social_media_handle: String,
+}
+
+#[cw_serde]
+pub struct QueryUsersBySocialMediaHandlePrefixMsg {
+    pub platform: String,
+    // Matched case insensitively and with or without leading @
+    pub prefix: String,
+    pub start_after_social_media_handle: Option<String>,
+    pub limit: Option<u32>,
+    pub include_start_after: Option<bool>,
+}
+
+#[cw_serde]
+pub struct UsersBySocialMediaHandlePrefixResponse {
+    pub users: Vec<User>,
+    pub count: usize,
+}
+
+#[cw_serde]
+pub struct QueryPendingUserMigrationMsg {
+    pub user_id: Uint64,
+}
+
+#[cw_serde]
+pub struct PendingUserMigrationResponse {
+    pub new_user_addr: Option<Addr>,
+}
+
+#[cw_serde]
+pub struct QueryRefereesMsg {
+    pub referrer_user_id: Uint64,
+    pub start_after_referee_user_id: Option<Uint64>,
+    pub limit: Option<u32>,
+    // Include start_after in the result if true
+    pub include_start_after: Option<bool>,
+}
+
+#[cw_serde]
+pub struct RefereesResponse {
+    pub referees: Vec<Referee>,
+    pub count: usize,
+    pub total_count: usize,
+}
+
 #[cw_serde]
 pub struct QueryMembershipSupplyMsg {
     pub membership_issuer_user_id: Uint64,
@@ -312,12 +681,20 @@ pub struct CostToBuyMembershipResponse {
     pub price: Uint128,
     // Fee paid to protocol
     pub protocol_fee: Uint128,
+    // Fee paid to trader's referrer, carved out of protocol fee
+    // Goes to protocol fee collector if trader has no referrer
+    pub referral_fee: Uint128,
     // Fee paid to membership issuer
     pub issuer_fee: Uint128,
     // Fee paid to all members
     pub all_members_fee: Uint128,
-    // Price + protocol fee + membership issuer fee + membership holder fee
+    // Price + protocol fee + referral fee + membership issuer fee + membership holder fee
     pub total_needed_from_user: Uint128,
+    // Denom of all amounts above, picked by membership issuer when enabling membership
+    pub fee_denom: String,
+    // Max amount of the membership a single holder can own, None means no cap
+    // Buy fails if buyer would hold more than this after buying
+    pub holding_cap: Option<Uint128>,
 }
 
 #[cw_serde]
@@ -332,10 +709,70 @@ pub struct CostToSellMembershipResponse {
     pub price: Uint128,
     // Fee paid to protocol
     pub protocol_fee: Uint128,
+    // Fee paid to trader's referrer, carved out of protocol fee
+    // Goes to protocol fee collector if trader has no referrer
+    pub referral_fee: Uint128,
     // Fee paid to membership issuer
     pub issuer_fee: Uint128,
     // Fee paid to all members
     pub all_members_fee: Uint128,
-    // Protocol fee + membership issuer fee + membership holder fee
+    // Protocol fee + referral fee + membership issuer fee + membership holder fee
     pub total_needed_from_user: Uint128,
+    // Price - total_needed_from_user, what seller receives when fees are deducted from proceeds
+    // 0 if fees exceed price
+    pub net_proceeds: Uint128,
+    // Denom of all amounts above, picked by membership issuer when enabling membership
+    pub fee_denom: String,
+}
+
+#[cw_serde]
+pub struct QueryTradeHistoryMsg {
+    pub membership_issuer_user_id: Uint64,
+    pub start_after_trade_id: Option<Uint64>,
+    pub limit: Option<u32>,
+    // Include start_after in the result if true
+    pub include_start_after: Option<bool>,
+}
+
+#[cw_serde]
+pub struct TradeHistoryResponse {
+    pub trades: Vec<Trade>,
+    pub count: usize,
+    pub total_count: usize,
+}
+
+#[cw_serde]
+pub struct QueryPriceCandlesMsg {
+    pub membership_issuer_user_id: Uint64,
+    // Default to interval in config
+    pub interval: Option<CandleInterval>,
+    // Start time of candle in seconds
+    pub start_after_start_time: Option<Uint64>,
+    pub limit: Option<u32>,
+    // Include start_after in the result if true
+    pub include_start_after: Option<bool>,
+}
+
+#[cw_serde]
+pub struct PriceCandlesResponse {
+    pub interval: CandleInterval,
+    pub candles: Vec<Candle>,
+    pub count: usize,
+}
+
+#[cw_serde]
+pub struct QueryTwapMsg {
+    pub membership_issuer_user_id: Uint64,
+    pub window_seconds: Uint64,
+}
+
+#[cw_serde]
+pub struct TwapResponse {
+    // Time weighted average price of 1 membership, rounded down
+    pub twap: Uint128,
+    // Price of 1 membership at current supply
+    pub spot_price: Uint128,
+    // Seconds of price history TWAP is actually based on
+    // Less than window_seconds when price history is shorter than the window, 0 when there is no history
+    pub observed_seconds: Uint64,
 }
@@ -0,0 +1,92 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Timestamp, Uint128, Uint256, Uint64};
+use shared_pkg::event::TradeSide;
+
+// A single buy or sell of a membership, kept on chain so charts don't depend on an indexer
+#[cw_serde]
+pub struct Trade {
+    // Trade ID, starts from 1 for each membership
+    pub id: Uint64,
+    pub block_height: Uint64,
+    pub timestamp: Timestamp,
+    pub trader_user_id: Uint64,
+    pub side: TradeSide,
+    pub amount: Uint128,
+    // Total price of the traded amount, not price per membership
+    pub price: Uint128,
+    pub protocol_fee: Uint128,
+    pub referral_fee: Uint128,
+    pub issuer_fee: Uint128,
+    pub all_members_fee: Uint128,
+}
+
+impl Trade {
+    // Average price per membership of the trade, rounded down
+    pub fn unit_price(&self) -> Uint128 {
+        self.price.checked_div(self.amount).unwrap_or_default()
+    }
+}
+
+// Length of each price candle
+#[cw_serde]
+#[derive(Default)]
+pub enum CandleInterval {
+    #[default]
+    Hourly,
+    Daily,
+}
+
+impl CandleInterval {
+    pub fn seconds(&self) -> u64 {
+        match self {
+            CandleInterval::Hourly => 60 * 60,
+            CandleInterval::Daily => 24 * 60 * 60,
+        }
+    }
+
+    // Used as part of storage key so candles of different intervals never mix
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CandleInterval::Hourly => "hourly",
+            CandleInterval::Daily => "daily",
+        }
+    }
+
+    // Start of the candle the time falls into
+    pub fn candle_start_time(&self, time: Timestamp) -> Timestamp {
+        Timestamp::from_seconds(time.seconds() - time.seconds() % self.seconds())
+    }
+}
+
+// Open, high, low and close of the price per membership over one interval
+#[cw_serde]
+pub struct Candle {
+    pub start_time: Timestamp,
+    pub open: Uint128,
+    pub high: Uint128,
+    pub low: Uint128,
+    pub close: Uint128,
+    // Number of memberships bought and sold in the candle
+    pub volume: Uint128,
+    pub trade_count: Uint64,
+}
+
+// Snapshot of the price accumulator of a membership, saved on every trade
+// TWAP between 2 points in time is the difference of their cumulative prices divided by elapsed seconds
+#[cw_serde]
+pub struct PriceObservation {
+    // Truncated to seconds
+    pub timestamp: Timestamp,
+    // Sum of spot price * seconds it stayed at that price, from membership enabled till timestamp
+    pub cumulative_price: Uint256,
+    // Spot price of 1 membership after the trade
+    pub price: Uint128,
+}
+
+impl PriceObservation {
+    // Extrapolate cumulative price to a later time, spot price stays the same until the next trade
+    pub fn cumulative_price_at(&self, time: Timestamp) -> Uint256 {
+        let elapsed_seconds = time.seconds().saturating_sub(self.timestamp.seconds());
+        self.cumulative_price + Uint256::from(self.price) * Uint256::from(elapsed_seconds)
+    }
+}
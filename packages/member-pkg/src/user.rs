@@ -1,6 +1,7 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128, Uint64};
-use shared_pkg::fee_share_config::FeeShareConfig;
+use cosmwasm_std::{Addr, Coin, Timestamp, Uint128, Uint64};
+use cw_utils::Expiration;
+use shared_pkg::{fee_share_config::FeeShareConfig, pricing::BondingCurve};
 
 use crate::config::FeeConfig;
 
@@ -31,20 +32,38 @@ pub struct MembershipIssuedByMe {
     // Number of members who hold the membership issued by the user
     // This could be smaller than membership_supply because each member can hold multiple memberships
     pub member_count: Uint128,
+    // Bonding curve picked when membership is enabled
+    // It never changes afterwards, otherwise selling would not return what buying cost
+    pub bonding_curve: BondingCurve,
+    // Denom picked when membership is enabled, all trades and fees of the membership are in this denom
+    // It never changes afterwards, same reason as bonding curve
+    pub fee_denom: String,
+}
+
+// Social media account registration admin has verified belongs to the user
+#[cw_serde]
+pub struct SocialLink {
+    // Platform in social media platform allowlist, e.g. twitter
+    pub platform: String,
+    // Handle as linked, lookup normalizes it with normalize_social_media_handle
+    pub handle: String,
+    pub linked_at: Timestamp,
 }
 
 #[cw_serde]
 pub struct User {
     // User ID, a global unique identifier that is monotonically increasing
     pub id: Uint64,
-    // User address
-    // TODO: P2: support cold wallet address and hot wallet address
-    // So user can use cold wallet to buy / sell key, hot wallet to post thread
+    // User address, can be changed via MigrateUser
+    // This is the cold wallet address, only it can buy / sell membership and update fee config
     pub addr: Addr,
-    // User's social media handle, only exists if the register admin has linked the social media handle for the user
-    pub social_media_handle: Option<String>,
+    // Optional hot wallet address set by the cold wallet address
+    // Hot wallet can post in thread contract and claim rewards on behalf of the user
+    pub hot_addr: Option<Addr>,
+    // User's social media accounts linked by the register admin, at most 1 per platform
+    pub social_links: Vec<SocialLink>,
 
-    // Social media handle is required to issue its own membership
+    // Social media link is required to issue its own membership, see membership eligibility in config
     // Membership issued by the user, only exists if the register admin has registered the membership for the user
     pub membership_issued_by_me: Option<MembershipIssuedByMe>,
 
@@ -53,7 +72,91 @@ pub struct User {
     // We store this field here because cosmwasm doesn't support O(1) getting size of map
     pub user_member_count: Uint128,
 
+    // User ID of the referrer, set at registration and cannot change
+    // Referrer receives part of the protocol fee when this user trades memberships
+    pub referrer_user_id: Option<Uint64>,
+    // Number of users registered with this user as referrer
+    // We store this field here because cosmwasm doesn't support O(1) getting size of map
+    pub referee_count: Uint128,
+
+    // If true then only verified users, i.e. users with linked social media, can buy this user's membership
+    pub only_verified_buyers: bool,
+
     // User customized fee config,
-    pub fee_config: Option<FeeConfig>,
+    pub fee_config: UserFeeConfig,
+    pub fee_share_config: Option<FeeShareConfig>,
+}
+
+// Fees membership issuer set for itself, fees left None follow default fee config in config
+// So a later change of the default still applies to fees issuer never set
+#[cw_serde]
+#[derive(Default)]
+pub struct UserFeeConfig {
+    pub trading_fee_bps_of_membership: Option<Uint64>,
+    // 0 means no cap, even if config has a default holding cap
+    pub holding_cap: Option<Uint128>,
+}
+
+impl UserFeeConfig {
+    // Default fee config with fees issuer set on top
+    // Fee denom stays config's default, membership's own fee denom is in membership issued by me
+    pub fn resolve(&self, default_fee_config: &FeeConfig) -> FeeConfig {
+        FeeConfig {
+            fee_denom: default_fee_config.fee_denom.clone(),
+            trading_fee_bps_of_membership: self
+                .trading_fee_bps_of_membership
+                .unwrap_or(default_fee_config.trading_fee_bps_of_membership),
+            holding_cap: match self.holding_cap {
+                None => default_fee_config.holding_cap,
+                Some(holding_cap) if holding_cap.is_zero() => None,
+                Some(holding_cap) => Some(holding_cap),
+            },
+        }
+    }
+}
+
+// Fee change issuer made while user fee change time-lock is set in config
+// Holders can see it coming and trade before it takes effect at effective_at
+#[cw_serde]
+pub struct PendingUserFeeChange {
+    pub trading_fee_bps_of_membership: Option<Uint64>,
     pub fee_share_config: Option<FeeShareConfig>,
+    pub effective_at: Expiration,
+}
+
+impl PendingUserFeeChange {
+    pub fn is_empty(&self) -> bool {
+        self.trading_fee_bps_of_membership.is_none() && self.fee_share_config.is_none()
+    }
+}
+
+impl User {
+    pub fn social_link(&self, platform: &str) -> Option<&SocialLink> {
+        self.social_links
+            .iter()
+            .find(|social_link| social_link.platform == platform)
+    }
+}
+
+// Platforms are matched case insensitively, e.g. Twitter and twitter are the same platform
+pub fn normalize_social_media_platform(platform: &str) -> String {
+    platform.trim().to_lowercase()
+}
+
+// Handles are matched case insensitively and with or without leading @, e.g. @Alice and alice are the same handle
+pub fn normalize_social_media_handle(social_media_handle: &str) -> String {
+    social_media_handle
+        .trim()
+        .trim_start_matches('@')
+        .to_lowercase()
+}
+
+// Used in referral map where key is the referrer, value is all its referees
+#[cw_serde]
+pub struct Referee {
+    // Referee's user ID
+    pub referee_user_id: Uint64,
+    // Total referral fee the referrer has earned from the referee's membership trading
+    // One coin per fee denom, fees in different denoms are never added up
+    pub earned: Vec<Coin>,
 }
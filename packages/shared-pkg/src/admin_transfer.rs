@@ -0,0 +1,27 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Addr;
+use cw_utils::Expiration;
+
+// Admin is replaced in 2 steps so a typo in the new address cannot lock the contract
+// Current admin proposes, proposed address accepts
+#[cw_serde]
+pub struct AdminProposal {
+    pub new_admin_addr: Addr,
+    // Proposal can no longer be accepted once expired
+    pub expiration: Expiration,
+}
+
+// ========== msg ==========
+
+#[cw_serde]
+pub struct ProposeNewAdminMsg {
+    pub new_admin_addr: String,
+    // Default to never expire
+    pub expiration: Option<Expiration>,
+}
+
+#[cw_serde]
+pub struct AcceptAdminMsg {}
+
+#[cw_serde]
+pub struct CancelAdminProposalMsg {}
@@ -0,0 +1,224 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Coin, Event, Uint128, Uint64};
+
+// All events share this prefix so indexers can pick them up without decoding execute msgs
+// Wasm module prepends "wasm-" to custom event types, e.g. wasm-cw_friend.trade
+pub const EVENT_PREFIX: &str = "cw_friend";
+
+fn new_event(name: &str) -> Event {
+    Event::new(format!("{EVENT_PREFIX}.{name}"))
+}
+
+// Every event of a type always has the same attributes, see README for each event's shape
+// Chain rejects empty attribute values so nothing is left out or emitted empty, e.g. free actions emit 0 fees
+
+#[cw_serde]
+pub enum TradeSide {
+    Buy,
+    Sell,
+}
+
+impl TradeSide {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TradeSide::Buy => "buy",
+            TradeSide::Sell => "sell",
+        }
+    }
+}
+
+// Emitted by membership contract on every buy and sell
+#[derive(Clone, Debug, PartialEq)]
+pub struct TradeEvent {
+    pub side: TradeSide,
+    pub trader_user_id: Uint64,
+    pub trader_addr: Addr,
+    pub membership_issuer_user_id: Uint64,
+    pub amount: Uint128,
+    // Total price of the traded amount, not price per membership
+    pub price: Uint128,
+    pub protocol_fee: Uint128,
+    pub referral_fee: Uint128,
+    pub issuer_fee: Uint128,
+    pub all_members_fee: Uint128,
+    // For buy, price plus all fees paid by the buyer
+    // For sell, fees paid with attached funds
+    // 0 for sell when fees are deducted from proceeds
+    pub total_paid: Uint128,
+    // What seller receives, 0 for buy
+    pub proceeds: Uint128,
+    pub refunded_amount: Uint128,
+    pub fee_denom: String,
+    // Membership supply after trade
+    pub new_supply: Uint128,
+    // Amount of the membership trader holds after trade
+    pub trader_new_hold_amount: Uint128,
+}
+
+impl From<TradeEvent> for Event {
+    fn from(trade: TradeEvent) -> Self {
+        new_event("trade")
+            .add_attribute("side", trade.side.as_str())
+            .add_attribute("trader_user_id", trade.trader_user_id)
+            .add_attribute("trader_addr", trade.trader_addr)
+            .add_attribute("membership_issuer_user_id", trade.membership_issuer_user_id)
+            .add_attribute("amount", trade.amount)
+            .add_attribute("price", trade.price)
+            .add_attribute("protocol_fee", trade.protocol_fee)
+            .add_attribute("referral_fee", trade.referral_fee)
+            .add_attribute("issuer_fee", trade.issuer_fee)
+            .add_attribute("all_members_fee", trade.all_members_fee)
+            .add_attribute("total_paid", trade.total_paid)
+            .add_attribute("proceeds", trade.proceeds)
+            .add_attribute("refunded_amount", trade.refunded_amount)
+            .add_attribute("fee_denom", trade.fee_denom)
+            .add_attribute("new_supply", trade.new_supply)
+            .add_attribute("trader_new_hold_amount", trade.trader_new_hold_amount)
+    }
+}
+
+// Emitted by thread contract when a thread is created, either directly or by asking in a new thread
+#[derive(Clone, Debug, PartialEq)]
+pub struct ThreadEvent {
+    pub thread_id: Uint64,
+    pub creator_user_id: Uint64,
+    // All 0 when thread is started by asking in a new thread, the question's thread msg event has the fees
+    pub fees: ThreadFees,
+}
+
+// Fees paid to start a new thread
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ThreadFees {
+    pub protocol_fee: Uint128,
+    pub referral_fee: Uint128,
+    pub total_paid: Uint128,
+    pub refunded_amount: Uint128,
+    pub fee_denom: String,
+}
+
+impl From<ThreadEvent> for Event {
+    fn from(thread: ThreadEvent) -> Self {
+        new_event("thread")
+            .add_attribute("thread_id", thread.thread_id)
+            .add_attribute("creator_user_id", thread.creator_user_id)
+            .add_attribute("protocol_fee", thread.fees.protocol_fee)
+            .add_attribute("referral_fee", thread.fees.referral_fee)
+            .add_attribute("total_paid", thread.fees.total_paid)
+            .add_attribute("refunded_amount", thread.fees.refunded_amount)
+            .add_attribute("fee_denom", thread.fees.fee_denom)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ThreadMsgKind {
+    Question,
+    Answer,
+    Reply,
+}
+
+impl ThreadMsgKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ThreadMsgKind::Question => "question",
+            ThreadMsgKind::Answer => "answer",
+            ThreadMsgKind::Reply => "reply",
+        }
+    }
+}
+
+// Emitted by thread contract on every question, answer and reply
+#[derive(Clone, Debug, PartialEq)]
+pub struct ThreadMsgEvent {
+    pub kind: ThreadMsgKind,
+    pub thread_id: Uint64,
+    pub thread_msg_id: Uint64,
+    pub creator_user_id: Uint64,
+    pub thread_creator_user_id: Uint64,
+    // User asked to for question, question asker for answer
+    // Replied to user for reply, thread creator when replying to the thread itself
+    pub to_user_id: Uint64,
+    // Question answered for answer, msg replied to for reply
+    // 0 when posted to the thread itself, i.e. question and reply to the thread, thread msg IDs start from 1
+    pub to_thread_msg_id: Uint64,
+    // Answer is free so its fees are all 0
+    pub fees: ThreadMsgFees,
+}
+
+// Fees paid to ask or reply in thread
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ThreadMsgFees {
+    pub protocol_fee: Uint128,
+    pub referral_fee: Uint128,
+    // Fee paid to the issuer and members of the membership asked / replied to
+    pub to_membership_issuer_fee: Uint128,
+    pub to_membership_all_members_fee: Uint128,
+    // Fee paid to the issuer and members of thread creator's membership
+    pub thread_creator_membership_issuer_fee: Uint128,
+    pub thread_creator_membership_all_members_fee: Uint128,
+    pub total_paid: Uint128,
+    pub refunded_amount: Uint128,
+    pub fee_denom: String,
+}
+
+impl From<ThreadMsgEvent> for Event {
+    fn from(thread_msg: ThreadMsgEvent) -> Self {
+        let fees = thread_msg.fees;
+        new_event("thread_msg")
+            .add_attribute("kind", thread_msg.kind.as_str())
+            .add_attribute("thread_id", thread_msg.thread_id)
+            .add_attribute("thread_msg_id", thread_msg.thread_msg_id)
+            .add_attribute("creator_user_id", thread_msg.creator_user_id)
+            .add_attribute("thread_creator_user_id", thread_msg.thread_creator_user_id)
+            .add_attribute("to_user_id", thread_msg.to_user_id)
+            .add_attribute("to_thread_msg_id", thread_msg.to_thread_msg_id)
+            .add_attribute("protocol_fee", fees.protocol_fee)
+            .add_attribute("referral_fee", fees.referral_fee)
+            .add_attribute("to_membership_issuer_fee", fees.to_membership_issuer_fee)
+            .add_attribute(
+                "to_membership_all_members_fee",
+                fees.to_membership_all_members_fee,
+            )
+            .add_attribute(
+                "thread_creator_membership_issuer_fee",
+                fees.thread_creator_membership_issuer_fee,
+            )
+            .add_attribute(
+                "thread_creator_membership_all_members_fee",
+                fees.thread_creator_membership_all_members_fee,
+            )
+            .add_attribute("total_paid", fees.total_paid)
+            .add_attribute("refunded_amount", fees.refunded_amount)
+            .add_attribute("fee_denom", fees.fee_denom)
+    }
+}
+
+// Emitted by distribution contract when user claims reward of a membership
+// Not emitted when there is nothing to claim
+#[derive(Clone, Debug, PartialEq)]
+pub struct RewardClaimEvent {
+    pub membership_issuer_user_id: Uint64,
+    pub user_id: Uint64,
+    // Reward is always sent to user's cold address
+    pub recipient_addr: Addr,
+    // Non zero rewards only, at least 1 coin
+    pub rewards: Vec<Coin>,
+}
+
+impl From<RewardClaimEvent> for Event {
+    fn from(reward_claim: RewardClaimEvent) -> Self {
+        let rewards = reward_claim
+            .rewards
+            .iter()
+            .map(|coin| coin.to_string())
+            .collect::<Vec<String>>()
+            .join(",");
+        new_event("reward_claim")
+            .add_attribute(
+                "membership_issuer_user_id",
+                reward_claim.membership_issuer_user_id,
+            )
+            .add_attribute("user_id", reward_claim.user_id)
+            .add_attribute("recipient_addr", reward_claim.recipient_addr)
+            .add_attribute("rewards", rewards)
+    }
+}
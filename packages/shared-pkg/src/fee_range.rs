@@ -0,0 +1,33 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Uint64;
+
+use crate::pricing::ONE_HUNDRED_PERCENT_IN_BASIS_POINTS;
+
+// Inclusive range in bps admin allows membership issuer to set one of its fees in
+#[cw_serde]
+pub struct BpsRange {
+    pub min: Uint64,
+    pub max: Uint64,
+}
+
+// Issuer fees can go up to 1 membership price by default, admin can tighten it later
+pub const DEFAULT_USER_FEE_BPS_RANGE: BpsRange = BpsRange {
+    min: Uint64::zero(),
+    max: Uint64::new(ONE_HUNDRED_PERCENT_IN_BASIS_POINTS),
+};
+
+impl Default for BpsRange {
+    fn default() -> Self {
+        DEFAULT_USER_FEE_BPS_RANGE
+    }
+}
+
+impl BpsRange {
+    pub fn is_valid(&self) -> bool {
+        self.min <= self.max
+    }
+
+    pub fn contains(&self, bps: Uint64) -> bool {
+        self.min <= bps && bps <= self.max
+    }
+}
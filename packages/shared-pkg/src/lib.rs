@@ -1 +1,9 @@
+pub mod admin_transfer;
+pub mod event;
+pub mod fee_range;
 pub mod fee_share_config;
+pub mod migrate;
+pub mod pricing;
+pub mod rbac;
+pub mod refund;
+pub mod timelock;
@@ -0,0 +1,81 @@
+use cosmwasm_std::{DepsMut, Env, StdError, Storage};
+use cw2::get_contract_version;
+use semver::Version;
+use thiserror::Error;
+
+// Migration checks shared by member, thread and distribution contracts
+// Each contract keeps its own registry of state migrations next to the old structs they read
+
+#[derive(Error, Debug, PartialEq)]
+pub enum MigrateError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Invalid contract version {version}")]
+    InvalidContractVersion { version: String },
+
+    #[error("Cannot migrate from different contract {previous_contract}")]
+    CannotMigrateFromDifferentContract { previous_contract: String },
+
+    #[error("Cannot migrate from version {previous_version} to older version {new_version}")]
+    CannotMigrateToOlderVersion {
+        previous_version: String,
+        new_version: String,
+    },
+}
+
+// Upgrades everything stored by older versions to the schema of the version it's registered with
+pub type StateMigration<E> = fn(DepsMut, &Env) -> Result<(), E>;
+
+// Only allow migrating from the same contract and never to an older version
+// contract_name is the name stored with cw2, e.g. crates.io:member
+// Returns version of the code being replaced
+pub fn assert_can_migrate(
+    storage: &dyn Storage,
+    contract_name: &str,
+    contract_version: &str,
+) -> Result<Version, MigrateError> {
+    let stored = get_contract_version(storage)?;
+
+    if stored.contract != contract_name {
+        return Err(MigrateError::CannotMigrateFromDifferentContract {
+            previous_contract: stored.contract,
+        });
+    }
+
+    let previous_version = parse_version(&stored.version)?;
+    let new_version = parse_version(contract_version)?;
+    if previous_version > new_version {
+        return Err(MigrateError::CannotMigrateToOlderVersion {
+            previous_version: previous_version.to_string(),
+            new_version: new_version.to_string(),
+        });
+    }
+
+    Ok(previous_version)
+}
+
+// Run in order every state migration registered with a version newer than previous version
+// state_migrations must be sorted by version
+pub fn migrate_state<E: From<MigrateError>>(
+    mut deps: DepsMut,
+    env: &Env,
+    previous_version: &Version,
+    state_migrations: &[(&str, StateMigration<E>)],
+) -> Result<(), E> {
+    for (version, state_migration) in state_migrations {
+        if *previous_version < parse_version(version)? {
+            state_migration(deps.branch(), env)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_version(version: &str) -> Result<Version, MigrateError> {
+    version
+        .parse()
+        .map_err(|_| MigrateError::InvalidContractVersion {
+            version: version.to_string(),
+        })
+}
@@ -0,0 +1,208 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    ConversionOverflowError, Decimal256, DivideByZeroError, OverflowError, OverflowOperation,
+    StdError, Uint128, Uint256, Uint64,
+};
+use thiserror::Error;
+
+// Membership pricing and fee math shared by member and thread contracts
+// Every operation is checked and returns an error instead of panicking
+// Fees are in basis points, 1 basis point is 0.01% so 10_000 basis points is 100%
+// Every result rounds down, so a price or fee never charges more than its exact value
+// and splitting a fee into shares never pays out more than the fee itself
+
+pub const ONE_HUNDRED_PERCENT_IN_BASIS_POINTS: u64 = 10_000;
+pub const BASIS_POINTS_PER_PERCENT: u64 = 100;
+
+// Fees were whole percentages before basis points, only used to migrate them
+pub fn checked_percentage_to_basis_points(percentage: Uint64) -> Result<Uint64, OverflowError> {
+    percentage.checked_mul(Uint64::new(BASIS_POINTS_PER_PERCENT))
+}
+
+// amount * basis_points / 10_000, rounded down
+// Product is computed in 256 bits, so only a result that doesn't fit in Uint128 overflows
+// i.e. never for basis points up to 100%
+pub fn checked_multiply_basis_points(
+    amount: Uint128,
+    basis_points: Uint64,
+) -> Result<Uint128, OverflowError> {
+    let result =
+        amount.full_mul(basis_points.u64()) / Uint256::from(ONE_HUNDRED_PERCENT_IN_BASIS_POINTS);
+    Uint128::try_from(result)
+        .map_err(|_| OverflowError::new(OverflowOperation::Mul, amount, basis_points))
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum PricingError {
+    #[error("{0}")]
+    Overflow(#[from] OverflowError),
+
+    // Only when curve has a zero divisor or growth, contracts reject such curves when they are configured
+    #[error("{0}")]
+    DivideByZero(#[from] DivideByZeroError),
+
+    // Price doesn't fit in Uint128
+    #[error("{0}")]
+    ConversionOverflow(#[from] ConversionOverflowError),
+}
+
+impl From<PricingError> for StdError {
+    fn from(err: PricingError) -> Self {
+        match err {
+            PricingError::Overflow(err) => err.into(),
+            PricingError::DivideByZero(err) => err.into(),
+            PricingError::ConversionOverflow(err) => err.into(),
+        }
+    }
+}
+
+// Divisor of the original quadratic curve
+pub const DEFAULT_QUADRATIC_BONDING_CURVE_DIVISOR: u128 = 16_000;
+
+// Bonding curve decides membership price based on membership supply
+// price(i) is the price of the membership when supply is i
+// Buying amount memberships when supply is s costs price(s) + price(s + 1) + ... + price(s + amount - 1)
+// Selling amount memberships back to supply s returns the same, so buy and sell always match
+// All prices are in fee denom's smallest unit, e.g. uluna
+#[cw_serde]
+pub enum BondingCurve {
+    // price(i) = i^2 * 1_000_000 / divisor
+    Quadratic {
+        divisor: Uint128,
+    },
+    // price(i) = base_price + slope * i
+    Linear {
+        base_price: Uint128,
+        slope: Uint128,
+    },
+    // price(i) = base_price * (1 + growth_bps / 10_000)^i
+    // Overflows quickly as supply grows, so keep growth_bps small
+    // growth_bps cannot be 0, use Linear with 0 slope for a flat price
+    Exponential {
+        base_price: Uint128,
+        growth_bps: Uint64,
+    },
+    // price(i) = max_price * i^2 / (i^2 + inflection_supply^2)
+    // Price grows slowly at first, fastest around inflection_supply, then flattens towards max_price
+    Sigmoid {
+        max_price: Uint128,
+        inflection_supply: Uint128,
+    },
+}
+
+impl Default for BondingCurve {
+    fn default() -> Self {
+        BondingCurve::Quadratic {
+            divisor: Uint128::from(DEFAULT_QUADRATIC_BONDING_CURVE_DIVISOR),
+        }
+    }
+}
+
+// 1 + 2 + ... + n
+fn sum_of_integers(n: Uint256) -> Result<Uint256, OverflowError> {
+    Ok(n.checked_mul(n.checked_add(Uint256::one())?)? / Uint256::from(2_u8))
+}
+
+// 1^2 + 2^2 + ... + n^2
+fn sum_of_squares(n: Uint256) -> Result<Uint256, OverflowError> {
+    Ok(n.checked_mul(n.checked_add(Uint256::one())?)?.checked_mul(
+        n.checked_mul(Uint256::from(2_u8))?
+            .checked_add(Uint256::one())?,
+    )? / Uint256::from(6_u8))
+}
+
+// Sum of f(first) + ... + f(last) from sums starting at 1, f(0) is 0 for every curve using it
+fn sum_between(
+    first: Uint256,
+    last: Uint256,
+    sum_to: fn(Uint256) -> Result<Uint256, OverflowError>,
+) -> Result<Uint256, OverflowError> {
+    let sum_before_first = if first.is_zero() {
+        Uint256::zero()
+    } else {
+        sum_to(first.checked_sub(Uint256::one())?)?
+    };
+    sum_to(last)?.checked_sub(sum_before_first)
+}
+
+fn exponent(value: Uint128, base: Decimal256) -> Result<u32, OverflowError> {
+    u32::try_from(value.u128()).map_err(|_| OverflowError::new(OverflowOperation::Pow, base, value))
+}
+
+impl BondingCurve {
+    // Total price of amount memberships starting from supply, rounded down
+    pub fn calculate_price(
+        &self,
+        supply: Uint128,
+        amount: Uint128,
+    ) -> Result<Uint128, PricingError> {
+        if amount.is_zero() {
+            return Ok(Uint128::zero());
+        }
+
+        let first = Uint256::from(supply);
+        let last = first
+            .checked_add(Uint256::from(amount))?
+            .checked_sub(Uint256::one())?;
+
+        let price = match self {
+            BondingCurve::Quadratic { divisor } => {
+                let summation = sum_between(first, last, sum_of_squares)?;
+                // 1_000_000 because 1 LUNA = 1_000_000 uluna
+                summation
+                    .checked_mul(Uint256::from(1_000_000_u64))?
+                    .checked_div(Uint256::from(*divisor))?
+            }
+            BondingCurve::Linear { base_price, slope } => {
+                let summation = sum_between(first, last, sum_of_integers)?;
+                Uint256::from(*base_price)
+                    .checked_mul(Uint256::from(amount))?
+                    .checked_add(Uint256::from(*slope).checked_mul(summation)?)?
+            }
+            BondingCurve::Exponential {
+                base_price,
+                growth_bps,
+            } => {
+                // Geometric series: base_price * growth^supply * (growth^amount - 1) / (growth - 1)
+                let growth_rate = Decimal256::bps(growth_bps.u64());
+                let growth = Decimal256::one().checked_add(growth_rate)?;
+                let numerator = growth.checked_pow(exponent(supply, growth)?)?.checked_mul(
+                    growth
+                        .checked_pow(exponent(amount, growth)?)?
+                        .checked_sub(Decimal256::one())?,
+                )?;
+                // Divide in atomics so dividing by a zero growth rate is a DivideByZeroError
+                let factor = numerator
+                    .atomics()
+                    .checked_mul(Decimal256::one().atomics())?
+                    .checked_div(growth_rate.atomics())?;
+                Uint256::from(*base_price).checked_mul(factor)? / Decimal256::one().atomics()
+            }
+            BondingCurve::Sigmoid {
+                max_price,
+                inflection_supply,
+            } => {
+                // No closed form for the sum, so we add up price of each membership
+                // Gas grows with amount, member contract caps amount per sigmoid trade
+                let inflection_supply_squared = Uint256::from(*inflection_supply)
+                    .checked_mul(Uint256::from(*inflection_supply))?;
+                let mut summation = Uint256::zero();
+                let mut i = first;
+                while i <= last {
+                    let i_squared = i.checked_mul(i)?;
+                    let denominator = i_squared.checked_add(inflection_supply_squared)?;
+                    // Price at supply 0 is 0, including when inflection supply is 0
+                    if !denominator.is_zero() {
+                        summation = summation.checked_add(
+                            Uint256::from(*max_price).checked_mul(i_squared)? / denominator,
+                        )?;
+                    }
+                    i = i.checked_add(Uint256::one())?;
+                }
+                summation
+            }
+        };
+
+        Ok(Uint128::try_from(price)?)
+    }
+}
@@ -0,0 +1,116 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Empty, StdResult, Storage};
+use cw_storage_plus::Map;
+
+// Named roles shared by member, thread and distribution contracts
+// Each contract keeps its own grants, being admin of one contract gives nothing in another
+#[cw_serde]
+pub enum Role {
+    // Can update config, enable / disable contract and grant / revoke roles
+    Admin,
+    // Can register users, link social media and enable membership on behalf of users
+    RegistrationOperator,
+    // Can update fees and fee denom allowlist
+    FeeManager,
+    // Can pause and unpause individual operations
+    Pauser,
+    // Can unlink social media of any user
+    Moderator,
+    // Can distribute fees to members, held by member and thread contracts in distribution contract
+    Distributor,
+}
+
+impl Role {
+    pub const ALL: [Role; 6] = [
+        Role::Admin,
+        Role::RegistrationOperator,
+        Role::FeeManager,
+        Role::Pauser,
+        Role::Moderator,
+        Role::Distributor,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Admin => "admin",
+            Role::RegistrationOperator => "registration_operator",
+            Role::FeeManager => "fee_manager",
+            Role::Pauser => "pauser",
+            Role::Moderator => "moderator",
+            Role::Distributor => "distributor",
+        }
+    }
+}
+
+// Key is (address, role), an address can hold multiple roles and a role can be held by multiple addresses
+pub const ALL_ROLE_GRANTS: Map<(&Addr, &str), Empty> = Map::new("ALL_ROLE_GRANTS");
+
+pub fn has_role(storage: &dyn Storage, addr: &Addr, role: &Role) -> StdResult<bool> {
+    Ok(ALL_ROLE_GRANTS
+        .may_load(storage, (addr, role.as_str()))?
+        .is_some())
+}
+
+pub fn has_any_role(storage: &dyn Storage, addr: &Addr, roles: &[Role]) -> StdResult<bool> {
+    for role in roles {
+        if has_role(storage, addr, role)? {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+pub fn grant_role(storage: &mut dyn Storage, addr: &Addr, role: &Role) -> StdResult<()> {
+    ALL_ROLE_GRANTS.save(storage, (addr, role.as_str()), &Empty {})
+}
+
+pub fn revoke_role(storage: &mut dyn Storage, addr: &Addr, role: &Role) {
+    ALL_ROLE_GRANTS.remove(storage, (addr, role.as_str()))
+}
+
+// Move role from the address previously set in config to the new one
+// Other holders of the role are kept
+pub fn transfer_role(
+    storage: &mut dyn Storage,
+    from_addr: &Addr,
+    to_addr: &Addr,
+    role: &Role,
+) -> StdResult<()> {
+    revoke_role(storage, from_addr, role);
+    grant_role(storage, to_addr, role)
+}
+
+// Roles held by address, in the order of Role::ALL
+pub fn load_roles(storage: &dyn Storage, addr: &Addr) -> StdResult<Vec<Role>> {
+    let mut roles = vec![];
+    for role in Role::ALL {
+        if has_role(storage, addr, &role)? {
+            roles.push(role);
+        }
+    }
+    Ok(roles)
+}
+
+// ========== msg ==========
+
+#[cw_serde]
+pub struct GrantRoleMsg {
+    pub addr: String,
+    pub role: Role,
+}
+
+#[cw_serde]
+pub struct RevokeRoleMsg {
+    pub addr: String,
+    pub role: Role,
+}
+
+#[cw_serde]
+pub struct QueryRolesMsg {
+    pub addr: String,
+}
+
+#[cw_serde]
+pub struct RolesResponse {
+    pub roles: Vec<Role>,
+}
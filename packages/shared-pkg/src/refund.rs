@@ -0,0 +1,27 @@
+use cosmwasm_std::{Addr, BankMsg, Coin, CosmosMsg, Uint128};
+
+// Build msg to send back what user paid on top of what is needed
+// Frontend may overpay on purpose to absorb price movement
+// Returns refunded amount and None as msg if there is nothing to refund
+pub fn build_refund_msg(
+    user_addr: &Addr,
+    user_paid_amount: Uint128,
+    total_needed_from_user: Uint128,
+    fee_denom: &str,
+) -> (Uint128, Option<CosmosMsg>) {
+    let refund_amount = user_paid_amount.saturating_sub(total_needed_from_user);
+    if refund_amount.is_zero() {
+        return (refund_amount, None);
+    }
+
+    (
+        refund_amount,
+        Some(CosmosMsg::Bank(BankMsg::Send {
+            to_address: user_addr.to_string(),
+            amount: vec![Coin {
+                denom: fee_denom.to_string(),
+                amount: refund_amount,
+            }],
+        })),
+    )
+}
@@ -0,0 +1,41 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Uint64};
+use cw_utils::{Duration, Expiration};
+
+// Sensitive config changes wait for the time-lock before they can be executed
+// so users can see them coming and leave, and admin can cancel a mistake
+// T is the UpdateConfigMsg of the contract, holding only the changes that wait
+#[cw_serde]
+pub struct PendingConfigChange<T> {
+    pub id: Uint64,
+    pub changes: T,
+    pub proposed_by: Addr,
+    // Block height or time from which the changes can be executed
+    pub executable_at: Expiration,
+}
+
+// Membership issuer fee changes wait 1 day by default so holders have time to react
+pub const DEFAULT_USER_FEE_CHANGE_TIMELOCK: Duration = Duration::Time(86_400);
+
+// Time-lock of 0 blocks or 0 seconds means no time-lock
+pub fn normalize_timelock(timelock: Duration) -> Option<Duration> {
+    match timelock {
+        Duration::Height(0) | Duration::Time(0) => None,
+        timelock => Some(timelock),
+    }
+}
+
+// ========== msg ==========
+
+#[cw_serde]
+pub struct ExecutePendingConfigChangeMsg {
+    pub id: Uint64,
+}
+
+#[cw_serde]
+pub struct CancelPendingConfigChangeMsg {
+    pub id: Uint64,
+}
+
+#[cw_serde]
+pub struct QueryPendingConfigChangesMsg {}
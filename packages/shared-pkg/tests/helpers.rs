@@ -0,0 +1,30 @@
+use cosmwasm_std::{Uint128, Uint64};
+use proptest::prelude::*;
+
+use shared_pkg::pricing::BondingCurve;
+
+pub fn bonding_curve() -> impl Strategy<Value = BondingCurve> {
+    prop_oneof![
+        (1..=1_000_000_u128).prop_map(|divisor| BondingCurve::Quadratic {
+            divisor: Uint128::new(divisor),
+        }),
+        (0..=1_000_000_u128, 0..=1_000_000_u128).prop_map(|(base_price, slope)| {
+            BondingCurve::Linear {
+                base_price: Uint128::new(base_price),
+                slope: Uint128::new(slope),
+            }
+        }),
+        (0..=1_000_000_u128, 1..=500_u64).prop_map(|(base_price, growth_bps)| {
+            BondingCurve::Exponential {
+                base_price: Uint128::new(base_price),
+                growth_bps: Uint64::new(growth_bps),
+            }
+        }),
+        (0..=1_000_000_000_u128, 1..=1_000_u128).prop_map(|(max_price, inflection_supply)| {
+            BondingCurve::Sigmoid {
+                max_price: Uint128::new(max_price),
+                inflection_supply: Uint128::new(inflection_supply),
+            }
+        }),
+    ]
+}
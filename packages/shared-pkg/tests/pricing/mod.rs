@@ -0,0 +1,7 @@
+mod test_calculate_price_at_supply_0;
+mod test_calculate_price_errors_instead_of_overflowing;
+mod test_calculate_price_errors_on_zero_divisor;
+mod test_fee_shares_never_exceed_fee;
+mod test_multiply_basis_points_rounds_down;
+mod test_multiply_bps_errors_instead_of_overflowing;
+mod test_selling_in_parts_never_returns_more_than_buying_whole;
@@ -0,0 +1,63 @@
+use cosmwasm_std::{Uint128, Uint64};
+
+use shared_pkg::pricing::BondingCurve;
+
+#[test]
+fn test_calculate_price_at_supply_0() {
+    let supply = Uint128::zero();
+
+    // Price of nothing is 0 on every curve
+    assert_eq!(
+        BondingCurve::default()
+            .calculate_price(supply, Uint128::zero())
+            .unwrap(),
+        Uint128::zero()
+    );
+
+    // First membership is free on quadratic curve, (0 + 1 + 4) * 1_000_000 / 16_000
+    assert_eq!(
+        BondingCurve::default()
+            .calculate_price(supply, Uint128::one())
+            .unwrap(),
+        Uint128::zero()
+    );
+    assert_eq!(
+        BondingCurve::default()
+            .calculate_price(supply, Uint128::from(3_u8))
+            .unwrap(),
+        Uint128::from(312_u64)
+    );
+
+    // 100 + 110 + 120
+    assert_eq!(
+        BondingCurve::Linear {
+            base_price: Uint128::from(100_u64),
+            slope: Uint128::from(10_u64),
+        }
+        .calculate_price(supply, Uint128::from(3_u8))
+        .unwrap(),
+        Uint128::from(330_u64)
+    );
+
+    // 1_000 + 1_100 + 1_210
+    assert_eq!(
+        BondingCurve::Exponential {
+            base_price: Uint128::from(1_000_u64),
+            growth_bps: Uint64::from(1_000_u64),
+        }
+        .calculate_price(supply, Uint128::from(3_u8))
+        .unwrap(),
+        Uint128::from(3_310_u64)
+    );
+
+    // 0 + 1_000 * 1 / 101 + 1_000 * 4 / 104, each rounded down
+    assert_eq!(
+        BondingCurve::Sigmoid {
+            max_price: Uint128::from(1_000_u64),
+            inflection_supply: Uint128::from(10_u64),
+        }
+        .calculate_price(supply, Uint128::from(3_u8))
+        .unwrap(),
+        Uint128::from(47_u64)
+    );
+}
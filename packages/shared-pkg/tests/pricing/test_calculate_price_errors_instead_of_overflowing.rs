@@ -0,0 +1,29 @@
+use cosmwasm_std::{Uint128, Uint64};
+
+use shared_pkg::pricing::{BondingCurve, PricingError};
+
+#[test]
+fn test_calculate_price_errors_instead_of_overflowing() {
+    // Sum of squares overflows 256 bits
+    let res = BondingCurve::Quadratic {
+        divisor: Uint128::one(),
+    }
+    .calculate_price(Uint128::MAX, Uint128::one());
+    assert!(matches!(res, Err(PricingError::Overflow(_))));
+
+    // Sum fits in 256 bits but price doesn't fit in Uint128
+    let res = BondingCurve::Linear {
+        base_price: Uint128::MAX,
+        slope: Uint128::one(),
+    }
+    .calculate_price(Uint128::zero(), Uint128::from(2_u8));
+    assert!(matches!(res, Err(PricingError::ConversionOverflow(_))));
+
+    // 2^300 doesn't fit in Decimal256
+    let res = BondingCurve::Exponential {
+        base_price: Uint128::one(),
+        growth_bps: Uint64::from(10_000_u64),
+    }
+    .calculate_price(Uint128::from(300_u64), Uint128::one());
+    assert!(matches!(res, Err(PricingError::Overflow(_))));
+}
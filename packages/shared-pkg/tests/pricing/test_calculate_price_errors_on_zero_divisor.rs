@@ -0,0 +1,20 @@
+use cosmwasm_std::{Uint128, Uint64};
+
+use shared_pkg::pricing::{BondingCurve, PricingError};
+
+#[test]
+fn test_calculate_price_errors_on_zero_divisor() {
+    let res = BondingCurve::Quadratic {
+        divisor: Uint128::zero(),
+    }
+    .calculate_price(Uint128::from(10_u8), Uint128::one());
+    assert!(matches!(res, Err(PricingError::DivideByZero(_))));
+
+    // Geometric series divides by growth
+    let res = BondingCurve::Exponential {
+        base_price: Uint128::from(1_000_u64),
+        growth_bps: Uint64::zero(),
+    }
+    .calculate_price(Uint128::from(10_u8), Uint128::one());
+    assert!(matches!(res, Err(PricingError::DivideByZero(_))));
+}
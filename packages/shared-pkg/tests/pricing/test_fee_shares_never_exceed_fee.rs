@@ -0,0 +1,21 @@
+use cosmwasm_std::{Uint128, Uint64};
+use proptest::prelude::*;
+
+use shared_pkg::pricing::{checked_multiply_basis_points, ONE_HUNDRED_PERCENT_IN_BASIS_POINTS};
+
+proptest! {
+    // Fee is split into shares the same way as protocol fee into referral fee
+    #[test]
+    fn test_fee_shares_never_exceed_fee(
+        price in 0..=u64::MAX as u128,
+        fee_bps in 0..=ONE_HUNDRED_PERCENT_IN_BASIS_POINTS,
+        share_bps in 0..=ONE_HUNDRED_PERCENT_IN_BASIS_POINTS,
+    ) {
+        let fee = checked_multiply_basis_points(Uint128::new(price), Uint64::new(fee_bps)).unwrap();
+        let share = checked_multiply_basis_points(fee, Uint64::new(share_bps)).unwrap();
+        let rest = checked_multiply_basis_points(fee, Uint64::new(ONE_HUNDRED_PERCENT_IN_BASIS_POINTS - share_bps)).unwrap();
+
+        prop_assert!(fee <= Uint128::new(price));
+        prop_assert!(share + rest <= fee);
+    }
+}
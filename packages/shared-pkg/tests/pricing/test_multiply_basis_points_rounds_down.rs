@@ -0,0 +1,21 @@
+use cosmwasm_std::{Uint128, Uint256, Uint64};
+use proptest::prelude::*;
+
+use shared_pkg::pricing::{checked_multiply_basis_points, ONE_HUNDRED_PERCENT_IN_BASIS_POINTS};
+
+proptest! {
+    #[test]
+    fn test_multiply_basis_points_rounds_down(
+        amount in any::<u128>(),
+        basis_points in 0..=ONE_HUNDRED_PERCENT_IN_BASIS_POINTS,
+    ) {
+        let result =
+            checked_multiply_basis_points(Uint128::new(amount), Uint64::new(basis_points)).unwrap();
+        let exact = Uint256::from(amount) * Uint256::from(basis_points);
+        let one_hundred_percent = Uint256::from(ONE_HUNDRED_PERCENT_IN_BASIS_POINTS);
+
+        prop_assert!(result <= Uint128::new(amount));
+        prop_assert!(Uint256::from(result) * one_hundred_percent <= exact);
+        prop_assert!(exact < (Uint256::from(result) + Uint256::one()) * one_hundred_percent);
+    }
+}
@@ -0,0 +1,21 @@
+use cosmwasm_std::{Uint128, Uint256, Uint64};
+use proptest::prelude::*;
+
+use shared_pkg::pricing::{checked_multiply_basis_points, ONE_HUNDRED_PERCENT_IN_BASIS_POINTS};
+
+proptest! {
+    #[test]
+    fn test_multiply_bps_errors_instead_of_overflowing(
+        amount in any::<u128>(),
+        basis_points in any::<u64>(),
+    ) {
+        let res = checked_multiply_basis_points(Uint128::new(amount), Uint64::new(basis_points));
+        let exact = Uint256::from(amount) * Uint256::from(basis_points)
+            / Uint256::from(ONE_HUNDRED_PERCENT_IN_BASIS_POINTS);
+
+        match Uint128::try_from(exact) {
+            Ok(expected) => prop_assert_eq!(res.unwrap(), expected),
+            Err(_) => prop_assert!(res.is_err()),
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use cosmwasm_std::Uint128;
+use proptest::prelude::*;
+
+use crate::helpers::bonding_curve;
+
+proptest! {
+    #[test]
+    fn test_selling_in_parts_never_returns_more_than_buying_whole(
+        bonding_curve in bonding_curve(),
+        supply in 1..=200_u128,
+        amount in 1..=100_u128,
+        first_part in 0..=100_u128,
+    ) {
+        let first_part = first_part.min(amount);
+        let buy_price = bonding_curve
+            .calculate_price(Uint128::new(supply), Uint128::new(amount))
+            .unwrap();
+
+        // Sell back from the top, first_part then the rest
+        let sell_price = bonding_curve
+            .calculate_price(Uint128::new(supply + amount - first_part), Uint128::new(first_part))
+            .unwrap()
+            + bonding_curve
+                .calculate_price(Uint128::new(supply), Uint128::new(amount - first_part))
+                .unwrap();
+
+        prop_assert!(sell_price <= buy_price);
+    }
+}
@@ -1,6 +1,7 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Uint128, Uint64};
-use shared_pkg::fee_share_config::FeeShareConfig;
+use cw_utils::Duration;
+use shared_pkg::{fee_range::BpsRange, fee_share_config::FeeShareConfig};
 
 #[cw_serde]
 pub struct ThreadConfig {
@@ -19,21 +20,60 @@ pub struct ThreadConfig {
 #[cw_serde]
 pub struct ProtocolFeeConfig {
     pub start_new_thread_fixed_cost: Uint128,
-    pub ask_in_thread_fee_percentage: Uint64,
+    pub ask_in_thread_fee_bps: Uint64,
     // NOTE: answer has no cost
-    pub reply_in_thread_fee_percentage: Uint64,
+    pub reply_in_thread_fee_bps: Uint64,
+    // Bps of protocol fee that goes to the referrer of the user paying thread fee
+    // If user has no referrer then it stays with protocol fee collector
+    pub referral_fee_bps: Uint64,
 }
 
 #[cw_serde]
 pub struct FeeConfig {
-    // Default Ask me fee in my 1 membership price percentage
-    pub ask_fee_percentage_of_membership: Uint64,
+    // Default Ask me fee in bps of my 1 membership price
+    pub ask_fee_bps_of_membership: Uint64,
     // How much to pay thread creator when someone ask in thread
-    pub ask_fee_to_thread_creator_percentage_of_membership: Uint64,
-    // Default Reply to me in my thread or my msg fee in my 1 membership price percentage
-    pub reply_fee_percentage_of_membership: Uint64,
+    pub ask_fee_to_thread_creator_bps_of_membership: Uint64,
+    // Default Reply to me in my thread or my msg fee in bps of my 1 membership price
+    pub reply_fee_bps_of_membership: Uint64,
     // How much to pay thread creator when someone ask in thread
-    pub reply_fee_to_thread_creator_percentage_of_membership: Uint64,
+    pub reply_fee_to_thread_creator_bps_of_membership: Uint64,
+}
+
+// Ranges membership issuer can set its own fees in, checked when issuer updates its fees
+#[cw_serde]
+#[derive(Default)]
+pub struct UserFeeRanges {
+    pub ask_fee_bps_of_membership: BpsRange,
+    pub ask_fee_to_thread_creator_bps_of_membership: BpsRange,
+    pub reply_fee_bps_of_membership: BpsRange,
+    pub reply_fee_to_thread_creator_bps_of_membership: BpsRange,
+}
+
+// Each operation can be paused independently on top of the contract wide enabled switch
+#[cw_serde]
+#[derive(Default)]
+pub struct PauseConfig {
+    pub start_new_thread_paused: bool,
+    pub ask_in_thread_paused: bool,
+    pub answer_in_thread_paused: bool,
+    pub reply_in_thread_paused: bool,
+}
+
+impl PauseConfig {
+    // Names of all paused operations
+    pub fn paused_operations(&self) -> Vec<String> {
+        [
+            ("start_new_thread", self.start_new_thread_paused),
+            ("ask_in_thread", self.ask_in_thread_paused),
+            ("answer_in_thread", self.answer_in_thread_paused),
+            ("reply_in_thread", self.reply_in_thread_paused),
+        ]
+        .iter()
+        .filter(|(_, paused)| *paused)
+        .map(|(operation, _)| operation.to_string())
+        .collect()
+    }
 }
 
 #[cw_serde]
@@ -43,10 +83,12 @@ pub struct Config {
     // In the future, there could be other contracts on top of membership contract
     // e.g. one that provides off chain thread functions
     pub member_contract_addr: Addr,
-    // Contract admin, able to upgrade contract
+    // Contract admin, able to upgrade contract, always holds admin role
     pub admin_addr: Addr,
     // Enable or disable all user posting thread / ask / reply / answer
     pub enabled: bool,
+    // Pause individual operations, only matters when contract is enabled
+    pub pause_config: PauseConfig,
 
     // Protocol fee collector, collects protocol fee
     pub protocol_fee_collector_addr: Addr,
@@ -58,4 +100,13 @@ pub struct Config {
     pub default_fee_config: FeeConfig,
 
     pub default_fee_share_config: FeeShareConfig,
+
+    // Delay before changes to fee collector, member contract, fees, time-locks and the delay itself take effect
+    // None means config changes take effect right away
+    pub config_change_timelock: Option<Duration>,
+    // Delay before membership issuer's own fee changes take effect, protects holders from sudden fee hikes
+    // None means issuer fee changes take effect right away
+    pub user_fee_change_timelock: Option<Duration>,
+    // Default fees must also be in these ranges
+    pub user_fee_ranges: UserFeeRanges,
 }
@@ -1,10 +1,21 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{Uint128, Uint64};
+use cw_utils::Duration;
+
+use member_pkg::user::Referee;
+use shared_pkg::{
+    admin_transfer::{AcceptAdminMsg, AdminProposal, CancelAdminProposalMsg, ProposeNewAdminMsg},
+    rbac::{GrantRoleMsg, QueryRolesMsg, RevokeRoleMsg, RolesResponse},
+    timelock::{
+        CancelPendingConfigChangeMsg, ExecutePendingConfigChangeMsg, PendingConfigChange,
+        QueryPendingConfigChangesMsg,
+    },
+};
 
 use crate::{
-    config::Config,
+    config::{Config, PauseConfig, UserFeeRanges},
     thread::{Thread, ThreadMsg},
-    user_config::UserConfig,
+    user_config::{PendingUserFeeChange, UserConfig},
 };
 
 // ========== instantiate ==========
@@ -31,34 +42,63 @@ pub struct InstantiateMsg {
 
     // Protocol fee for starting a new thread
     pub protocol_fee_start_new_thread_fixed_cost: Option<Uint128>,
-    // Protocol fee percentage for asking in a thread
-    pub protocol_fee_ask_in_thread_fee_percentage: Option<Uint64>,
-    // Protocol fee percentage for replying in a thread
-    pub protocol_fee_reply_in_thread_fee_percentage: Option<Uint64>,
-
-    // Default ask me fee in my 1 membership price percentage
-    pub default_ask_fee_percentage_of_membership: Option<Uint64>,
+    // Protocol fee in bps for asking in a thread
+    pub protocol_fee_ask_in_thread_fee_bps: Option<Uint64>,
+    // Protocol fee in bps for replying in a thread
+    pub protocol_fee_reply_in_thread_fee_bps: Option<Uint64>,
+    // Bps of protocol fee that goes to user's referrer, default to 0
+    pub protocol_fee_referral_fee_bps: Option<Uint64>,
+
+    // Default ask me fee in bps of my 1 membership price
+    pub default_ask_fee_bps_of_membership: Option<Uint64>,
     // How much to pay thread creator when someone ask in thread
-    pub default_ask_fee_to_thread_creator_percentage_of_membership: Option<Uint64>,
-    // Default reply to me in my thread or my msg fee in my 1 membership price percentage
-    pub default_reply_fee_percentage_of_membership: Option<Uint64>,
+    pub default_ask_fee_to_thread_creator_bps_of_membership: Option<Uint64>,
+    // Default reply to me in my thread or my msg fee in bps of my 1 membership price
+    pub default_reply_fee_bps_of_membership: Option<Uint64>,
     // How much to pay thread creator when someone reply in thread
-    pub default_reply_fee_to_thread_creator_percentage_of_membership: Option<Uint64>,
+    pub default_reply_fee_to_thread_creator_bps_of_membership: Option<Uint64>,
+
+    // Default thread fee to membership issuer fee in bps
+    pub default_share_to_issuer_bps: Option<Uint64>,
+    // Default thread fee to membership holder fee in bps
+    pub default_share_to_all_members_bps: Option<Uint64>,
 
-    // Default thread fee to membership issuer fee percentage
-    pub default_share_to_issuer_percentage: Option<Uint64>,
-    // Default thread fee to membership holder fee percentage
-    pub default_share_to_all_members_percentage: Option<Uint64>,
+    // Delay before membership issuer's fee changes take effect, default to 1 day
+    // Set to 0 blocks or 0 seconds to let issuer fee changes take effect right away
+    pub user_fee_change_timelock: Option<Duration>,
+
+    // Ranges membership issuer can set its own fees in, default to 0% to 100%
+    pub user_fee_ranges: Option<UserFeeRanges>,
 }
 
 // ========== execute ==========
 
 #[cw_serde]
+#[allow(clippy::large_enum_variant)]
 pub enum ExecuteMsg {
     // =================== ADMIN ONLY ===================
     Enable(EnableMsg),
     Disable(DisableMsg),
+    // Fee manager can also update fees and fixed cost
+    // When config change time-lock is set, changes to fee collector, member contract, fees
+    // and the time-lock itself are queued as a pending config change, other changes take effect right away
     UpdateConfig(UpdateConfigMsg),
+    // Fee manager can also execute a pending config change once its time-lock has passed
+    ExecutePendingConfigChange(ExecutePendingConfigChangeMsg),
+    // Fee manager can also cancel a pending config change
+    CancelPendingConfigChange(CancelPendingConfigChangeMsg),
+    // Propose a new admin, replacing any previous proposal
+    ProposeNewAdmin(ProposeNewAdminMsg),
+    // Only the proposed address can accept before the proposal expires
+    AcceptAdmin(AcceptAdminMsg),
+    // Cancel the pending admin proposal
+    CancelAdminProposal(CancelAdminProposalMsg),
+    // Grant a role to an address, a role can be held by multiple addresses
+    GrantRole(GrantRoleMsg),
+    // Revoke a role from an address, admin in config cannot lose its role this way
+    RevokeRole(RevokeRoleMsg),
+    // Pauser can also pause or unpause individual operations
+    UpdatePauseConfig(UpdatePauseConfigMsg),
 
     // ================== MEMBERSHIP ISSUER ONLY ==================
     UpdateUserConfig(UpdateUserConfigMsg),
@@ -91,8 +131,17 @@ pub struct EnableMsg {}
 pub struct DisableMsg {}
 
 #[cw_serde]
+pub struct UpdatePauseConfigMsg {
+    pub start_new_thread_paused: Option<bool>,
+    pub ask_in_thread_paused: Option<bool>,
+    pub answer_in_thread_paused: Option<bool>,
+    pub reply_in_thread_paused: Option<bool>,
+}
+
+// Admin is replaced with ProposeNewAdmin and AcceptAdmin so a typo cannot lock the contract
+#[cw_serde]
+#[derive(Default)]
 pub struct UpdateConfigMsg {
-    pub admin_addr: Option<String>,
     pub protocol_fee_collector_addr: Option<String>,
     pub member_contract_addr: Option<String>,
 
@@ -103,27 +152,37 @@ pub struct UpdateConfigMsg {
     pub max_thread_msg_length: Option<Uint64>,
 
     pub protocol_fee_start_new_thread_fixed_cost: Option<Uint128>,
-    pub protocol_fee_ask_in_thread_fee_percentage: Option<Uint64>,
-    pub protocol_fee_reply_in_thread_fee_percentage: Option<Uint64>,
-
-    pub default_ask_fee_percentage_of_membership: Option<Uint64>,
-    pub default_ask_fee_to_thread_creator_percentage_of_membership: Option<Uint64>,
-    pub default_reply_fee_percentage_of_membership: Option<Uint64>,
-    pub default_reply_fee_to_thread_creator_percentage_of_membership: Option<Uint64>,
-
-    pub default_share_to_issuer_percentage: Option<Uint64>,
-    pub default_share_to_all_members_percentage: Option<Uint64>,
+    pub protocol_fee_ask_in_thread_fee_bps: Option<Uint64>,
+    pub protocol_fee_reply_in_thread_fee_bps: Option<Uint64>,
+    pub protocol_fee_referral_fee_bps: Option<Uint64>,
+
+    pub default_ask_fee_bps_of_membership: Option<Uint64>,
+    pub default_ask_fee_to_thread_creator_bps_of_membership: Option<Uint64>,
+    pub default_reply_fee_bps_of_membership: Option<Uint64>,
+    pub default_reply_fee_to_thread_creator_bps_of_membership: Option<Uint64>,
+
+    pub default_share_to_issuer_bps: Option<Uint64>,
+    pub default_share_to_all_members_bps: Option<Uint64>,
+
+    // Set to 0 blocks or 0 seconds to remove the time-lock
+    pub config_change_timelock: Option<Duration>,
+    // Set to 0 blocks or 0 seconds to let issuer fee changes take effect right away
+    pub user_fee_change_timelock: Option<Duration>,
+    // Only applies to later issuer fee updates, fees issuers already set are kept
+    // Pending issuer fee changes outside the new ranges are dropped instead of taking effect
+    pub user_fee_ranges: Option<UserFeeRanges>,
 }
 
 #[cw_serde]
 pub struct UpdateUserConfigMsg {
     pub user_id: Uint64,
-    pub ask_fee_percentage_of_membership: Option<Uint64>,
-    pub ask_fee_to_thread_creator_percentage_of_membership: Option<Uint64>,
-    pub reply_fee_percentage_of_membership: Option<Uint64>,
-    pub reply_fee_to_thread_creator_percentage_of_membership: Option<Uint64>,
-    pub share_to_issuer_percentage: Option<Uint64>,
-    pub share_to_all_members_percentage: Option<Uint64>,
+    // All fields are fees, they wait for user fee change time-lock in config
+    pub ask_fee_bps_of_membership: Option<Uint64>,
+    pub ask_fee_to_thread_creator_bps_of_membership: Option<Uint64>,
+    pub reply_fee_bps_of_membership: Option<Uint64>,
+    pub reply_fee_to_thread_creator_bps_of_membership: Option<Uint64>,
+    pub share_to_issuer_bps: Option<Uint64>,
+    pub share_to_all_members_bps: Option<Uint64>,
 }
 
 #[cw_serde]
@@ -182,6 +241,11 @@ pub struct ReplyInThreadMsg {
     pub content: String,
 }
 
+// ========== migrate ==========
+
+#[cw_serde]
+pub struct MigrateMsg {}
+
 // ========== query ==========
 
 #[derive(QueryResponses)]
@@ -190,6 +254,22 @@ pub enum QueryMsg {
     #[returns(ConfigResponse)]
     QueryConfig(QueryConfigMsg),
 
+    // Returns which operations are paused
+    #[returns(PauseStatusResponse)]
+    QueryPauseStatus(QueryPauseStatusMsg),
+
+    // Returns roles granted to an address
+    #[returns(RolesResponse)]
+    QueryRoles(QueryRolesMsg),
+
+    // Returns pending admin proposal and config changes waiting for the time-lock
+    #[returns(PendingConfigChangesResponse)]
+    QueryPendingConfigChanges(QueryPendingConfigChangesMsg),
+
+    // Returns ranges membership issuer can set its own fees in
+    #[returns(UserFeeRangesResponse)]
+    QueryUserFeeRanges(QueryUserFeeRangesMsg),
+
     #[returns(UserConfigResponse)]
     QueryUserConfig(QueryUserConfigMsg),
 
@@ -219,6 +299,11 @@ pub enum QueryMsg {
 
     #[returns(ThreadMsgsResponse)]
     QueryThreadMsgsByIDs(QueryThreadMsgsByIDsMsg),
+
+    // Returns referral fee the referrer earned from each referee's thread fees, with pagination
+    // Only referees that have paid thread fees are listed
+    #[returns(RefereesResponse)]
+    QueryReferees(QueryRefereesMsg),
 }
 
 #[cw_serde]
@@ -229,6 +314,34 @@ pub struct ConfigResponse {
     pub config: Config,
 }
 
+#[cw_serde]
+pub struct QueryPauseStatusMsg {}
+
+#[cw_serde]
+pub struct PauseStatusResponse {
+    // When contract is disabled all user facing operations are unavailable regardless of pause config
+    pub enabled: bool,
+    pub pause_config: PauseConfig,
+    // Names of paused operations, e.g. ask_in_thread
+    pub paused_operations: Vec<String>,
+}
+
+#[cw_serde]
+pub struct PendingConfigChangesResponse {
+    pub pending_admin_proposal: Option<AdminProposal>,
+    pub config_change_timelock: Option<Duration>,
+    // Sorted by ID, i.e. in the order they were proposed
+    pub pending_config_changes: Vec<PendingConfigChange<UpdateConfigMsg>>,
+}
+
+#[cw_serde]
+pub struct QueryUserFeeRangesMsg {}
+
+#[cw_serde]
+pub struct UserFeeRangesResponse {
+    pub user_fee_ranges: UserFeeRanges,
+}
+
 #[cw_serde]
 pub struct QueryUserConfigMsg {
     pub user_id: Uint64,
@@ -236,7 +349,10 @@ pub struct QueryUserConfigMsg {
 
 #[cw_serde]
 pub struct UserConfigResponse {
+    // Fee config already reflects pending fee change that has taken effect
     pub user_config: UserConfig,
+    // Issuer fee change that has not taken effect yet
+    pub pending_fee_change: Option<PendingUserFeeChange>,
 }
 
 #[cw_serde]
@@ -247,6 +363,9 @@ pub struct QueryCostToStartNewThreadMsg {
 #[cw_serde]
 pub struct CostToStartNewThreadResponse {
     pub protocol_fee: Uint128,
+    // Fee paid to thread creator's referrer, carved out of protocol fee
+    // Goes to protocol fee collector if thread creator has no referrer
+    pub referral_fee: Uint128,
 }
 
 #[cw_serde]
@@ -265,6 +384,9 @@ pub struct QueryCostToAskInThreadMsg {
 pub struct CostToAskInThreadResponse {
     // Fee paid to protocol
     pub protocol_fee: Uint128,
+    // Fee paid to asker's referrer, carved out of protocol fee
+    // Goes to protocol fee collector if asker has no referrer
+    pub referral_fee: Uint128,
     // Fee paid to answerer membership issuer
     pub ask_to_membership_issuer_fee: Uint128,
     // Fee paid to answerer membership holders
@@ -273,9 +395,11 @@ pub struct CostToAskInThreadResponse {
     pub thread_creator_membership_issuer_fee: Uint128,
     // Fee paid to thread creator membership holders, 0 if asker is the thread creator
     pub thread_creator_membership_all_members_fee: Uint128,
-    // Protocol fee + answer membership issuer fee + answer membership holder fee
+    // Protocol fee + referral fee + answer membership issuer fee + answer membership holder fee
     // + thread creator membership issuer fee + thread creator membership holder fee
     pub total_needed_from_user: Uint128,
+    // Denom of all amounts above, which is the denom of the membership asked / replied to
+    pub fee_denom: String,
 }
 
 #[cw_serde]
@@ -295,6 +419,9 @@ pub struct QueryCostToReplyInThreadMsg {
 pub struct CostToReplyInThreadResponse {
     // Fee paid to protocol
     pub protocol_fee: Uint128,
+    // Fee paid to replier's referrer, carved out of protocol fee
+    // Goes to protocol fee collector if replier has no referrer
+    pub referral_fee: Uint128,
     // Fee paid to membership issuer
     pub reply_to_membership_issuer_fee: Uint128,
     // Fee paid to all membership holders
@@ -303,9 +430,11 @@ pub struct CostToReplyInThreadResponse {
     pub thread_creator_membership_issuer_fee: Uint128,
     // Fee paid to thread creator membership holders, 0 if replier is the thread creator
     pub thread_creator_membership_all_members_fee: Uint128,
-    // Protocol fee + reply to membership issuer fee + reply to membership holder fee
+    // Protocol fee + referral fee + reply to membership issuer fee + reply to membership holder fee
     // + thread creator membership issuer fee + thread creator membership holder fee
     pub total_needed_from_user: Uint128,
+    // Denom of all amounts above, which is the denom of the membership asked / replied to
+    pub fee_denom: String,
 }
 
 #[cw_serde]
@@ -375,3 +504,18 @@ pub struct QueryThreadMsgsByIDsMsg {
 pub struct ThreadMsgsResponse {
     pub thread_msgs: Vec<ThreadMsg>,
 }
+
+#[cw_serde]
+pub struct QueryRefereesMsg {
+    pub referrer_user_id: Uint64,
+    pub start_after_referee_user_id: Option<Uint64>,
+    pub limit: Option<u32>,
+    // Include start_after in the result if true
+    pub include_start_after: Option<bool>,
+}
+
+#[cw_serde]
+pub struct RefereesResponse {
+    pub referees: Vec<Referee>,
+    pub count: usize,
+}
@@ -1,4 +1,6 @@
 use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Uint64;
+use cw_utils::Expiration;
 use shared_pkg::fee_share_config::FeeShareConfig;
 
 use crate::config::FeeConfig;
@@ -7,7 +9,61 @@ use crate::config::FeeConfig;
 // It has access to the thread contract.
 // User struct here is only storing thread specific user config, it can be seen as an extension of the user struct in membership contract
 #[cw_serde]
+#[derive(Default)]
 pub struct UserConfig {
-    pub fee_config: Option<FeeConfig>,
+    pub fee_config: UserFeeConfig,
     pub fee_share_config: Option<FeeShareConfig>,
 }
+
+// Fees membership issuer set for itself, fees left None follow default fee config in config
+// So a later change of the default still applies to fees issuer never set
+#[cw_serde]
+#[derive(Default)]
+pub struct UserFeeConfig {
+    pub ask_fee_bps_of_membership: Option<Uint64>,
+    pub ask_fee_to_thread_creator_bps_of_membership: Option<Uint64>,
+    pub reply_fee_bps_of_membership: Option<Uint64>,
+    pub reply_fee_to_thread_creator_bps_of_membership: Option<Uint64>,
+}
+
+impl UserFeeConfig {
+    // Default fee config with fees issuer set on top
+    pub fn resolve(&self, default_fee_config: &FeeConfig) -> FeeConfig {
+        FeeConfig {
+            ask_fee_bps_of_membership: self
+                .ask_fee_bps_of_membership
+                .unwrap_or(default_fee_config.ask_fee_bps_of_membership),
+            ask_fee_to_thread_creator_bps_of_membership: self
+                .ask_fee_to_thread_creator_bps_of_membership
+                .unwrap_or(default_fee_config.ask_fee_to_thread_creator_bps_of_membership),
+            reply_fee_bps_of_membership: self
+                .reply_fee_bps_of_membership
+                .unwrap_or(default_fee_config.reply_fee_bps_of_membership),
+            reply_fee_to_thread_creator_bps_of_membership: self
+                .reply_fee_to_thread_creator_bps_of_membership
+                .unwrap_or(default_fee_config.reply_fee_to_thread_creator_bps_of_membership),
+        }
+    }
+}
+
+// Fee change issuer made while user fee change time-lock is set in config
+// Askers and repliers can see it coming before it takes effect at effective_at
+#[cw_serde]
+pub struct PendingUserFeeChange {
+    pub ask_fee_bps_of_membership: Option<Uint64>,
+    pub ask_fee_to_thread_creator_bps_of_membership: Option<Uint64>,
+    pub reply_fee_bps_of_membership: Option<Uint64>,
+    pub reply_fee_to_thread_creator_bps_of_membership: Option<Uint64>,
+    pub fee_share_config: Option<FeeShareConfig>,
+    pub effective_at: Expiration,
+}
+
+impl PendingUserFeeChange {
+    pub fn is_empty(&self) -> bool {
+        self.ask_fee_bps_of_membership.is_none()
+            && self.ask_fee_to_thread_creator_bps_of_membership.is_none()
+            && self.reply_fee_bps_of_membership.is_none()
+            && self.reply_fee_to_thread_creator_bps_of_membership.is_none()
+            && self.fee_share_config.is_none()
+    }
+}
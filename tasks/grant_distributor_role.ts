@@ -0,0 +1,37 @@
+import { MsgExecuteContract } from "@terra-money/feather.js";
+import {
+  createSignBroadcastCatch,
+  getLCD,
+  getMnemonicKey,
+  getWallet,
+} from "./util";
+import {
+  CHAIN_PREFIX,
+  DISTRIBUTION_CONTRACT_ADDR,
+  THREAD_CONTRACT_ADDR,
+} from "./env";
+
+const lcd = getLCD();
+
+const mnemonicKey1 = getMnemonicKey();
+const wallet = getWallet(lcd, mnemonicKey1);
+const myAddress = wallet.key.accAddress(CHAIN_PREFIX);
+
+const grantDistributorRole = async () => {
+  // Member contract gets distributor role when distribution contract is instantiated
+  const distributionContractGrantRoleMsg = {
+    grant_role: {
+      addr: THREAD_CONTRACT_ADDR!,
+      role: "distributor",
+    },
+  };
+  const grantRoleMsg = new MsgExecuteContract(
+    myAddress,
+    DISTRIBUTION_CONTRACT_ADDR!,
+    distributionContractGrantRoleMsg
+  );
+
+  createSignBroadcastCatch(wallet, [grantRoleMsg], true);
+};
+
+grantDistributorRole();